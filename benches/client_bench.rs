@@ -20,7 +20,7 @@ use std::hint::black_box;
 
 fn customer_serialization(c: &mut Criterion) {
     let customer = Customer {
-        id: Some("cust-12345".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000012345".parse().unwrap()),
         customer_number: Some("C001".to_string()),
         name: Some("Acme Corporation AB".to_string()),
         email: Some("contact@acme.se".to_string()),
@@ -38,7 +38,7 @@ fn customer_serialization(c: &mut Criterion) {
 
 fn customer_deserialization(c: &mut Criterion) {
     let json = r#"{
-        "Id": "cust-12345",
+        "Id": "c0000000-0000-4000-8000-000000012345",
         "CustomerNumber": "C001",
         "Name": "Acme Corporation AB",
         "Email": "contact@acme.se",
@@ -57,15 +57,15 @@ fn customer_deserialization(c: &mut Criterion) {
 
 fn invoice_with_rows_serialization(c: &mut Criterion) {
     let invoice = Invoice {
-        id: Some("inv-12345".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000012345".parse().unwrap()),
         invoice_number: Some("1001".to_string()),
-        customer_id: Some("cust-12345".to_string()),
+        customer_id: Some("c0000000-0000-4000-8000-000000012345".parse().unwrap()),
         total_amount: Some(money!(10000.00)),
         total_vat_amount: Some(money!(2500.00)),
         total_amount_including_vat: Some(money!(12500.00)),
         rows: vec![InvoiceRow {
-            id: Some("row-0".to_string()),
-            article_id: Some("art-0".to_string()),
+            id: Some("e0000000-0000-4000-8000-000000000000".parse().unwrap()),
+            article_id: Some("a0000000-0000-4000-8000-000000000000".parse().unwrap()),
             text: Some("Product description".to_string()),
             unit_price: Some(money!(100.0)),
             quantity: Some(money!(2.0)),
@@ -83,23 +83,23 @@ fn invoice_with_rows_serialization(c: &mut Criterion) {
 
 fn invoice_with_rows_deserialization(c: &mut Criterion) {
     let json = r#"{
-        "Id": "inv-12345",
+        "Id": "f0000000-0000-4000-8000-000000012345",
         "InvoiceNumber": "1001",
-        "CustomerId": "cust-12345",
+        "CustomerId": "c0000000-0000-4000-8000-000000012345",
         "TotalAmount": 10000.00,
         "TotalVatAmount": 2500.00,
         "TotalAmountIncludingVat": 12500.00,
         "Rows": [
-            {"Id": "row-0", "ArticleId": "art-0", "Text": "Product 0", "UnitPrice": 100.00, "Quantity": 2.0, "TotalAmount": 200.00},
-            {"Id": "row-1", "ArticleId": "art-1", "Text": "Product 1", "UnitPrice": 200.00, "Quantity": 2.0, "TotalAmount": 400.00},
-            {"Id": "row-2", "ArticleId": "art-2", "Text": "Product 2", "UnitPrice": 300.00, "Quantity": 2.0, "TotalAmount": 600.00},
-            {"Id": "row-3", "ArticleId": "art-3", "Text": "Product 3", "UnitPrice": 400.00, "Quantity": 2.0, "TotalAmount": 800.00},
-            {"Id": "row-4", "ArticleId": "art-4", "Text": "Product 4", "UnitPrice": 500.00, "Quantity": 2.0, "TotalAmount": 1000.00},
-            {"Id": "row-5", "ArticleId": "art-5", "Text": "Product 5", "UnitPrice": 600.00, "Quantity": 2.0, "TotalAmount": 1200.00},
-            {"Id": "row-6", "ArticleId": "art-6", "Text": "Product 6", "UnitPrice": 700.00, "Quantity": 2.0, "TotalAmount": 1400.00},
-            {"Id": "row-7", "ArticleId": "art-7", "Text": "Product 7", "UnitPrice": 800.00, "Quantity": 2.0, "TotalAmount": 1600.00},
-            {"Id": "row-8", "ArticleId": "art-8", "Text": "Product 8", "UnitPrice": 900.00, "Quantity": 2.0, "TotalAmount": 1800.00},
-            {"Id": "row-9", "ArticleId": "art-9", "Text": "Product 9", "UnitPrice": 1000.00, "Quantity": 2.0, "TotalAmount": 2000.00}
+            {"Id": "e0000000-0000-4000-8000-000000000000", "ArticleId": "a0000000-0000-4000-8000-000000000000", "Text": "Product 0", "UnitPrice": 100.00, "Quantity": 2.0, "TotalAmount": 200.00},
+            {"Id": "e0000000-0000-4000-8000-000000000001", "ArticleId": "a0000000-0000-4000-8000-000000000001", "Text": "Product 1", "UnitPrice": 200.00, "Quantity": 2.0, "TotalAmount": 400.00},
+            {"Id": "e0000000-0000-4000-8000-000000000002", "ArticleId": "a0000000-0000-4000-8000-000000000002", "Text": "Product 2", "UnitPrice": 300.00, "Quantity": 2.0, "TotalAmount": 600.00},
+            {"Id": "e0000000-0000-4000-8000-000000000003", "ArticleId": "a0000000-0000-4000-8000-000000000003", "Text": "Product 3", "UnitPrice": 400.00, "Quantity": 2.0, "TotalAmount": 800.00},
+            {"Id": "e0000000-0000-4000-8000-000000000004", "ArticleId": "a0000000-0000-4000-8000-000000000004", "Text": "Product 4", "UnitPrice": 500.00, "Quantity": 2.0, "TotalAmount": 1000.00},
+            {"Id": "e0000000-0000-4000-8000-000000000005", "ArticleId": "a0000000-0000-4000-8000-000000000005", "Text": "Product 5", "UnitPrice": 600.00, "Quantity": 2.0, "TotalAmount": 1200.00},
+            {"Id": "e0000000-0000-4000-8000-000000000006", "ArticleId": "a0000000-0000-4000-8000-000000000006", "Text": "Product 6", "UnitPrice": 700.00, "Quantity": 2.0, "TotalAmount": 1400.00},
+            {"Id": "e0000000-0000-4000-8000-000000000007", "ArticleId": "a0000000-0000-4000-8000-000000000007", "Text": "Product 7", "UnitPrice": 800.00, "Quantity": 2.0, "TotalAmount": 1600.00},
+            {"Id": "e0000000-0000-4000-8000-000000000008", "ArticleId": "a0000000-0000-4000-8000-000000000008", "Text": "Product 8", "UnitPrice": 900.00, "Quantity": 2.0, "TotalAmount": 1800.00},
+            {"Id": "e0000000-0000-4000-8000-000000000009", "ArticleId": "a0000000-0000-4000-8000-000000000009", "Text": "Product 9", "UnitPrice": 1000.00, "Quantity": 2.0, "TotalAmount": 2000.00}
         ]
     }"#;
 
@@ -119,7 +119,7 @@ fn paginated_response_deserialization(c: &mut Criterion) {
         let customers: Vec<String> = (0..*size)
             .map(|i| {
                 format!(
-                    r#"{{"Id": "cust-{}", "CustomerNumber": "C{:04}", "Name": "Customer {}", "Email": "cust{}@example.com", "IsActive": true}}"#,
+                    r#"{{"Id": "c0000000-0000-4000-8000-{:012}", "CustomerNumber": "C{:04}", "Name": "Customer {}", "Email": "cust{}@example.com", "IsActive": true}}"#,
                     i, i, i, i
                 )
             })
@@ -155,14 +155,14 @@ fn paginated_response_deserialization(c: &mut Criterion) {
 
 fn article_serialization(c: &mut Criterion) {
     let article = Article {
-        id: Some("art-12345".to_string()),
+        id: Some("a0000000-0000-4000-8000-000000012345".parse().unwrap()),
         article_number: Some("ART001".to_string()),
         name: Some("Premium Widget".to_string()),
         unit: Some("pcs".to_string()),
         sales_price: Some(money!(299.99)),
         purchase_price: Some(money!(149.99)),
         is_active: Some(true),
-        vat_rate_id: Some("vat-25".to_string()),
+        vat_rate_id: Some("fa000000-0000-4000-8000-000000000025".parse().unwrap()),
         ..Default::default()
     };
 
@@ -173,14 +173,14 @@ fn article_serialization(c: &mut Criterion) {
 
 fn article_deserialization(c: &mut Criterion) {
     let json = r#"{
-        "Id": "art-12345",
+        "Id": "a0000000-0000-4000-8000-000000012345",
         "ArticleNumber": "ART001",
         "Name": "Premium Widget",
         "Unit": "pcs",
         "SalesPrice": 299.99,
         "PurchasePrice": 149.99,
         "IsActive": true,
-        "VatRateId": "vat-25",
+        "VatRateId": "fa000000-0000-4000-8000-000000000025",
         "CreatedUtc": "2024-01-15T10:30:00Z",
         "ModifiedUtc": "2024-01-16T14:45:30Z"
     }"#;
@@ -6,7 +6,7 @@
 //! ```
 
 use chrono::Utc;
-use spiris::{money, AccessToken, Client, Invoice, InvoiceRow};
+use spiris::{money, AccessToken, Client, CustomerId, Invoice, InvoiceRow};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,8 +15,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .expect("Please set SPIRIS_ACCESS_TOKEN environment variable");
 
     // You also need a customer ID to create an invoice
-    let customer_id = std::env::var("SPIRIS_CUSTOMER_ID")
-        .expect("Please set SPIRIS_CUSTOMER_ID environment variable");
+    let customer_id: CustomerId = std::env::var("SPIRIS_CUSTOMER_ID")
+        .expect("Please set SPIRIS_CUSTOMER_ID environment variable")
+        .parse()?;
 
     // Create an access token
     let token = AccessToken::new(access_token, 3600, None);
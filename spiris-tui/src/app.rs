@@ -4,7 +4,13 @@
 //! for handling user input, navigating screens, and managing data.
 
 use anyhow::Result;
-use spiris::{AccessToken, Article, Client, Customer, Invoice, InvoiceRow, PaginationParams};
+use spiris::locale::{Language, Localize};
+use spiris::validate::{Validate, ValidationMessage};
+use spiris::{
+    AccessToken, Article, ArticleCreate, ArticleId, Client, Customer, CustomerCreate, CustomerId,
+    Invoice, InvoiceCreate, InvoiceId, InvoiceRow, InvoiceRowCreate, PaginationParams,
+    ValidationError,
+};
 use std::path::PathBuf;
 use crate::config::Config;
 
@@ -15,16 +21,16 @@ pub enum Screen {
     Auth,
     Customers,
     CustomerCreate,
-    CustomerEdit(String),
-    CustomerDetail(String),
+    CustomerEdit(CustomerId),
+    CustomerDetail(CustomerId),
     Invoices,
     InvoiceCreate,
-    InvoiceEdit(String),
-    InvoiceDetail(String),
+    InvoiceEdit(InvoiceId),
+    InvoiceDetail(InvoiceId),
     Articles,
     ArticleCreate,
-    ArticleEdit(String),
-    ArticleDetail(String),
+    ArticleEdit(ArticleId),
+    ArticleDetail(ArticleId),
     Search,
     Export,
     Help,
@@ -513,13 +519,13 @@ impl App {
                     // Delete key - show confirmation dialog
                     match &self.screen {
                         Screen::CustomerDetail(ref id) => {
-                            self.confirm_delete = Some(("customer".to_string(), id.clone()));
+                            self.confirm_delete = Some(("customer".to_string(), id.to_string()));
                         }
                         Screen::InvoiceDetail(ref id) => {
-                            self.confirm_delete = Some(("invoice".to_string(), id.clone()));
+                            self.confirm_delete = Some(("invoice".to_string(), id.to_string()));
                         }
                         Screen::ArticleDetail(ref id) => {
-                            self.confirm_delete = Some(("article".to_string(), id.clone()));
+                            self.confirm_delete = Some(("article".to_string(), id.to_string()));
                         }
                         _ => {}
                     }
@@ -766,7 +772,13 @@ impl App {
         // Pre-populate form data with existing invoice data
         if let Screen::InvoiceEdit(ref id) = self.screen {
             if let Some(invoice) = self.invoices.iter().find(|i| i.id.as_deref() == Some(id)) {
-                self.form_data.push(invoice.customer_id.clone().unwrap_or_default());
+                self.form_data.push(
+                    invoice
+                        .customer_id
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                );
                 self.form_data.push(invoice.remarks.clone().unwrap_or_default());
                 // Calculate amount from total
                 let amount = invoice.total_amount.unwrap_or(0.0);
@@ -776,121 +788,89 @@ impl App {
         }
     }
 
-    fn validate_email(email: &str) -> bool {
-        // Simple email validation: must have @ with text before and after,
-        // and a '.' after the @
-        if email.len() < 5 {
-            return false;
-        }
-        if let Some(at_pos) = email.find('@') {
-            // Must have at least one char before @
-            if at_pos == 0 {
-                return false;
-            }
-            // Must have at least 3 chars after @ (e.g., "a.b")
-            let after_at = &email[at_pos + 1..];
-            if after_at.len() < 3 {
-                return false;
-            }
-            // Must have a '.' in the domain part
-            if !after_at.contains('.') {
-                return false;
-            }
-            // Domain can't start with '.'
-            if after_at.starts_with('.') {
-                return false;
-            }
-            return true;
-        }
-        false
-    }
-
-    fn validate_number(s: &str) -> bool {
-        s.parse::<f64>().is_ok() && s.parse::<f64>().unwrap_or(0.0) >= 0.0
-    }
-
+    /// Check the value being entered with the request's `Validate` rules and
+    /// show the errors for the current field, localized like API errors.
     fn validate_current_input(&mut self) -> bool {
-        self.validation_error = None;
-
-        match &self.screen {
-            Screen::CustomerCreate | Screen::CustomerEdit(_) => {
-                match self.input_field {
-                    0 => {
-                        // Name validation
-                        if self.input.trim().is_empty() {
-                            self.validation_error = Some("Name cannot be empty".to_string());
-                            return false;
-                        }
-                    }
-                    1 => {
-                        // Email validation
-                        if !Self::validate_email(&self.input) {
-                            self.validation_error = Some("Invalid email format".to_string());
-                            return false;
-                        }
-                    }
-                    2 => {
-                        // Phone validation (optional but if provided should not be empty)
-                        if self.input.trim().is_empty() {
-                            self.validation_error = Some("Phone cannot be empty".to_string());
-                            return false;
-                        }
+        let errors = {
+            let mut values: Vec<&str> = self
+                .form_data
+                .iter()
+                .take(self.input_field)
+                .map(String::as_str)
+                .collect();
+            values.push(&self.input);
+            let value = |index: usize| values.get(index).copied().unwrap_or_default();
+
+            let (fields, errors): (&[&str], Vec<ValidationError>) = match &self.screen {
+                Screen::CustomerCreate | Screen::CustomerEdit(_) => {
+                    let customer = CustomerCreate::new(value(0).to_string())
+                        .email(value(1).to_string())
+                        .phone(value(2).to_string())
+                        .website(value(3).to_string());
+                    let mut errors = customer.validate().err().unwrap_or_default();
+                    // The form asks for a phone number although the API does not.
+                    if value(2).trim().is_empty() {
+                        errors.push(field_error("Phone", ValidationMessage::Required));
                     }
-                    3 => {
-                        // Website is optional, no validation
+                    (&["Name", "Email", "Phone", "Website"], errors)
+                }
+                Screen::ArticleCreate | Screen::ArticleEdit(_) => {
+                    let mut article = ArticleCreate::new(value(0).to_string());
+                    let mut errors = Vec::new();
+                    match parse_amount("SalesPrice", value(1)) {
+                        Ok(price) => article = article.sales_price(price),
+                        Err(error) => errors.push(error),
                     }
-                    _ => {}
+                    errors.extend(article.validate().err().unwrap_or_default());
+                    (&["Name", "SalesPrice"], errors)
                 }
-            }
-            Screen::ArticleCreate | Screen::ArticleEdit(_) => {
-                match self.input_field {
-                    0 => {
-                        // Name validation
-                        if self.input.trim().is_empty() {
-                            self.validation_error = Some("Article name cannot be empty".to_string());
-                            return false;
+                Screen::InvoiceCreate | Screen::InvoiceEdit(_) => {
+                    let mut errors = Vec::new();
+                    let amount = match parse_amount("Rows[0].UnitPrice", value(2)) {
+                        Ok(amount) if amount < 0.0 => {
+                            errors.push(field_error(
+                                "Rows[0].UnitPrice",
+                                ValidationMessage::Negative,
+                            ));
+                            amount
                         }
-                    }
-                    1 => {
-                        // Price validation
-                        if !Self::validate_number(&self.input) {
-                            self.validation_error = Some("Price must be a valid positive number".to_string());
-                            return false;
+                        Ok(amount) => amount,
+                        Err(error) => {
+                            errors.push(error);
+                            0.0
                         }
-                    }
-                    _ => {}
-                }
-            }
-            Screen::InvoiceCreate | Screen::InvoiceEdit(_) => {
-                match self.input_field {
-                    0 => {
-                        // Customer ID validation
-                        if self.input.trim().is_empty() {
-                            self.validation_error = Some("Customer ID cannot be empty".to_string());
-                            return false;
+                    };
+                    match value(0).trim().parse::<CustomerId>() {
+                        Ok(customer_id) => {
+                            let row = InvoiceRowCreate::with_text(value(1).to_string(), amount, 1.0);
+                            let invoice = InvoiceCreate::new(customer_id).row(row);
+                            errors.extend(invoice.validate().err().unwrap_or_default());
                         }
-                    }
-                    1 => {
-                        // Description validation
-                        if self.input.trim().is_empty() {
-                            self.validation_error = Some("Description cannot be empty".to_string());
-                            return false;
+                        Err(_) if value(0).trim().is_empty() => {
+                            errors.push(field_error("CustomerId", ValidationMessage::Required));
                         }
-                    }
-                    2 => {
-                        // Amount validation
-                        if !Self::validate_number(&self.input) {
-                            self.validation_error = Some("Amount must be a valid positive number".to_string());
-                            return false;
+                        Err(_) => {
+                            errors.push(field_error("CustomerId", ValidationMessage::InvalidFormat));
                         }
                     }
-                    _ => {}
+                    (&["CustomerId", "Rows[0].Text", "Rows[0].UnitPrice"], errors)
                 }
-            }
-            _ => {}
-        }
+                _ => (&[], Vec::new()),
+            };
 
-        true
+            let field = fields.get(self.input_field).copied().unwrap_or_default();
+            errors
+                .into_iter()
+                .filter(|error| error.field == field)
+                .collect::<Vec<_>>()
+        };
+
+        if errors.is_empty() {
+            self.validation_error = None;
+            return true;
+        }
+        self.validation_error = Some(spiris::Error::Validation(errors).localize(self.language()));
+        false
     }
 
     fn should_submit_form(&self) -> bool {
@@ -926,7 +906,7 @@ impl App {
                             self.load_customers().await?;
                         }
                         Err(e) => {
-                            self.set_error(format!("Failed to create customer: {}", e));
+                            self.set_error("Kunde inte skapa kunden", "Failed to create customer", &e);
                         }
                     }
                 }
@@ -952,7 +932,7 @@ impl App {
                             self.load_customers().await?;
                         }
                         Err(e) => {
-                            self.set_error(format!("Failed to update customer: {}", e));
+                            self.set_error("Kunde inte uppdatera kunden", "Failed to update customer", &e);
                         }
                     }
                 }
@@ -972,7 +952,7 @@ impl App {
                             self.load_articles().await?;
                         }
                         Err(e) => {
-                            self.set_error(format!("Failed to create article: {}", e));
+                            self.set_error("Kunde inte skapa artikeln", "Failed to create article", &e);
                         }
                     }
                 }
@@ -993,15 +973,22 @@ impl App {
                             self.load_articles().await?;
                         }
                         Err(e) => {
-                            self.set_error(format!("Failed to update article: {}", e));
+                            self.set_error("Kunde inte uppdatera artikeln", "Failed to update article", &e);
                         }
                     }
                 }
                 Screen::InvoiceCreate => {
                     if self.form_data.len() >= 3 {
+                        let customer_id: CustomerId = match self.form_data[0].parse() {
+                            Ok(customer_id) => customer_id,
+                            Err(e) => {
+                                self.set_error("Kunde inte skapa fakturan", "Failed to create invoice", &e);
+                                return Ok(());
+                            }
+                        };
                         let amount: f64 = self.form_data[2].parse().unwrap_or(0.0);
                         let invoice = Invoice {
-                            customer_id: Some(self.form_data[0].clone()),
+                            customer_id: Some(customer_id),
                             remarks: Some(self.form_data[1].clone()),
                             rows: vec![InvoiceRow {
                                 text: Some(self.form_data[1].clone()),
@@ -1019,17 +1006,24 @@ impl App {
                                 self.load_invoices().await?;
                             }
                             Err(e) => {
-                                self.set_error(format!("Failed to create invoice: {}", e));
+                                self.set_error("Kunde inte skapa fakturan", "Failed to create invoice", &e);
                             }
                         }
                     }
                 }
                 Screen::InvoiceEdit(id) => {
                     if self.form_data.len() >= 3 {
+                        let customer_id: CustomerId = match self.form_data[0].parse() {
+                            Ok(customer_id) => customer_id,
+                            Err(e) => {
+                                self.set_error("Kunde inte uppdatera fakturan", "Failed to update invoice", &e);
+                                return Ok(());
+                            }
+                        };
                         let amount: f64 = self.form_data[2].parse().unwrap_or(0.0);
                         let invoice = Invoice {
                             id: Some(id.clone()),
-                            customer_id: Some(self.form_data[0].clone()),
+                            customer_id: Some(customer_id),
                             remarks: Some(self.form_data[1].clone()),
                             rows: vec![InvoiceRow {
                                 text: Some(self.form_data[1].clone()),
@@ -1047,7 +1041,7 @@ impl App {
                                 self.load_invoices().await?;
                             }
                             Err(e) => {
-                                self.set_error(format!("Failed to update invoice: {}", e));
+                                self.set_error("Kunde inte uppdatera fakturan", "Failed to update invoice", &e);
                             }
                         }
                     }
@@ -1237,7 +1231,7 @@ impl App {
                     self.error_message = None;
                 }
                 Err(e) => {
-                    self.set_error(format!("Failed to load customers: {}", e));
+                    self.set_error("Kunde inte hämta kunder", "Failed to load customers", &e);
                     self.loading = false;
                 }
             }
@@ -1260,7 +1254,7 @@ impl App {
                     self.error_message = None;
                 }
                 Err(e) => {
-                    self.set_error(format!("Failed to load invoices: {}", e));
+                    self.set_error("Kunde inte hämta fakturor", "Failed to load invoices", &e);
                     self.loading = false;
                 }
             }
@@ -1284,7 +1278,7 @@ impl App {
                     self.error_message = None;
                 }
                 Err(e) => {
-                    self.set_error(format!("Failed to load articles: {}", e));
+                    self.set_error("Kunde inte hämta artiklar", "Failed to load articles", &e);
                     self.loading = false;
                 }
             }
@@ -1543,7 +1537,10 @@ impl App {
             };
             self.set_status(format!("Exported to {}: {}", format_name, messages.join(", ")));
         } else {
-            self.set_error("No data to export".to_string());
+            let message = self
+                .language()
+                .pick("Det finns inget att exportera", "No data to export");
+            self.set_error_message(message.to_string());
         }
 
         Ok(())
@@ -1636,23 +1633,42 @@ impl App {
         self.message_timer = 30; // 3 seconds at 10 ticks per second
     }
 
-    fn set_error(&mut self, message: String) {
+    /// The configured language for messages.
+    fn language(&self) -> Language {
+        self.config
+            .display
+            .language
+            .parse::<Language>()
+            .unwrap_or(Language::English)
+    }
+
+    /// Show an error with a context in the configured language.
+    fn set_error(&mut self, sv: &str, en: &str, error: &spiris::Error) {
+        let language = self.language();
+        let context = language.pick(sv, en);
+        self.set_error_message(format!("{}: {}", context, error.localize(language)));
+    }
+
+    fn set_error_message(&mut self, message: String) {
         self.error_message = Some(message);
         self.message_timer = 50; // 5 seconds at 10 ticks per second
     }
 
     fn execute_delete(&mut self) {
         if let Some((entity_type, id)) = self.confirm_delete.take() {
-            match entity_type.as_str() {
-                "customer" => self.delete_customer(id),
-                "invoice" => self.delete_invoice(id),
-                "article" => self.delete_article(id),
-                _ => {}
+            let result = match entity_type.as_str() {
+                "customer" => id.parse().map(|id| self.delete_customer(id)),
+                "invoice" => id.parse().map(|id| self.delete_invoice(id)),
+                "article" => id.parse().map(|id| self.delete_article(id)),
+                _ => Ok(()),
+            };
+            if let Err(e) = result {
+                self.set_error("Kunde inte ta bort posten", "Failed to delete item", &e);
             }
         }
     }
 
-    fn delete_customer(&mut self, id: String) {
+    fn delete_customer(&mut self, id: CustomerId) {
         if let Some(client) = &self.client {
             let client = client.clone();
             let id_clone = id.clone();
@@ -1669,7 +1685,7 @@ impl App {
         }
     }
 
-    fn delete_invoice(&mut self, id: String) {
+    fn delete_invoice(&mut self, id: InvoiceId) {
         if let Some(client) = &self.client {
             let client = client.clone();
             let id_clone = id.clone();
@@ -1686,7 +1702,7 @@ impl App {
         }
     }
 
-    fn delete_article(&mut self, id: String) {
+    fn delete_article(&mut self, id: ArticleId) {
         if let Some(client) = &self.client {
             let client = client.clone();
             let id_clone = id.clone();
@@ -1808,6 +1824,22 @@ impl App {
     }
 }
 
+/// A form error on `field`, in the shape `Validate` reports.
+fn field_error(field: &str, message: ValidationMessage) -> ValidationError {
+    ValidationError {
+        field: field.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Parse an amount typed into a form.
+fn parse_amount(field: &str, input: &str) -> std::result::Result<f64, ValidationError> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| field_error(field, ValidationMessage::InvalidFormat))
+}
+
 impl Clone for App {
     fn clone(&self) -> Self {
         Self {
@@ -1905,25 +1937,6 @@ mod tests {
         assert_eq!(app.search_mode, SearchMode::All);
     }
 
-    #[test]
-    fn test_validation_email() {
-        assert!(App::validate_email("test@example.com"));
-        assert!(App::validate_email("user@domain.co.uk"));
-        assert!(!App::validate_email("invalid"));
-        assert!(!App::validate_email("no@domain"));
-        assert!(!App::validate_email("@missing.com"));
-    }
-
-    #[test]
-    fn test_validation_number() {
-        assert!(App::validate_number("123.45"));
-        assert!(App::validate_number("0"));
-        assert!(App::validate_number("100"));
-        assert!(!App::validate_number("-10"));
-        assert!(!App::validate_number("abc"));
-        assert!(!App::validate_number("12.34.56"));
-    }
-
     #[test]
     fn test_handle_char_in_normal_mode() {
         let mut app = App::new();
@@ -1988,6 +2001,75 @@ mod tests {
         assert!(app.status_message.is_none());
     }
 
+    #[test]
+    fn test_set_error_is_localized() {
+        let mut app = App::new();
+        let error = spiris::Error::from_api_response(404, String::new());
+
+        app.config.display.language = "sv".to_string();
+        app.set_error("Kunde inte hämta kunder", "Failed to load customers", &error);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Kunde inte hämta kunder: Posten hittades inte.")
+        );
+
+        app.config.display.language = "en".to_string();
+        app.set_error("Kunde inte hämta kunder", "Failed to load customers", &error);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Failed to load customers: The item was not found.")
+        );
+    }
+
+    #[test]
+    fn test_validate_current_input_is_localized() {
+        let mut app = App::new();
+        app.config.display.language = "sv".to_string();
+        app.screen = Screen::ArticleCreate;
+        app.form_data.push("Konsulttimme".to_string());
+        app.input_field = 1;
+
+        app.input = "-5".to_string();
+        assert!(!app.validate_current_input());
+        assert_eq!(
+            app.validation_error.as_deref(),
+            Some("Kontrollera uppgifterna: Försäljningspris: Får inte vara negativt")
+        );
+
+        app.input = "abc".to_string();
+        assert!(!app.validate_current_input());
+        assert_eq!(
+            app.validation_error.as_deref(),
+            Some("Kontrollera uppgifterna: Försäljningspris: Ogiltigt format")
+        );
+
+        app.input = "950".to_string();
+        assert!(app.validate_current_input());
+        assert!(app.validation_error.is_none());
+
+        app.config.display.language = "en".to_string();
+        app.screen = Screen::CustomerCreate;
+        app.form_data = vec!["Acme AB".to_string()];
+        app.input = "info@acme".to_string();
+        assert!(!app.validate_current_input());
+        assert_eq!(
+            app.validation_error.as_deref(),
+            Some("Please check: Email address: Invalid email address")
+        );
+    }
+
+    #[test]
+    fn test_execute_delete_reports_invalid_id() {
+        let mut app = App::new();
+        app.config.display.language = "sv".to_string();
+        app.confirm_delete = Some(("customer".to_string(), "not-a-guid".to_string()));
+
+        app.execute_delete();
+        assert!(app.confirm_delete.is_none());
+        let message = app.error_message.as_deref().unwrap();
+        assert!(message.starts_with("Kunde inte ta bort posten: Ogiltig begäran"));
+    }
+
     #[test]
     fn test_sort_order_cycle() {
         let mut app = App::new();
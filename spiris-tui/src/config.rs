@@ -40,6 +40,10 @@ pub struct DisplayConfig {
     /// Auto-refresh interval in seconds (0 = disabled)
     #[serde(default)]
     pub auto_refresh_interval: u64,
+
+    /// Language for error messages ("sv" or "en")
+    #[serde(default = "default_language")]
+    pub language: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +92,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_language() -> String {
+    "en".to_string()
+}
+
 fn default_page_size() -> u32 {
     50
 }
@@ -122,6 +130,7 @@ impl Default for DisplayConfig {
             show_line_numbers: true,
             show_keyboard_hints: true,
             auto_refresh_interval: 0,
+            language: default_language(),
         }
     }
 }
@@ -0,0 +1,654 @@
+//! Currency-aware amounts for reports.
+//!
+//! The API stores amounts as bare numbers, with the currency in a separate
+//! field such as [`Invoice::currency_code`](crate::Invoice::currency_code).
+//! An [`Amount`] keeps the two together, so adding SEK to EUR is an error
+//! rather than a wrong total:
+//!
+//! - Arithmetic between amounts is checked and fails on mixed currencies.
+//! - [`Amount::round`] rounds to the currency's ISO 4217 minor units, e.g.
+//!   öre for SEK and whole yen for JPY.
+//! - [`ExchangeRates`] converts between currencies.
+//! - [`Amount::format`] renders Swedish (`1 234,50 kr`) or English
+//!   (`SEK 1,234.50`) text.
+//!
+//! Amounts are opt-in. Read them from documents with [`HasCurrency`], or
+//! store them in your own types with the [`value`] serde adapter, which
+//! reads and writes the API's flat number fields.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::amount::{Amount, CurrencyCode, ExchangeRates};
+//! use spiris::locale::Language;
+//! use spiris::money;
+//!
+//! let sek = Amount::new(money!(1000.0), CurrencyCode::SEK);
+//! let eur = Amount::new(money!(50.0), CurrencyCode::EUR);
+//! assert!(sek.checked_add(eur).is_err());
+//!
+//! let rates = ExchangeRates::new().rate(CurrencyCode::EUR, CurrencyCode::SEK, money!(11.5));
+//! let total = sek.checked_add(rates.convert(eur, CurrencyCode::SEK)?)?;
+//! assert_eq!(total.format(Language::Swedish), "1 575,00 kr");
+//! # Ok::<(), spiris::amount::AmountError>(())
+//! ```
+
+use crate::error::{Error, Result};
+use crate::locale::Language;
+use crate::types::{
+    round_money, BankAccount, CustomerInvoiceDraft, CustomerLedgerItem, Invoice, Money, Order,
+    Quotation, SupplierInvoice, SupplierInvoiceDraft, SupplierLedgerItem,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An ISO 4217 currency code such as `SEK`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    /// Swedish krona.
+    pub const SEK: CurrencyCode = CurrencyCode(*b"SEK");
+    /// Euro.
+    pub const EUR: CurrencyCode = CurrencyCode(*b"EUR");
+    /// US dollar.
+    pub const USD: CurrencyCode = CurrencyCode(*b"USD");
+    /// Norwegian krone.
+    pub const NOK: CurrencyCode = CurrencyCode(*b"NOK");
+    /// Danish krone.
+    pub const DKK: CurrencyCode = CurrencyCode(*b"DKK");
+    /// Pound sterling.
+    pub const GBP: CurrencyCode = CurrencyCode(*b"GBP");
+
+    /// Parse a three-letter code; lowercase letters are accepted.
+    pub fn parse(s: &str) -> Result<Self> {
+        let bytes = s.trim().as_bytes();
+        match bytes {
+            [a, b, c] if bytes.iter().all(u8::is_ascii_alphabetic) => Ok(CurrencyCode([
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ])),
+            _ => Err(Error::InvalidRequest(format!(
+                "Invalid currency code: {:?}",
+                s
+            ))),
+        }
+    }
+
+    /// Get the code as a string.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Number of decimals of the currency's minor unit.
+    pub fn minor_units(&self) -> u32 {
+        match self.as_str() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            "CLF" | "UYW" => 4,
+            _ => 2,
+        }
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        CurrencyCode::parse(s)
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CurrencyCode({})", self.as_str())
+    }
+}
+
+impl Serialize for CurrencyCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        CurrencyCode::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Errors from amount arithmetic and conversion.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AmountError {
+    /// Two amounts in different currencies were combined.
+    #[error("cannot combine {left} with {right}")]
+    CurrencyMismatch {
+        /// Currency of the left operand.
+        left: CurrencyCode,
+        /// Currency of the right operand.
+        right: CurrencyCode,
+    },
+
+    /// No exchange rate is known between two currencies.
+    #[error("no exchange rate from {from} to {to}")]
+    MissingRate {
+        /// Source currency.
+        from: CurrencyCode,
+        /// Target currency.
+        to: CurrencyCode,
+    },
+
+    /// The result does not fit in [`Money`].
+    ///
+    /// Only returned with the `decimal` feature; `f64` arithmetic saturates
+    /// to infinity instead.
+    #[error("amount overflow")]
+    Overflow,
+}
+
+#[cfg(feature = "decimal")]
+fn checked_add(a: Money, b: Money) -> Option<Money> {
+    a.checked_add(b)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn checked_add(a: Money, b: Money) -> Option<Money> {
+    Some(a + b)
+}
+
+#[cfg(feature = "decimal")]
+fn checked_sub(a: Money, b: Money) -> Option<Money> {
+    a.checked_sub(b)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn checked_sub(a: Money, b: Money) -> Option<Money> {
+    Some(a - b)
+}
+
+#[cfg(feature = "decimal")]
+fn checked_mul(a: Money, b: Money) -> Option<Money> {
+    a.checked_mul(b)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn checked_mul(a: Money, b: Money) -> Option<Money> {
+    Some(a * b)
+}
+
+#[cfg(feature = "decimal")]
+fn checked_div(a: Money, b: Money) -> Option<Money> {
+    a.checked_div(b)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn checked_div(a: Money, b: Money) -> Option<Money> {
+    Some(a / b)
+}
+
+/// A monetary value in a currency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Amount {
+    /// The value, as stored in the API's flat number fields.
+    pub value: Money,
+    /// The currency of `value`.
+    pub currency: CurrencyCode,
+}
+
+impl Amount {
+    /// Create an amount.
+    pub fn new(value: Money, currency: CurrencyCode) -> Self {
+        Self { value, currency }
+    }
+
+    /// Zero in a currency.
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self::new(Money::default(), currency)
+    }
+
+    /// Add two amounts in the same currency.
+    pub fn checked_add(self, other: Amount) -> std::result::Result<Amount, AmountError> {
+        self.same_currency(other)?;
+        let value = checked_add(self.value, other.value).ok_or(AmountError::Overflow)?;
+        Ok(Amount::new(value, self.currency))
+    }
+
+    /// Subtract an amount in the same currency.
+    pub fn checked_sub(self, other: Amount) -> std::result::Result<Amount, AmountError> {
+        self.same_currency(other)?;
+        let value = checked_sub(self.value, other.value).ok_or(AmountError::Overflow)?;
+        Ok(Amount::new(value, self.currency))
+    }
+
+    /// Multiply by a factor, such as a quantity or a percentage.
+    pub fn checked_mul(self, factor: Money) -> std::result::Result<Amount, AmountError> {
+        let value = checked_mul(self.value, factor).ok_or(AmountError::Overflow)?;
+        Ok(Amount::new(value, self.currency))
+    }
+
+    /// Sum amounts that must all be in `currency`.
+    pub fn sum(
+        currency: CurrencyCode,
+        amounts: impl IntoIterator<Item = Amount>,
+    ) -> std::result::Result<Amount, AmountError> {
+        amounts
+            .into_iter()
+            .try_fold(Amount::zero(currency), Amount::checked_add)
+    }
+
+    /// Round to the currency's minor units, half away from zero.
+    pub fn round(self) -> Amount {
+        Amount::new(
+            round_money(self.value, self.currency.minor_units()),
+            self.currency,
+        )
+    }
+
+    /// Format for display, rounded to minor units.
+    ///
+    /// Swedish puts the currency after the number (`kr` for SEK) and groups
+    /// thousands with spaces; English puts the code first and uses commas.
+    pub fn format(&self, language: Language) -> String {
+        let (sign, integer, fraction) = self.parts();
+        match language {
+            Language::Swedish => {
+                let number = join(sign, &group(&integer, ' '), ',', &fraction);
+                match self.currency {
+                    CurrencyCode::SEK => format!("{} kr", number),
+                    currency => format!("{} {}", number, currency),
+                }
+            }
+            Language::English => format!(
+                "{} {}",
+                self.currency,
+                join(sign, &group(&integer, ','), '.', &fraction)
+            ),
+        }
+    }
+
+    fn same_currency(self, other: Amount) -> std::result::Result<(), AmountError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(AmountError::CurrencyMismatch {
+                left: self.currency,
+                right: other.currency,
+            })
+        }
+    }
+
+    /// Split the rounded value into sign, integer digits and fraction digits.
+    fn parts(&self) -> (&'static str, String, String) {
+        let decimals = self.currency.minor_units() as usize;
+        let text = format!("{:.*}", decimals, self.round().value);
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(rest) if rest.chars().any(|c| c.is_ascii_digit() && c != '0') => ("-", rest),
+            Some(rest) => ("", rest),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        (sign, integer.to_string(), fraction.to_string())
+    }
+}
+
+fn group(integer: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(integer.len() + integer.len() / 3);
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn join(sign: &str, integer: &str, decimal_point: char, fraction: &str) -> String {
+    if fraction.is_empty() {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}{}{}", sign, integer, decimal_point, fraction)
+    }
+}
+
+impl fmt::Display for Amount {
+    /// Formats as `1234.50 SEK`, rounded to minor units.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = self.currency.minor_units() as usize;
+        write!(f, "{:.*} {}", decimals, self.round().value, self.currency)
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount::new(-self.value, self.currency)
+    }
+}
+
+impl From<Amount> for Money {
+    fn from(amount: Amount) -> Money {
+        amount.value
+    }
+}
+
+/// Exchange rates between pairs of currencies.
+///
+/// A rate is the price of one unit of `from` in `to`, the way
+/// [`SupplierInvoice::currency_rate`](crate::SupplierInvoice::currency_rate)
+/// gives the SEK price of one unit of the invoice currency. The inverse
+/// direction is derived automatically.
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeRates {
+    rates: HashMap<(CurrencyCode, CurrencyCode), Money>,
+}
+
+impl ExchangeRates {
+    /// Create an empty rate table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the price of one `from` in `to`.
+    pub fn rate(mut self, from: CurrencyCode, to: CurrencyCode, rate: Money) -> Self {
+        self.rates.insert((from, to), rate);
+        self
+    }
+
+    /// Get the price of one `from` in `to`.
+    pub fn get(&self, from: CurrencyCode, to: CurrencyCode) -> Option<Money> {
+        let zero = Money::default();
+        if from == to {
+            return Some(crate::money!(1.0));
+        }
+        if let Some(rate) = self.rates.get(&(from, to)).filter(|rate| **rate != zero) {
+            return Some(*rate);
+        }
+        self.rates
+            .get(&(to, from))
+            .filter(|rate| **rate != zero)
+            .and_then(|rate| checked_div(crate::money!(1.0), *rate))
+    }
+
+    /// Convert an amount, rounding to the target currency's minor units.
+    pub fn convert(
+        &self,
+        amount: Amount,
+        to: CurrencyCode,
+    ) -> std::result::Result<Amount, AmountError> {
+        let rate = self
+            .get(amount.currency, to)
+            .ok_or(AmountError::MissingRate {
+                from: amount.currency,
+                to,
+            })?;
+        Ok(Amount::new(amount.value, to).checked_mul(rate)?.round())
+    }
+}
+
+/// Documents that store their currency in a `CurrencyCode` field.
+pub trait HasCurrency {
+    /// The document currency, if set and valid.
+    fn currency(&self) -> Option<CurrencyCode>;
+
+    /// Pair one of the document's amount fields with its currency.
+    ///
+    /// ```
+    /// use spiris::amount::HasCurrency;
+    /// use spiris::{money, Invoice};
+    ///
+    /// let invoice = Invoice {
+    ///     currency_code: Some("EUR".to_string()),
+    ///     total_amount: Some(money!(120.0)),
+    ///     ..Default::default()
+    /// };
+    /// let total = invoice.amount(invoice.total_amount).unwrap();
+    /// assert_eq!(total.to_string(), "120.00 EUR");
+    /// ```
+    fn amount(&self, value: Option<Money>) -> Option<Amount> {
+        Some(Amount::new(value?, self.currency()?))
+    }
+}
+
+macro_rules! impl_has_currency {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl HasCurrency for $type {
+                fn currency(&self) -> Option<CurrencyCode> {
+                    self.currency_code.as_deref().and_then(|code| code.parse().ok())
+                }
+            }
+        )*
+    };
+}
+
+impl_has_currency!(
+    BankAccount,
+    CustomerInvoiceDraft,
+    CustomerLedgerItem,
+    Invoice,
+    Order,
+    Quotation,
+    SupplierInvoice,
+    SupplierInvoiceDraft,
+    SupplierLedgerItem,
+);
+
+/// Serde adapter that stores an [`Amount`] as the API's bare number.
+///
+/// Only the value is written; keep the currency in its own field, as the API
+/// does. A bare number carries no currency, so reading assumes SEK, the
+/// accounting currency of Spiris companies. Read amounts in other currencies
+/// as [`Money`] and pair them with the document currency through
+/// [`HasCurrency::amount`].
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use spiris::amount::Amount;
+///
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(rename_all = "PascalCase")]
+/// struct Summary {
+///     #[serde(with = "spiris::amount::value")]
+///     total_amount: Amount,
+/// }
+///
+/// let summary: Summary = serde_json::from_str(r#"{"TotalAmount": 1250.5}"#).unwrap();
+/// assert_eq!(summary.total_amount.to_string(), "1250.50 SEK");
+/// ```
+pub mod value {
+    use super::{Amount, CurrencyCode};
+    use crate::types::Money;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Write the amount's value as a bare number.
+    pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&amount.value, serializer)
+    }
+
+    /// Read a bare number as an amount in SEK.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        <Money as Deserialize>::deserialize(deserializer)
+            .map(|value| Amount::new(value, CurrencyCode::SEK))
+    }
+
+    /// The same adapter for `Option<Amount>`, for the API's optional fields.
+    pub mod option {
+        use super::{Amount, CurrencyCode, Money};
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Write the amount's value as a bare number, or `null`.
+        pub fn serialize<S: Serializer>(
+            amount: &Option<Amount>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            amount.map(|amount| amount.value).serialize(serializer)
+        }
+
+        /// Read a bare number as an amount in SEK, or `null` as `None`.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Amount>, D::Error> {
+            Ok(Option::<Money>::deserialize(deserializer)?
+                .map(|value| Amount::new(value, CurrencyCode::SEK)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money;
+
+    #[test]
+    fn test_currency_code() {
+        assert_eq!("sek".parse::<CurrencyCode>().unwrap(), CurrencyCode::SEK);
+        assert!(CurrencyCode::parse("SE").is_err());
+        assert!(CurrencyCode::parse("S3K").is_err());
+        assert_eq!(CurrencyCode::parse("JPY").unwrap().minor_units(), 0);
+        assert_eq!(CurrencyCode::parse("KWD").unwrap().minor_units(), 3);
+        assert_eq!(CurrencyCode::SEK.minor_units(), 2);
+
+        let json = serde_json::to_string(&CurrencyCode::EUR).unwrap();
+        assert_eq!(json, r#""EUR""#);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Amount::new(money!(10.5), CurrencyCode::SEK);
+        let b = Amount::new(money!(2.25), CurrencyCode::SEK);
+        let eur = Amount::new(money!(1.0), CurrencyCode::EUR);
+
+        assert_eq!(a.checked_add(b).unwrap().value, money!(12.75));
+        assert_eq!(a.checked_sub(b).unwrap().value, money!(8.25));
+        assert_eq!(
+            a.checked_add(eur),
+            Err(AmountError::CurrencyMismatch {
+                left: CurrencyCode::SEK,
+                right: CurrencyCode::EUR
+            })
+        );
+        assert!(Amount::sum(CurrencyCode::SEK, [a, b, eur]).is_err());
+        assert_eq!((-a.checked_mul(money!(2.0)).unwrap()).value, money!(-21.0));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        let max = Amount::new(Money::MAX, CurrencyCode::SEK);
+        let one = Amount::new(money!(1.0), CurrencyCode::SEK);
+
+        assert_eq!(max.checked_add(one), Err(AmountError::Overflow));
+        assert_eq!((-max).checked_sub(one), Err(AmountError::Overflow));
+        assert_eq!(max.checked_mul(money!(2.0)), Err(AmountError::Overflow));
+
+        let rates = ExchangeRates::new().rate(CurrencyCode::EUR, CurrencyCode::SEK, money!(11.5));
+        let eur = Amount::new(Money::MAX, CurrencyCode::EUR);
+        assert_eq!(
+            rates.convert(eur, CurrencyCode::SEK),
+            Err(AmountError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_round_to_minor_units() {
+        let jpy = CurrencyCode::parse("JPY").unwrap();
+        assert_eq!(Amount::new(money!(99.5), jpy).round().value, money!(100.0));
+        assert_eq!(
+            Amount::new(money!(1.005), CurrencyCode::SEK).round().value,
+            money!(1.01)
+        );
+    }
+
+    #[test]
+    fn test_exchange_rates() {
+        let rates = ExchangeRates::new().rate(CurrencyCode::EUR, CurrencyCode::SEK, money!(11.5));
+        let eur = Amount::new(money!(10.0), CurrencyCode::EUR);
+        let sek = Amount::new(money!(115.0), CurrencyCode::SEK);
+
+        assert_eq!(rates.convert(eur, CurrencyCode::SEK).unwrap(), sek);
+        assert_eq!(rates.convert(sek, CurrencyCode::EUR).unwrap(), eur);
+        assert_eq!(rates.convert(sek, CurrencyCode::SEK).unwrap(), sek);
+        assert_eq!(
+            rates.convert(eur, CurrencyCode::USD),
+            Err(AmountError::MissingRate {
+                from: CurrencyCode::EUR,
+                to: CurrencyCode::USD
+            })
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let amount = Amount::new(money!(-1234567.891), CurrencyCode::SEK);
+        assert_eq!(amount.format(Language::Swedish), "-1 234 567,89 kr");
+        assert_eq!(amount.format(Language::English), "SEK -1,234,567.89");
+        assert_eq!(amount.to_string(), "-1234567.89 SEK");
+
+        let eur = Amount::new(money!(0.5), CurrencyCode::EUR);
+        assert_eq!(eur.format(Language::Swedish), "0,50 EUR");
+
+        let jpy = Amount::new(money!(1500.0), CurrencyCode::parse("JPY").unwrap());
+        assert_eq!(jpy.format(Language::English), "JPY 1,500");
+
+        let tiny = Amount::new(money!(-0.001), CurrencyCode::SEK);
+        assert_eq!(tiny.format(Language::Swedish), "0,00 kr");
+    }
+
+    #[test]
+    fn test_serialization() {
+        let amount = Amount::new(money!(99.95), CurrencyCode::SEK);
+        let json = serde_json::to_value(amount).unwrap();
+        assert_eq!(json["Currency"], "SEK");
+
+        let back: Amount = serde_json::from_value(json).unwrap();
+        assert_eq!(back, amount);
+    }
+
+    #[test]
+    fn test_flat_value_round_trip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Totals {
+            #[serde(with = "value")]
+            total_amount: Amount,
+            #[serde(with = "value::option")]
+            total_vat_amount: Option<Amount>,
+        }
+
+        let totals = Totals {
+            total_amount: Amount::new(money!(1250.5), CurrencyCode::SEK),
+            total_vat_amount: None,
+        };
+        let json = serde_json::to_value(&totals).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "TotalAmount": serde_json::to_value(money!(1250.5)).unwrap(),
+                "TotalVatAmount": null,
+            })
+        );
+        assert_eq!(serde_json::from_value::<Totals>(json).unwrap(), totals);
+
+        let invoice: Totals =
+            serde_json::from_str(r#"{"TotalAmount": 100.0, "TotalVatAmount": 25.0}"#).unwrap();
+        assert_eq!(
+            invoice.total_vat_amount,
+            Some(Amount::new(money!(25.0), CurrencyCode::SEK))
+        );
+    }
+}
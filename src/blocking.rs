@@ -0,0 +1,571 @@
+//! Blocking client for synchronous code.
+//!
+//! [`Client`] wraps the async [`crate::Client`] and drives it on an internal
+//! single-threaded tokio runtime. It exposes the same endpoint accessors, with
+//! synchronous methods that return the result directly.
+//!
+//! Requires the `blocking` feature.
+//!
+//! # Panics
+//!
+//! Like `reqwest::blocking`, the blocking client must not be used from
+//! within an async runtime. Calling its methods from async code panics.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::blocking::Client;
+//! use spiris::{AccessToken, Customer};
+//!
+//! # fn example() -> Result<(), spiris::Error> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//!
+//! let customers = client.customers().list(None)?;
+//! println!("Found {} customers", customers.data.len());
+//!
+//! let customer = Customer {
+//!     name: Some("Acme Corporation".to_string()),
+//!     ..Default::default()
+//! };
+//! let created = client.customers().create(&customer)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::auth::AccessToken;
+use crate::client::ClientConfig;
+use crate::endpoints::approvals::ApprovalAction;
+use crate::endpoints::fiscal_years::OpeningBalance;
+use crate::endpoints::*;
+use crate::error::{Error, Result};
+use crate::ids::{
+    ArticleId, AttachmentId, CostCenterItemId, CustomerId, CustomerInvoiceDraftId,
+    CustomerLedgerItemId, FiscalYearId, InvoiceId, MessageThreadId, OrderId, QuotationId,
+    SupplierId, SupplierInvoiceDraftId, SupplierInvoiceId, SupplierLedgerItemId, VatReportId,
+    VoucherId,
+};
+use crate::types::{
+    Account, AccountBalance, AccountType, AttachmentLink, CompanySettings, ConvertDraftOptions,
+    CostCenterItem, CreateRequest, ForeignPaymentCode, Invoice, InvoicePayment, Message,
+    MessageThread, PaginatedResponse, PaginationParams, SupplierInvoice, UpdateRequest,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking client for the Spiris API.
+///
+/// Cloning is cheap; clones share the underlying HTTP client, token and
+/// runtime.
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Create a new blocking client with an access token.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be started. Use
+    /// [`Client::from_async`] to handle that error.
+    pub fn new(access_token: AccessToken) -> Self {
+        Self::with_config(access_token, ClientConfig::default())
+    }
+
+    /// Create a new blocking client with custom configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be started. Use
+    /// [`Client::from_async`] to handle that error.
+    pub fn with_config(access_token: AccessToken, config: ClientConfig) -> Self {
+        Self::from_async(crate::Client::with_config(access_token, config))
+            .expect("failed to start the blocking client runtime")
+    }
+
+    /// Wrap an existing async client.
+    pub fn from_async(client: crate::Client) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::InvalidConfig(format!("Failed to start runtime: {}", e)))?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Get the underlying async client.
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Update the access token.
+    pub fn set_access_token(&self, token: AccessToken) {
+        self.inner.set_access_token(token)
+    }
+
+    /// Get the current access token.
+    pub fn get_access_token(&self) -> AccessToken {
+        self.inner.get_access_token()
+    }
+
+    /// Check if the current token is expired.
+    pub fn is_token_expired(&self) -> bool {
+        self.inner.is_token_expired()
+    }
+
+    /// Make a GET request to an API endpoint.
+    pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.runtime.block_on(self.inner.get(path))
+    }
+
+    /// Make a GET request with query parameters.
+    pub fn get_with_params<T: DeserializeOwned, P: Serialize>(
+        &self,
+        path: &str,
+        params: &P,
+    ) -> Result<T> {
+        self.runtime
+            .block_on(self.inner.get_with_params(path, params))
+    }
+
+    /// Make a POST request to an API endpoint.
+    pub fn post<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
+        self.runtime.block_on(self.inner.post(path, body))
+    }
+
+    /// Make a PUT request to an API endpoint.
+    pub fn put<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
+        self.runtime.block_on(self.inner.put(path, body))
+    }
+
+    /// Make a DELETE request to an API endpoint.
+    pub fn delete(&self, path: &str) -> Result<()> {
+        self.runtime.block_on(self.inner.delete(path))
+    }
+
+    /// Make a GET request and return the raw response body.
+    pub fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        self.runtime.block_on(self.inner.get_bytes(path))
+    }
+
+    fn endpoint<'a, E>(&'a self, inner: E) -> Endpoint<'a, E> {
+        Endpoint {
+            inner,
+            runtime: &self.runtime,
+        }
+    }
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client").finish_non_exhaustive()
+    }
+}
+
+/// A blocking handle to an API endpoint.
+///
+/// Returned by the endpoint accessors on [`Client`], such as
+/// [`Client::customers`]. The available methods mirror the async endpoint
+/// `E`, except for streams.
+pub struct Endpoint<'a, E> {
+    pub(crate) inner: E,
+    runtime: &'a Runtime,
+}
+
+impl<E> Endpoint<'_, E> {
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+macro_rules! accessors {
+    ($($(#[$meta:meta])* $name:ident => $endpoint:ident;)*) => {
+        impl Client {
+            $(
+                $(#[$meta])*
+                pub fn $name(&self) -> Endpoint<'_, $endpoint<'_>> {
+                    self.endpoint($endpoint::new(&self.inner))
+                }
+            )*
+        }
+    };
+}
+
+accessors! {
+    /// Access the customers endpoint.
+    customers => CustomersEndpoint;
+    /// Access the invoices endpoint.
+    invoices => InvoicesEndpoint;
+    /// Access the articles endpoint.
+    articles => ArticlesEndpoint;
+    /// Access the customer invoice drafts endpoint.
+    customer_invoice_drafts => CustomerInvoiceDraftsEndpoint;
+    /// Access the customer ledger items endpoint.
+    customer_ledger_items => CustomerLedgerItemsEndpoint;
+    /// Access the customer labels endpoint.
+    customer_labels => CustomerLabelsEndpoint;
+    /// Access the suppliers endpoint.
+    suppliers => SuppliersEndpoint;
+    /// Access the supplier invoices endpoint.
+    supplier_invoices => SupplierInvoicesEndpoint;
+    /// Access the accounts endpoint.
+    accounts => AccountsEndpoint;
+    /// Access the fiscal years endpoint.
+    fiscal_years => FiscalYearsEndpoint;
+    /// Access the VAT codes endpoint.
+    vat_codes => VatCodesEndpoint;
+    /// Access the vouchers endpoint.
+    vouchers => VouchersEndpoint;
+    /// Access the bank accounts endpoint.
+    bank_accounts => BankAccountsEndpoint;
+    /// Access the projects endpoint.
+    projects => ProjectsEndpoint;
+    /// Access the cost centers endpoint.
+    cost_centers => CostCentersEndpoint;
+    /// Access the allocation periods endpoint.
+    allocation_periods => AllocationPeriodsEndpoint;
+    /// Access the orders endpoint.
+    orders => OrdersEndpoint;
+    /// Access the quotations endpoint.
+    quotations => QuotationsEndpoint;
+    /// Access the supplier invoice drafts endpoint.
+    supplier_invoice_drafts => SupplierInvoiceDraftsEndpoint;
+    /// Access the supplier ledger items endpoint.
+    supplier_ledger_items => SupplierLedgerItemsEndpoint;
+    /// Access the supplier labels endpoint.
+    supplier_labels => SupplierLabelsEndpoint;
+    /// Access the article labels endpoint.
+    article_labels => ArticleLabelsEndpoint;
+    /// Access the article account codings endpoint.
+    article_account_codings => ArticleAccountCodingsEndpoint;
+    /// Access the units endpoint.
+    units => UnitsEndpoint;
+    /// Access the delivery methods endpoint.
+    delivery_methods => DeliveryMethodsEndpoint;
+    /// Access the delivery terms endpoint.
+    delivery_terms => DeliveryTermsEndpoint;
+    /// Access the terms of payment endpoint.
+    terms_of_payment => TermsOfPaymentEndpoint;
+    /// Access the attachments endpoint.
+    attachments => AttachmentsEndpoint;
+    /// Access the documents endpoint.
+    documents => DocumentsEndpoint;
+    /// Access the company settings endpoint.
+    company_settings => CompanySettingsEndpoint;
+    /// Access the countries endpoint.
+    countries => CountriesEndpoint;
+    /// Access the currencies endpoint.
+    currencies => CurrenciesEndpoint;
+    /// Access the users endpoint.
+    users => UsersEndpoint;
+    /// Access the banks endpoint.
+    banks => BanksEndpoint;
+    /// Access the messages endpoint.
+    messages => MessagesEndpoint;
+    /// Access the approvals endpoint.
+    approvals => ApprovalsEndpoint;
+}
+
+// Hand-written endpoints. Endpoints defined with `define_endpoint!` get their
+// blocking methods from the macro.
+
+impl<'a> Endpoint<'a, CustomersEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Customer,
+        CustomerId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, InvoicesEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Invoice,
+        InvoiceId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+
+    crate::__blocking_methods! {
+        /// Register a payment for an invoice.
+        fn register_payment(&self, invoice_id: &InvoiceId, payment: &InvoicePayment) -> ();
+
+        /// Get the invoice as a PDF.
+        fn get_pdf(&self, invoice_id: &InvoiceId) -> Vec<u8>;
+
+        /// Send the invoice as an e-invoice.
+        fn send_einvoice(&self, invoice_id: &InvoiceId) -> ();
+    }
+}
+
+impl<'a> Endpoint<'a, ArticlesEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Article,
+        ArticleId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, SuppliersEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Supplier,
+        SupplierId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, SupplierInvoicesEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::SupplierInvoice,
+        SupplierInvoiceId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+
+    crate::__blocking_methods! {
+        /// Register a payment for a supplier invoice.
+        fn register_payment(&self, invoice_id: &SupplierInvoiceId, payment: &InvoicePayment) -> ();
+    }
+}
+
+impl<'a> Endpoint<'a, VouchersEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Voucher,
+        VoucherId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, OrdersEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Order,
+        OrderId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, QuotationsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::Quotation,
+        QuotationId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, CustomerInvoiceDraftsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::CustomerInvoiceDraft,
+        CustomerInvoiceDraftId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+
+    crate::__blocking_methods! {
+        /// Convert a draft into a customer invoice.
+        fn convert(&self, id: &CustomerInvoiceDraftId, options: Option<ConvertDraftOptions>) -> Invoice;
+    }
+}
+
+impl<'a> Endpoint<'a, SupplierInvoiceDraftsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::SupplierInvoiceDraft,
+        SupplierInvoiceDraftId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+
+    crate::__blocking_methods! {
+        /// Convert a draft into a supplier invoice.
+        fn convert(&self, id: &SupplierInvoiceDraftId) -> SupplierInvoice;
+    }
+}
+
+impl<'a> Endpoint<'a, CustomerLedgerItemsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::CustomerLedgerItem,
+        CustomerLedgerItemId,
+        list,
+        get,
+        create,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, SupplierLedgerItemsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(
+        crate::types::SupplierLedgerItem,
+        SupplierLedgerItemId,
+        list,
+        get,
+        create,
+        search
+    );
+}
+
+impl<'a> Endpoint<'a, FiscalYearsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(crate::types::FiscalYear, FiscalYearId, list, get, create);
+
+    crate::__blocking_methods! {
+        /// Get opening balances.
+        fn get_opening_balances(&self) -> Vec<OpeningBalance>;
+
+        /// Update opening balances.
+        fn update_opening_balances(&self, balances: &[OpeningBalance]) -> Vec<OpeningBalance>;
+    }
+}
+
+impl<'a> Endpoint<'a, AccountsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(Account, str, list, create);
+
+    crate::__blocking_methods! {
+        /// List accounts for a specific fiscal year.
+        fn list_by_fiscal_year(
+            &self,
+            fiscal_year_id: &FiscalYearId,
+            params: Option<PaginationParams>,
+        ) -> PaginatedResponse<Account>;
+
+        /// Get a specific account.
+        fn get(&self, fiscal_year_id: &FiscalYearId, account_number: &str) -> Account;
+
+        /// Update an existing account.
+        fn update(&self, fiscal_year_id: &FiscalYearId, account_number: &str, account: &Account) -> Account;
+
+        /// Get the standard chart of accounts.
+        fn get_standard_accounts(&self) -> PaginatedResponse<Account>;
+
+        /// Get account balances at a date.
+        fn get_balances(&self, date: &str) -> PaginatedResponse<AccountBalance>;
+
+        /// Get the balance of an account at a date.
+        fn get_balance(&self, account_number: &str, date: &str) -> AccountBalance;
+
+        /// Get the account types.
+        fn get_account_types(&self) -> PaginatedResponse<AccountType>;
+    }
+}
+
+impl<'a> Endpoint<'a, AttachmentsEndpoint<'a>> {
+    crate::__blocking_endpoint_impl!(crate::types::Attachment, AttachmentId, list, get, delete);
+
+    crate::__blocking_methods! {
+        /// Download the content of an attachment.
+        fn get_content(&self, id: &AttachmentId) -> Vec<u8>;
+
+        /// Link an attachment to a document.
+        fn create_link(&self, link: &AttachmentLink) -> AttachmentLink;
+
+        /// Remove an attachment link.
+        fn delete_link(&self, id: &AttachmentId) -> ();
+    }
+}
+
+impl<'a> Endpoint<'a, CompanySettingsEndpoint<'a>> {
+    crate::__blocking_methods! {
+        /// Get the company settings.
+        fn get(&self) -> CompanySettings;
+
+        /// Update the company settings.
+        fn update(&self, settings: &CompanySettings) -> CompanySettings;
+    }
+}
+
+impl<'a> Endpoint<'a, MessagesEndpoint<'a>> {
+    crate::__blocking_methods! {
+        /// Get a message thread.
+        fn get_thread(&self, id: &MessageThreadId) -> MessageThread;
+
+        /// Update a message thread.
+        fn update_thread(&self, id: &MessageThreadId, thread: &MessageThread) -> MessageThread;
+
+        /// Add a message to a thread.
+        fn add_message(&self, thread_id: &MessageThreadId, message: &Message) -> Message;
+    }
+}
+
+impl<'a> Endpoint<'a, ApprovalsEndpoint<'a>> {
+    crate::__blocking_methods! {
+        /// Approve or reject a VAT report.
+        fn approve_vat_report(&self, id: &VatReportId, action: &ApprovalAction) -> ();
+
+        /// Approve or reject a supplier invoice.
+        fn approve_supplier_invoice(&self, id: &SupplierInvoiceId, action: &ApprovalAction) -> ();
+    }
+}
+
+// Extra methods on endpoints defined with `define_endpoint!`.
+
+impl<'a> Endpoint<'a, BanksEndpoint<'a>> {
+    crate::__blocking_methods! {
+        /// List all foreign payment codes.
+        fn list_foreign_payment_codes(&self) -> PaginatedResponse<ForeignPaymentCode>;
+    }
+}
+
+impl<'a> Endpoint<'a, CostCentersEndpoint<'a>> {
+    crate::__blocking_methods! {
+        /// List all cost center items with optional pagination.
+        fn list_items(&self, params: Option<PaginationParams>) -> PaginatedResponse<CostCenterItem>;
+
+        /// Get a specific cost center item by ID.
+        fn get_item(&self, id: &CostCenterItemId) -> CostCenterItem;
+
+        /// Create a new cost center item.
+        fn create_item(&self, item: &impl CreateRequest<CostCenterItem>) -> CostCenterItem;
+
+        /// Update an existing cost center item.
+        fn update_item(&self, id: &CostCenterItemId, item: &impl UpdateRequest<CostCenterItem>) -> CostCenterItem;
+    }
+}
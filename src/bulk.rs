@@ -0,0 +1,403 @@
+//! Bulk create, update and delete with a per-item report.
+//!
+//! Requires the `bulk` feature. A [`Bulk`] runs a list of
+//! [`BulkOperation`]s against one endpoint with bounded concurrency. Every
+//! request still goes through the client's retry and rate limiting, so a
+//! large batch does not exceed the API quota.
+//!
+//! Failures do not stop the run. The returned [`BulkReport`] lists the
+//! outcome of every operation, including validation errors, and can be
+//! stored as JSON. Passing it to [`Bulk::resume_from`] re-runs only the
+//! operations that did not succeed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::bulk::{Bulk, BulkOperation, BulkOptions};
+//! use spiris::{AccessToken, Client, Customer};
+//!
+//! # async fn example(customers: Vec<Customer>) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//!
+//! let operations = customers.into_iter().map(BulkOperation::Create);
+//! let bulk = Bulk::with_options(client.customers(), BulkOptions::new().concurrency(8));
+//! let report = bulk.run(operations).await;
+//!
+//! println!("{} succeeded, {} failed", report.succeeded(), report.failed());
+//! std::fs::write("report.json", serde_json::to_string(&report)?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::endpoints::{
+    ArticleLabelsEndpoint, ArticlesEndpoint, BankAccountsEndpoint, CustomerInvoiceDraftsEndpoint,
+    CustomerLabelsEndpoint, CustomersEndpoint, InvoicesEndpoint, OrdersEndpoint, ProjectsEndpoint,
+    QuotationsEndpoint, SupplierInvoiceDraftsEndpoint, SupplierInvoicesEndpoint,
+    SupplierLabelsEndpoint, SuppliersEndpoint, TermsOfPaymentEndpoint, UnitsEndpoint,
+    VouchersEndpoint,
+};
+use crate::error::{Error, Result, ValidationError};
+use crate::ids::{
+    ArticleId, ArticleLabelId, BankAccountId, CustomerId, CustomerInvoiceDraftId, CustomerLabelId,
+    InvoiceId, OrderId, ProjectId, QuotationId, SupplierId, SupplierInvoiceDraftId,
+    SupplierInvoiceId, SupplierLabelId, TermsOfPaymentId, UnitId, VoucherId,
+};
+use crate::types::{
+    Article, ArticleLabel, BankAccount, Customer, CustomerInvoiceDraft, CustomerLabel,
+    Identifiable, Invoice, Order, Project, Quotation, Supplier, SupplierInvoice,
+    SupplierInvoiceDraft, SupplierLabel, TermsOfPayment, Unit, Voucher,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An endpoint that supports create, update and delete.
+pub trait BulkTarget {
+    /// The resource type.
+    type Item: Identifiable + Sync;
+
+    /// The id type taken by `update` and `delete`.
+    type Id: AsRef<str> + Sync;
+
+    /// Create an item.
+    fn create(&self, item: &Self::Item) -> impl Future<Output = Result<Self::Item>> + Send;
+
+    /// Update the item with the given ID.
+    fn update(
+        &self,
+        id: &Self::Id,
+        item: &Self::Item,
+    ) -> impl Future<Output = Result<Self::Item>> + Send;
+
+    /// Delete the item with the given ID.
+    fn delete(&self, id: &Self::Id) -> impl Future<Output = Result<()>> + Send;
+}
+
+macro_rules! impl_bulk_target {
+    ($($endpoint:ident => $type:ty, $id:ty);* $(;)?) => {
+        $(
+            impl BulkTarget for $endpoint<'_> {
+                type Item = $type;
+                type Id = $id;
+
+                async fn create(&self, item: &$type) -> Result<$type> {
+                    $endpoint::create(self, item).await
+                }
+
+                async fn update(&self, id: &$id, item: &$type) -> Result<$type> {
+                    $endpoint::update(self, id, item).await
+                }
+
+                async fn delete(&self, id: &$id) -> Result<()> {
+                    $endpoint::delete(self, id).await
+                }
+            }
+        )*
+    };
+}
+
+impl_bulk_target!(
+    ArticleLabelsEndpoint => ArticleLabel, ArticleLabelId;
+    ArticlesEndpoint => Article, ArticleId;
+    BankAccountsEndpoint => BankAccount, BankAccountId;
+    CustomerInvoiceDraftsEndpoint => CustomerInvoiceDraft, CustomerInvoiceDraftId;
+    CustomerLabelsEndpoint => CustomerLabel, CustomerLabelId;
+    CustomersEndpoint => Customer, CustomerId;
+    InvoicesEndpoint => Invoice, InvoiceId;
+    OrdersEndpoint => Order, OrderId;
+    ProjectsEndpoint => Project, ProjectId;
+    QuotationsEndpoint => Quotation, QuotationId;
+    SupplierInvoiceDraftsEndpoint => SupplierInvoiceDraft, SupplierInvoiceDraftId;
+    SupplierInvoicesEndpoint => SupplierInvoice, SupplierInvoiceId;
+    SupplierLabelsEndpoint => SupplierLabel, SupplierLabelId;
+    SuppliersEndpoint => Supplier, SupplierId;
+    TermsOfPaymentEndpoint => TermsOfPayment, TermsOfPaymentId;
+    UnitsEndpoint => Unit, UnitId;
+    VouchersEndpoint => Voucher, VoucherId;
+);
+
+/// A single operation in a bulk run.
+#[derive(Debug, Clone)]
+pub enum BulkOperation<T, I> {
+    /// Create a new item.
+    Create(T),
+    /// Update the item with the given ID.
+    Update(I, T),
+    /// Delete the item with the given ID.
+    Delete(I),
+}
+
+impl<T, I: AsRef<str>> BulkOperation<T, I> {
+    /// The kind of operation.
+    pub fn kind(&self) -> OperationKind {
+        match self {
+            BulkOperation::Create(_) => OperationKind::Create,
+            BulkOperation::Update(..) => OperationKind::Update,
+            BulkOperation::Delete(_) => OperationKind::Delete,
+        }
+    }
+
+    /// The ID targeted by an update or delete.
+    pub fn target_id(&self) -> Option<&str> {
+        match self {
+            BulkOperation::Create(_) => None,
+            BulkOperation::Update(id, _) | BulkOperation::Delete(id) => Some(id.as_ref()),
+        }
+    }
+}
+
+/// The kind of a [`BulkOperation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationKind {
+    /// Create.
+    Create,
+    /// Update.
+    Update,
+    /// Delete.
+    Delete,
+}
+
+/// Options for a bulk run.
+#[derive(Debug, Clone)]
+pub struct BulkOptions {
+    /// Maximum number of requests in flight.
+    pub concurrency: usize,
+    /// Skip the remaining operations once this many have failed.
+    pub max_failures: Option<usize>,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            max_failures: None,
+        }
+    }
+}
+
+impl BulkOptions {
+    /// Create options with the defaults: 4 concurrent requests, no failure limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of requests in flight (at least 1).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Skip the remaining operations once `max_failures` have failed.
+    pub fn max_failures(mut self, max_failures: usize) -> Self {
+        self.max_failures = Some(max_failures);
+        self
+    }
+}
+
+/// Outcome of one operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Outcome {
+    /// The request succeeded.
+    Succeeded {
+        /// ID of the created, updated or deleted item.
+        id: Option<String>,
+    },
+    /// The request failed.
+    Failed {
+        /// HTTP status code, if the API responded.
+        status_code: Option<u16>,
+        /// Error message.
+        message: String,
+        /// Field-level validation errors from the API.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        validation_errors: Vec<ValidationError>,
+    },
+    /// The operation was not attempted because too many had failed.
+    Skipped,
+}
+
+impl Outcome {
+    fn failed(error: &Error) -> Self {
+        Outcome::Failed {
+            status_code: error.status_code(),
+            message: error.to_string(),
+            validation_errors: error.validation_errors().unwrap_or_default().to_vec(),
+        }
+    }
+}
+
+/// Report entry for one operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemReport {
+    /// Position of the operation in the input.
+    pub index: usize,
+    /// The kind of operation.
+    pub kind: OperationKind,
+    /// ID targeted by an update or delete.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    /// What happened.
+    pub outcome: Outcome,
+}
+
+impl ItemReport {
+    /// Whether the operation succeeded.
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome, Outcome::Succeeded { .. })
+    }
+}
+
+/// Per-item report of a bulk run, ordered by input position.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkReport {
+    /// One entry per operation.
+    pub items: Vec<ItemReport>,
+}
+
+impl BulkReport {
+    /// Number of operations that succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Succeeded { .. }))
+    }
+
+    /// Number of operations that failed.
+    pub fn failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Failed { .. }))
+    }
+
+    /// Number of operations that were skipped.
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Skipped))
+    }
+
+    /// Whether every operation succeeded.
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(ItemReport::is_success)
+    }
+
+    fn count(&self, f: impl Fn(&Outcome) -> bool) -> usize {
+        self.items.iter().filter(|item| f(&item.outcome)).count()
+    }
+
+    /// The earlier success for the operation at `index`, if it is the same
+    /// operation.
+    fn previous_success(
+        &self,
+        index: usize,
+        kind: OperationKind,
+        target_id: Option<&str>,
+    ) -> Option<&ItemReport> {
+        self.items
+            .iter()
+            .find(|item| item.index == index)
+            .filter(|item| {
+                item.is_success() && item.kind == kind && item.target_id.as_deref() == target_id
+            })
+    }
+}
+
+/// Runs bulk operations against a [`BulkTarget`].
+#[derive(Debug, Clone)]
+pub struct Bulk<S> {
+    target: S,
+    options: BulkOptions,
+    previous: Option<BulkReport>,
+}
+
+impl<S: BulkTarget> Bulk<S> {
+    /// Create a bulk runner with the default options.
+    pub fn new(target: S) -> Self {
+        Self::with_options(target, BulkOptions::default())
+    }
+
+    /// Create a bulk runner with custom options.
+    pub fn with_options(target: S, options: BulkOptions) -> Self {
+        Self {
+            target,
+            options,
+            previous: None,
+        }
+    }
+
+    /// Continue an earlier run.
+    ///
+    /// Operations that succeeded in `report` are not sent again; their entry
+    /// is copied into the new report. The operations must be passed to
+    /// [`run`](Self::run) in the same order as before. An operation whose
+    /// kind or target ID differs from the earlier entry is run again.
+    pub fn resume_from(mut self, report: BulkReport) -> Self {
+        self.previous = Some(report);
+        self
+    }
+
+    /// Run the operations and report the outcome of each.
+    pub async fn run<I>(&self, operations: I) -> BulkReport
+    where
+        I: IntoIterator<Item = BulkOperation<S::Item, S::Id>>,
+    {
+        let failures = AtomicUsize::new(0);
+        let failures = &failures;
+
+        let mut items: Vec<ItemReport> =
+            futures::stream::iter(operations.into_iter().enumerate())
+                .map(|(index, operation)| async move {
+                    let kind = operation.kind();
+                    let target_id = operation.target_id().map(str::to_string);
+
+                    if let Some(previous) = self.previous.as_ref().and_then(|report| {
+                        report.previous_success(index, kind, target_id.as_deref())
+                    }) {
+                        return previous.clone();
+                    }
+
+                    let over_limit = self
+                        .options
+                        .max_failures
+                        .is_some_and(|max| failures.load(Ordering::SeqCst) >= max);
+                    let outcome = if over_limit {
+                        Outcome::Skipped
+                    } else {
+                        match self.execute(operation).await {
+                            Ok(id) => Outcome::Succeeded { id },
+                            Err(error) => {
+                                failures.fetch_add(1, Ordering::SeqCst);
+                                Outcome::failed(&error)
+                            }
+                        }
+                    };
+
+                    ItemReport {
+                        index,
+                        kind,
+                        target_id,
+                        outcome,
+                    }
+                })
+                .buffer_unordered(self.options.concurrency.max(1))
+                .collect()
+                .await;
+
+        items.sort_by_key(|item| item.index);
+        BulkReport { items }
+    }
+
+    async fn execute(&self, operation: BulkOperation<S::Item, S::Id>) -> Result<Option<String>> {
+        match operation {
+            BulkOperation::Create(item) => {
+                let created = self.target.create(&item).await?;
+                Ok(created.id().map(str::to_string))
+            }
+            BulkOperation::Update(id, item) => {
+                self.target.update(&id, &item).await?;
+                Ok(Some(id.as_ref().to_string()))
+            }
+            BulkOperation::Delete(id) => {
+                self.target.delete(&id).await?;
+                Ok(Some(id.as_ref().to_string()))
+            }
+        }
+    }
+}
@@ -49,6 +49,19 @@ pub struct ClientConfig {
 
     /// Middleware stack for request/response interception.
     pub middleware: MiddlewareStack,
+
+    /// Record `POST`, `PUT` and `DELETE` requests instead of sending them.
+    pub dry_run: bool,
+
+    /// Reject responses that contain fields the types do not model.
+    pub strict_deserialization: bool,
+
+    /// Checker that compares responses with the types they decode into.
+    pub schema_drift: Option<crate::drift::SchemaDriftChecker>,
+
+    /// Store for offline snapshots and queued writes (requires `offline` feature).
+    #[cfg(feature = "offline")]
+    pub offline_store: Option<crate::offline::OfflineStore>,
 }
 
 impl Default for ClientConfig {
@@ -63,6 +76,11 @@ impl Default for ClientConfig {
             #[cfg(feature = "rate-limit")]
             rate_limit_config: None,
             middleware: MiddlewareStack::new(),
+            dry_run: false,
+            strict_deserialization: false,
+            schema_drift: None,
+            #[cfg(feature = "offline")]
+            offline_store: None,
         }
     }
 }
@@ -116,6 +134,48 @@ impl ClientConfig {
         self
     }
 
+    /// Set the store used to answer `GET` requests while offline.
+    ///
+    /// Successful `GET` responses are saved in the store. When the API
+    /// cannot be reached or the rate limit is exhausted, the saved response
+    /// is returned instead; see [`Response::cached_at`](crate::Response::cached_at).
+    #[cfg(feature = "offline")]
+    pub fn offline_store(mut self, store: crate::offline::OfflineStore) -> Self {
+        self.offline_store = Some(store);
+        self
+    }
+
+    /// Enable or disable dry-run mode.
+    ///
+    /// In dry-run mode, `GET` requests are sent as usual, while mutations are
+    /// recorded in the client's [`DryRunPlan`](crate::dry_run::DryRunPlan)
+    /// and answered with an echo of the request body.
+    pub fn dry_run(mut self, enable: bool) -> Self {
+        self.dry_run = enable;
+        self
+    }
+
+    /// Enable or disable strict deserialization.
+    ///
+    /// By default, response fields the types do not model are kept in their
+    /// `extra` map. In strict mode, such responses fail with
+    /// [`Error::UnknownFields`](crate::Error::UnknownFields) instead, which is
+    /// useful for contract tests. See [`strict`](crate::strict).
+    pub fn strict_deserialization(mut self, enable: bool) -> Self {
+        self.strict_deserialization = enable;
+        self
+    }
+
+    /// Check responses for schema drift.
+    ///
+    /// Every decoded response is compared with its target type, and new,
+    /// missing and mistyped fields are aggregated per endpoint. Keep a clone
+    /// of the checker to read its report. See [`drift`](crate::drift).
+    pub fn schema_drift(mut self, checker: crate::drift::SchemaDriftChecker) -> Self {
+        self.schema_drift = Some(checker);
+        self
+    }
+
     /// Add a middleware to the stack.
     ///
     /// Middleware is executed in the order it is added for requests,
@@ -174,6 +234,8 @@ pub struct Client {
     rate_limiter: Option<crate::rate_limit::ApiRateLimiter>,
     /// Middleware stack for request/response interception.
     middleware: MiddlewareStack,
+    /// Recorded mutations when dry-run mode is enabled.
+    dry_run_plan: Option<crate::dry_run::DryRunPlan>,
 }
 
 impl Client {
@@ -196,6 +258,7 @@ impl Client {
             .map(crate::rate_limit::ApiRateLimiter::new);
 
         let middleware = config.middleware.clone();
+        let dry_run_plan = config.dry_run.then(crate::dry_run::DryRunPlan::new);
 
         Self {
             http_client,
@@ -205,6 +268,7 @@ impl Client {
             #[cfg(feature = "rate-limit")]
             rate_limiter,
             middleware,
+            dry_run_plan,
         }
     }
 
@@ -221,6 +285,51 @@ impl Client {
         self.access_token.read().unwrap().clone()
     }
 
+    /// Get the offline store, if one is configured.
+    #[cfg(feature = "offline")]
+    pub fn offline_store(&self) -> Option<&crate::offline::OfflineStore> {
+        self.config.offline_store.as_ref()
+    }
+
+    /// Get the recorded mutations, if dry-run mode is enabled.
+    pub fn dry_run_plan(&self) -> Option<&crate::dry_run::DryRunPlan> {
+        self.dry_run_plan.as_ref()
+    }
+
+    /// Name the endpoint of a request for schema drift reports, if a
+    /// checker is configured.
+    pub(crate) fn drift_endpoint(&self, method: &Method, url: &Url) -> Option<String> {
+        self.config.schema_drift.as_ref()?;
+        Some(crate::drift::endpoint_name(
+            method.as_str(),
+            &self.config.base_url,
+            url.as_str(),
+        ))
+    }
+
+    /// Deserialize a response body, honouring strict mode and schema drift
+    /// checking.
+    pub(crate) fn decode_json<T: DeserializeOwned>(
+        &self,
+        json: &[u8],
+        endpoint: Option<&str>,
+    ) -> Result<T> {
+        let decode = || match (&self.config.schema_drift, endpoint) {
+            (Some(checker), Some(endpoint)) => checker.decode(endpoint, json),
+            _ => serde_json::from_slice(json),
+        };
+        if !self.config.strict_deserialization {
+            return Ok(decode()?);
+        }
+        let (result, fields) = crate::strict::collect(decode);
+        let value = result?;
+        if fields.is_empty() {
+            Ok(value)
+        } else {
+            Err(Error::UnknownFields(fields))
+        }
+    }
+
     /// Check if the current access token is expired.
     pub fn is_token_expired(&self) -> bool {
         self.access_token.read().unwrap().is_expired()
@@ -295,7 +404,7 @@ impl Client {
     }
 
     /// Build a URL for an API endpoint.
-    fn build_url(&self, path: &str) -> Result<Url> {
+    pub(crate) fn build_url(&self, path: &str) -> Result<Url> {
         let base = Url::parse(&self.config.base_url)?;
         // Strip leading "/" to ensure proper joining with base URL
         let path = path.strip_prefix('/').unwrap_or(path);
@@ -303,22 +412,38 @@ impl Client {
         Ok(url)
     }
 
-    /// Build a request with authentication headers.
-    fn build_request(&self, method: Method, url: Url) -> Result<RequestBuilder> {
+    /// Build a request with the default headers, without authentication.
+    pub(crate) fn base_request(&self, method: Method, url: Url) -> RequestBuilder {
+        self.http_client
+            .request(method, url)
+            .header(header::USER_AGENT, &self.config.user_agent)
+            .header(header::ACCEPT, "application/json")
+    }
+
+    /// Add the authentication header to a request.
+    fn authorize(&self, request: RequestBuilder) -> Result<RequestBuilder> {
         let token = self.access_token.read().unwrap();
 
         if token.is_expired() {
             return Err(Error::TokenExpired);
         }
 
-        let request = self
-            .http_client
-            .request(method, url)
-            .header(header::AUTHORIZATION, token.authorization_header())
-            .header(header::USER_AGENT, &self.config.user_agent)
-            .header(header::ACCEPT, "application/json");
+        Ok(request.header(header::AUTHORIZATION, token.authorization_header()))
+    }
 
-        Ok(request)
+    /// Authenticate and send a request through the retry, rate limiting and
+    /// middleware pipeline.
+    ///
+    /// The returned metadata wraps the undecoded HTTP response.
+    pub(crate) async fn send_request(
+        &self,
+        method: &str,
+        url: &str,
+        request: RequestBuilder,
+    ) -> Result<crate::response::Response<Response>> {
+        self.ensure_valid_token().await?;
+        let request = self.authorize(request)?;
+        self.execute_request(method, url, request).await
     }
 
     /// Execute a request and handle the response with automatic retry on transient errors.
@@ -327,7 +452,7 @@ impl Client {
         method: &str,
         url: &str,
         request: RequestBuilder,
-    ) -> Result<Response> {
+    ) -> Result<crate::response::Response<Response>> {
         #[cfg(feature = "tracing")]
         let span = tracing::info_span!("api_request", %method, %url);
         #[cfg(feature = "tracing")]
@@ -366,7 +491,7 @@ impl Client {
         // Log the result
         #[cfg(feature = "tracing")]
         match &result {
-            Ok(response) => {
+            Ok((response, _)) => {
                 info!(
                     status = response.status().as_u16(),
                     duration_ms = elapsed.as_millis() as u64,
@@ -385,7 +510,7 @@ impl Client {
         // Process middleware on_response handlers
         if !self.middleware.is_empty() {
             let response_ctx = match &result {
-                Ok(response) => ResponseContext::new(
+                Ok((response, _)) => ResponseContext::new(
                     method.to_string(),
                     url.to_string(),
                     response.status().as_u16(),
@@ -403,15 +528,24 @@ impl Client {
             self.middleware.process_response(&response_ctx);
         }
 
-        result
+        result.map(|(response, attempts)| crate::response::Response {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            elapsed,
+            attempts,
+            cached_at: None,
+            data: response,
+        })
     }
 
     /// Inner request execution with retry logic.
-    async fn execute_request_inner(&self, request: RequestBuilder) -> Result<Response> {
+    ///
+    /// Returns the response together with the number of attempts made.
+    async fn execute_request_inner(&self, request: RequestBuilder) -> Result<(Response, u32)> {
         // If retries are disabled, just send directly
         if self.config.retry_config.max_retries == 0 {
             let response = request.send().await?;
-            return self.handle_response(response).await;
+            return Ok((self.handle_response(response).await?, 1));
         }
 
         // Clone the request for potential retries
@@ -425,21 +559,26 @@ impl Client {
         let first_result = self.handle_response(response).await;
 
         match first_result {
-            Ok(response) => Ok(response),
+            Ok(response) => Ok((response, 1)),
             Err(err) if crate::retry::is_retryable_error(&err) => {
                 #[cfg(feature = "tracing")]
                 warn!(error = %err, "Request failed, will retry");
 
                 // Use retry logic for retryable errors
-                crate::retry::retry_request(&self.config.retry_config, || async {
-                    // We need to rebuild the request each time
-                    let url = request_clone
-                        .try_clone()
-                        .ok_or_else(|| Error::InvalidRequest("Request cannot be cloned".into()))?;
-                    let response = url.send().await?;
-                    self.handle_response(response).await
+                let mut attempts = 1;
+                let response = crate::retry::retry_request(&self.config.retry_config, || {
+                    attempts += 1;
+                    async {
+                        // We need to rebuild the request each time
+                        let url = request_clone.try_clone().ok_or_else(|| {
+                            Error::InvalidRequest("Request cannot be cloned".into())
+                        })?;
+                        let response = url.send().await?;
+                        self.handle_response(response).await
+                    }
                 })
-                .await
+                .await?;
+                Ok((response, attempts))
             }
             Err(err) => Err(err),
         }
@@ -454,36 +593,41 @@ impl Client {
 
         match status {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(response),
-            StatusCode::UNAUTHORIZED => Err(Error::AuthError("Unauthorized".to_string())),
-            StatusCode::FORBIDDEN => Err(Error::AuthError("Forbidden".to_string())),
-            StatusCode::NOT_FOUND => {
-                let raw_body = response.text().await.unwrap_or_default();
-                Err(Error::NotFound(raw_body))
-            }
-            StatusCode::TOO_MANY_REQUESTS => {
-                let raw_body = response.text().await.unwrap_or_default();
-                Err(Error::RateLimitExceeded(raw_body))
-            }
-            StatusCode::BAD_REQUEST => {
-                let raw_body = response.text().await.unwrap_or_default();
-                Err(Error::InvalidRequest(raw_body))
-            }
             _ => {
+                let headers = response.headers().clone();
                 let raw_body = response.text().await.unwrap_or_default();
-                Err(Error::from_api_response(status.as_u16(), raw_body))
+                Err(Error::from_api_response(status.as_u16(), raw_body).with_headers(headers))
             }
         }
     }
 
+    /// Start building a raw request to an API endpoint.
+    ///
+    /// Use this for endpoints that have no typed accessor, or when you need
+    /// custom headers or access to the response status and headers. The
+    /// request goes through the same authentication, retry, rate limiting
+    /// and middleware pipeline as the typed endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use spiris::{Client, Method};
+    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let report: serde_json::Value = client
+    ///     .request(Method::GET, "/vatreports")
+    ///     .query(&[("fromDate", "2024-01-01")])
+    ///     .send_json()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request(&self, method: Method, path: &str) -> crate::request::RequestBuilder<'_> {
+        crate::request::RequestBuilder::new(self, method, path)
+    }
+
     /// Make a GET request to an API endpoint.
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self.build_request(Method::GET, url)?;
-        let response = self.execute_request("GET", &url_str, request).await?;
-        let data = response.json().await?;
-        Ok(data)
+        self.request(Method::GET, path).send_json().await
     }
 
     /// Make a GET request with query parameters.
@@ -492,62 +636,34 @@ impl Client {
         path: &str,
         params: &P,
     ) -> Result<T> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self.build_request(Method::GET, url)?.query(params);
-        let response = self.execute_request("GET", &url_str, request).await?;
-        let data = response.json().await?;
-        Ok(data)
+        self.request(Method::GET, path)
+            .query(params)
+            .send_json()
+            .await
     }
 
     /// Make a POST request to create a resource.
     pub async fn post<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self
-            .build_request(Method::POST, url)?
-            .header(header::CONTENT_TYPE, "application/json")
-            .json(body);
-        let response = self.execute_request("POST", &url_str, request).await?;
-        let data = response.json().await?;
-        Ok(data)
+        self.request(Method::POST, path)
+            .json(body)
+            .send_json()
+            .await
     }
 
     /// Make a PUT request to update a resource.
     pub async fn put<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self
-            .build_request(Method::PUT, url)?
-            .header(header::CONTENT_TYPE, "application/json")
-            .json(body);
-        let response = self.execute_request("PUT", &url_str, request).await?;
-        let data = response.json().await?;
-        Ok(data)
+        self.request(Method::PUT, path).json(body).send_json().await
     }
 
     /// Make a DELETE request to remove a resource.
     pub async fn delete(&self, path: &str) -> Result<()> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self.build_request(Method::DELETE, url)?;
-        self.execute_request("DELETE", &url_str, request).await?;
+        self.request(Method::DELETE, path).send_bytes().await?;
         Ok(())
     }
 
     /// Make a GET request that returns raw bytes (for binary data like PDFs).
     pub async fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
-        self.ensure_valid_token().await?;
-        let url = self.build_url(path)?;
-        let url_str = url.to_string();
-        let request = self.build_request(Method::GET, url)?;
-        let response = self.execute_request("GET", &url_str, request).await?;
-        let bytes = response.bytes().await?;
-        Ok(bytes.to_vec())
+        self.request(Method::GET, path).send_bytes().await
     }
 }
 
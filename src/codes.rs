@@ -0,0 +1,183 @@
+//! Typed integer codes.
+//!
+//! Some fields, such as [`Voucher::voucher_type`](crate::types::Voucher::voucher_type),
+//! are sent by the API as integers. They are modelled as enums that
+//! serialize to and from those integers. Values not known to this client are
+//! kept in an `Unknown` variant, so new codes added by the API still
+//! round-trip unchanged.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::codes::VoucherType;
+//!
+//! let manual: VoucherType = serde_json::from_str("2")?;
+//! assert_eq!(manual, VoucherType::ManualVoucher);
+//!
+//! let future: VoucherType = serde_json::from_str("99")?;
+//! assert_eq!(future, VoucherType::Unknown(99));
+//! assert_eq!(serde_json::to_string(&future)?, "99");
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Define an enum mapped to API integer codes, with an `Unknown` fallback.
+macro_rules! define_codes {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $code:literal,)*
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum $name {
+                $($(#[$variant_meta])* $variant,)*
+                /// A code not known to this client.
+                Unknown(i32),
+            }
+
+            impl $name {
+                /// Get the integer code used by the API.
+                pub fn code(self) -> i32 {
+                    match self {
+                        $($name::$variant => $code,)*
+                        $name::Unknown(code) => code,
+                    }
+                }
+            }
+
+            impl From<i32> for $name {
+                fn from(code: i32) -> Self {
+                    match code {
+                        $($code => $name::$variant,)*
+                        code => $name::Unknown(code),
+                    }
+                }
+            }
+
+            impl From<$name> for i32 {
+                fn from(value: $name) -> i32 {
+                    value.code()
+                }
+            }
+
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> std::result::Result<S::Ok, S::Error> {
+                    serializer.serialize_i32(self.code())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> std::result::Result<Self, D::Error> {
+                    i32::deserialize(deserializer).map($name::from)
+                }
+            }
+        )*
+    };
+}
+
+define_codes! {
+    /// Kind of a [`Voucher`](crate::types::Voucher).
+    VoucherType {
+        /// Manually entered voucher.
+        ManualVoucher = 2,
+        /// Deposit from a transfer between bank accounts.
+        BankAccountTransferDeposit = 5,
+        /// Withdrawal from a transfer between bank accounts.
+        BankAccountTransferWithdrawal = 6,
+        /// Purchase receipt.
+        PurchaseReceipt = 7,
+        /// VAT report.
+        VatReport = 8,
+        /// Imported from an SIE file.
+        SieImport = 9,
+        /// Bank transaction deposit.
+        BankTransactionDeposit = 10,
+        /// Bank transaction withdrawal.
+        BankTransactionWithdrawal = 11,
+        /// Supplier invoice.
+        SupplierInvoiceDebit = 12,
+        /// Supplier credit invoice.
+        SupplierInvoiceCredit = 13,
+        /// Customer invoice.
+        CustomerInvoiceDebit = 14,
+        /// Customer credit invoice.
+        CustomerInvoiceCredit = 15,
+        /// Claim on a card acquirer.
+        ClaimOnCardAcquirer = 16,
+        /// Tax return.
+        TaxReturn = 17,
+        /// Allocation period.
+        AllocationPeriod = 18,
+        /// Correction of an allocation period.
+        AllocationPeriodCorrection = 19,
+        /// Inventory event.
+        InventoryEvent = 20,
+        /// Employer report.
+        EmployerReport = 21,
+        /// Return of an employer report.
+        EmployerReportReturn = 22,
+    }
+
+    /// Kind of document an attachment or message thread refers to.
+    DocumentType {
+        /// Supplier invoice.
+        SupplierInvoice = 1,
+        /// Receipt.
+        Receipt = 2,
+        /// Voucher.
+        Voucher = 3,
+        /// Supplier invoice draft.
+        SupplierInvoiceDraft = 4,
+        /// Allocation period.
+        AllocationPeriod = 5,
+        /// Transfer between bank accounts.
+        Transfer = 6,
+    }
+
+    /// Bookkeeping method of a [`FiscalYear`](crate::types::FiscalYear).
+    BookkeepingMethod {
+        /// Transactions are booked when invoices are issued or received.
+        Invoice = 0,
+        /// Transactions are booked when payments are made.
+        Cash = 1,
+        /// No bookkeeping.
+        NoBookkeeping = 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        assert_eq!(VoucherType::from(2), VoucherType::ManualVoucher);
+        assert_eq!(i32::from(VoucherType::CustomerInvoiceDebit), 14);
+        assert_eq!(BookkeepingMethod::from(1), BookkeepingMethod::Cash);
+        assert_eq!(DocumentType::from(42), DocumentType::Unknown(42));
+        assert_eq!(DocumentType::Unknown(42).code(), 42);
+    }
+
+    #[test]
+    fn test_serde_uses_integers() {
+        let json = serde_json::to_string(&DocumentType::Receipt).unwrap();
+        assert_eq!(json, "2");
+
+        let method: BookkeepingMethod = serde_json::from_str("0").unwrap();
+        assert_eq!(method, BookkeepingMethod::Invoice);
+
+        let unknown: VoucherType = serde_json::from_str("0").unwrap();
+        assert_eq!(unknown, VoucherType::Unknown(0));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "0");
+        assert!(serde_json::from_str::<VoucherType>("\"Manual\"").is_err());
+    }
+}
@@ -0,0 +1,277 @@
+//! Field-level diffs and conflict detection for updates.
+//!
+//! [`diff`] compares two values of any serializable type, such as
+//! [`Customer`](crate::Customer) or [`Invoice`](crate::Invoice), and lists the
+//! fields that differ. Paths use the API field names, e.g. `Name`,
+//! `InvoiceAddress.City` or `Rows[1].Quantity`.
+//!
+//! Endpoints whose items carry `ModifiedUtc` also have an
+//! `update_if_unmodified` method that uses it to avoid overwriting changes
+//! made by someone else since the item was fetched.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::diff::{diff, ConditionalUpdate};
+//! use spiris::{AccessToken, Client, CustomerId};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//! let id: CustomerId = "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse()?;
+//!
+//! let original = client.customers().get(&id).await?;
+//! let mut edited = original.clone();
+//! edited.email = Some("billing@example.com".to_string());
+//!
+//! for change in diff(&original, &edited)? {
+//!     println!("{}", change); // Email: null -> "billing@example.com"
+//! }
+//!
+//! match client.customers().update_if_unmodified(&id, &original, &edited).await? {
+//!     ConditionalUpdate::Updated(customer) => println!("saved {:?}", customer.id),
+//!     ConditionalUpdate::Conflict(conflict) => {
+//!         for change in &conflict.theirs {
+//!             println!("changed by someone else: {}", change);
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::types::Modified;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// A field that differs between two values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Path of the field, e.g. `InvoiceAddress.City` or `Rows[0].Quantity`.
+    pub path: String,
+    /// The old value; `null` if the field was absent.
+    pub old: Value,
+    /// The new value; `null` if the field was removed.
+    pub new: Value,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.path, self.old, self.new)
+    }
+}
+
+/// List the fields that differ between `old` and `new`.
+///
+/// Nested objects are compared field by field and arrays element by
+/// element. Absent fields are treated as `null`.
+pub fn diff<T: Serialize + ?Sized>(old: &T, new: &T) -> Result<Vec<FieldChange>> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+    let mut changes = Vec::new();
+    diff_values(String::new(), &old, &new, &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(path: String, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_values(
+                    child,
+                    old_map.get(key).unwrap_or(&Value::Null),
+                    new_map.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items)) => {
+            for index in 0..old_items.len().max(new_items.len()) {
+                diff_values(
+                    format!("{}[{}]", path, index),
+                    old_items.get(index).unwrap_or(&Value::Null),
+                    new_items.get(index).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        _ if old != new => changes.push(FieldChange {
+            path,
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Result of an `update_if_unmodified` call.
+#[derive(Debug, Clone)]
+pub enum ConditionalUpdate<T> {
+    /// The server copy was unchanged and the update was sent.
+    Updated(T),
+    /// The server copy changed since the original was fetched; nothing was
+    /// sent.
+    Conflict(UpdateConflict<T>),
+}
+
+impl<T> ConditionalUpdate<T> {
+    /// Get the updated item, or `None` on conflict.
+    pub fn updated(self) -> Option<T> {
+        match self {
+            ConditionalUpdate::Updated(item) => Some(item),
+            ConditionalUpdate::Conflict(_) => None,
+        }
+    }
+
+    /// Check if the update was rejected because of a conflict.
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, ConditionalUpdate::Conflict(_))
+    }
+}
+
+/// Changes made on the server since an item was fetched.
+#[derive(Debug, Clone)]
+pub struct UpdateConflict<T> {
+    /// The item as it is now on the server.
+    pub current: T,
+    /// Fields changed on the server since the original was fetched.
+    pub theirs: Vec<FieldChange>,
+    /// Fields changed locally.
+    pub ours: Vec<FieldChange>,
+    /// Paths changed both locally and on the server, to different values.
+    pub overlapping: Vec<String>,
+}
+
+impl<T: Serialize + Modified> UpdateConflict<T> {
+    /// Compare the server copy with the original.
+    ///
+    /// Returns `None` if `current` still matches `original`, both in
+    /// `ModifiedUtc` and field values.
+    pub fn detect(original: &T, modified: &T, current: T) -> Result<Option<Self>> {
+        let theirs = diff(original, &current)?;
+        if theirs.is_empty() && original.modified_utc() == current.modified_utc() {
+            return Ok(None);
+        }
+
+        let ours = diff(original, modified)?;
+        let overlapping = ours
+            .iter()
+            .filter(|change| {
+                theirs
+                    .iter()
+                    .any(|their| their.path == change.path && their.new != change.new)
+            })
+            .map(|change| change.path.clone())
+            .collect();
+
+        Ok(Some(Self {
+            current,
+            theirs,
+            ours,
+            overlapping,
+        }))
+    }
+}
+
+impl<T> UpdateConflict<T> {
+    /// Check if the local and server changes touch different fields.
+    pub fn is_disjoint(&self) -> bool {
+        self.overlapping.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Address, Customer, Invoice, InvoiceRow};
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    fn customer(name: &str) -> Customer {
+        Customer {
+            id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
+            name: Some(name.to_string()),
+            modified_utc: Some(Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_nested_paths() {
+        let old = customer("Acme");
+        let mut new = old.clone();
+        new.name = Some("Acme AB".to_string());
+        new.invoice_address = Some(Address {
+            city: Some("Lund".to_string()),
+            ..Default::default()
+        });
+
+        let changes = diff(&old, &new).unwrap();
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["InvoiceAddress", "Name"]);
+        assert_eq!(changes[1].old, json!("Acme"));
+        assert_eq!(changes[1].to_string(), r#"Name: "Acme" -> "Acme AB""#);
+
+        let mut moved = new.clone();
+        moved.invoice_address.as_mut().unwrap().city = Some("Malmö".to_string());
+        let changes = diff(&new, &moved).unwrap();
+        assert_eq!(changes[0].path, "InvoiceAddress.City");
+    }
+
+    #[test]
+    fn test_diff_compares_array_elements() {
+        let row = |text: &str| InvoiceRow {
+            text: Some(text.to_string()),
+            ..Default::default()
+        };
+        let old = Invoice {
+            rows: vec![row("Consulting")],
+            ..Default::default()
+        };
+        let new = Invoice {
+            rows: vec![row("Travel"), row("Consulting")],
+            ..Default::default()
+        };
+
+        let changes = diff(&old, &new).unwrap();
+        assert_eq!(changes[0].path, "Rows[0].Text");
+        assert_eq!(changes[1].path, "Rows[1]");
+        assert_eq!(changes[1].old, Value::Null);
+        assert!(diff(&old, &old).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_detect_conflicts() {
+        let original = customer("Acme");
+        let mut ours = original.clone();
+        ours.email = Some("a@example.com".to_string());
+
+        assert!(UpdateConflict::detect(&original, &ours, original.clone())
+            .unwrap()
+            .is_none());
+
+        let mut current = customer("Acme Holding");
+        current.modified_utc = Some(Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap());
+        let conflict = UpdateConflict::detect(&original, &ours, current.clone())
+            .unwrap()
+            .unwrap();
+        assert!(conflict.is_disjoint());
+        assert_eq!(conflict.ours.len(), 1);
+        assert_eq!(conflict.theirs.len(), 2);
+
+        ours.name = Some("Acme AB".to_string());
+        let conflict = UpdateConflict::detect(&original, &ours, current)
+            .unwrap()
+            .unwrap();
+        assert_eq!(conflict.overlapping, vec!["Name".to_string()]);
+    }
+}
@@ -0,0 +1,746 @@
+//! Schema drift detection.
+//!
+//! A [`SchemaDriftChecker`] compares each JSON response with the type it is
+//! decoded into, such as [`Customer`](crate::Customer) or
+//! [`PaginatedResponse<Invoice>`](crate::PaginatedResponse), and reports:
+//!
+//! - **new fields**: fields the type does not model (kept in `extra`),
+//! - **missing fields**: modelled fields the endpoint returned before but
+//!   left out this time,
+//! - **type mismatches**: fields whose value does not fit the modelled type,
+//!   which makes the request fail.
+//!
+//! It also counts the modelled fields that come back `null`. Findings are
+//! aggregated per endpoint, e.g. `GET /customers/{id}`. Each finding is
+//! passed to the [`on_drift`](SchemaDriftChecker::on_drift) callback and,
+//! with the `tracing` feature, logged as a warning the first time it is seen.
+//!
+//! Field paths use the API field names, with `[]` for array elements, e.g.
+//! `Data[].Rows[].UnitPrice`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::drift::SchemaDriftChecker;
+//! use spiris::{AccessToken, Client, ClientConfig};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let checker = SchemaDriftChecker::new().on_drift(|drift| {
+//!     eprintln!("{}: new {:?}", drift.endpoint, drift.new_fields);
+//! });
+//! let client = Client::with_config(
+//!     AccessToken::new("token".to_string(), 3600, None),
+//!     ClientConfig::new().schema_drift(checker.clone()),
+//! );
+//!
+//! client.customers().list(None).await?;
+//!
+//! for (endpoint, drift) in checker.report() {
+//!     println!("{}: {:?}", endpoint, drift.null_fields);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Drift found in a single response.
+///
+/// Only findings not seen before for the endpoint are included.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Drift {
+    /// Endpoint, e.g. `GET /customers/{id}`.
+    pub endpoint: String,
+    /// Fields the target type does not model.
+    pub new_fields: Vec<String>,
+    /// Modelled fields the endpoint returned before but not this time.
+    pub missing_fields: Vec<String>,
+    /// Fields whose value does not fit the modelled type.
+    pub type_mismatches: Vec<String>,
+}
+
+impl Drift {
+    /// Check if nothing was found.
+    pub fn is_empty(&self) -> bool {
+        self.new_fields.is_empty()
+            && self.missing_fields.is_empty()
+            && self.type_mismatches.is_empty()
+    }
+}
+
+/// Drift aggregated over all responses from one endpoint.
+///
+/// The maps count the responses each field was reported in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EndpointDrift {
+    /// Number of responses checked.
+    pub responses: u64,
+    /// Fields the target type does not model.
+    pub new_fields: BTreeMap<String, u64>,
+    /// Modelled fields the endpoint returned before but left out.
+    pub missing_fields: BTreeMap<String, u64>,
+    /// Fields whose value does not fit the modelled type.
+    pub type_mismatches: BTreeMap<String, u64>,
+    /// Modelled fields that came back `null`.
+    pub null_fields: BTreeMap<String, u64>,
+}
+
+#[derive(Default)]
+struct EndpointState {
+    drift: EndpointDrift,
+    /// Modelled fields returned so far.
+    seen: BTreeSet<String>,
+}
+
+type Callback = Arc<dyn Fn(&Drift) + Send + Sync>;
+
+/// Opt-in checker that compares responses with the types they decode into.
+///
+/// Register it with
+/// [`ClientConfig::schema_drift`](crate::ClientConfig::schema_drift). Cheap
+/// to clone; clones share the same report.
+#[derive(Clone, Default)]
+pub struct SchemaDriftChecker {
+    endpoints: Arc<Mutex<HashMap<String, EndpointState>>>,
+    callback: Option<Callback>,
+}
+
+impl fmt::Debug for SchemaDriftChecker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaDriftChecker")
+            .field("endpoints", &self.lock().len())
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl SchemaDriftChecker {
+    /// Create a checker with no callback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` when a response shows drift not seen before for its
+    /// endpoint.
+    pub fn on_drift<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Drift) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Get the aggregated drift of every endpoint checked so far.
+    pub fn report(&self) -> BTreeMap<String, EndpointDrift> {
+        self.lock()
+            .iter()
+            .map(|(endpoint, state)| (endpoint.clone(), state.drift.clone()))
+            .collect()
+    }
+
+    /// Get the aggregated drift of one endpoint, e.g. `GET /customers`.
+    pub fn endpoint(&self, endpoint: &str) -> Option<EndpointDrift> {
+        self.lock().get(endpoint).map(|state| state.drift.clone())
+    }
+
+    /// Forget everything checked so far.
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, EndpointState>> {
+        self.endpoints.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Decode a response body and record its drift.
+    pub(crate) fn decode<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        json: &[u8],
+    ) -> serde_json::Result<T> {
+        let (result, shape) = probe::<T>(json);
+        let mismatch = match &result {
+            Err(error) if error.classify() == serde_json::error::Category::Data => Some((
+                shape.failed_at.clone().unwrap_or_default(),
+                error.to_string(),
+            )),
+            _ => None,
+        };
+        if result.is_ok() || mismatch.is_some() {
+            self.record(endpoint, shape, mismatch);
+        }
+        result
+    }
+
+    fn record(&self, endpoint: &str, shape: Shape, mismatch: Option<(String, String)>) {
+        let mut endpoints = self.lock();
+        let state = endpoints.entry(endpoint.to_string()).or_default();
+        let totals = &mut state.drift;
+        totals.responses += 1;
+
+        let mut missing: BTreeSet<String> = state
+            .seen
+            .iter()
+            .filter(|path| !shape.fields.contains(*path) && shape.objects.contains(parent(path)))
+            .cloned()
+            .collect();
+        let mut mismatches = BTreeSet::new();
+        if let Some((path, message)) = mismatch {
+            match message
+                .strip_prefix("missing field `")
+                .and_then(|rest| rest.split('`').next())
+            {
+                Some(field) => missing.insert(join(&path, field)),
+                None => mismatches.insert(if path.is_empty() {
+                    "(root)".to_string()
+                } else {
+                    path
+                }),
+            };
+        }
+        state.seen.extend(shape.fields.iter().cloned());
+
+        let mut drift = Drift {
+            endpoint: endpoint.to_string(),
+            ..Default::default()
+        };
+        for (found, counts, first_seen) in [
+            (
+                &shape.new_fields,
+                &mut totals.new_fields,
+                &mut drift.new_fields,
+            ),
+            (
+                &missing,
+                &mut totals.missing_fields,
+                &mut drift.missing_fields,
+            ),
+            (
+                &mismatches,
+                &mut totals.type_mismatches,
+                &mut drift.type_mismatches,
+            ),
+        ] {
+            for path in found {
+                let count = counts.entry(path.clone()).or_insert(0);
+                if *count == 0 {
+                    first_seen.push(path.clone());
+                }
+                *count += 1;
+            }
+        }
+        for path in &shape.nulls {
+            *totals.null_fields.entry(path.clone()).or_insert(0) += 1;
+        }
+        drop(endpoints);
+
+        if drift.is_empty() {
+            return;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            endpoint = %drift.endpoint,
+            new_fields = ?drift.new_fields,
+            missing_fields = ?drift.missing_fields,
+            type_mismatches = ?drift.type_mismatches,
+            "Schema drift detected"
+        );
+        if let Some(callback) = &self.callback {
+            callback(&drift);
+        }
+    }
+}
+
+/// Name an endpoint by method and path, with GUID segments replaced by `{id}`.
+pub(crate) fn endpoint_name(method: &str, base_url: &str, url: &str) -> String {
+    let path = url
+        .strip_prefix(base_url.trim_end_matches('/'))
+        .unwrap_or(url);
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let path: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            if crate::ids::is_guid(segment) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    format!("{} {}", method, path.join("/"))
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('.').map_or("", |(parent, _)| parent)
+}
+
+/// The fields a response provided, as seen while decoding it.
+#[derive(Debug, Default)]
+struct Shape {
+    /// Modelled fields present, including `null` ones.
+    fields: BTreeSet<String>,
+    /// Modelled fields that were `null`.
+    nulls: BTreeSet<String>,
+    /// Fields the type does not model.
+    new_fields: BTreeSet<String>,
+    /// Typed objects present; `""` is the root.
+    objects: BTreeSet<String>,
+    /// Innermost path where decoding failed.
+    failed_at: Option<String>,
+}
+
+fn probe<T: DeserializeOwned>(json: &[u8]) -> (serde_json::Result<T>, Shape) {
+    let shape = RefCell::new(Shape::default());
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let result = T::deserialize(Probe {
+        de: &mut deserializer,
+        shape: &shape,
+        path: String::new(),
+        entry: false,
+    })
+    .and_then(|value| deserializer.end().map(|()| value));
+    (result, shape.into_inner())
+}
+
+/// Deserializer wrapper that records the shape of the value.
+///
+/// Typed objects (structs and maps) are deserialized with
+/// `deserialize_struct` or `deserialize_map`. Their modelled fields ask for
+/// a specific type, while fields collected into a flattened `extra` map are
+/// deserialized with `deserialize_any`.
+struct Probe<'s, D> {
+    de: D,
+    shape: &'s RefCell<Shape>,
+    path: String,
+    /// Whether this is the value of a field in a typed object.
+    entry: bool,
+}
+
+impl<'s, D> Probe<'s, D> {
+    fn wrap<V>(&self, visitor: V, typed: bool, nullable: bool) -> ProbeVisitor<'s, V> {
+        ProbeVisitor {
+            visitor,
+            shape: self.shape,
+            path: self.path.clone(),
+            typed,
+            nullable,
+        }
+    }
+
+    fn field(&self) {
+        if self.entry {
+            self.shape.borrow_mut().fields.insert(self.path.clone());
+        }
+    }
+}
+
+/// Record where decoding failed; the innermost path is reported first.
+fn failed<E>(shape: &RefCell<Shape>, path: &str, error: E) -> E {
+    shape
+        .borrow_mut()
+        .failed_at
+        .get_or_insert_with(|| path.to_string());
+    error
+}
+
+macro_rules! probe_methods {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.field();
+                let visitor = self.wrap(visitor, false, false);
+                let result = self.de.$method($($arg,)* visitor);
+                result.map_err(|e| failed(self.shape, &self.path, e))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Probe<'_, D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        if self.entry {
+            // Not a modelled field; its contents are not part of the schema.
+            self.shape.borrow_mut().new_fields.insert(self.path.clone());
+            return self.de.deserialize_any(visitor);
+        }
+        let visitor = self.wrap(visitor, false, false);
+        let result = self.de.deserialize_any(visitor);
+        result.map_err(|e| failed(self.shape, &self.path, e))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        self.field();
+        let visitor = self.wrap(visitor, false, self.entry);
+        let result = self.de.deserialize_option(visitor);
+        result.map_err(|e| failed(self.shape, &self.path, e))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        self.field();
+        self.shape.borrow_mut().objects.insert(self.path.clone());
+        let visitor = self.wrap(visitor, true, false);
+        let result = self.de.deserialize_map(visitor);
+        result.map_err(|e| failed(self.shape, &self.path, e))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.field();
+        self.shape.borrow_mut().objects.insert(self.path.clone());
+        let visitor = self.wrap(visitor, true, false);
+        let result = self.de.deserialize_struct(name, fields, visitor);
+        result.map_err(|e| failed(self.shape, &self.path, e))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.field();
+        let result = self.de.deserialize_enum(name, variants, visitor);
+        result.map_err(|e| failed(self.shape, &self.path, e))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+        self.de.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.de.is_human_readable()
+    }
+
+    probe_methods! {
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_identifier();
+    }
+}
+
+/// Visitor wrapper that keeps probing nested values.
+struct ProbeVisitor<'s, V> {
+    visitor: V,
+    shape: &'s RefCell<Shape>,
+    path: String,
+    /// Whether map values are fields of a typed object.
+    typed: bool,
+    /// Whether a `null` should be recorded as a null field.
+    nullable: bool,
+}
+
+macro_rules! forward_visits {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.visitor.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for ProbeVisitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        if self.nullable {
+            self.shape.borrow_mut().nulls.insert(self.path.clone());
+        }
+        self.visitor.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.visitor.visit_some(Probe {
+            de: deserializer,
+            shape: self.shape,
+            path: self.path,
+            entry: false,
+        })
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.visitor.visit_newtype_struct(Probe {
+            de: deserializer,
+            shape: self.shape,
+            path: self.path,
+            entry: false,
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.visitor.visit_seq(ProbeSeq {
+            seq,
+            shape: self.shape,
+            path: format!("{}[]", self.path),
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.visitor.visit_map(ProbeMap {
+            map,
+            shape: self.shape,
+            path: self.path,
+            typed: self.typed,
+            key: None,
+        })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.visitor.visit_enum(data)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        self.visitor.visit_borrowed_str(value)
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, value: &'de [u8]) -> Result<Self::Value, E> {
+        self.visitor.visit_borrowed_bytes(value)
+    }
+
+    forward_visits! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+}
+
+struct ProbeSeq<'s, A> {
+    seq: A,
+    shape: &'s RefCell<Shape>,
+    path: String,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for ProbeSeq<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        self.seq.next_element_seed(ProbeSeed {
+            seed,
+            shape: self.shape,
+            path: self.path.clone(),
+            entry: false,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.seq.size_hint()
+    }
+}
+
+struct ProbeMap<'s, A> {
+    map: A,
+    shape: &'s RefCell<Shape>,
+    path: String,
+    typed: bool,
+    key: Option<String>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for ProbeMap<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let Some(key) = self.map.next_key::<String>()? else {
+            return Ok(None);
+        };
+        self.key = Some(key.clone());
+        seed.deserialize(de::value::StringDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, A::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.map.next_value_seed(ProbeSeed {
+            seed,
+            shape: self.shape,
+            path: join(&self.path, &key),
+            entry: self.typed,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+struct ProbeSeed<'s, S> {
+    seed: S,
+    shape: &'s RefCell<Shape>,
+    path: String,
+    entry: bool,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for ProbeSeed<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.seed.deserialize(Probe {
+            de: deserializer,
+            shape: self.shape,
+            path: self.path,
+            entry: self.entry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Customer, Invoice, PaginatedResponse};
+
+    const META: &str = r#""Meta": {"CurrentPage": 0, "PageSize": 50, "TotalPages": 1, "TotalCount": 1,
+        "HasNextPage": false, "HasPreviousPage": false}"#;
+
+    #[test]
+    fn test_probe_classifies_fields() {
+        let json = format!(
+            r#"{{"Data": [{{"InvoiceNumber": "1", "Remarks": null, "Origin": "Web",
+                "Rows": [{{"Text": "A", "Margin": {{"Value": 3}}}}]}}], {}}}"#,
+            META
+        );
+        let (result, shape) = probe::<PaginatedResponse<Invoice>>(json.as_bytes());
+        let page = result.unwrap();
+        assert_eq!(page.data[0].extra["Origin"], "Web");
+
+        let new: Vec<_> = shape.new_fields.iter().map(String::as_str).collect();
+        assert_eq!(new, vec!["Data[].Origin", "Data[].Rows[].Margin"]);
+        assert!(shape.fields.contains("Data[].InvoiceNumber"));
+        assert!(shape.fields.contains("Meta.TotalCount"));
+        assert!(shape.fields.contains("Data[].Rows[].Text"));
+        assert_eq!(
+            shape.nulls.iter().collect::<Vec<_>>(),
+            vec!["Data[].Remarks"]
+        );
+        assert!(shape.objects.contains("Data[].Rows[]"));
+    }
+
+    #[test]
+    fn test_untyped_values_have_no_schema() {
+        let (result, shape) = probe::<serde_json::Value>(br#"{"A": {"B": [1, null]}}"#);
+        assert!(result.is_ok());
+        assert!(shape.fields.is_empty() && shape.new_fields.is_empty());
+    }
+
+    #[test]
+    fn test_checker_aggregates_per_endpoint() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = reported.clone();
+        let checker = SchemaDriftChecker::new().on_drift(move |drift| {
+            sink.lock().unwrap().push(drift.clone());
+        });
+        let endpoint = "GET /customers/{id}";
+
+        let customer: Customer = checker
+            .decode(endpoint, br#"{"Name": "Acme", "Email": null, "Tier": 1}"#)
+            .unwrap();
+        assert_eq!(customer.extra["Tier"], 1);
+        checker
+            .decode::<Customer>(endpoint, br#"{"Email": "a@example.com", "Tier": 2}"#)
+            .unwrap();
+        let err = checker
+            .decode::<Customer>(endpoint, br#"{"Name": 42}"#)
+            .unwrap_err();
+        assert!(err.is_data());
+
+        let drift = checker.endpoint(endpoint).unwrap();
+        assert_eq!(drift.responses, 3);
+        assert_eq!(drift.new_fields["Tier"], 2);
+        assert_eq!(drift.null_fields["Email"], 1);
+        assert_eq!(drift.missing_fields["Name"], 1);
+        assert_eq!(drift.missing_fields["Email"], 1);
+        assert_eq!(drift.type_mismatches["Name"], 1);
+
+        // The callback only hears about each finding once.
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 3);
+        assert_eq!(reported[0].new_fields, vec!["Tier"]);
+        assert_eq!(reported[1].missing_fields, vec!["Name"]);
+        assert_eq!(reported[2].type_mismatches, vec!["Name"]);
+    }
+
+    #[test]
+    fn test_endpoint_name() {
+        assert_eq!(
+            endpoint_name(
+                "GET",
+                "https://example.com/v2/",
+                "https://example.com/v2/customers/5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b?$select=Name"
+            ),
+            "GET /customers/{id}"
+        );
+    }
+}
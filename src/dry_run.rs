@@ -0,0 +1,155 @@
+//! Dry-run mode: record mutations instead of sending them.
+//!
+//! With [`ClientConfig::dry_run`](crate::ClientConfig::dry_run) enabled, `GET`
+//! requests are sent as usual, but `POST`, `PUT`, `PATCH` and `DELETE`
+//! requests are added to a [`DryRunPlan`] and answered locally. The
+//! synthetic response echoes the request body, so `create` and `update`
+//! return the item that would have been sent, and `delete` succeeds.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::{AccessToken, Client, ClientConfig, Customer};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::with_config(
+//!     AccessToken::new("token".to_string(), 3600, None),
+//!     ClientConfig::new().dry_run(true),
+//! );
+//!
+//! let customer = Customer {
+//!     name: Some("Acme AB".to_string()),
+//!     ..Default::default()
+//! };
+//! client.customers().create(&customer).await?;
+//!
+//! for request in client.dry_run_plan().unwrap().requests() {
+//!     println!("{}", request);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::response::Response;
+use reqwest::{Method, StatusCode};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A request that would have been sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Full request URL, including query parameters.
+    pub url: String,
+    /// Request body; non-JSON bodies are kept as a string.
+    pub body: Option<serde_json::Value>,
+}
+
+impl fmt::Display for PlannedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        if let Some(body) = &self.body {
+            write!(f, " {}", body)?;
+        }
+        Ok(())
+    }
+}
+
+/// The mutations recorded by a dry-run client, in the order they were made.
+///
+/// Cheap to clone; clones share the same list.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunPlan {
+    requests: Arc<Mutex<Vec<PlannedRequest>>>,
+}
+
+impl DryRunPlan {
+    /// Create an empty plan.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the recorded requests.
+    pub fn requests(&self) -> Vec<PlannedRequest> {
+        self.lock().clone()
+    }
+
+    /// Remove and return the recorded requests.
+    pub fn take(&self) -> Vec<PlannedRequest> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Number of recorded requests.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Check if no requests were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<PlannedRequest>> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Check if a request with this method is recorded instead of sent.
+    pub(crate) fn intercepts(method: &Method) -> bool {
+        !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+    }
+
+    /// Record a request and build the synthetic response.
+    pub(crate) fn record(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Response<reqwest::Response>> {
+        let request = request.build()?;
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(<[u8]>::to_vec);
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(method = %request.method(), url = %request.url(), "Dry run: request recorded");
+
+        self.lock().push(PlannedRequest {
+            method: request.method().clone(),
+            url: request.url().to_string(),
+            body: body.as_deref().map(|bytes| {
+                serde_json::from_slice(bytes).unwrap_or_else(|_| {
+                    serde_json::Value::String(String::from_utf8_lossy(bytes).into_owned())
+                })
+            }),
+        });
+
+        let status = match *request.method() {
+            Method::POST => StatusCode::CREATED,
+            Method::DELETE => StatusCode::NO_CONTENT,
+            _ => StatusCode::OK,
+        };
+        // Actions such as `convert` send no body but return an object.
+        let echo = match body {
+            Some(body) if body != b"null" => body,
+            _ if status == StatusCode::NO_CONTENT => Vec::new(),
+            _ => b"{}".to_vec(),
+        };
+        let echo = http::Response::builder()
+            .status(status)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(echo)
+            .expect("synthetic response is valid");
+        let response = reqwest::Response::from(echo);
+
+        Ok(Response {
+            status: status.as_u16(),
+            headers: response.headers().clone(),
+            elapsed: Duration::ZERO,
+            attempts: 0,
+            cached_at: None,
+            data: response,
+        })
+    }
+}
@@ -2,16 +2,25 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{Account, AccountBalance, AccountType, PaginatedResponse, PaginationParams};
+use crate::ids::FiscalYearId;
+use crate::types::{
+    Account, AccountBalance, AccountType, CreateRequest, PaginatedResponse, PaginationParams,
+};
+use std::borrow::Cow;
 
 /// Accounts endpoint for managing chart of accounts.
+#[derive(Clone)]
 pub struct AccountsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(AccountsEndpoint);
+
 impl<'a> AccountsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all accounts with optional pagination.
@@ -29,7 +38,7 @@ impl<'a> AccountsEndpoint<'a> {
     /// List accounts for a specific fiscal year.
     pub async fn list_by_fiscal_year(
         &self,
-        fiscal_year_id: &str,
+        fiscal_year_id: &FiscalYearId,
         params: Option<PaginationParams>,
     ) -> Result<PaginatedResponse<Account>> {
         let path = format!("/accounts/{}", fiscal_year_id);
@@ -41,20 +50,24 @@ impl<'a> AccountsEndpoint<'a> {
     }
 
     /// Get a specific account by fiscal year and account number.
-    pub async fn get(&self, fiscal_year_id: &str, account_number: &str) -> Result<Account> {
+    pub async fn get(
+        &self,
+        fiscal_year_id: &FiscalYearId,
+        account_number: &str,
+    ) -> Result<Account> {
         let path = format!("/accounts/{}/{}", fiscal_year_id, account_number);
         self.client.get(&path).await
     }
 
     /// Create a new account.
-    pub async fn create(&self, account: &Account) -> Result<Account> {
+    pub async fn create(&self, account: &impl CreateRequest<Account>) -> Result<Account> {
         self.client.post("/accounts", account).await
     }
 
     /// Update an existing account.
     pub async fn update(
         &self,
-        fiscal_year_id: &str,
+        fiscal_year_id: &FiscalYearId,
         account_number: &str,
         account: &Account,
     ) -> Result<Account> {
@@ -84,3 +97,8 @@ impl<'a> AccountsEndpoint<'a> {
         self.client.get("/accountTypes").await
     }
 }
+
+impl AccountsEndpoint<'_> {
+    crate::__endpoint_meta_impl!("/accounts", crate::types::Account, str, list, create);
+    crate::__endpoint_stream_impl!("/accounts", crate::types::Account);
+}
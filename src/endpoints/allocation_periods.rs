@@ -1,9 +1,10 @@
 //! Allocation periods API endpoint.
 
+use crate::ids::AllocationPeriodId;
 use crate::types::AllocationPeriod;
 
 crate::define_endpoint! {
     /// Allocation periods endpoint for managing accounting periods.
-    AllocationPeriodsEndpoint, "/allocationperiods", AllocationPeriod,
-    caps: [list, get, create]
+    AllocationPeriodsEndpoint, "/allocationperiods", AllocationPeriod, id: AllocationPeriodId,
+    caps: [list, get, create, stream]
 }
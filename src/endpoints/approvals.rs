@@ -2,7 +2,9 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::{SupplierInvoiceId, VatReportId};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -11,25 +13,38 @@ pub struct ApprovalAction {
     pub comment: Option<String>,
 }
 
+#[derive(Clone)]
 pub struct ApprovalsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(ApprovalsEndpoint);
+
 impl<'a> ApprovalsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
-    pub async fn approve_vat_report(&self, id: &str, action: &ApprovalAction) -> Result<()> {
+    pub async fn approve_vat_report(
+        &self,
+        id: &VatReportId,
+        action: &ApprovalAction,
+    ) -> Result<()> {
         self.client
-            .put::<(), _>(&format!("/approval/vatreport/{}", id), action)
+            .put::<serde::de::IgnoredAny, _>(&format!("/approval/vatreport/{}", id), action)
             .await?;
         Ok(())
     }
 
-    pub async fn approve_supplier_invoice(&self, id: &str, action: &ApprovalAction) -> Result<()> {
+    pub async fn approve_supplier_invoice(
+        &self,
+        id: &SupplierInvoiceId,
+        action: &ApprovalAction,
+    ) -> Result<()> {
         self.client
-            .put::<(), _>(&format!("/approval/supplierinvoice/{}", id), action)
+            .put::<serde::de::IgnoredAny, _>(&format!("/approval/supplierinvoice/{}", id), action)
             .await?;
         Ok(())
     }
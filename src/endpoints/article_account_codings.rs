@@ -1,9 +1,10 @@
 //! Article account codings API endpoint.
 
+use crate::ids::ArticleAccountCodingId;
 use crate::types::ArticleAccountCoding;
 
 crate::define_endpoint! {
     /// Article account codings endpoint for accessing article accounting mappings.
-    ArticleAccountCodingsEndpoint, "/articleaccountcodings", ArticleAccountCoding,
-    caps: [list, get]
+    ArticleAccountCodingsEndpoint, "/articleaccountcodings", ArticleAccountCoding, id: ArticleAccountCodingId,
+    caps: [list, get, stream]
 }
@@ -1,9 +1,10 @@
 //! Article labels API endpoint.
 
+use crate::ids::ArticleLabelId;
 use crate::types::ArticleLabel;
 
 crate::define_endpoint! {
     /// Article labels endpoint for managing article categorization.
-    ArticleLabelsEndpoint, "/articlelabels", ArticleLabel,
-    caps: [list, get, create, update, delete]
+    ArticleLabelsEndpoint, "/articlelabels", ArticleLabel, id: ArticleLabelId,
+    caps: [list, get, create, update, delete, stream]
 }
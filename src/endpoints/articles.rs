@@ -2,7 +2,11 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{Article, PaginatedResponse, PaginationParams, QueryParams};
+use crate::ids::ArticleId;
+use crate::types::{
+    Article, CreateRequest, PaginatedResponse, PaginationParams, QueryParams, UpdateRequest,
+};
+use std::borrow::Cow;
 
 /// Articles endpoint for managing products and services.
 ///
@@ -24,14 +28,19 @@ use crate::types::{Article, PaginatedResponse, PaginationParams, QueryParams};
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct ArticlesEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(ArticlesEndpoint);
+
 impl<'a> ArticlesEndpoint<'a> {
     /// Create a new articles endpoint.
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all articles with optional pagination.
@@ -70,14 +79,14 @@ impl<'a> ArticlesEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let article = client.articles().get("article-id-123").await?;
+    /// # use spiris::{ArticleId, Client};
+    /// # async fn example(client: &Client, id: &ArticleId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let article = client.articles().get(id).await?;
     /// println!("Article: {:?}", article.name);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, id: &str) -> Result<Article> {
+    pub async fn get(&self, id: &ArticleId) -> Result<Article> {
         let path = format!("/articles/{}", id);
         self.client.get(&path).await
     }
@@ -103,7 +112,7 @@ impl<'a> ArticlesEndpoint<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create(&self, article: &Article) -> Result<Article> {
+    pub async fn create(&self, article: &impl CreateRequest<Article>) -> Result<Article> {
         self.client.post("/articles", article).await
     }
 
@@ -117,15 +126,19 @@ impl<'a> ArticlesEndpoint<'a> {
     /// # Example
     ///
     /// ```ignore
-    /// # use spiris::{Client, money};
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let mut article = client.articles().get("article-id-123").await?;
+    /// # use spiris::{money, ArticleId, Client};
+    /// # async fn example(client: &Client, id: &ArticleId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut article = client.articles().get(id).await?;
     /// article.sales_price = Some(money!(1200.0));
-    /// let updated = client.articles().update("article-id-123", &article).await?;
+    /// let updated = client.articles().update(id, &article).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update(&self, id: &str, article: &Article) -> Result<Article> {
+    pub async fn update(
+        &self,
+        id: &ArticleId,
+        article: &impl UpdateRequest<Article>,
+    ) -> Result<Article> {
         let path = format!("/articles/{}", id);
         self.client.put(&path, article).await
     }
@@ -139,13 +152,13 @@ impl<'a> ArticlesEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// client.articles().delete("article-id-123").await?;
+    /// # use spiris::{ArticleId, Client};
+    /// # async fn example(client: &Client, id: &ArticleId) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.articles().delete(id).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &ArticleId) -> Result<()> {
         let path = format!("/articles/{}", id);
         self.client.delete(&path).await
     }
@@ -185,23 +198,21 @@ impl<'a> ArticlesEndpoint<'a> {
         let params = CombinedParams { query, pagination };
         self.client.get_with_params("/articles", &params).await
     }
+}
 
-    /// Stream all articles, automatically paginating through results.
-    ///
-    /// Requires the `stream` feature.
-    #[cfg(feature = "stream")]
-    pub fn list_stream(&self) -> impl futures::Stream<Item = Result<Article>> + '_ {
-        self.list_stream_with_page_size(crate::pagination::DEFAULT_PAGE_SIZE)
-    }
-
-    /// Stream all articles with a custom page size.
-    ///
-    /// Requires the `stream` feature.
-    #[cfg(feature = "stream")]
-    pub fn list_stream_with_page_size(
-        &self,
-        page_size: u32,
-    ) -> impl futures::Stream<Item = Result<Article>> + '_ {
-        crate::paginated_stream!(page_size, |params| self.list(Some(params)))
-    }
+impl ArticlesEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/articles",
+        crate::types::Article,
+        crate::ids::ArticleId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/articles", crate::types::Article);
+    crate::__endpoint_search_stream_impl!("/articles", crate::types::Article);
+    crate::__endpoint_conditional_update_impl!(crate::types::Article, crate::ids::ArticleId);
 }
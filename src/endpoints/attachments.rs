@@ -2,15 +2,22 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::AttachmentId;
 use crate::types::{Attachment, AttachmentLink, PaginatedResponse, PaginationParams};
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct AttachmentsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(AttachmentsEndpoint);
+
 impl<'a> AttachmentsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn list(
@@ -24,15 +31,15 @@ impl<'a> AttachmentsEndpoint<'a> {
         }
     }
 
-    pub async fn get(&self, id: &str) -> Result<Attachment> {
+    pub async fn get(&self, id: &AttachmentId) -> Result<Attachment> {
         self.client.get(&format!("/attachments/{}", id)).await
     }
 
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &AttachmentId) -> Result<()> {
         self.client.delete(&format!("/attachments/{}", id)).await
     }
 
-    pub async fn get_content(&self, id: &str) -> Result<Vec<u8>> {
+    pub async fn get_content(&self, id: &AttachmentId) -> Result<Vec<u8>> {
         self.client
             .get_bytes(&format!("/attachments/{}/content", id))
             .await
@@ -42,9 +49,21 @@ impl<'a> AttachmentsEndpoint<'a> {
         self.client.post("/attachmentlinks", link).await
     }
 
-    pub async fn delete_link(&self, id: &str) -> Result<()> {
+    pub async fn delete_link(&self, id: &AttachmentId) -> Result<()> {
         self.client
             .delete(&format!("/attachmentlinks/{}", id))
             .await
     }
 }
+
+impl AttachmentsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/attachments",
+        crate::types::Attachment,
+        crate::ids::AttachmentId,
+        list,
+        get,
+        delete
+    );
+    crate::__endpoint_stream_impl!("/attachments", crate::types::Attachment);
+}
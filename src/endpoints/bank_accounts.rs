@@ -1,9 +1,10 @@
 //! Bank accounts API endpoint.
 
+use crate::ids::BankAccountId;
 use crate::types::BankAccount;
 
 crate::define_endpoint! {
     /// Bank accounts endpoint for managing payment accounts.
-    BankAccountsEndpoint, "/bankaccounts", BankAccount,
-    caps: [list, get, create, update, delete]
+    BankAccountsEndpoint, "/bankaccounts", BankAccount, id: BankAccountId,
+    caps: [list, get, create, update, delete, stream]
 }
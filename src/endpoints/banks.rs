@@ -5,7 +5,7 @@ use crate::types::{Bank, ForeignPaymentCode, PaginatedResponse};
 crate::define_endpoint! {
     /// Banks endpoint for accessing bank information.
     BanksEndpoint, "/banks", Bank,
-    caps: [list],
+    caps: [list, stream],
     extra: {
         /// List all foreign payment codes.
         pub async fn list_foreign_payment_codes(
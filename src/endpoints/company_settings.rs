@@ -3,14 +3,20 @@
 use crate::client::Client;
 use crate::error::Result;
 use crate::types::CompanySettings;
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct CompanySettingsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(CompanySettingsEndpoint);
+
 impl<'a> CompanySettingsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn get(&self) -> Result<CompanySettings> {
@@ -1,11 +1,14 @@
 //! Cost centers API endpoint.
 
-use crate::types::{CostCenter, CostCenterItem, PaginatedResponse, PaginationParams};
+use crate::ids::{CostCenterId, CostCenterItemId};
+use crate::types::{
+    CostCenter, CostCenterItem, CreateRequest, PaginatedResponse, PaginationParams, UpdateRequest,
+};
 
 crate::define_endpoint! {
     /// Cost centers endpoint for managing cost center tracking.
-    CostCentersEndpoint, "/costcenters", CostCenter,
-    caps: [list, update],
+    CostCentersEndpoint, "/costcenters", CostCenter, id: CostCenterId,
+    caps: [list, update, stream],
     extra: {
         /// List all cost center items with optional pagination.
         pub async fn list_items(
@@ -22,17 +25,17 @@ crate::define_endpoint! {
         }
 
         /// Get a specific cost center item by ID.
-        pub async fn get_item(&self, id: &str) -> crate::error::Result<CostCenterItem> {
+        pub async fn get_item(&self, id: &CostCenterItemId) -> crate::error::Result<CostCenterItem> {
             self.client.get(&format!("/costcenteritems/{}", id)).await
         }
 
         /// Create a new cost center item.
-        pub async fn create_item(&self, item: &CostCenterItem) -> crate::error::Result<CostCenterItem> {
+        pub async fn create_item(&self, item: &impl CreateRequest<CostCenterItem>) -> crate::error::Result<CostCenterItem> {
             self.client.post("/costcenteritems", item).await
         }
 
         /// Update an existing cost center item.
-        pub async fn update_item(&self, id: &str, item: &CostCenterItem) -> crate::error::Result<CostCenterItem> {
+        pub async fn update_item(&self, id: &CostCenterItemId, item: &impl UpdateRequest<CostCenterItem>) -> crate::error::Result<CostCenterItem> {
             self.client
                 .put(&format!("/costcenteritems/{}", id), item)
                 .await
@@ -5,5 +5,5 @@ use crate::types::Country;
 crate::define_endpoint! {
     /// Countries endpoint for accessing available countries.
     CountriesEndpoint, "/countries", Country,
-    caps: [list, get]
+    caps: [list, get, stream]
 }
@@ -5,5 +5,5 @@ use crate::types::Currency;
 crate::define_endpoint! {
     /// Currencies endpoint for accessing available currencies.
     CurrenciesEndpoint, "/currencies", Currency,
-    caps: [list]
+    caps: [list, stream]
 }
@@ -2,19 +2,26 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::CustomerInvoiceDraftId;
 use crate::types::{
-    ConvertDraftOptions, CustomerInvoiceDraft, Invoice, PaginatedResponse, PaginationParams,
-    QueryParams,
+    ConvertDraftOptions, CreateRequest, CustomerInvoiceDraft, Invoice, PaginatedResponse,
+    PaginationParams, QueryParams, UpdateRequest,
 };
+use std::borrow::Cow;
 
 /// Customer invoice drafts endpoint for managing draft invoices.
+#[derive(Clone)]
 pub struct CustomerInvoiceDraftsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(CustomerInvoiceDraftsEndpoint);
+
 impl<'a> CustomerInvoiceDraftsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all customer invoice drafts with optional pagination.
@@ -32,28 +39,31 @@ impl<'a> CustomerInvoiceDraftsEndpoint<'a> {
     }
 
     /// Get a specific customer invoice draft by ID.
-    pub async fn get(&self, id: &str) -> Result<CustomerInvoiceDraft> {
+    pub async fn get(&self, id: &CustomerInvoiceDraftId) -> Result<CustomerInvoiceDraft> {
         let path = format!("/customerinvoicedrafts/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new customer invoice draft.
-    pub async fn create(&self, draft: &CustomerInvoiceDraft) -> Result<CustomerInvoiceDraft> {
+    pub async fn create(
+        &self,
+        draft: &impl CreateRequest<CustomerInvoiceDraft>,
+    ) -> Result<CustomerInvoiceDraft> {
         self.client.post("/customerinvoicedrafts", draft).await
     }
 
     /// Update an existing customer invoice draft.
     pub async fn update(
         &self,
-        id: &str,
-        draft: &CustomerInvoiceDraft,
+        id: &CustomerInvoiceDraftId,
+        draft: &impl UpdateRequest<CustomerInvoiceDraft>,
     ) -> Result<CustomerInvoiceDraft> {
         let path = format!("/customerinvoicedrafts/{}", id);
         self.client.put(&path, draft).await
     }
 
     /// Delete a customer invoice draft.
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &CustomerInvoiceDraftId) -> Result<()> {
         let path = format!("/customerinvoicedrafts/{}", id);
         self.client.delete(&path).await
     }
@@ -64,7 +74,11 @@ impl<'a> CustomerInvoiceDraftsEndpoint<'a> {
     ///
     /// * `id` - The draft ID to convert
     /// * `options` - Optional conversion options (send type, etc.)
-    pub async fn convert(&self, id: &str, options: Option<ConvertDraftOptions>) -> Result<Invoice> {
+    pub async fn convert(
+        &self,
+        id: &CustomerInvoiceDraftId,
+        options: Option<ConvertDraftOptions>,
+    ) -> Result<Invoice> {
         let path = format!("/customerinvoicedrafts/{}/convert", id);
         let body = options.unwrap_or_default();
         self.client.post(&path, &body).await
@@ -90,3 +104,26 @@ impl<'a> CustomerInvoiceDraftsEndpoint<'a> {
             .await
     }
 }
+
+impl CustomerInvoiceDraftsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/customerinvoicedrafts",
+        crate::types::CustomerInvoiceDraft,
+        crate::ids::CustomerInvoiceDraftId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/customerinvoicedrafts", crate::types::CustomerInvoiceDraft);
+    crate::__endpoint_search_stream_impl!(
+        "/customerinvoicedrafts",
+        crate::types::CustomerInvoiceDraft
+    );
+    crate::__endpoint_conditional_update_impl!(
+        crate::types::CustomerInvoiceDraft,
+        crate::ids::CustomerInvoiceDraftId
+    );
+}
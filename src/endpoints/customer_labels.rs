@@ -1,9 +1,10 @@
 //! Customer labels API endpoint.
 
+use crate::ids::CustomerLabelId;
 use crate::types::CustomerLabel;
 
 crate::define_endpoint! {
     /// Customer labels endpoint for managing customer categorization.
-    CustomerLabelsEndpoint, "/customerlabels", CustomerLabel,
-    caps: [list, get, create, update, delete]
+    CustomerLabelsEndpoint, "/customerlabels", CustomerLabel, id: CustomerLabelId,
+    caps: [list, get, create, update, delete, stream]
 }
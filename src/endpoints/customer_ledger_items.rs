@@ -2,16 +2,25 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{CustomerLedgerItem, PaginatedResponse, PaginationParams, QueryParams};
+use crate::ids::CustomerLedgerItemId;
+use crate::types::{
+    CreateRequest, CustomerLedgerItem, PaginatedResponse, PaginationParams, QueryParams,
+};
+use std::borrow::Cow;
 
 /// Customer ledger items endpoint for managing payment records.
+#[derive(Clone)]
 pub struct CustomerLedgerItemsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(CustomerLedgerItemsEndpoint);
+
 impl<'a> CustomerLedgerItemsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all customer ledger items with optional pagination.
@@ -29,13 +38,16 @@ impl<'a> CustomerLedgerItemsEndpoint<'a> {
     }
 
     /// Get a specific customer ledger item by ID.
-    pub async fn get(&self, id: &str) -> Result<CustomerLedgerItem> {
+    pub async fn get(&self, id: &CustomerLedgerItemId) -> Result<CustomerLedgerItem> {
         let path = format!("/customerledgeritems/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new customer ledger item.
-    pub async fn create(&self, item: &CustomerLedgerItem) -> Result<CustomerLedgerItem> {
+    pub async fn create(
+        &self,
+        item: &impl CreateRequest<CustomerLedgerItem>,
+    ) -> Result<CustomerLedgerItem> {
         self.client.post("/customerledgeritems", item).await
     }
 
@@ -59,3 +71,17 @@ impl<'a> CustomerLedgerItemsEndpoint<'a> {
             .await
     }
 }
+
+impl CustomerLedgerItemsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/customerledgeritems",
+        crate::types::CustomerLedgerItem,
+        crate::ids::CustomerLedgerItemId,
+        list,
+        get,
+        create,
+        search
+    );
+    crate::__endpoint_stream_impl!("/customerledgeritems", crate::types::CustomerLedgerItem);
+    crate::__endpoint_search_stream_impl!("/customerledgeritems", crate::types::CustomerLedgerItem);
+}
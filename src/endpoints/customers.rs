@@ -2,7 +2,11 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{Customer, PaginatedResponse, PaginationParams, QueryParams};
+use crate::ids::CustomerId;
+use crate::types::{
+    CreateRequest, Customer, PaginatedResponse, PaginationParams, QueryParams, UpdateRequest,
+};
+use std::borrow::Cow;
 
 /// Customers endpoint for managing customer records.
 ///
@@ -24,14 +28,19 @@ use crate::types::{Customer, PaginatedResponse, PaginationParams, QueryParams};
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct CustomersEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(CustomersEndpoint);
+
 impl<'a> CustomersEndpoint<'a> {
     /// Create a new customers endpoint.
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all customers with optional pagination.
@@ -70,14 +79,14 @@ impl<'a> CustomersEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let customer = client.customers().get("customer-id-123").await?;
+    /// # use spiris::{Client, CustomerId};
+    /// # async fn example(client: &Client, id: &CustomerId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let customer = client.customers().get(id).await?;
     /// println!("Customer: {:?}", customer.name);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, id: &str) -> Result<Customer> {
+    pub async fn get(&self, id: &CustomerId) -> Result<Customer> {
         let path = format!("/customers/{}", id);
         self.client.get(&path).await
     }
@@ -102,7 +111,7 @@ impl<'a> CustomersEndpoint<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create(&self, customer: &Customer) -> Result<Customer> {
+    pub async fn create(&self, customer: &impl CreateRequest<Customer>) -> Result<Customer> {
         self.client.post("/customers", customer).await
     }
 
@@ -116,15 +125,19 @@ impl<'a> CustomersEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let mut customer = client.customers().get("customer-id-123").await?;
+    /// # use spiris::{Client, CustomerId};
+    /// # async fn example(client: &Client, id: &CustomerId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut customer = client.customers().get(id).await?;
     /// customer.email = Some("newemail@acme.com".to_string());
-    /// let updated = client.customers().update("customer-id-123", &customer).await?;
+    /// let updated = client.customers().update(id, &customer).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update(&self, id: &str, customer: &Customer) -> Result<Customer> {
+    pub async fn update(
+        &self,
+        id: &CustomerId,
+        customer: &impl UpdateRequest<Customer>,
+    ) -> Result<Customer> {
         let path = format!("/customers/{}", id);
         self.client.put(&path, customer).await
     }
@@ -138,13 +151,13 @@ impl<'a> CustomersEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// client.customers().delete("customer-id-123").await?;
+    /// # use spiris::{Client, CustomerId};
+    /// # async fn example(client: &Client, id: &CustomerId) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.customers().delete(id).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &CustomerId) -> Result<()> {
         let path = format!("/customers/{}", id);
         self.client.delete(&path).await
     }
@@ -184,48 +197,21 @@ impl<'a> CustomersEndpoint<'a> {
         let params = CombinedParams { query, pagination };
         self.client.get_with_params("/customers", &params).await
     }
+}
 
-    /// Stream all customers, automatically paginating through results.
-    ///
-    /// This method returns a Stream that fetches customers page by page,
-    /// yielding individual customers. Use this when you need to process
-    /// all customers without loading everything into memory at once.
-    ///
-    /// Requires the `stream` feature.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// use futures::StreamExt;
-    /// use tokio::pin;
-    /// use spiris::{Client, AccessToken};
-    ///
-    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let token = AccessToken::new("token".to_string(), 3600, None);
-    ///     let client = Client::new(token);
-    ///
-    ///     let stream = client.customers().list_stream();
-    ///     pin!(stream);
-    ///     while let Some(result) = stream.next().await {
-    ///         let customer = result?;
-    ///         println!("Customer: {:?}", customer.name);
-    ///     }
-    ///     Ok(())
-    /// }
-    /// ```
-    #[cfg(feature = "stream")]
-    pub fn list_stream(&self) -> impl futures::Stream<Item = Result<Customer>> + '_ {
-        self.list_stream_with_page_size(crate::pagination::DEFAULT_PAGE_SIZE)
-    }
-
-    /// Stream all customers with a custom page size.
-    ///
-    /// Requires the `stream` feature.
-    #[cfg(feature = "stream")]
-    pub fn list_stream_with_page_size(
-        &self,
-        page_size: u32,
-    ) -> impl futures::Stream<Item = Result<Customer>> + '_ {
-        crate::paginated_stream!(page_size, |params| self.list(Some(params)))
-    }
+impl CustomersEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/customers",
+        crate::types::Customer,
+        crate::ids::CustomerId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/customers", crate::types::Customer);
+    crate::__endpoint_search_stream_impl!("/customers", crate::types::Customer);
+    crate::__endpoint_conditional_update_impl!(crate::types::Customer, crate::ids::CustomerId);
 }
@@ -1,9 +1,10 @@
 //! Delivery methods API endpoint.
 
+use crate::ids::DeliveryMethodId;
 use crate::types::DeliveryMethod;
 
 crate::define_endpoint! {
     /// Delivery methods endpoint for managing shipping methods.
-    DeliveryMethodsEndpoint, "/deliverymethods", DeliveryMethod,
-    caps: [list, get]
+    DeliveryMethodsEndpoint, "/deliverymethods", DeliveryMethod, id: DeliveryMethodId,
+    caps: [list, get, stream]
 }
@@ -1,9 +1,10 @@
 //! Delivery terms API endpoint.
 
+use crate::ids::DeliveryTermId;
 use crate::types::DeliveryTerm;
 
 crate::define_endpoint! {
     /// Delivery terms endpoint for managing delivery terms.
-    DeliveryTermsEndpoint, "/deliveryterms", DeliveryTerm,
-    caps: [list, get]
+    DeliveryTermsEndpoint, "/deliveryterms", DeliveryTerm, id: DeliveryTermId,
+    caps: [list, get, stream]
 }
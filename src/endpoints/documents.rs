@@ -1,9 +1,10 @@
 //! Documents API endpoint.
 
+use crate::ids::DocumentId;
 use crate::types::Document;
 
 crate::define_endpoint! {
     /// Documents endpoint for accessing document information.
-    DocumentsEndpoint, "/documents", Document,
+    DocumentsEndpoint, "/documents", Document, id: DocumentId,
     caps: [get]
 }
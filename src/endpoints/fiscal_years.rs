@@ -2,8 +2,10 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{FiscalYear, PaginatedResponse, PaginationParams};
+use crate::ids::FiscalYearId;
+use crate::types::{CreateRequest, FiscalYear, PaginatedResponse, PaginationParams};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Opening balance entry.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -16,13 +18,18 @@ pub struct OpeningBalance {
 }
 
 /// Fiscal years endpoint for managing accounting periods.
+#[derive(Clone)]
 pub struct FiscalYearsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(FiscalYearsEndpoint);
+
 impl<'a> FiscalYearsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all fiscal years with optional pagination.
@@ -38,13 +45,13 @@ impl<'a> FiscalYearsEndpoint<'a> {
     }
 
     /// Get a specific fiscal year by ID.
-    pub async fn get(&self, id: &str) -> Result<FiscalYear> {
+    pub async fn get(&self, id: &FiscalYearId) -> Result<FiscalYear> {
         let path = format!("/fiscalyears/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new fiscal year.
-    pub async fn create(&self, fiscal_year: &FiscalYear) -> Result<FiscalYear> {
+    pub async fn create(&self, fiscal_year: &impl CreateRequest<FiscalYear>) -> Result<FiscalYear> {
         self.client.post("/fiscalyears", fiscal_year).await
     }
 
@@ -65,3 +72,15 @@ impl<'a> FiscalYearsEndpoint<'a> {
             .await
     }
 }
+
+impl FiscalYearsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/fiscalyears",
+        crate::types::FiscalYear,
+        crate::ids::FiscalYearId,
+        list,
+        get,
+        create
+    );
+    crate::__endpoint_stream_impl!("/fiscalyears", crate::types::FiscalYear);
+}
@@ -2,7 +2,12 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{Invoice, InvoicePayment, PaginatedResponse, PaginationParams, QueryParams};
+use crate::ids::InvoiceId;
+use crate::types::{
+    CreateRequest, Invoice, InvoicePayment, PaginatedResponse, PaginationParams, QueryParams,
+    UpdateRequest,
+};
+use std::borrow::Cow;
 
 /// Invoices endpoint for managing customer invoices.
 ///
@@ -24,14 +29,19 @@ use crate::types::{Invoice, InvoicePayment, PaginatedResponse, PaginationParams,
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct InvoicesEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(InvoicesEndpoint);
+
 impl<'a> InvoicesEndpoint<'a> {
     /// Create a new invoices endpoint.
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all customer invoices with optional pagination.
@@ -72,14 +82,14 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let invoice = client.invoices().get("invoice-id-123").await?;
+    /// # use spiris::{Client, InvoiceId};
+    /// # async fn example(client: &Client, id: &InvoiceId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let invoice = client.invoices().get(id).await?;
     /// println!("Invoice #{:?}", invoice.invoice_number);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, id: &str) -> Result<Invoice> {
+    pub async fn get(&self, id: &InvoiceId) -> Result<Invoice> {
         let path = format!("/customerinvoices/{}", id);
         self.client.get(&path).await
     }
@@ -113,7 +123,7 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create(&self, invoice: &Invoice) -> Result<Invoice> {
+    pub async fn create(&self, invoice: &impl CreateRequest<Invoice>) -> Result<Invoice> {
         self.client.post("/customerinvoices", invoice).await
     }
 
@@ -127,15 +137,19 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// let mut invoice = client.invoices().get("invoice-id-123").await?;
+    /// # use spiris::{Client, InvoiceId};
+    /// # async fn example(client: &Client, id: &InvoiceId) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut invoice = client.invoices().get(id).await?;
     /// invoice.remarks = Some("Updated remarks".to_string());
-    /// let updated = client.invoices().update("invoice-id-123", &invoice).await?;
+    /// let updated = client.invoices().update(id, &invoice).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update(&self, id: &str, invoice: &Invoice) -> Result<Invoice> {
+    pub async fn update(
+        &self,
+        id: &InvoiceId,
+        invoice: &impl UpdateRequest<Invoice>,
+    ) -> Result<Invoice> {
         let path = format!("/customerinvoices/{}", id);
         self.client.put(&path, invoice).await
     }
@@ -149,13 +163,13 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use spiris::Client;
-    /// # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
-    /// client.invoices().delete("invoice-id-123").await?;
+    /// # use spiris::{Client, InvoiceId};
+    /// # async fn example(client: &Client, id: &InvoiceId) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.invoices().delete(id).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &InvoiceId) -> Result<()> {
         let path = format!("/customerinvoices/{}", id);
         self.client.delete(&path).await
     }
@@ -203,9 +217,15 @@ impl<'a> InvoicesEndpoint<'a> {
     ///
     /// * `invoice_id` - The invoice ID
     /// * `payment` - The payment details
-    pub async fn register_payment(&self, invoice_id: &str, payment: &InvoicePayment) -> Result<()> {
+    pub async fn register_payment(
+        &self,
+        invoice_id: &InvoiceId,
+        payment: &InvoicePayment,
+    ) -> Result<()> {
         let path = format!("/customerinvoices/{}/payments", invoice_id);
-        self.client.post::<(), _>(&path, payment).await?;
+        self.client
+            .post::<serde::de::IgnoredAny, _>(&path, payment)
+            .await?;
         Ok(())
     }
 
@@ -218,7 +238,7 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Returns
     ///
     /// The PDF as raw bytes.
-    pub async fn get_pdf(&self, invoice_id: &str) -> Result<Vec<u8>> {
+    pub async fn get_pdf(&self, invoice_id: &InvoiceId) -> Result<Vec<u8>> {
         let path = format!("/customerinvoices/{}/pdf", invoice_id);
         self.client.get_bytes(&path).await
     }
@@ -228,28 +248,28 @@ impl<'a> InvoicesEndpoint<'a> {
     /// # Arguments
     ///
     /// * `invoice_id` - The invoice ID to send electronically
-    pub async fn send_einvoice(&self, invoice_id: &str) -> Result<()> {
+    pub async fn send_einvoice(&self, invoice_id: &InvoiceId) -> Result<()> {
         let path = format!("/customerinvoices/{}/einvoice", invoice_id);
-        self.client.post::<(), _>(&path, &()).await?;
+        self.client
+            .post::<serde::de::IgnoredAny, _>(&path, &())
+            .await?;
         Ok(())
     }
+}
 
-    /// Stream all invoices, automatically paginating through results.
-    ///
-    /// Requires the `stream` feature.
-    #[cfg(feature = "stream")]
-    pub fn list_stream(&self) -> impl futures::Stream<Item = Result<Invoice>> + '_ {
-        self.list_stream_with_page_size(crate::pagination::DEFAULT_PAGE_SIZE)
-    }
-
-    /// Stream all invoices with a custom page size.
-    ///
-    /// Requires the `stream` feature.
-    #[cfg(feature = "stream")]
-    pub fn list_stream_with_page_size(
-        &self,
-        page_size: u32,
-    ) -> impl futures::Stream<Item = Result<Invoice>> + '_ {
-        crate::paginated_stream!(page_size, |params| self.list(Some(params)))
-    }
+impl InvoicesEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/customerinvoices",
+        crate::types::Invoice,
+        crate::ids::InvoiceId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/customerinvoices", crate::types::Invoice);
+    crate::__endpoint_search_stream_impl!("/customerinvoices", crate::types::Invoice);
+    crate::__endpoint_conditional_update_impl!(crate::types::Invoice, crate::ids::InvoiceId);
 }
@@ -2,28 +2,43 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::MessageThreadId;
 use crate::types::{Message, MessageThread};
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct MessagesEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(MessagesEndpoint);
+
 impl<'a> MessagesEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
-    pub async fn get_thread(&self, id: &str) -> Result<MessageThread> {
+    pub async fn get_thread(&self, id: &MessageThreadId) -> Result<MessageThread> {
         self.client.get(&format!("/messagethreads/{}", id)).await
     }
 
-    pub async fn update_thread(&self, id: &str, thread: &MessageThread) -> Result<MessageThread> {
+    pub async fn update_thread(
+        &self,
+        id: &MessageThreadId,
+        thread: &MessageThread,
+    ) -> Result<MessageThread> {
         self.client
             .put(&format!("/messagethreads/{}", id), thread)
             .await
     }
 
-    pub async fn add_message(&self, thread_id: &str, message: &Message) -> Result<Message> {
+    pub async fn add_message(
+        &self,
+        thread_id: &MessageThreadId,
+        message: &Message,
+    ) -> Result<Message> {
         self.client
             .post(&format!("/messagethreads/{}", thread_id), message)
             .await
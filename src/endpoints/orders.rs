@@ -2,15 +2,24 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{Order, PaginatedResponse, PaginationParams, QueryParams};
+use crate::ids::OrderId;
+use crate::types::{
+    CreateRequest, Order, PaginatedResponse, PaginationParams, QueryParams, UpdateRequest,
+};
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct OrdersEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(OrdersEndpoint);
+
 impl<'a> OrdersEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn list(&self, params: Option<PaginationParams>) -> Result<PaginatedResponse<Order>> {
@@ -21,19 +30,19 @@ impl<'a> OrdersEndpoint<'a> {
         }
     }
 
-    pub async fn get(&self, id: &str) -> Result<Order> {
+    pub async fn get(&self, id: &OrderId) -> Result<Order> {
         self.client.get(&format!("/orders/{}", id)).await
     }
 
-    pub async fn create(&self, order: &Order) -> Result<Order> {
+    pub async fn create(&self, order: &impl CreateRequest<Order>) -> Result<Order> {
         self.client.post("/orders", order).await
     }
 
-    pub async fn update(&self, id: &str, order: &Order) -> Result<Order> {
+    pub async fn update(&self, id: &OrderId, order: &impl UpdateRequest<Order>) -> Result<Order> {
         self.client.put(&format!("/orders/{}", id), order).await
     }
 
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &OrderId) -> Result<()> {
         self.client.delete(&format!("/orders/{}", id)).await
     }
 
@@ -54,3 +63,20 @@ impl<'a> OrdersEndpoint<'a> {
             .await
     }
 }
+
+impl OrdersEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/orders",
+        crate::types::Order,
+        crate::ids::OrderId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/orders", crate::types::Order);
+    crate::__endpoint_search_stream_impl!("/orders", crate::types::Order);
+    crate::__endpoint_conditional_update_impl!(crate::types::Order, crate::ids::OrderId);
+}
@@ -1,9 +1,13 @@
 //! Projects API endpoint.
 
+use crate::ids::ProjectId;
 use crate::types::Project;
 
 crate::define_endpoint! {
     /// Projects endpoint for managing project tracking.
-    ProjectsEndpoint, "/projects", Project,
-    caps: [list, get, create, update, delete, search]
+    ProjectsEndpoint, "/projects", Project, id: ProjectId,
+    caps: [list, get, create, update, delete, search, stream],
+    extra: {
+        crate::__endpoint_conditional_update_impl!(Project, ProjectId);
+    }
 }
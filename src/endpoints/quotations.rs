@@ -2,15 +2,24 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{PaginatedResponse, PaginationParams, QueryParams, Quotation};
+use crate::ids::QuotationId;
+use crate::types::{
+    CreateRequest, PaginatedResponse, PaginationParams, QueryParams, Quotation, UpdateRequest,
+};
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct QuotationsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(QuotationsEndpoint);
+
 impl<'a> QuotationsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn list(
@@ -24,21 +33,25 @@ impl<'a> QuotationsEndpoint<'a> {
         }
     }
 
-    pub async fn get(&self, id: &str) -> Result<Quotation> {
+    pub async fn get(&self, id: &QuotationId) -> Result<Quotation> {
         self.client.get(&format!("/quotations/{}", id)).await
     }
 
-    pub async fn create(&self, quotation: &Quotation) -> Result<Quotation> {
+    pub async fn create(&self, quotation: &impl CreateRequest<Quotation>) -> Result<Quotation> {
         self.client.post("/quotations", quotation).await
     }
 
-    pub async fn update(&self, id: &str, quotation: &Quotation) -> Result<Quotation> {
+    pub async fn update(
+        &self,
+        id: &QuotationId,
+        quotation: &impl UpdateRequest<Quotation>,
+    ) -> Result<Quotation> {
         self.client
             .put(&format!("/quotations/{}", id), quotation)
             .await
     }
 
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &QuotationId) -> Result<()> {
         self.client.delete(&format!("/quotations/{}", id)).await
     }
 
@@ -59,3 +72,20 @@ impl<'a> QuotationsEndpoint<'a> {
             .await
     }
 }
+
+impl QuotationsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/quotations",
+        crate::types::Quotation,
+        crate::ids::QuotationId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/quotations", crate::types::Quotation);
+    crate::__endpoint_search_stream_impl!("/quotations", crate::types::Quotation);
+    crate::__endpoint_conditional_update_impl!(crate::types::Quotation, crate::ids::QuotationId);
+}
@@ -2,17 +2,25 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::SupplierInvoiceDraftId;
 use crate::types::{
-    PaginatedResponse, PaginationParams, QueryParams, SupplierInvoice, SupplierInvoiceDraft,
+    CreateRequest, PaginatedResponse, PaginationParams, QueryParams, SupplierInvoice,
+    SupplierInvoiceDraft, UpdateRequest,
 };
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct SupplierInvoiceDraftsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(SupplierInvoiceDraftsEndpoint);
+
 impl<'a> SupplierInvoiceDraftsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn list(
@@ -28,33 +36,36 @@ impl<'a> SupplierInvoiceDraftsEndpoint<'a> {
         }
     }
 
-    pub async fn get(&self, id: &str) -> Result<SupplierInvoiceDraft> {
+    pub async fn get(&self, id: &SupplierInvoiceDraftId) -> Result<SupplierInvoiceDraft> {
         self.client
             .get(&format!("/supplierinvoicedrafts/{}", id))
             .await
     }
 
-    pub async fn create(&self, draft: &SupplierInvoiceDraft) -> Result<SupplierInvoiceDraft> {
+    pub async fn create(
+        &self,
+        draft: &impl CreateRequest<SupplierInvoiceDraft>,
+    ) -> Result<SupplierInvoiceDraft> {
         self.client.post("/supplierinvoicedrafts", draft).await
     }
 
     pub async fn update(
         &self,
-        id: &str,
-        draft: &SupplierInvoiceDraft,
+        id: &SupplierInvoiceDraftId,
+        draft: &impl UpdateRequest<SupplierInvoiceDraft>,
     ) -> Result<SupplierInvoiceDraft> {
         self.client
             .put(&format!("/supplierinvoicedrafts/{}", id), draft)
             .await
     }
 
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &SupplierInvoiceDraftId) -> Result<()> {
         self.client
             .delete(&format!("/supplierinvoicedrafts/{}", id))
             .await
     }
 
-    pub async fn convert(&self, id: &str) -> Result<SupplierInvoice> {
+    pub async fn convert(&self, id: &SupplierInvoiceDraftId) -> Result<SupplierInvoice> {
         self.client
             .post(&format!("/supplierinvoicedrafts/{}/convert", id), &())
             .await
@@ -80,3 +91,26 @@ impl<'a> SupplierInvoiceDraftsEndpoint<'a> {
             .await
     }
 }
+
+impl SupplierInvoiceDraftsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/supplierinvoicedrafts",
+        crate::types::SupplierInvoiceDraft,
+        crate::ids::SupplierInvoiceDraftId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/supplierinvoicedrafts", crate::types::SupplierInvoiceDraft);
+    crate::__endpoint_search_stream_impl!(
+        "/supplierinvoicedrafts",
+        crate::types::SupplierInvoiceDraft
+    );
+    crate::__endpoint_conditional_update_impl!(
+        crate::types::SupplierInvoiceDraft,
+        crate::ids::SupplierInvoiceDraftId
+    );
+}
@@ -2,18 +2,26 @@
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ids::SupplierInvoiceId;
 use crate::types::{
-    InvoicePayment, PaginatedResponse, PaginationParams, QueryParams, SupplierInvoice,
+    CreateRequest, InvoicePayment, PaginatedResponse, PaginationParams, QueryParams,
+    SupplierInvoice, UpdateRequest,
 };
+use std::borrow::Cow;
 
 /// Supplier invoices endpoint for managing accounts payable.
+#[derive(Clone)]
 pub struct SupplierInvoicesEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(SupplierInvoicesEndpoint);
+
 impl<'a> SupplierInvoicesEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all supplier invoices with optional pagination.
@@ -31,32 +39,45 @@ impl<'a> SupplierInvoicesEndpoint<'a> {
     }
 
     /// Get a specific supplier invoice by ID.
-    pub async fn get(&self, id: &str) -> Result<SupplierInvoice> {
+    pub async fn get(&self, id: &SupplierInvoiceId) -> Result<SupplierInvoice> {
         let path = format!("/supplierinvoices/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new supplier invoice.
-    pub async fn create(&self, invoice: &SupplierInvoice) -> Result<SupplierInvoice> {
+    pub async fn create(
+        &self,
+        invoice: &impl CreateRequest<SupplierInvoice>,
+    ) -> Result<SupplierInvoice> {
         self.client.post("/supplierinvoices", invoice).await
     }
 
     /// Update an existing supplier invoice.
-    pub async fn update(&self, id: &str, invoice: &SupplierInvoice) -> Result<SupplierInvoice> {
+    pub async fn update(
+        &self,
+        id: &SupplierInvoiceId,
+        invoice: &impl UpdateRequest<SupplierInvoice>,
+    ) -> Result<SupplierInvoice> {
         let path = format!("/supplierinvoices/{}", id);
         self.client.put(&path, invoice).await
     }
 
     /// Delete a supplier invoice.
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &SupplierInvoiceId) -> Result<()> {
         let path = format!("/supplierinvoices/{}", id);
         self.client.delete(&path).await
     }
 
     /// Register a payment for a supplier invoice.
-    pub async fn register_payment(&self, invoice_id: &str, payment: &InvoicePayment) -> Result<()> {
+    pub async fn register_payment(
+        &self,
+        invoice_id: &SupplierInvoiceId,
+        payment: &InvoicePayment,
+    ) -> Result<()> {
         let path = format!("/supplierinvoices/{}/payments", invoice_id);
-        self.client.post::<(), _>(&path, payment).await?;
+        self.client
+            .post::<serde::de::IgnoredAny, _>(&path, payment)
+            .await?;
         Ok(())
     }
 
@@ -80,3 +101,23 @@ impl<'a> SupplierInvoicesEndpoint<'a> {
             .await
     }
 }
+
+impl SupplierInvoicesEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/supplierinvoices",
+        crate::types::SupplierInvoice,
+        crate::ids::SupplierInvoiceId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/supplierinvoices", crate::types::SupplierInvoice);
+    crate::__endpoint_search_stream_impl!("/supplierinvoices", crate::types::SupplierInvoice);
+    crate::__endpoint_conditional_update_impl!(
+        crate::types::SupplierInvoice,
+        crate::ids::SupplierInvoiceId
+    );
+}
@@ -1,9 +1,10 @@
 //! Supplier labels API endpoint.
 
+use crate::ids::SupplierLabelId;
 use crate::types::SupplierLabel;
 
 crate::define_endpoint! {
     /// Supplier labels endpoint for managing supplier categorization.
-    SupplierLabelsEndpoint, "/supplierlabels", SupplierLabel,
-    caps: [list, get, create, update, delete]
+    SupplierLabelsEndpoint, "/supplierlabels", SupplierLabel, id: SupplierLabelId,
+    caps: [list, get, create, update, delete, stream]
 }
@@ -2,15 +2,24 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{PaginatedResponse, PaginationParams, QueryParams, SupplierLedgerItem};
+use crate::ids::SupplierLedgerItemId;
+use crate::types::{
+    CreateRequest, PaginatedResponse, PaginationParams, QueryParams, SupplierLedgerItem,
+};
+use std::borrow::Cow;
 
+#[derive(Clone)]
 pub struct SupplierLedgerItemsEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(SupplierLedgerItemsEndpoint);
+
 impl<'a> SupplierLedgerItemsEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     pub async fn list(
@@ -26,13 +35,16 @@ impl<'a> SupplierLedgerItemsEndpoint<'a> {
         }
     }
 
-    pub async fn get(&self, id: &str) -> Result<SupplierLedgerItem> {
+    pub async fn get(&self, id: &SupplierLedgerItemId) -> Result<SupplierLedgerItem> {
         self.client
             .get(&format!("/supplierledgeritems/{}", id))
             .await
     }
 
-    pub async fn create(&self, item: &SupplierLedgerItem) -> Result<SupplierLedgerItem> {
+    pub async fn create(
+        &self,
+        item: &impl CreateRequest<SupplierLedgerItem>,
+    ) -> Result<SupplierLedgerItem> {
         self.client.post("/supplierledgeritems", item).await
     }
 
@@ -56,3 +68,17 @@ impl<'a> SupplierLedgerItemsEndpoint<'a> {
             .await
     }
 }
+
+impl SupplierLedgerItemsEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/supplierledgeritems",
+        crate::types::SupplierLedgerItem,
+        crate::ids::SupplierLedgerItemId,
+        list,
+        get,
+        create,
+        search
+    );
+    crate::__endpoint_stream_impl!("/supplierledgeritems", crate::types::SupplierLedgerItem);
+    crate::__endpoint_search_stream_impl!("/supplierledgeritems", crate::types::SupplierLedgerItem);
+}
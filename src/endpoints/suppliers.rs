@@ -2,16 +2,25 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{PaginatedResponse, PaginationParams, QueryParams, Supplier};
+use crate::ids::SupplierId;
+use crate::types::{
+    CreateRequest, PaginatedResponse, PaginationParams, QueryParams, Supplier, UpdateRequest,
+};
+use std::borrow::Cow;
 
 /// Suppliers endpoint for managing supplier records.
+#[derive(Clone)]
 pub struct SuppliersEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(SuppliersEndpoint);
+
 impl<'a> SuppliersEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all suppliers with optional pagination.
@@ -27,24 +36,28 @@ impl<'a> SuppliersEndpoint<'a> {
     }
 
     /// Get a specific supplier by ID.
-    pub async fn get(&self, id: &str) -> Result<Supplier> {
+    pub async fn get(&self, id: &SupplierId) -> Result<Supplier> {
         let path = format!("/suppliers/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new supplier.
-    pub async fn create(&self, supplier: &Supplier) -> Result<Supplier> {
+    pub async fn create(&self, supplier: &impl CreateRequest<Supplier>) -> Result<Supplier> {
         self.client.post("/suppliers", supplier).await
     }
 
     /// Update an existing supplier.
-    pub async fn update(&self, id: &str, supplier: &Supplier) -> Result<Supplier> {
+    pub async fn update(
+        &self,
+        id: &SupplierId,
+        supplier: &impl UpdateRequest<Supplier>,
+    ) -> Result<Supplier> {
         let path = format!("/suppliers/{}", id);
         self.client.put(&path, supplier).await
     }
 
     /// Delete a supplier.
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &SupplierId) -> Result<()> {
         let path = format!("/suppliers/{}", id);
         self.client.delete(&path).await
     }
@@ -67,3 +80,20 @@ impl<'a> SuppliersEndpoint<'a> {
         self.client.get_with_params("/suppliers", &params).await
     }
 }
+
+impl SuppliersEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/suppliers",
+        crate::types::Supplier,
+        crate::ids::SupplierId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/suppliers", crate::types::Supplier);
+    crate::__endpoint_search_stream_impl!("/suppliers", crate::types::Supplier);
+    crate::__endpoint_conditional_update_impl!(crate::types::Supplier, crate::ids::SupplierId);
+}
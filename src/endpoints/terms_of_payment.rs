@@ -1,9 +1,10 @@
 //! Terms of payment API endpoint.
 
+use crate::ids::TermsOfPaymentId;
 use crate::types::TermsOfPayment;
 
 crate::define_endpoint! {
     /// Terms of payment endpoint for managing payment terms.
-    TermsOfPaymentEndpoint, "/termsofpayments", TermsOfPayment,
-    caps: [list, get, create, update, delete]
+    TermsOfPaymentEndpoint, "/termsofpayments", TermsOfPayment, id: TermsOfPaymentId,
+    caps: [list, get, create, update, delete, stream]
 }
@@ -1,9 +1,10 @@
 //! Units API endpoint.
 
+use crate::ids::UnitId;
 use crate::types::Unit;
 
 crate::define_endpoint! {
     /// Units endpoint for managing measurement units.
-    UnitsEndpoint, "/units", Unit,
-    caps: [list, get, create, update, delete]
+    UnitsEndpoint, "/units", Unit, id: UnitId,
+    caps: [list, get, create, update, delete, stream]
 }
@@ -1,9 +1,10 @@
 //! Users API endpoint.
 
+use crate::ids::UserId;
 use crate::types::User;
 
 crate::define_endpoint! {
     /// Users endpoint for accessing user information.
-    UsersEndpoint, "/users", User,
-    caps: [list, get]
+    UsersEndpoint, "/users", User, id: UserId,
+    caps: [list, get, stream]
 }
@@ -1,9 +1,10 @@
 //! VAT codes API endpoint.
 
+use crate::ids::VatCodeId;
 use crate::types::VatCode;
 
 crate::define_endpoint! {
     /// VAT codes endpoint for managing tax rates.
-    VatCodesEndpoint, "/vatcodes", VatCode,
-    caps: [list, get]
+    VatCodesEndpoint, "/vatcodes", VatCode, id: VatCodeId,
+    caps: [list, get, stream]
 }
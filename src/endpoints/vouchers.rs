@@ -2,16 +2,25 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::types::{PaginatedResponse, PaginationParams, QueryParams, Voucher};
+use crate::ids::VoucherId;
+use crate::types::{
+    CreateRequest, PaginatedResponse, PaginationParams, QueryParams, UpdateRequest, Voucher,
+};
+use std::borrow::Cow;
 
 /// Vouchers endpoint for managing journal entries.
+#[derive(Clone)]
 pub struct VouchersEndpoint<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
+crate::__endpoint_owned_impl!(VouchersEndpoint);
+
 impl<'a> VouchersEndpoint<'a> {
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: Cow::Borrowed(client),
+        }
     }
 
     /// List all vouchers with optional pagination.
@@ -27,24 +36,28 @@ impl<'a> VouchersEndpoint<'a> {
     }
 
     /// Get a specific voucher by ID.
-    pub async fn get(&self, id: &str) -> Result<Voucher> {
+    pub async fn get(&self, id: &VoucherId) -> Result<Voucher> {
         let path = format!("/vouchers/{}", id);
         self.client.get(&path).await
     }
 
     /// Create a new voucher.
-    pub async fn create(&self, voucher: &Voucher) -> Result<Voucher> {
+    pub async fn create(&self, voucher: &impl CreateRequest<Voucher>) -> Result<Voucher> {
         self.client.post("/vouchers", voucher).await
     }
 
     /// Update an existing voucher.
-    pub async fn update(&self, id: &str, voucher: &Voucher) -> Result<Voucher> {
+    pub async fn update(
+        &self,
+        id: &VoucherId,
+        voucher: &impl UpdateRequest<Voucher>,
+    ) -> Result<Voucher> {
         let path = format!("/vouchers/{}", id);
         self.client.put(&path, voucher).await
     }
 
     /// Delete a voucher.
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &VoucherId) -> Result<()> {
         let path = format!("/vouchers/{}", id);
         self.client.delete(&path).await
     }
@@ -67,3 +80,20 @@ impl<'a> VouchersEndpoint<'a> {
         self.client.get_with_params("/vouchers", &params).await
     }
 }
+
+impl VouchersEndpoint<'_> {
+    crate::__endpoint_meta_impl!(
+        "/vouchers",
+        crate::types::Voucher,
+        crate::ids::VoucherId,
+        list,
+        get,
+        create,
+        update,
+        delete,
+        search
+    );
+    crate::__endpoint_stream_impl!("/vouchers", crate::types::Voucher);
+    crate::__endpoint_search_stream_impl!("/vouchers", crate::types::Voucher);
+    crate::__endpoint_conditional_update_impl!(crate::types::Voucher, crate::ids::VoucherId);
+}
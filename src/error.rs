@@ -1,6 +1,7 @@
 //! Error types for the Visma eAccounting API client.
 
-use serde::{Deserialize, Serialize};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use thiserror::Error;
 
@@ -31,7 +32,10 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[serde(rename_all = "PascalCase")]
 pub struct ApiErrorResponse {
     /// Error code from the API (if provided).
-    #[serde(default)]
+    ///
+    /// Numeric codes are converted to their string form. Use
+    /// [`code`](Self::code) for the typed [`ApiErrorCode`].
+    #[serde(default, deserialize_with = "deserialize_error_code")]
     pub error_code: Option<String>,
 
     /// Human-readable error message.
@@ -55,6 +59,11 @@ impl ApiErrorResponse {
         }
     }
 
+    /// Get the error code as a typed [`ApiErrorCode`], if one was provided.
+    pub fn code(&self) -> Option<ApiErrorCode> {
+        self.error_code.as_deref().map(ApiErrorCode::from)
+    }
+
     /// Check if this error contains validation errors.
     pub fn has_validation_errors(&self) -> bool {
         !self.validation_errors.is_empty()
@@ -83,11 +92,101 @@ impl fmt::Display for ApiErrorResponse {
     }
 }
 
+/// Accept the error code as either a string or a number.
+fn deserialize_error_code<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Code {
+        Text(String),
+        Number(i64),
+    }
+
+    Ok(
+        Option::<Code>::deserialize(deserializer)?.map(|code| match code {
+            Code::Text(text) => text,
+            Code::Number(number) => number.to_string(),
+        }),
+    )
+}
+
+/// Error codes returned by the Spiris API.
+///
+/// Only codes that appear in the
+/// [API documentation](https://developer.visma.com/api/eaccounting) are
+/// listed. They are parsed from [`ApiErrorResponse::error_code`] ignoring
+/// case and underscores, so both `NOT_FOUND` and `NotFound` match. Every
+/// other code, including numeric ones, is kept in [`ApiErrorCode::Other`].
+///
+/// # Example
+///
+/// ```
+/// use spiris::error::{ApiErrorCode, Error};
+///
+/// fn failed_validation(err: &Error) -> bool {
+///     matches!(err.api_error_code(), Some(ApiErrorCode::ValidationError))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    /// The request body failed validation (`VALIDATION_ERROR`).
+    ValidationError,
+    /// The requested resource does not exist (`NOT_FOUND`).
+    NotFound,
+    /// An unexpected server error occurred (`INTERNAL_ERROR`).
+    InternalError,
+    /// A code not known to this client.
+    Other(String),
+}
+
+impl ApiErrorCode {
+    /// Get the error code as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiErrorCode::ValidationError => "VALIDATION_ERROR",
+            ApiErrorCode::NotFound => "NOT_FOUND",
+            ApiErrorCode::InternalError => "INTERNAL_ERROR",
+            ApiErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(code: &str) -> Self {
+        const KNOWN: &[ApiErrorCode] = &[
+            ApiErrorCode::ValidationError,
+            ApiErrorCode::NotFound,
+            ApiErrorCode::InternalError,
+        ];
+
+        let code = code.trim();
+        let normalized = |s: &str| -> String {
+            s.chars()
+                .filter(|c| *c != '_')
+                .map(|c| c.to_ascii_lowercase())
+                .collect()
+        };
+        KNOWN
+            .iter()
+            .find(|known| normalized(known.as_str()) == normalized(code))
+            .cloned()
+            .unwrap_or_else(|| ApiErrorCode::Other(code.to_string()))
+    }
+}
+
+impl fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// A field-level validation error from the API.
 ///
 /// These are typically returned when a request body fails validation,
 /// such as missing required fields or invalid field values.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ValidationError {
     /// The field name that failed validation.
@@ -116,8 +215,11 @@ pub enum Error {
 
     /// API returned an error response.
     ///
-    /// This error includes the HTTP status code, a structured response
-    /// (if parseable), and the raw response body for debugging.
+    /// Every non-success HTTP status is reported as this variant. It includes
+    /// the HTTP status code, a structured response (if parseable), the raw
+    /// response body for debugging and the response headers. Use the helpers
+    /// such as [`Error::is_not_found`] and [`Error::request_id`] instead of
+    /// matching on status codes by hand.
     ///
     /// # Example
     ///
@@ -125,7 +227,7 @@ pub enum Error {
     /// use spiris::error::Error;
     ///
     /// fn check_error(err: &Error) {
-    ///     if let Error::ApiError { status_code, response, raw_body } = err {
+    ///     if let Error::ApiError { status_code, response, .. } = err {
     ///         println!("Status: {}", status_code);
     ///         println!("Message: {}", response.message);
     ///         if response.has_validation_errors() {
@@ -144,9 +246,14 @@ pub enum Error {
         response: ApiErrorResponse,
         /// Raw response body for debugging.
         raw_body: String,
+        /// Response headers.
+        headers: Box<HeaderMap>,
     },
 
     /// Authentication failed.
+    ///
+    /// Used for local failures such as invalid webhook signatures. HTTP 401
+    /// and 403 responses are reported as [`Error::ApiError`].
     #[error("Authentication failed: {0}")]
     AuthError(String),
 
@@ -159,17 +266,37 @@ pub enum Error {
     InvalidConfig(String),
 
     /// Rate limit exceeded.
+    ///
+    /// HTTP 429 responses are reported as [`Error::ApiError`]; see
+    /// [`Error::is_rate_limited`].
     #[error("Rate limit exceeded: {0}")]
     RateLimitExceeded(String),
 
     /// Resource not found.
+    ///
+    /// HTTP 404 responses are reported as [`Error::ApiError`]; see
+    /// [`Error::is_not_found`].
     #[error("Resource not found: {0}")]
     NotFound(String),
 
     /// Invalid request parameters.
+    ///
+    /// Used for requests rejected before they are sent. HTTP 400 responses
+    /// are reported as [`Error::ApiError`].
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
 
+    /// A request failed client-side validation.
+    ///
+    /// Returned by [`Validate`](crate::validate::Validate). The errors have
+    /// the same shape as the API's, and [`Error::validation_errors`]
+    /// returns them for both.
+    #[error(
+        "Request failed validation: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    Validation(Vec<ValidationError>),
+
     /// URL parsing error.
     #[error("URL parsing failed: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -177,6 +304,24 @@ pub enum Error {
     /// OAuth2 error.
     #[error("OAuth2 error: {0}")]
     OAuth2Error(String),
+
+    /// The response contains fields the client does not model.
+    ///
+    /// Only returned in strict mode; see
+    /// [`ClientConfig::strict_deserialization`](crate::ClientConfig::strict_deserialization).
+    #[error("Response contains unknown fields: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
+
+    /// Local database error from the mirror or offline store.
+    #[cfg(any(feature = "mirror", feature = "offline"))]
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+}
+
+impl From<Vec<ValidationError>> for Error {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Error::Validation(errors)
+    }
 }
 
 impl Error {
@@ -192,6 +337,27 @@ impl Error {
             status_code,
             response,
             raw_body,
+            headers: Box::default(),
+        }
+    }
+
+    /// Attach response headers to an API error.
+    ///
+    /// Other error variants are returned unchanged.
+    pub fn with_headers(self, headers: HeaderMap) -> Self {
+        match self {
+            Error::ApiError {
+                status_code,
+                response,
+                raw_body,
+                ..
+            } => Error::ApiError {
+                status_code,
+                response,
+                raw_body,
+                headers: Box::new(headers),
+            },
+            other => other,
         }
     }
 
@@ -201,7 +367,7 @@ impl Error {
     pub fn is_retryable(&self) -> bool {
         match self {
             Error::RateLimitExceeded(_) => true,
-            Error::ApiError { status_code, .. } => *status_code >= 500,
+            Error::ApiError { status_code, .. } => *status_code >= 500 || *status_code == 429,
             Error::Http(e) => e.is_timeout() || e.is_connect(),
             _ => false,
         }
@@ -215,9 +381,65 @@ impl Error {
         }
     }
 
-    /// Get the validation errors if this is an API error with validation failures.
+    /// Get the parsed error response if this is an API error.
+    pub fn api_response(&self) -> Option<&ApiErrorResponse> {
+        match self {
+            Error::ApiError { response, .. } => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Get the typed error code if this is an API error with a code.
+    pub fn api_error_code(&self) -> Option<ApiErrorCode> {
+        self.api_response().and_then(ApiErrorResponse::code)
+    }
+
+    /// Get the response headers if this is an API error.
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        match self {
+            Error::ApiError { headers, .. } => Some(headers),
+            _ => None,
+        }
+    }
+
+    /// Get the server-assigned request id if this is an API error.
+    ///
+    /// Checks the headers listed in
+    /// [`REQUEST_ID_HEADERS`](crate::response::REQUEST_ID_HEADERS).
+    pub fn request_id(&self) -> Option<&str> {
+        let headers = self.headers()?;
+        crate::response::REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+    }
+
+    /// Check if the API responded with 404 Not Found.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound(_)) || self.status_code() == Some(404)
+    }
+
+    /// Check if the API rejected the credentials (401 or 403).
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, Error::AuthError(_) | Error::TokenExpired)
+            || matches!(self.status_code(), Some(401 | 403))
+    }
+
+    /// Check if the API responded with 429 Too Many Requests.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Error::RateLimitExceeded(_)) || self.status_code() == Some(429)
+    }
+
+    /// Check if the API rejected the request as invalid (400).
+    pub fn is_bad_request(&self) -> bool {
+        matches!(self, Error::InvalidRequest(_) | Error::Validation(_))
+            || self.status_code() == Some(400)
+    }
+
+    /// Get the validation errors from the API or from client-side
+    /// validation.
     pub fn validation_errors(&self) -> Option<&[ValidationError]> {
         match self {
+            Error::Validation(errors) => Some(errors),
             Error::ApiError { response, .. } if response.has_validation_errors() => {
                 Some(&response.validation_errors)
             }
@@ -343,6 +565,7 @@ mod tests {
             status_code,
             response,
             raw_body,
+            ..
         } = err
         {
             assert_eq!(status_code, 404);
@@ -363,6 +586,7 @@ mod tests {
             status_code,
             response,
             raw_body,
+            ..
         } = err
         {
             assert_eq!(status_code, 500);
@@ -406,4 +630,73 @@ mod tests {
 
         assert!(Error::TokenExpired.validation_errors().is_none());
     }
+
+    #[test]
+    fn test_api_error_code_parsing() {
+        assert_eq!(
+            ApiErrorCode::from("VALIDATION_ERROR"),
+            ApiErrorCode::ValidationError
+        );
+        assert_eq!(ApiErrorCode::from("notfound"), ApiErrorCode::NotFound);
+        assert_eq!(
+            ApiErrorCode::from("SomethingNew"),
+            ApiErrorCode::Other("SomethingNew".to_string())
+        );
+        assert_eq!(ApiErrorCode::InternalError.to_string(), "INTERNAL_ERROR");
+    }
+
+    #[test]
+    fn test_api_error_code_from_number() {
+        let json = r#"{"ErrorCode": 4000, "Message": "Failed"}"#;
+        let response: ApiErrorResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.error_code, Some("4000".to_string()));
+        assert_eq!(
+            response.code(),
+            Some(ApiErrorCode::Other("4000".to_string()))
+        );
+
+        let json = r#"{"ErrorCode": 4004, "Message": "No such customer"}"#;
+        let err = Error::from_api_response(400, json.to_string());
+        assert_eq!(
+            err.api_error_code(),
+            Some(ApiErrorCode::Other("4004".to_string()))
+        );
+        assert!(!err.is_not_found());
+        assert!(!err.is_auth_error());
+    }
+
+    #[test]
+    fn test_error_api_error_code() {
+        let json = r#"{"ErrorCode": "NOT_FOUND", "Message": "Missing"}"#;
+        let err = Error::from_api_response(404, json.to_string());
+        assert_eq!(err.api_error_code(), Some(ApiErrorCode::NotFound));
+        assert!(Error::TokenExpired.api_error_code().is_none());
+    }
+
+    #[test]
+    fn test_error_with_headers_and_request_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", "req-123".parse().unwrap());
+
+        let err = Error::from_api_response(404, "Not found".to_string()).with_headers(headers);
+        assert_eq!(err.request_id(), Some("req-123"));
+        assert!(err.headers().unwrap().contains_key("x-request-id"));
+        assert!(err.is_not_found());
+        assert!(!err.is_auth_error());
+
+        assert!(Error::from_api_response(500, String::new())
+            .request_id()
+            .is_none());
+        assert!(Error::TokenExpired.request_id().is_none());
+    }
+
+    #[test]
+    fn test_error_status_helpers() {
+        assert!(Error::from_api_response(401, String::new()).is_auth_error());
+        assert!(Error::from_api_response(403, String::new()).is_auth_error());
+        assert!(Error::from_api_response(429, String::new()).is_rate_limited());
+        assert!(Error::from_api_response(429, String::new()).is_retryable());
+        assert!(Error::from_api_response(400, String::new()).is_bad_request());
+        assert!(!Error::from_api_response(500, String::new()).is_not_found());
+    }
 }
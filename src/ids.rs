@@ -0,0 +1,326 @@
+//! Typed resource identifiers.
+//!
+//! The API identifies resources by GUID. Each resource has its own id type,
+//! such as [`CustomerId`] or [`InvoiceId`], so a customer id cannot be passed
+//! to [`InvoicesEndpoint::get`](crate::endpoints::InvoicesEndpoint::get) by
+//! mistake. Ids are validated when parsed or deserialized and serialize as
+//! plain strings.
+//!
+//! References that can point at several kinds of resource, such as
+//! [`AttachmentLink::document_id`](crate::types::AttachmentLink::document_id),
+//! and row ids use the untyped [`Guid`].
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::ids::CustomerId;
+//!
+//! let id: CustomerId = "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse()?;
+//! assert_eq!(id.as_str(), "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b");
+//!
+//! assert!(CustomerId::parse("cust-123").is_err());
+//! # Ok::<(), spiris::Error>(())
+//! ```
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// A GUID in the `8-4-4-4-12` hex format used by the API.
+///
+/// The original spelling is kept, so ids round-trip unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Guid(String);
+
+impl Guid {
+    /// Parse a GUID such as `5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b`.
+    pub fn parse(s: &str) -> Result<Self> {
+        Self::from_string(s.to_string(), "GUID")
+    }
+
+    /// Get the GUID as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn from_string(s: String, kind: &str) -> Result<Self> {
+        if is_guid(&s) {
+            Ok(Self(s))
+        } else {
+            Err(Error::InvalidRequest(format!("Invalid {}: {:?}", kind, s)))
+        }
+    }
+}
+
+pub(crate) fn is_guid(s: &str) -> bool {
+    const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+
+    let mut groups = s.split('-');
+    GROUPS.iter().all(|&len| {
+        groups
+            .next()
+            .is_some_and(|group| group.len() == len && group.bytes().all(|b| b.is_ascii_hexdigit()))
+    }) && groups.next().is_none()
+}
+
+/// Implement the string conversions and serde support shared by all id types.
+macro_rules! impl_id_traits {
+    ($name:ident, $parse:expr) => {
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                $name::parse(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = Error;
+
+            fn try_from(s: &str) -> Result<Self> {
+                $name::parse(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(s: String) -> Result<Self> {
+                ($parse)(s)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.as_str().to_string()
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                ($parse)(s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+impl_id_traits!(Guid, |s| Guid::from_string(s, "GUID"));
+
+/// Define typed id wrappers around [`Guid`].
+macro_rules! define_ids {
+    ($($(#[$meta:meta])* $name:ident => $kind:literal;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $name(Guid);
+
+            impl $name {
+                /// Parse and validate an id.
+                pub fn parse(s: &str) -> Result<Self> {
+                    Guid::from_string(s.to_string(), concat!($kind, " id")).map(Self)
+                }
+
+                /// Get the id as a string.
+                pub fn as_str(&self) -> &str {
+                    self.0.as_str()
+                }
+
+                /// Get the underlying GUID.
+                pub fn guid(&self) -> &Guid {
+                    &self.0
+                }
+
+                /// Wrap a GUID without checking that it identifies this kind
+                /// of resource.
+                pub fn from_guid_unchecked(guid: Guid) -> Self {
+                    Self(guid)
+                }
+            }
+
+            impl From<$name> for Guid {
+                fn from(id: $name) -> Guid {
+                    id.0
+                }
+            }
+
+            impl_id_traits!($name, |s| Guid::from_string(s, concat!($kind, " id")).map($name));
+        )*
+    };
+}
+
+define_ids! {
+    /// Identifies an [`Article`](crate::types::Article).
+    ArticleId => "article";
+    /// Identifies an [`ArticleAccountCoding`](crate::types::ArticleAccountCoding).
+    ArticleAccountCodingId => "article account coding";
+    /// Identifies an [`ArticleLabel`](crate::types::ArticleLabel).
+    ArticleLabelId => "article label";
+    /// Identifies an [`AllocationPeriod`](crate::types::AllocationPeriod).
+    AllocationPeriodId => "allocation period";
+    /// Identifies an [`Attachment`](crate::types::Attachment).
+    AttachmentId => "attachment";
+    /// Identifies a [`Bank`](crate::types::Bank).
+    BankId => "bank";
+    /// Identifies a [`BankAccount`](crate::types::BankAccount).
+    BankAccountId => "bank account";
+    /// Identifies a [`CostCenter`](crate::types::CostCenter).
+    CostCenterId => "cost center";
+    /// Identifies a [`CostCenterItem`](crate::types::CostCenterItem).
+    CostCenterItemId => "cost center item";
+    /// Identifies a [`Customer`](crate::types::Customer).
+    CustomerId => "customer";
+    /// Identifies a [`CustomerInvoiceDraft`](crate::types::CustomerInvoiceDraft).
+    CustomerInvoiceDraftId => "customer invoice draft";
+    /// Identifies a [`CustomerLabel`](crate::types::CustomerLabel).
+    CustomerLabelId => "customer label";
+    /// Identifies a [`CustomerLedgerItem`](crate::types::CustomerLedgerItem).
+    CustomerLedgerItemId => "customer ledger item";
+    /// Identifies a [`DeliveryMethod`](crate::types::DeliveryMethod).
+    DeliveryMethodId => "delivery method";
+    /// Identifies a [`DeliveryTerm`](crate::types::DeliveryTerm).
+    DeliveryTermId => "delivery term";
+    /// Identifies a [`Document`](crate::types::Document).
+    DocumentId => "document";
+    /// Identifies a [`FiscalYear`](crate::types::FiscalYear).
+    FiscalYearId => "fiscal year";
+    /// Identifies a customer [`Invoice`](crate::types::Invoice).
+    InvoiceId => "invoice";
+    /// Identifies a [`Message`](crate::types::Message).
+    MessageId => "message";
+    /// Identifies a [`MessageThread`](crate::types::MessageThread).
+    MessageThreadId => "message thread";
+    /// Identifies an [`Order`](crate::types::Order).
+    OrderId => "order";
+    /// Identifies a [`Project`](crate::types::Project).
+    ProjectId => "project";
+    /// Identifies a [`Quotation`](crate::types::Quotation).
+    QuotationId => "quotation";
+    /// Identifies a [`Supplier`](crate::types::Supplier).
+    SupplierId => "supplier";
+    /// Identifies a [`SupplierInvoice`](crate::types::SupplierInvoice).
+    SupplierInvoiceId => "supplier invoice";
+    /// Identifies a [`SupplierInvoiceDraft`](crate::types::SupplierInvoiceDraft).
+    SupplierInvoiceDraftId => "supplier invoice draft";
+    /// Identifies a [`SupplierLabel`](crate::types::SupplierLabel).
+    SupplierLabelId => "supplier label";
+    /// Identifies a [`SupplierLedgerItem`](crate::types::SupplierLedgerItem).
+    SupplierLedgerItemId => "supplier ledger item";
+    /// Identifies a [`TermsOfPayment`](crate::types::TermsOfPayment).
+    TermsOfPaymentId => "terms of payment";
+    /// Identifies a [`Unit`](crate::types::Unit).
+    UnitId => "unit";
+    /// Identifies a [`User`](crate::types::User).
+    UserId => "user";
+    /// Identifies a [`VatCode`](crate::types::VatCode).
+    VatCodeId => "VAT code";
+    /// Identifies a VAT report awaiting approval.
+    VatReportId => "VAT report";
+    /// Identifies a [`Voucher`](crate::types::Voucher).
+    VoucherId => "voucher";
+}
+
+/// Identifies the VAT rate applied to an article or row.
+///
+/// Articles and rows reference their VAT rate by the id of its
+/// [`VatCode`](crate::types::VatCode), so this is the same type.
+pub type VatRateId = VatCodeId;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUID: &str = "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b";
+
+    #[test]
+    fn test_parse_validates_format() {
+        assert_eq!(CustomerId::parse(GUID).unwrap().as_str(), GUID);
+        assert!(Guid::parse(&GUID.to_uppercase()).is_ok());
+
+        for invalid in [
+            "",
+            "cust-123",
+            "5f3a8c2e1b4d4e6f9a0b7c8d9e0f1a2b",
+            "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2",
+            "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b-00",
+            "zf3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b",
+        ] {
+            assert!(
+                Guid::parse(invalid).is_err(),
+                "{:?} should be rejected",
+                invalid
+            );
+        }
+
+        let err = InvoiceId::parse("cust-123").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Invalid request: Invalid invoice id: "cust-123""#
+        );
+    }
+
+    #[test]
+    fn test_serde_is_transparent() {
+        let id: ArticleId = serde_json::from_str(&format!("\"{}\"", GUID)).unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), format!("\"{}\"", GUID));
+        assert!(serde_json::from_str::<ArticleId>("\"art-1\"").is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let id: VoucherId = GUID.parse().unwrap();
+        assert_eq!(id, GUID);
+        assert_eq!(&*id, GUID);
+        assert_eq!(id.to_string(), GUID);
+        assert_eq!(String::from(id.clone()), GUID);
+
+        let guid = Guid::from(id);
+        let project = ProjectId::from_guid_unchecked(guid.clone());
+        assert_eq!(project.guid(), &guid);
+    }
+}
@@ -5,11 +5,24 @@
 //! ## Features
 //!
 //! - **OAuth2 Authentication**: Complete OAuth2 flow support with token refresh
-//! - **Type-safe API**: Strongly typed request/response models
+//! - **Type-safe API**: Strongly typed request/response models, resource ids and integer codes, see [`ids`] and [`codes`]
 //! - **Async/Await**: Built on tokio and reqwest for async operations
+//! - **Blocking Client**: Synchronous facade in [`blocking`] (requires the `blocking` feature)
+//! - **Bulk Operations**: Concurrent create/update/delete with a per-item report in [`bulk`] (requires the `bulk` feature)
+//! - **Conflict Detection**: Field-level diffs and `update_if_unmodified` in [`diff`]
+//! - **Dry Run**: Record mutations for review instead of sending them, see [`dry_run`]
+//! - **Forward Compatibility**: Unknown response fields are kept on update; strict mode reports them, see [`strict`]
+//! - **Schema Drift**: Opt-in per-endpoint report of new, missing and mistyped response fields, see [`drift`]
 //! - **Automatic Retries**: Exponential backoff for transient failures
 //! - **Request Tracing**: Built-in logging support with tracing
 //! - **Rate Limiting**: Automatic handling of API rate limits
+//! - **Local Mirror**: SQLite copy of the main resources in [`mirror`] (requires the `mirror` feature)
+//! - **Offline Mode**: Snapshot fallback for reads and a write outbox in [`offline`] (requires the `offline` feature)
+//! - **Currency-Aware Amounts**: Checked arithmetic, ISO 4217 rounding, conversion and formatting in [`amount`]
+//! - **Invoice Totals**: Local row, VAT and öresavrundning calculation matching the API in [`totals`]
+//! - **Balanced Vouchers**: Voucher builder checked against the chart of accounts and fiscal years in [`voucher`]
+//! - **Swedish Identifiers**: Checksum validation and formatting of organisation numbers, OCR, bankgiro, plusgiro, bank accounts and IBAN in [`validate`]
+//! - **Client-Side Validation**: Check requests before sending, with errors shaped like the API's, see [`validate::Validate`]
 //! - **Comprehensive Coverage**: Support for customers, invoices, articles, and more
 //!
 //! ## Quick Start
@@ -138,41 +151,79 @@
 //! # }
 //! ```
 
+pub mod amount;
 pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "bulk")]
+pub mod bulk;
 pub mod client;
+pub mod codes;
+pub mod diff;
+pub mod drift;
+pub mod dry_run;
 pub mod endpoints;
 pub mod error;
+pub mod ids;
+pub mod locale;
 #[macro_use]
 pub mod macros;
 pub mod middleware;
-#[cfg(feature = "stream")]
+#[cfg(feature = "mirror")]
+pub mod mirror;
+#[cfg(feature = "offline")]
+pub mod offline;
 pub mod pagination;
 pub mod query;
 #[cfg(feature = "rate-limit")]
 pub mod rate_limit;
+pub mod request;
+pub mod response;
 pub mod retry;
+pub mod strict;
+pub mod sync;
+pub mod totals;
 pub mod types;
+pub mod validate;
+pub mod voucher;
 #[cfg(feature = "webhooks")]
 pub mod webhooks;
 
 // Re-export commonly used types
 pub use auth::{AccessToken, OAuth2Config, OAuth2Handler};
 pub use client::{Client, ClientConfig};
-pub use error::{ApiErrorResponse, Error, Result, ValidationError};
+pub use codes::{BookkeepingMethod, DocumentType, VoucherType};
+pub use error::{ApiErrorCode, ApiErrorResponse, Error, Result, ValidationError};
+pub use ids::{
+    AllocationPeriodId, ArticleAccountCodingId, ArticleId, ArticleLabelId, AttachmentId,
+    BankAccountId, BankId, CostCenterId, CostCenterItemId, CustomerId, CustomerInvoiceDraftId,
+    CustomerLabelId, CustomerLedgerItemId, DeliveryMethodId, DeliveryTermId, DocumentId,
+    FiscalYearId, Guid, InvoiceId, MessageId, MessageThreadId, OrderId, ProjectId, QuotationId,
+    SupplierId, SupplierInvoiceDraftId, SupplierInvoiceId, SupplierLabelId, SupplierLedgerItemId,
+    TermsOfPaymentId, UnitId, UserId, VatCodeId, VatRateId, VatReportId, VoucherId,
+};
 #[cfg(feature = "rate-limit")]
 pub use rate_limit::RateLimitConfig;
+pub use request::Method;
+pub use response::{RawResponse, Response};
 pub use retry::RetryConfig;
 pub use types::{
     Account, AccountBalance, AccountType, Address, AllocationPeriod, Article, ArticleAccountCoding,
     ArticleCreate, ArticleLabel, ArticleUpdate, Attachment, AttachmentLink, Bank, BankAccount,
-    CompanySettings, ConvertDraftOptions, CostCenter, CostCenterItem, Country, Currency, Customer,
-    CustomerCreate, CustomerInvoiceDraft, CustomerInvoiceDraftRow, CustomerLabel,
-    CustomerLedgerItem, CustomerUpdate, DeliveryMethod, DeliveryTerm, Document, FiscalYear,
-    ForeignPaymentCode, Invoice, InvoiceCreate, InvoicePayment, InvoiceRow, InvoiceRowCreate,
-    InvoiceUpdate, Message, MessageThread, Money, Order, OrderRow, PaginatedResponse,
-    PaginationParams, Project, QueryParams, Quotation, QuotationRow, ResponseMetadata, Supplier,
-    SupplierInvoice, SupplierInvoiceDraft, SupplierInvoiceRow, SupplierLabel, SupplierLedgerItem,
-    TermsOfPayment, Unit, User, VatCode, Voucher, VoucherRow,
+    CompanySettings, ConvertDraftOptions, CostCenter, CostCenterItem, CostCenterItemCreate,
+    CostCenterItemUpdate, Country, CreateRequest, Currency, Customer, CustomerCreate,
+    CustomerInvoiceDraft, CustomerInvoiceDraftCreate, CustomerInvoiceDraftRow,
+    CustomerInvoiceDraftUpdate, CustomerLabel, CustomerLedgerItem, CustomerUpdate, DeliveryMethod,
+    DeliveryTerm, Document, FiscalYear, FiscalYearCreate, ForeignPaymentCode, Identifiable,
+    Invoice, InvoiceCreate, InvoicePayment, InvoiceRow, InvoiceRowCreate, InvoiceUpdate, Message,
+    MessageThread, Modified, Money, Order, OrderCreate, OrderRow, OrderUpdate, PaginatedResponse,
+    PaginationParams, Project, ProjectCreate, ProjectUpdate, QueryParams, Quotation,
+    QuotationCreate, QuotationRow, QuotationUpdate, ResponseMetadata, Supplier, SupplierCreate,
+    SupplierInvoice, SupplierInvoiceCreate, SupplierInvoiceDraft, SupplierInvoiceDraftCreate,
+    SupplierInvoiceDraftUpdate, SupplierInvoiceRow, SupplierInvoiceRowCreate,
+    SupplierInvoiceUpdate, SupplierLabel, SupplierLedgerItem, SupplierUpdate, TermsOfPayment, Unit,
+    UpdateRequest, User, VatCode, Voucher, VoucherCreate, VoucherRow, VoucherRowCreate,
+    VoucherUpdate,
 };
 
 // Add endpoint accessors to the Client
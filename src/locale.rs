@@ -0,0 +1,510 @@
+//! Localized, user-facing error messages.
+//!
+//! [`Error`] and [`ValidationError`] carry messages meant for developers. This
+//! module renders them as short messages for end users in Swedish or English,
+//! translating known API error codes, field names (for example
+//! `CorporateIdentityNumber` becomes "Organisationsnummer") and the
+//! [`ValidationMessage`]s of client-side validation. Anything without a
+//! translation falls back to the message returned by the API.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::locale::{Language, Localize};
+//! use spiris::Error;
+//!
+//! let err = Error::from_api_response(
+//!     400,
+//!     r#"{"Message": "Validation failed",
+//!         "ValidationErrors": [{"Field": "CorporateIdentityNumber", "Message": "Invalid format"}]}"#
+//!         .to_string(),
+//! );
+//!
+//! assert_eq!(
+//!     err.localize(Language::Swedish),
+//!     "Kontrollera uppgifterna: Organisationsnummer: Ogiltigt format"
+//! );
+//! ```
+
+use crate::error::{ApiErrorCode, ApiErrorResponse, Error, ValidationError};
+use crate::validate::ValidationMessage;
+use std::fmt;
+use std::str::FromStr;
+
+/// Language used for user-facing messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    /// Swedish (`sv`).
+    #[default]
+    Swedish,
+    /// English (`en`).
+    English,
+}
+
+impl Language {
+    /// Get the ISO 639-1 code for the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Swedish => "sv",
+            Language::English => "en",
+        }
+    }
+
+    /// Choose between a Swedish and an English text.
+    pub fn pick<'a>(self, sv: &'a str, en: &'a str) -> &'a str {
+        match self {
+            Language::Swedish => sv,
+            Language::English => en,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+
+    /// Parse a language code such as `sv`, `sv-SE`, `en` or `en_GB`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let primary = s
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match primary.as_str() {
+            "sv" | "swedish" | "svenska" => Ok(Language::Swedish),
+            "en" | "english" | "engelska" => Ok(Language::English),
+            _ => Err(Error::InvalidConfig(format!("Unsupported language: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Types that can be rendered as a user-facing message.
+pub trait Localize {
+    /// Render the value as a message in the given language.
+    fn localize(&self, language: Language) -> String;
+}
+
+/// Get the display name of an API field, if it has a translation.
+///
+/// Accepts plain field names (`EmailAddress`) as well as paths into nested
+/// objects or arrays (`Rows[0].UnitPrice`), in which case the last segment is
+/// translated.
+pub fn field_label(field: &str, language: Language) -> Option<&'static str> {
+    let name = field
+        .rsplit('.')
+        .next()
+        .unwrap_or(field)
+        .split('[')
+        .next()
+        .unwrap_or_default();
+
+    let (sv, en) = match name {
+        "CorporateIdentityNumber" => ("Organisationsnummer", "Corporate identity number"),
+        "VatNumber" => ("Momsregistreringsnummer", "VAT number"),
+        "Name" => ("Namn", "Name"),
+        "CustomerNumber" => ("Kundnummer", "Customer number"),
+        "SupplierNumber" => ("Leverantörsnummer", "Supplier number"),
+        "ArticleNumber" => ("Artikelnummer", "Article number"),
+        "Number" => ("Nummer", "Number"),
+        "CustomerId" => ("Kund", "Customer"),
+        "SupplierId" => ("Leverantör", "Supplier"),
+        "ArticleId" => ("Artikel", "Article"),
+        "EmailAddress" | "Email" => ("E-postadress", "Email address"),
+        "Telephone" | "Phone" => ("Telefon", "Phone"),
+        "MobilePhone" => ("Mobiltelefon", "Mobile phone"),
+        "WwwAddress" | "Website" => ("Webbplats", "Website"),
+        "InvoiceAddress1" | "Address1" => ("Adress", "Address"),
+        "InvoiceAddress2" | "Address2" => ("Adressrad 2", "Address line 2"),
+        "InvoicePostalCode" | "PostalCode" | "ZipCode" => ("Postnummer", "Postal code"),
+        "InvoiceCity" | "City" => ("Ort", "City"),
+        "InvoiceCountryCode" | "CountryCode" => ("Land", "Country"),
+        "CurrencyCode" => ("Valuta", "Currency"),
+        "TermsOfPaymentId" => ("Betalningsvillkor", "Terms of payment"),
+        "OcrNumber" => ("OCR-nummer", "OCR reference"),
+        "InvoiceDate" => ("Fakturadatum", "Invoice date"),
+        "DueDate" => ("Förfallodatum", "Due date"),
+        "DeliveryDate" => ("Leveransdatum", "Delivery date"),
+        "OrderDate" => ("Orderdatum", "Order date"),
+        "QuotationDate" => ("Offertdatum", "Quotation date"),
+        "YourReference" => ("Er referens", "Your reference"),
+        "OurReference" => ("Vår referens", "Our reference"),
+        "Rows" => ("Rader", "Rows"),
+        "Text" | "Description" => ("Beskrivning", "Description"),
+        "Quantity" => ("Antal", "Quantity"),
+        "UnitPrice" => ("À-pris", "Unit price"),
+        "SalesPrice" | "NetPrice" => ("Försäljningspris", "Sales price"),
+        "PurchasePrice" => ("Inköpspris", "Purchase price"),
+        "DiscountPercentage" => ("Rabatt", "Discount"),
+        "UnitId" | "Unit" => ("Enhet", "Unit"),
+        "VatRate" | "VatRateId" | "VatCode" => ("Momssats", "VAT rate"),
+        "VoucherDate" => ("Verifikationsdatum", "Voucher date"),
+        "VoucherText" => ("Verifikationstext", "Voucher text"),
+        "AccountNumber" => ("Kontonummer", "Account number"),
+        "DebitAmount" => ("Debet", "Debit"),
+        "CreditAmount" => ("Kredit", "Credit"),
+        "Amount" | "TotalAmount" => ("Belopp", "Amount"),
+        "FiscalYearId" => ("Räkenskapsår", "Fiscal year"),
+        "StartDate" => ("Startdatum", "Start date"),
+        "EndDate" => ("Slutdatum", "End date"),
+        "BankgiroNumber" | "BankGiroNumber" => ("Bankgironummer", "Bankgiro number"),
+        "PlusgiroNumber" | "PlusGiroNumber" => ("Plusgironummer", "Plusgiro number"),
+        "BankAccountNumber" => ("Bankkontonummer", "Bank account number"),
+        "Iban" => ("IBAN", "IBAN"),
+        "Bic" => ("BIC", "BIC"),
+        _ => return None,
+    };
+
+    Some(language.pick(sv, en))
+}
+
+impl Localize for ApiErrorCode {
+    fn localize(&self, language: Language) -> String {
+        api_error_code_message(self, language)
+            .map(str::to_string)
+            .unwrap_or_else(|| self.as_str().to_string())
+    }
+}
+
+fn api_error_code_message(code: &ApiErrorCode, language: Language) -> Option<&'static str> {
+    let (sv, en) = match code {
+        ApiErrorCode::ValidationError => (
+            "Uppgifterna är inte giltiga.",
+            "The information is not valid.",
+        ),
+        ApiErrorCode::NotFound => NOT_FOUND,
+        ApiErrorCode::InternalError => INTERNAL_ERROR,
+        ApiErrorCode::Other(_) => return None,
+    };
+
+    Some(language.pick(sv, en))
+}
+
+const NOT_FOUND: (&str, &str) = ("Posten hittades inte.", "The item was not found.");
+
+const RATE_LIMITED: (&str, &str) = (
+    "För många anrop. Försök igen om en stund.",
+    "Too many requests. Please try again shortly.",
+);
+
+const INTERNAL_ERROR: (&str, &str) = (
+    "Spiris har tekniska problem. Försök igen senare.",
+    "Spiris is having technical problems. Please try again later.",
+);
+
+fn status_message(status_code: u16, language: Language) -> Option<&'static str> {
+    let (sv, en) = match status_code {
+        401 => (
+            "Du är inte inloggad. Logga in igen.",
+            "You are not signed in. Please sign in again.",
+        ),
+        403 => (
+            "Du saknar behörighet för åtgärden.",
+            "You do not have permission to do this.",
+        ),
+        404 => NOT_FOUND,
+        409 | 412 => (
+            "Posten har ändrats av någon annan. Hämta den igen och försök på nytt.",
+            "The item was changed by someone else. Reload it and try again.",
+        ),
+        429 => RATE_LIMITED,
+        500..=599 => INTERNAL_ERROR,
+        _ => return None,
+    };
+
+    Some(language.pick(sv, en))
+}
+
+impl Localize for ValidationMessage {
+    fn localize(&self, language: Language) -> String {
+        let label = |field: &str| field_label(field, language).unwrap_or(field).to_string();
+        let (sv, en) = match self {
+            ValidationMessage::Required => ("Obligatoriskt", "Required"),
+            ValidationMessage::TooLong(max) => {
+                return match language {
+                    Language::Swedish => format!("Får vara högst {} tecken", max),
+                    Language::English => format!("Must be at most {} characters", max),
+                }
+            }
+            ValidationMessage::InvalidEmail => ("Ogiltig e-postadress", "Invalid email address"),
+            ValidationMessage::Negative => ("Får inte vara negativt", "Must not be negative"),
+            ValidationMessage::UnknownVatRate => ("Okänd momssats", "Unknown VAT rate"),
+            ValidationMessage::NotPercentage => {
+                ("Måste vara mellan 0 och 100", "Must be between 0 and 100")
+            }
+            ValidationMessage::Before(field) => {
+                return format!(
+                    "{} {}",
+                    language.pick("Får inte vara före", "Must not be before"),
+                    label(field)
+                )
+            }
+            ValidationMessage::NotAfter(field) => {
+                return format!(
+                    "{} {}",
+                    language.pick("Måste vara efter", "Must be after"),
+                    label(field)
+                )
+            }
+            ValidationMessage::InvalidFormat => ("Ogiltigt format", "Invalid format"),
+            ValidationMessage::InvalidCheckDigit => {
+                ("Ogiltig kontrollsiffra", "Invalid check digit")
+            }
+            ValidationMessage::InvalidDate => ("Ogiltigt datum", "Invalid date"),
+            ValidationMessage::InvalidLengthDigit => {
+                ("Ogiltig längdsiffra", "Invalid length digit")
+            }
+            ValidationMessage::UnknownClearingNumber => {
+                ("Okänt clearingnummer", "Unknown clearing number")
+            }
+        };
+        language.pick(sv, en).to_string()
+    }
+}
+
+impl Localize for ValidationError {
+    fn localize(&self, language: Language) -> String {
+        let message = ValidationMessage::parse(&self.message)
+            .map(|message| message.localize(language))
+            .unwrap_or_else(|| self.message.clone());
+        match field_label(&self.field, language) {
+            Some(label) => format!("{}: {}", label, message),
+            None if self.field.is_empty() => message,
+            None => format!("{}: {}", self.field, message),
+        }
+    }
+}
+
+/// One message listing every field, for API and client-side validation alike.
+fn localize_validation_errors(errors: &[ValidationError], language: Language) -> String {
+    let fields: Vec<String> = errors.iter().map(|e| e.localize(language)).collect();
+    format!(
+        "{} {}",
+        language.pick("Kontrollera uppgifterna:", "Please check:"),
+        fields.join("; ")
+    )
+}
+
+impl Localize for ApiErrorResponse {
+    fn localize(&self, language: Language) -> String {
+        if self.has_validation_errors() {
+            return localize_validation_errors(&self.validation_errors, language);
+        }
+
+        if let Some(message) = self
+            .code()
+            .and_then(|code| api_error_code_message(&code, language))
+        {
+            return message.to_string();
+        }
+
+        self.message.clone()
+    }
+}
+
+impl Localize for Error {
+    fn localize(&self, language: Language) -> String {
+        let with_detail = |sv: &'static str, en: &'static str, detail: &str| {
+            let message = language.pick(sv, en);
+            if detail.is_empty() {
+                message.to_string()
+            } else {
+                format!("{}: {}", message, detail)
+            }
+        };
+
+        match self {
+            Error::ApiError {
+                status_code,
+                response,
+                ..
+            } => {
+                let coded = response.has_validation_errors()
+                    || response
+                        .code()
+                        .is_some_and(|code| api_error_code_message(&code, language).is_some());
+                if coded {
+                    return response.localize(language);
+                }
+                if let Some(message) = status_message(*status_code, language) {
+                    return message.to_string();
+                }
+                if !response.message.is_empty() {
+                    return response.message.clone();
+                }
+                match language {
+                    Language::Swedish => format!("Något gick fel (status {}).", status_code),
+                    Language::English => format!("Something went wrong (status {}).", status_code),
+                }
+            }
+            Error::TokenExpired => language
+                .pick(
+                    "Din inloggning har gått ut. Logga in igen.",
+                    "Your session has expired. Please sign in again.",
+                )
+                .to_string(),
+            Error::Http(_) => language
+                .pick(
+                    "Kunde inte nå Spiris. Kontrollera internetanslutningen.",
+                    "Could not reach Spiris. Check your internet connection.",
+                )
+                .to_string(),
+            Error::Json(_) | Error::UnknownFields(_) => language
+                .pick(
+                    "Oväntat svar från Spiris.",
+                    "Unexpected response from Spiris.",
+                )
+                .to_string(),
+            Error::RateLimitExceeded(_) => {
+                language.pick(RATE_LIMITED.0, RATE_LIMITED.1).to_string()
+            }
+            Error::AuthError(detail) => with_detail(
+                "Autentiseringen misslyckades",
+                "Authentication failed",
+                detail,
+            ),
+            Error::NotFound(detail) => {
+                with_detail("Posten hittades inte", "The item was not found", detail)
+            }
+            Error::InvalidRequest(detail) => {
+                with_detail("Ogiltig begäran", "Invalid request", detail)
+            }
+            Error::Validation(errors) => localize_validation_errors(errors, language),
+            Error::InvalidConfig(detail) => {
+                with_detail("Felaktig inställning", "Invalid setting", detail)
+            }
+            Error::UrlParseError(e) => {
+                with_detail("Felaktig adress", "Invalid address", &e.to_string())
+            }
+            Error::OAuth2Error(detail) => {
+                with_detail("Inloggningen misslyckades", "Sign-in failed", detail)
+            }
+            #[cfg(any(feature = "mirror", feature = "offline"))]
+            Error::Database(e) => with_detail(
+                "Den lokala databasen kunde inte läsas",
+                "The local database could not be read",
+                &e.to_string(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_parsing() {
+        assert_eq!("sv".parse::<Language>().unwrap(), Language::Swedish);
+        assert_eq!("sv-SE".parse::<Language>().unwrap(), Language::Swedish);
+        assert_eq!("en_GB".parse::<Language>().unwrap(), Language::English);
+        assert!("fi".parse::<Language>().is_err());
+        assert_eq!(Language::default(), Language::Swedish);
+    }
+
+    #[test]
+    fn test_field_label() {
+        assert_eq!(
+            field_label("CorporateIdentityNumber", Language::Swedish),
+            Some("Organisationsnummer")
+        );
+        assert_eq!(
+            field_label("Rows[2].UnitPrice", Language::English),
+            Some("Unit price")
+        );
+        assert_eq!(field_label("SomethingElse", Language::Swedish), None);
+    }
+
+    #[test]
+    fn test_validation_error_localize() {
+        let err = ValidationError {
+            field: "EmailAddress".to_string(),
+            message: "Invalid format".to_string(),
+        };
+        assert_eq!(
+            err.localize(Language::Swedish),
+            "E-postadress: Ogiltigt format"
+        );
+
+        let err = ValidationError {
+            field: "DueDate".to_string(),
+            message: ValidationMessage::Before("InvoiceDate".to_string()).into(),
+        };
+        assert_eq!(
+            err.localize(Language::Swedish),
+            "Förfallodatum: Får inte vara före Fakturadatum"
+        );
+        assert_eq!(
+            err.localize(Language::English),
+            "Due date: Must not be before Invoice date"
+        );
+
+        let err = ValidationError {
+            field: "Custom".to_string(),
+            message: "Bad".to_string(),
+        };
+        assert_eq!(err.localize(Language::English), "Custom: Bad");
+    }
+
+    #[test]
+    fn test_error_code_takes_precedence() {
+        let err = Error::from_api_response(
+            400,
+            r#"{"ErrorCode": "VALIDATION_ERROR", "Message": "Bad input"}"#.to_string(),
+        );
+        assert_eq!(
+            err.localize(Language::Swedish),
+            "Uppgifterna är inte giltiga."
+        );
+        assert_eq!(
+            err.localize(Language::English),
+            "The information is not valid."
+        );
+    }
+
+    #[test]
+    fn test_status_fallback() {
+        let err = Error::from_api_response(404, String::new());
+        assert_eq!(err.localize(Language::Swedish), "Posten hittades inte.");
+
+        let err = Error::from_api_response(503, "Service Unavailable".to_string());
+        assert_eq!(
+            err.localize(Language::English),
+            "Spiris is having technical problems. Please try again later."
+        );
+    }
+
+    #[test]
+    fn test_raw_message_fallback() {
+        let err = Error::from_api_response(
+            400,
+            r#"{"ErrorCode": "Weird", "Message": "Invoice has already been sent"}"#.to_string(),
+        );
+        assert_eq!(
+            err.localize(Language::Swedish),
+            "Invoice has already been sent"
+        );
+
+        let err = Error::from_api_response(418, String::new());
+        assert_eq!(
+            err.localize(Language::Swedish),
+            "Något gick fel (status 418)."
+        );
+    }
+
+    #[test]
+    fn test_local_errors() {
+        assert_eq!(
+            Error::TokenExpired.localize(Language::Swedish),
+            "Din inloggning har gått ut. Logga in igen."
+        );
+        assert_eq!(
+            Error::InvalidRequest("Missing id".to_string()).localize(Language::English),
+            "Invalid request: Missing id"
+        );
+    }
+}
@@ -16,14 +16,28 @@
 /// - `update` - Update an existing item
 /// - `delete` - Delete an item
 /// - `search` - Search with query parameters
-/// - `stream` - Paginated streaming (requires `stream` feature)
+/// - `stream` - `list_stream` pagination streams, plus `search_stream` when
+///   `search` is also given (requires `stream` feature and `list`)
+///
+/// Every capability except `stream` also gets a `*_with_meta` variant
+/// (`list_with_meta`, `get_with_meta`, ...) that returns a
+/// [`Response`](crate::response::Response) with the HTTP status, headers and
+/// timing alongside the parsed body.
+///
+/// With the `blocking` feature, every capability except `stream` is also
+/// available synchronously on [`blocking::Endpoint`](crate::blocking::Endpoint).
+///
+/// # Ids
+///
+/// `id: Type` sets the id type taken by `get`, `update` and `delete`,
+/// usually one of the [`ids`](crate::ids) types. Without it they take `&str`.
 ///
 /// # Example
 ///
 /// ```ignore
 /// // Full CRUD endpoint with search and streaming
 /// define_endpoint! {
-///     CustomersEndpoint, "/customers", Customer,
+///     CustomersEndpoint, "/customers", Customer, id: CustomerId,
 ///     caps: [list, get, create, update, delete, search, stream]
 /// }
 ///
@@ -35,28 +49,349 @@
 /// ```
 #[macro_export]
 macro_rules! define_endpoint {
-    // Main entry point
+    // Without `id:`, items are looked up by a plain string such as a code
     (
         $(#[$outer:meta])*
         $endpoint:ident, $path:literal, $type:ty,
         caps: [$($cap:ident),* $(,)?]
         $(, extra: { $($extra:tt)* })?
+    ) => {
+        $crate::define_endpoint! {
+            $(#[$outer])*
+            $endpoint, $path, $type, id: str,
+            caps: [$($cap),*]
+            $(, extra: { $($extra)* })?
+        }
+    };
+
+    // Main entry point
+    (
+        $(#[$outer:meta])*
+        $endpoint:ident, $path:literal, $type:ty, id: $id:ty,
+        caps: [$($cap:ident),* $(,)?]
+        $(, extra: { $($extra:tt)* })?
     ) => {
         $(#[$outer])*
+        #[derive(Clone)]
         pub struct $endpoint<'a> {
-            client: &'a $crate::client::Client,
+            client: ::std::borrow::Cow<'a, $crate::client::Client>,
         }
 
+        $crate::__endpoint_owned_impl!($endpoint);
+
         impl<'a> $endpoint<'a> {
             /// Create a new endpoint instance.
             pub(crate) fn new(client: &'a $crate::client::Client) -> Self {
-                Self { client }
+                Self {
+                    client: ::std::borrow::Cow::Borrowed(client),
+                }
             }
 
-            $crate::__endpoint_impl!($path, $type, $($cap),*);
+            $crate::__endpoint_impl!($path, $type, $id, $($cap),*);
+            $crate::__endpoint_search_stream_caps!($path, $type, false false; $($cap),*);
+            $crate::__endpoint_meta_impl!($path, $type, $id, $($cap),*);
 
             $($($extra)*)?
         }
+
+        #[cfg(feature = "blocking")]
+        impl<'a> $crate::blocking::Endpoint<'a, $endpoint<'a>> {
+            $crate::__blocking_endpoint_impl!($type, $id, $($cap),*);
+        }
+    };
+}
+
+/// Internal macro for implementing `into_owned` on an endpoint handle.
+///
+/// Also used directly by hand-written endpoints, which share the same
+/// `client: Cow<'a, Client>` layout.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_owned_impl {
+    ($endpoint:ident) => {
+        impl $endpoint<'_> {
+            /// Convert this handle into one that owns its client.
+            ///
+            /// The client is cheap to clone (it shares its connection pool,
+            /// token and rate limiter), so the returned `'static` handle can
+            /// be stored in a struct or moved into a spawned task.
+            pub fn into_owned(self) -> $endpoint<'static> {
+                $endpoint {
+                    client: ::std::borrow::Cow::Owned(self.client.into_owned()),
+                }
+            }
+        }
+    };
+}
+
+/// Internal macro for implementing the pagination streams of an endpoint.
+///
+/// Also used directly by hand-written endpoints, which need a `list` method
+/// taking `Option<PaginationParams>`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_stream_impl {
+    ($path:literal, $type:ty) => {
+        /// Stream all items, automatically paginating through results.
+        ///
+        /// The stream owns a clone of the client, so it is `'static` and can
+        /// be stored in a struct or moved into a spawned task.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn list_stream(
+            &self,
+        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + Send + 'static {
+            self.list_stream_with($crate::pagination::StreamOptions::new())
+        }
+
+        /// Stream all items with a custom page size.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn list_stream_with_page_size(
+            &self,
+            page_size: u32,
+        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + Send + 'static {
+            self.list_stream_with($crate::pagination::StreamOptions::new().page_size(page_size))
+        }
+
+        /// Stream all items with a page size, item cap, page prefetching and
+        /// progress callback.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn list_stream_with(
+            &self,
+            options: $crate::pagination::StreamOptions,
+        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + Send + 'static {
+            $crate::pagination::flatten_pages(self.list_page_stream(options))
+        }
+
+        /// Stream all items one page at a time, for batch consumers.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn list_page_stream(
+            &self,
+            options: $crate::pagination::StreamOptions,
+        ) -> impl futures::Stream<Item = $crate::error::Result<Vec<$type>>> + Send + 'static {
+            let endpoint = self.clone().into_owned();
+            $crate::paginated_page_stream!(options: options, |params| endpoint.list(Some(params)))
+        }
+
+        /// Stream all items, recording a
+        /// [`Checkpoint`](crate::pagination::Checkpoint) that
+        /// [`resume_stream`](Self::resume_stream) can continue from.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn list_stream_resumable(
+            &self,
+            options: $crate::pagination::StreamOptions,
+        ) -> $crate::pagination::ResumableStream<$type> {
+            let checkpoint = $crate::pagination::Checkpoint::start($path, None, &options);
+            self.stream_from_checkpoint(checkpoint, options)
+        }
+
+        /// Resume a list or search stream from a saved checkpoint.
+        ///
+        /// The page size and query come from the checkpoint; `options`
+        /// supplies the item cap and progress callback.
+        ///
+        /// Requires the `stream` feature.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidConfig`](crate::Error::InvalidConfig) if
+        /// the checkpoint was taken from another endpoint.
+        #[cfg(feature = "stream")]
+        pub fn resume_stream(
+            &self,
+            checkpoint: $crate::pagination::Checkpoint,
+            options: $crate::pagination::StreamOptions,
+        ) -> $crate::error::Result<$crate::pagination::ResumableStream<$type>> {
+            if checkpoint.path != $path {
+                return Err($crate::error::Error::InvalidConfig(format!(
+                    "checkpoint for {} cannot resume a {} stream",
+                    checkpoint.path, $path
+                )));
+            }
+            Ok(self.stream_from_checkpoint(checkpoint, options))
+        }
+
+        #[cfg(feature = "stream")]
+        fn stream_from_checkpoint(
+            &self,
+            checkpoint: $crate::pagination::Checkpoint,
+            options: $crate::pagination::StreamOptions,
+        ) -> $crate::pagination::ResumableStream<$type> {
+            #[derive(serde::Serialize)]
+            struct CombinedParams {
+                #[serde(flatten)]
+                query: $crate::types::QueryParams,
+                #[serde(flatten)]
+                pagination: $crate::types::PaginationParams,
+            }
+
+            let endpoint = self.clone().into_owned();
+            let query = checkpoint.query.clone();
+            $crate::pagination::resumable_stream(checkpoint, options, move |pagination| {
+                let endpoint = endpoint.clone();
+                let query = query.clone();
+                async move {
+                    match query {
+                        Some(query) => {
+                            endpoint
+                                .client
+                                .get_with_params($path, &CombinedParams { query, pagination })
+                                .await
+                        }
+                        None => endpoint.list(Some(pagination)).await,
+                    }
+                }
+            })
+        }
+    };
+}
+
+/// Internal macro for implementing the search streams of an endpoint.
+///
+/// Generated only for endpoints with both the `search` and `stream`
+/// capabilities, alongside [`__endpoint_stream_impl!`]. Also used directly by
+/// hand-written endpoints that have a `search` method.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_search_stream_impl {
+    ($path:literal, $type:ty) => {
+        /// Stream all items matching `query`, automatically paginating through
+        /// results.
+        ///
+        /// Pages are requested by the stream; `query` supplies the filter and
+        /// field selection.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn search_stream(
+            &self,
+            query: $crate::types::QueryParams,
+        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + Send + 'static {
+            self.search_stream_with(query, $crate::pagination::StreamOptions::new())
+        }
+
+        /// Stream all items matching `query` with a page size, item cap, page
+        /// prefetching and progress callback.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn search_stream_with(
+            &self,
+            query: $crate::types::QueryParams,
+            options: $crate::pagination::StreamOptions,
+        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + Send + 'static {
+            $crate::pagination::flatten_pages(self.search_page_stream(query, options))
+        }
+
+        /// Stream all items matching `query` one page at a time, for batch
+        /// consumers.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn search_page_stream(
+            &self,
+            query: $crate::types::QueryParams,
+            options: $crate::pagination::StreamOptions,
+        ) -> impl futures::Stream<Item = $crate::error::Result<Vec<$type>>> + Send + 'static {
+            #[derive(serde::Serialize)]
+            struct CombinedParams {
+                #[serde(flatten)]
+                query: $crate::types::QueryParams,
+                #[serde(flatten)]
+                pagination: $crate::types::PaginationParams,
+            }
+
+            let endpoint = self.clone().into_owned();
+            $crate::paginated_page_stream!(options: options, |pagination| {
+                let params = CombinedParams {
+                    query: query.clone(),
+                    pagination,
+                };
+                let client = &endpoint.client;
+                async move {
+                    client
+                        .get_with_params::<$crate::types::PaginatedResponse<$type>, _>(
+                            $path, &params,
+                        )
+                        .await
+                }
+            })
+        }
+
+        /// Stream all items matching `query`, recording a
+        /// [`Checkpoint`](crate::pagination::Checkpoint) that
+        /// [`resume_stream`](Self::resume_stream) can continue from.
+        ///
+        /// Requires the `stream` feature.
+        #[cfg(feature = "stream")]
+        pub fn search_stream_resumable(
+            &self,
+            query: $crate::types::QueryParams,
+            options: $crate::pagination::StreamOptions,
+        ) -> $crate::pagination::ResumableStream<$type> {
+            let checkpoint = $crate::pagination::Checkpoint::start($path, Some(query), &options);
+            self.stream_from_checkpoint(checkpoint, options)
+        }
+    };
+}
+
+/// Internal macro that generates the search streams only when the capability
+/// list contains both `search` and `stream`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_search_stream_caps {
+    ($path:literal, $type:ty, true true;) => {
+        $crate::__endpoint_search_stream_impl!($path, $type);
+    };
+    ($path:literal, $type:ty, $search:tt $stream:tt;) => {};
+    ($path:literal, $type:ty, $search:tt $stream:tt; search $(, $rest:ident)*) => {
+        $crate::__endpoint_search_stream_caps!($path, $type, true $stream; $($rest),*);
+    };
+    ($path:literal, $type:ty, $search:tt $stream:tt; stream $(, $rest:ident)*) => {
+        $crate::__endpoint_search_stream_caps!($path, $type, $search true; $($rest),*);
+    };
+    ($path:literal, $type:ty, $search:tt $stream:tt; $cap:ident $(, $rest:ident)*) => {
+        $crate::__endpoint_search_stream_caps!($path, $type, $search $stream; $($rest),*);
+    };
+}
+
+/// Internal macro for implementing `update_if_unmodified` on an endpoint.
+///
+/// Requires `get` and `update` methods and a type implementing
+/// [`Modified`](crate::types::Modified).
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_conditional_update_impl {
+    ($type:ty, $id:ty) => {
+        /// Update an item only if it is unchanged since `original` was fetched.
+        ///
+        /// Re-fetches the item and compares its `ModifiedUtc` and field values
+        /// with `original`. If they match, `modified` is sent; otherwise nothing
+        /// is sent and the changes on both sides are returned as a
+        /// [`Conflict`](crate::diff::ConditionalUpdate::Conflict).
+        pub async fn update_if_unmodified(
+            &self,
+            id: &$id,
+            original: &$type,
+            modified: &$type,
+        ) -> $crate::error::Result<$crate::diff::ConditionalUpdate<$type>> {
+            let current = self.get(id).await?;
+            match $crate::diff::UpdateConflict::detect(original, modified, current)? {
+                Some(conflict) => Ok($crate::diff::ConditionalUpdate::Conflict(conflict)),
+                None => Ok($crate::diff::ConditionalUpdate::Updated(
+                    self.update(id, modified).await?,
+                )),
+            }
+        }
     };
 }
 
@@ -65,10 +400,10 @@ macro_rules! define_endpoint {
 #[doc(hidden)]
 macro_rules! __endpoint_impl {
     // Base case - no more capabilities
-    ($path:literal, $type:ty,) => {};
+    ($path:literal, $type:ty, $id:ty,) => {};
 
     // list capability
-    ($path:literal, $type:ty, list $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, list $(, $rest:ident)*) => {
         /// List all items with optional pagination.
         pub async fn list(
             &self,
@@ -81,51 +416,51 @@ macro_rules! __endpoint_impl {
             }
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // get capability
-    ($path:literal, $type:ty, get $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, get $(, $rest:ident)*) => {
         /// Get a specific item by ID.
-        pub async fn get(&self, id: &str) -> $crate::error::Result<$type> {
+        pub async fn get(&self, id: &$id) -> $crate::error::Result<$type> {
             self.client.get(&format!(concat!($path, "/{}"), id)).await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // create capability
-    ($path:literal, $type:ty, create $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, create $(, $rest:ident)*) => {
         /// Create a new item.
-        pub async fn create(&self, item: &$type) -> $crate::error::Result<$type> {
+        pub async fn create(&self, item: &impl $crate::types::CreateRequest<$type>) -> $crate::error::Result<$type> {
             self.client.post($path, item).await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // update capability
-    ($path:literal, $type:ty, update $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, update $(, $rest:ident)*) => {
         /// Update an existing item.
-        pub async fn update(&self, id: &str, item: &$type) -> $crate::error::Result<$type> {
+        pub async fn update(&self, id: &$id, item: &impl $crate::types::UpdateRequest<$type>) -> $crate::error::Result<$type> {
             self.client.put(&format!(concat!($path, "/{}"), id), item).await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // delete capability
-    ($path:literal, $type:ty, delete $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, delete $(, $rest:ident)*) => {
         /// Delete an item.
-        pub async fn delete(&self, id: &str) -> $crate::error::Result<()> {
+        pub async fn delete(&self, id: &$id) -> $crate::error::Result<()> {
             self.client.delete(&format!(concat!($path, "/{}"), id)).await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // search capability
-    ($path:literal, $type:ty, search $(, $rest:ident)*) => {
+    ($path:literal, $type:ty, $id:ty, search $(, $rest:ident)*) => {
         /// Search items with custom query parameters.
         pub async fn search(
             &self,
@@ -144,36 +479,251 @@ macro_rules! __endpoint_impl {
             self.client.get_with_params($path, &params).await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
     };
 
     // stream capability
-    ($path:literal, $type:ty, stream $(, $rest:ident)*) => {
-        /// Stream all items, automatically paginating through results.
-        ///
-        /// Requires the `stream` feature.
-        #[cfg(feature = "stream")]
-        pub fn list_stream(&self) -> impl futures::Stream<Item = $crate::error::Result<$type>> + '_ {
-            self.list_stream_with_page_size($crate::pagination::DEFAULT_PAGE_SIZE)
+    ($path:literal, $type:ty, $id:ty, stream $(, $rest:ident)*) => {
+        $crate::__endpoint_stream_impl!($path, $type);
+
+        $crate::__endpoint_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // Handle trailing comma
+    ($path:literal, $type:ty, $id:ty, $cap:ident,) => {
+        $crate::__endpoint_impl!($path, $type, $id, $cap);
+    };
+}
+
+/// Internal macro for implementing the `*_with_meta` variants of endpoint
+/// capabilities.
+///
+/// Also used directly by hand-written endpoints to get the same variants.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __endpoint_meta_impl {
+    // Base case - no more capabilities
+    ($path:literal, $type:ty, $id:ty,) => {};
+
+    // list capability
+    ($path:literal, $type:ty, $id:ty, list $(, $rest:ident)*) => {
+        /// List all items with optional pagination, including response metadata.
+        pub async fn list_with_meta(
+            &self,
+            params: Option<$crate::types::PaginationParams>,
+        ) -> $crate::error::Result<$crate::response::Response<$crate::types::PaginatedResponse<$type>>> {
+            let request = self.client.request($crate::request::Method::GET, $path);
+            match params {
+                Some(params) => request.query(&params),
+                None => request,
+            }
+            .send_json_with_meta()
+            .await
         }
 
-        /// Stream all items with a custom page size.
-        ///
-        /// Requires the `stream` feature.
-        #[cfg(feature = "stream")]
-        pub fn list_stream_with_page_size(
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // get capability
+    ($path:literal, $type:ty, $id:ty, get $(, $rest:ident)*) => {
+        /// Get a specific item by ID, including response metadata.
+        pub async fn get_with_meta(
             &self,
-            page_size: u32,
-        ) -> impl futures::Stream<Item = $crate::error::Result<$type>> + '_ {
-            $crate::paginated_stream!(page_size, |params| self.list(Some(params)))
+            id: &$id,
+        ) -> $crate::error::Result<$crate::response::Response<$type>> {
+            self.client
+                .request($crate::request::Method::GET, &format!(concat!($path, "/{}"), id))
+                .send_json_with_meta()
+                .await
         }
 
-        $crate::__endpoint_impl!($path, $type, $($rest),*);
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
     };
 
-    // Handle trailing comma
-    ($path:literal, $type:ty, $cap:ident,) => {
-        $crate::__endpoint_impl!($path, $type, $cap);
+    // create capability
+    ($path:literal, $type:ty, $id:ty, create $(, $rest:ident)*) => {
+        /// Create a new item, including response metadata.
+        pub async fn create_with_meta(
+            &self,
+            item: &impl $crate::types::CreateRequest<$type>,
+        ) -> $crate::error::Result<$crate::response::Response<$type>> {
+            self.client
+                .request($crate::request::Method::POST, $path)
+                .json(item)
+                .send_json_with_meta()
+                .await
+        }
+
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // update capability
+    ($path:literal, $type:ty, $id:ty, update $(, $rest:ident)*) => {
+        /// Update an existing item, including response metadata.
+        pub async fn update_with_meta(
+            &self,
+            id: &$id,
+            item: &impl $crate::types::UpdateRequest<$type>,
+        ) -> $crate::error::Result<$crate::response::Response<$type>> {
+            self.client
+                .request($crate::request::Method::PUT, &format!(concat!($path, "/{}"), id))
+                .json(item)
+                .send_json_with_meta()
+                .await
+        }
+
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // delete capability
+    ($path:literal, $type:ty, $id:ty, delete $(, $rest:ident)*) => {
+        /// Delete an item, including response metadata.
+        pub async fn delete_with_meta(
+            &self,
+            id: &$id,
+        ) -> $crate::error::Result<$crate::response::Response<()>> {
+            let response = self
+                .client
+                .request($crate::request::Method::DELETE, &format!(concat!($path, "/{}"), id))
+                .send_raw()
+                .await?;
+            Ok(response.map(|_| ()))
+        }
+
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // search capability
+    ($path:literal, $type:ty, $id:ty, search $(, $rest:ident)*) => {
+        /// Search items with custom query parameters, including response metadata.
+        pub async fn search_with_meta(
+            &self,
+            query: $crate::types::QueryParams,
+            pagination: Option<$crate::types::PaginationParams>,
+        ) -> $crate::error::Result<$crate::response::Response<$crate::types::PaginatedResponse<$type>>> {
+            #[derive(serde::Serialize)]
+            struct CombinedParams {
+                #[serde(flatten)]
+                query: $crate::types::QueryParams,
+                #[serde(flatten)]
+                pagination: Option<$crate::types::PaginationParams>,
+            }
+
+            self.client
+                .request($crate::request::Method::GET, $path)
+                .query(&CombinedParams { query, pagination })
+                .send_json_with_meta()
+                .await
+        }
+
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+
+    // stream capability has no metadata variant
+    ($path:literal, $type:ty, $id:ty, stream $(, $rest:ident)*) => {
+        $crate::__endpoint_meta_impl!($path, $type, $id, $($rest),*);
+    };
+}
+
+/// Internal macro for implementing blocking methods that delegate to the
+/// async endpoint.
+///
+/// Used directly for hand-written endpoint methods, and by
+/// `__blocking_endpoint_impl!` for the standard capabilities.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __blocking_methods {
+    ($(
+        $(#[$meta:meta])*
+        fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)* $(,)?) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $method(&self $(, $arg: $arg_ty)*) -> $crate::error::Result<$ret> {
+                self.block_on(self.inner.$method($($arg),*))
+            }
+        )*
+    };
+}
+
+/// Internal macro for implementing the blocking variants of endpoint
+/// capabilities.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __blocking_endpoint_impl {
+    // Base case - no more capabilities
+    ($type:ty, $id:ty,) => {};
+
+    // list capability
+    ($type:ty, $id:ty, list $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// List all items with optional pagination.
+            fn list(
+                &self,
+                params: Option<$crate::types::PaginationParams>,
+            ) -> $crate::types::PaginatedResponse<$type>;
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // get capability
+    ($type:ty, $id:ty, get $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// Get a specific item by ID.
+            fn get(&self, id: &$id) -> $type;
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // create capability
+    ($type:ty, $id:ty, create $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// Create a new item.
+            fn create(&self, item: &impl $crate::types::CreateRequest<$type>) -> $type;
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // update capability
+    ($type:ty, $id:ty, update $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// Update an existing item.
+            fn update(&self, id: &$id, item: &impl $crate::types::UpdateRequest<$type>) -> $type;
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // delete capability
+    ($type:ty, $id:ty, delete $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// Delete an item.
+            fn delete(&self, id: &$id) -> ();
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // search capability
+    ($type:ty, $id:ty, search $(, $rest:ident)*) => {
+        $crate::__blocking_methods! {
+            /// Search items with custom query parameters.
+            fn search(
+                &self,
+                query: $crate::types::QueryParams,
+                pagination: Option<$crate::types::PaginationParams>,
+            ) -> $crate::types::PaginatedResponse<$type>;
+        }
+
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
+    };
+
+    // stream capability has no blocking variant
+    ($type:ty, $id:ty, stream $(, $rest:ident)*) => {
+        $crate::__blocking_endpoint_impl!($type, $id, $($rest),*);
     };
 }
 
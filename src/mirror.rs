@@ -0,0 +1,553 @@
+//! Local SQLite mirror of the main accounting resources.
+//!
+//! A [`Mirror`] keeps customers, suppliers, articles, invoices, supplier
+//! invoices, vouchers, accounts and ledger items in a SQLite database so they
+//! can be queried offline with plain SQL. Requires the `mirror` feature.
+//!
+//! Every resource has its own table with the item's JSON in a `data` column
+//! and the commonly queried fields exposed as indexed columns (see
+//! [`SCHEMA`]). The schema version is stored in `PRAGMA user_version`.
+//!
+//! Resources that carry `ModifiedUtc` are refreshed incrementally with the
+//! [`sync`](crate::sync) engine; its state is stored in the `sync_state`
+//! table, so a refresh after a restart only pulls what changed. Accounts and
+//! ledger items have no modification timestamp and are replaced in full on
+//! every refresh. Each resource is written in a single transaction, so a
+//! failed refresh never leaves a table half updated.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::mirror::Mirror;
+//! use spiris::{AccessToken, Client};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//! let mut mirror = Mirror::open("spiris.db")?;
+//! mirror.refresh(&client).await?;
+//!
+//! let mut statement = mirror.connection().prepare(
+//!     "SELECT invoice_number, total_amount_including_vat FROM invoices
+//!      WHERE customer_id = ?1 ORDER BY invoice_date",
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::ids::FiscalYearId;
+use crate::pagination::fetch_all;
+use crate::sync::{Change, SyncConfig, SyncSource, SyncState, Syncer};
+use crate::types::{
+    Account, Article, Customer, CustomerLedgerItem, Invoice, Supplier, SupplierInvoice,
+    SupplierLedgerItem, Voucher,
+};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// Items requested per page when a table is replaced wholesale.
+const PAGE_SIZE: u32 = 500;
+
+/// Version of [`SCHEMA`], stored in `PRAGMA user_version`.
+pub const SCHEMA_VERSION: i32 = 1;
+
+/// The mirror's tables and indexes.
+///
+/// Columns other than `id` and `data` are generated from the JSON and are
+/// part of the stable schema for external queries.
+pub const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS customers (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    customer_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.CustomerNumber')) VIRTUAL,
+    corporate_identity_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.CorporateIdentityNumber')) VIRTUAL,
+    name TEXT GENERATED ALWAYS AS (json_extract(data, '$.Name')) VIRTUAL,
+    email TEXT GENERATED ALWAYS AS (json_extract(data, '$.Email')) VIRTUAL,
+    is_active INTEGER GENERATED ALWAYS AS (json_extract(data, '$.IsActive')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS customers_customer_number ON customers (customer_number);
+CREATE INDEX IF NOT EXISTS customers_name ON customers (name);
+CREATE INDEX IF NOT EXISTS customers_modified_utc ON customers (modified_utc);
+
+CREATE TABLE IF NOT EXISTS suppliers (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    supplier_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.SupplierNumber')) VIRTUAL,
+    corporate_identity_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.CorporateIdentityNumber')) VIRTUAL,
+    name TEXT GENERATED ALWAYS AS (json_extract(data, '$.Name')) VIRTUAL,
+    is_active INTEGER GENERATED ALWAYS AS (json_extract(data, '$.IsActive')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS suppliers_supplier_number ON suppliers (supplier_number);
+CREATE INDEX IF NOT EXISTS suppliers_name ON suppliers (name);
+CREATE INDEX IF NOT EXISTS suppliers_modified_utc ON suppliers (modified_utc);
+
+CREATE TABLE IF NOT EXISTS articles (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    article_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.ArticleNumber')) VIRTUAL,
+    name TEXT GENERATED ALWAYS AS (json_extract(data, '$.Name')) VIRTUAL,
+    sales_price REAL GENERATED ALWAYS AS (json_extract(data, '$.SalesPrice')) VIRTUAL,
+    is_active INTEGER GENERATED ALWAYS AS (json_extract(data, '$.IsActive')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS articles_article_number ON articles (article_number);
+CREATE INDEX IF NOT EXISTS articles_name ON articles (name);
+CREATE INDEX IF NOT EXISTS articles_modified_utc ON articles (modified_utc);
+
+CREATE TABLE IF NOT EXISTS invoices (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    invoice_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.InvoiceNumber')) VIRTUAL,
+    customer_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.CustomerId')) VIRTUAL,
+    invoice_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.InvoiceDate')) VIRTUAL,
+    due_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.DueDate')) VIRTUAL,
+    currency_code TEXT GENERATED ALWAYS AS (json_extract(data, '$.CurrencyCode')) VIRTUAL,
+    total_amount_including_vat REAL GENERATED ALWAYS AS (json_extract(data, '$.TotalAmountIncludingVat')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS invoices_invoice_number ON invoices (invoice_number);
+CREATE INDEX IF NOT EXISTS invoices_customer_id ON invoices (customer_id);
+CREATE INDEX IF NOT EXISTS invoices_invoice_date ON invoices (invoice_date);
+CREATE INDEX IF NOT EXISTS invoices_due_date ON invoices (due_date);
+CREATE INDEX IF NOT EXISTS invoices_modified_utc ON invoices (modified_utc);
+
+CREATE TABLE IF NOT EXISTS supplier_invoices (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    invoice_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.InvoiceNumber')) VIRTUAL,
+    supplier_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.SupplierId')) VIRTUAL,
+    invoice_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.InvoiceDate')) VIRTUAL,
+    due_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.DueDate')) VIRTUAL,
+    is_paid INTEGER GENERATED ALWAYS AS (json_extract(data, '$.IsPaid')) VIRTUAL,
+    total_amount_including_vat REAL GENERATED ALWAYS AS (json_extract(data, '$.TotalAmountIncludingVat')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS supplier_invoices_supplier_id ON supplier_invoices (supplier_id);
+CREATE INDEX IF NOT EXISTS supplier_invoices_invoice_date ON supplier_invoices (invoice_date);
+CREATE INDEX IF NOT EXISTS supplier_invoices_due_date ON supplier_invoices (due_date);
+CREATE INDEX IF NOT EXISTS supplier_invoices_modified_utc ON supplier_invoices (modified_utc);
+
+CREATE TABLE IF NOT EXISTS vouchers (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    voucher_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.VoucherNumber')) VIRTUAL,
+    voucher_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.VoucherDate')) VIRTUAL,
+    voucher_type INTEGER GENERATED ALWAYS AS (json_extract(data, '$.VoucherType')) VIRTUAL,
+    modified_utc TEXT GENERATED ALWAYS AS (json_extract(data, '$.ModifiedUtc')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS vouchers_voucher_number ON vouchers (voucher_number);
+CREATE INDEX IF NOT EXISTS vouchers_voucher_date ON vouchers (voucher_date);
+CREATE INDEX IF NOT EXISTS vouchers_modified_utc ON vouchers (modified_utc);
+
+CREATE TABLE IF NOT EXISTS accounts (
+    data TEXT NOT NULL,
+    account_number TEXT GENERATED ALWAYS AS (json_extract(data, '$.AccountNumber')) VIRTUAL,
+    fiscal_year_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.FiscalYearId')) VIRTUAL,
+    name TEXT GENERATED ALWAYS AS (json_extract(data, '$.Name')) VIRTUAL,
+    account_type INTEGER GENERATED ALWAYS AS (json_extract(data, '$.AccountType')) VIRTUAL,
+    is_active INTEGER GENERATED ALWAYS AS (json_extract(data, '$.IsActive')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS accounts_account_number ON accounts (account_number, fiscal_year_id);
+
+CREATE TABLE IF NOT EXISTS customer_ledger_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    customer_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.CustomerId')) VIRTUAL,
+    customer_invoice_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.CustomerInvoiceId')) VIRTUAL,
+    amount REAL GENERATED ALWAYS AS (json_extract(data, '$.Amount')) VIRTUAL,
+    payment_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.PaymentDate')) VIRTUAL,
+    voucher_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.VoucherId')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS customer_ledger_items_customer_id ON customer_ledger_items (customer_id);
+CREATE INDEX IF NOT EXISTS customer_ledger_items_invoice_id ON customer_ledger_items (customer_invoice_id);
+CREATE INDEX IF NOT EXISTS customer_ledger_items_payment_date ON customer_ledger_items (payment_date);
+
+CREATE TABLE IF NOT EXISTS supplier_ledger_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    supplier_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.SupplierId')) VIRTUAL,
+    supplier_invoice_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.SupplierInvoiceId')) VIRTUAL,
+    amount REAL GENERATED ALWAYS AS (json_extract(data, '$.Amount')) VIRTUAL,
+    payment_date TEXT GENERATED ALWAYS AS (json_extract(data, '$.PaymentDate')) VIRTUAL,
+    voucher_id TEXT GENERATED ALWAYS AS (json_extract(data, '$.VoucherId')) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS supplier_ledger_items_supplier_id ON supplier_ledger_items (supplier_id);
+CREATE INDEX IF NOT EXISTS supplier_ledger_items_invoice_id ON supplier_ledger_items (supplier_invoice_id);
+CREATE INDEX IF NOT EXISTS supplier_ledger_items_payment_date ON supplier_ledger_items (payment_date);
+
+CREATE TABLE IF NOT EXISTS sync_state (
+    resource TEXT PRIMARY KEY NOT NULL,
+    state TEXT,
+    refreshed_at TEXT NOT NULL
+);
+"#;
+
+/// A resource kept in the mirror.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// Customers, table `customers`.
+    Customers,
+    /// Suppliers, table `suppliers`.
+    Suppliers,
+    /// Articles, table `articles`.
+    Articles,
+    /// Customer invoices, table `invoices`.
+    Invoices,
+    /// Supplier invoices, table `supplier_invoices`.
+    SupplierInvoices,
+    /// Vouchers, table `vouchers`.
+    Vouchers,
+    /// Chart of accounts, table `accounts`.
+    Accounts,
+    /// Customer payments, table `customer_ledger_items`.
+    CustomerLedgerItems,
+    /// Supplier payments, table `supplier_ledger_items`.
+    SupplierLedgerItems,
+}
+
+impl Resource {
+    /// Every mirrored resource, in refresh order.
+    pub const ALL: [Resource; 9] = [
+        Resource::Customers,
+        Resource::Suppliers,
+        Resource::Articles,
+        Resource::Invoices,
+        Resource::SupplierInvoices,
+        Resource::Vouchers,
+        Resource::Accounts,
+        Resource::CustomerLedgerItems,
+        Resource::SupplierLedgerItems,
+    ];
+
+    /// Name of the resource's table.
+    pub fn table(&self) -> &'static str {
+        match self {
+            Resource::Customers => "customers",
+            Resource::Suppliers => "suppliers",
+            Resource::Articles => "articles",
+            Resource::Invoices => "invoices",
+            Resource::SupplierInvoices => "supplier_invoices",
+            Resource::Vouchers => "vouchers",
+            Resource::Accounts => "accounts",
+            Resource::CustomerLedgerItems => "customer_ledger_items",
+            Resource::SupplierLedgerItems => "supplier_ledger_items",
+        }
+    }
+
+    /// Whether the resource is refreshed incrementally.
+    ///
+    /// Resources without `ModifiedUtc` are replaced in full instead.
+    pub fn is_incremental(&self) -> bool {
+        !matches!(
+            self,
+            Resource::Accounts | Resource::CustomerLedgerItems | Resource::SupplierLedgerItems
+        )
+    }
+}
+
+/// A type stored in the mirror.
+pub trait Mirrored: Serialize + DeserializeOwned {
+    /// The resource the type is stored as.
+    const RESOURCE: Resource;
+}
+
+macro_rules! impl_mirrored {
+    ($($type:ty => $resource:ident),* $(,)?) => {
+        $(
+            impl Mirrored for $type {
+                const RESOURCE: Resource = Resource::$resource;
+            }
+        )*
+    };
+}
+
+impl_mirrored!(
+    Customer => Customers,
+    Supplier => Suppliers,
+    Article => Articles,
+    Invoice => Invoices,
+    SupplierInvoice => SupplierInvoices,
+    Voucher => Vouchers,
+    Account => Accounts,
+    CustomerLedgerItem => CustomerLedgerItems,
+    SupplierLedgerItem => SupplierLedgerItems,
+);
+
+/// Outcome of refreshing one resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshReport {
+    /// The refreshed resource.
+    pub resource: Resource,
+    /// Rows inserted or updated.
+    pub upserted: usize,
+    /// Rows deleted.
+    pub deleted: usize,
+    /// Whether the table was replaced in full.
+    pub full: bool,
+}
+
+/// A SQLite mirror of the accounting resources.
+pub struct Mirror {
+    conn: Connection,
+    config: SyncConfig,
+}
+
+impl Mirror {
+    /// Open or create a mirror database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Create a mirror in memory, mainly for tests.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    /// Use an existing connection, creating the schema if needed.
+    pub fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+        Ok(Self {
+            conn,
+            config: SyncConfig::default(),
+        })
+    }
+
+    /// Set the configuration of incremental refreshes.
+    pub fn sync_config(mut self, config: SyncConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The underlying connection, for running queries.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Refresh every resource.
+    ///
+    /// Resources are refreshed one by one; if one fails, the ones already
+    /// refreshed keep their new data.
+    pub async fn refresh(&mut self, client: &Client) -> Result<Vec<RefreshReport>> {
+        let mut reports = Vec::with_capacity(Resource::ALL.len());
+        for resource in Resource::ALL {
+            reports.push(self.refresh_resource(client, resource).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Refresh a single resource.
+    pub async fn refresh_resource(
+        &mut self,
+        client: &Client,
+        resource: Resource,
+    ) -> Result<RefreshReport> {
+        match resource {
+            Resource::Customers => self.sync(resource, client.customers()).await,
+            Resource::Suppliers => self.sync(resource, client.suppliers()).await,
+            Resource::Articles => self.sync(resource, client.articles()).await,
+            Resource::Invoices => self.sync(resource, client.invoices()).await,
+            Resource::SupplierInvoices => self.sync(resource, client.supplier_invoices()).await,
+            Resource::Vouchers => self.sync(resource, client.vouchers()).await,
+            Resource::Accounts => {
+                let endpoint = client.accounts();
+                let items = fetch_all(PAGE_SIZE, |params| endpoint.list(Some(params))).await?;
+                self.replace(resource, &items, |_: &Account| None)
+            }
+            Resource::CustomerLedgerItems => {
+                let endpoint = client.customer_ledger_items();
+                let items = fetch_all(PAGE_SIZE, |params| endpoint.list(Some(params))).await?;
+                self.replace(resource, &items, |item: &CustomerLedgerItem| {
+                    item.id.as_deref()
+                })
+            }
+            Resource::SupplierLedgerItems => {
+                let endpoint = client.supplier_ledger_items();
+                let items = fetch_all(PAGE_SIZE, |params| endpoint.list(Some(params))).await?;
+                self.replace(resource, &items, |item: &SupplierLedgerItem| {
+                    item.id.as_deref()
+                })
+            }
+        }
+    }
+
+    /// When `resource` was last refreshed successfully.
+    pub fn last_refreshed(&self, resource: Resource) -> Result<Option<DateTime<Utc>>> {
+        let refreshed_at: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT refreshed_at FROM sync_state WHERE resource = ?1",
+                [resource.table()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(refreshed_at
+            .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+            .map(|value| value.with_timezone(&Utc)))
+    }
+
+    /// Load an item by ID.
+    ///
+    /// Accounts have no ID and the same account number exists once per
+    /// fiscal year, so they are looked up with
+    /// [`get_account`](Self::get_account) instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`](crate::Error::InvalidConfig) for
+    /// [`Account`].
+    pub fn get<T: Mirrored>(&self, id: &str) -> Result<Option<T>> {
+        if T::RESOURCE == Resource::Accounts {
+            return Err(Error::InvalidConfig(
+                "accounts are looked up by fiscal year and number with Mirror::get_account"
+                    .to_string(),
+            ));
+        }
+        let sql = format!("SELECT data FROM {} WHERE id = ?1", T::RESOURCE.table());
+        self.query_one(&sql, [id])
+    }
+
+    /// Load the account with `account_number` in a fiscal year.
+    pub fn get_account(
+        &self,
+        fiscal_year_id: &FiscalYearId,
+        account_number: &str,
+    ) -> Result<Option<Account>> {
+        self.query_one(
+            "SELECT data FROM accounts WHERE account_number = ?1 AND fiscal_year_id = ?2",
+            [account_number, fiscal_year_id.as_ref()],
+        )
+    }
+
+    fn query_one<T: DeserializeOwned>(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Option<T>> {
+        let data: Option<String> = self
+            .conn
+            .query_row(sql, params, |row| row.get(0))
+            .optional()?;
+        Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+    }
+
+    /// Load every item of a type.
+    pub fn all<T: Mirrored>(&self) -> Result<Vec<T>> {
+        let sql = format!("SELECT data FROM {}", T::RESOURCE.table());
+        let mut statement = self.conn.prepare(&sql)?;
+        let rows = statement.query_map([], |row| row.get::<_, String>(0))?;
+        let mut items = Vec::new();
+        for data in rows {
+            items.push(serde_json::from_str(&data?)?);
+        }
+        Ok(items)
+    }
+
+    async fn sync<S>(&mut self, resource: Resource, source: S) -> Result<RefreshReport>
+    where
+        S: SyncSource,
+        S::Item: Serialize,
+    {
+        let mut state = self.load_state(resource)?;
+        let changes = Syncer::with_config(source, self.config.clone())
+            .sync(&mut state)
+            .await?;
+
+        let table = resource.table();
+        let upsert = format!(
+            "INSERT INTO {table} (id, data) VALUES (?1, ?2)
+             ON CONFLICT(id) DO UPDATE SET data = excluded.data"
+        );
+        let delete = format!("DELETE FROM {table} WHERE id = ?1");
+
+        let mut report = RefreshReport {
+            resource,
+            upserted: 0,
+            deleted: 0,
+            full: false,
+        };
+        let tx = self.conn.transaction()?;
+        for change in &changes {
+            match change {
+                Change::Created(item) | Change::Updated(item) => {
+                    let Some(id) = change.id() else { continue };
+                    tx.execute(&upsert, params![id, serde_json::to_string(item)?])?;
+                    report.upserted += 1;
+                }
+                Change::Deleted(id) => {
+                    report.deleted += tx.execute(&delete, [id])?;
+                }
+            }
+        }
+        save_state(&tx, resource, Some(&state))?;
+        tx.commit()?;
+        Ok(report)
+    }
+
+    fn replace<T, F>(&mut self, resource: Resource, items: &[T], id: F) -> Result<RefreshReport>
+    where
+        T: Serialize,
+        F: Fn(&T) -> Option<&str>,
+    {
+        let table = resource.table();
+        let tx = self.conn.transaction()?;
+        let deleted = tx.execute(&format!("DELETE FROM {table}"), [])?;
+        let mut upserted = 0;
+        if resource == Resource::Accounts {
+            // Account numbers repeat across fiscal years, so accounts have
+            // no single-column key.
+            let insert = format!("INSERT INTO {table} (data) VALUES (?1)");
+            for item in items {
+                tx.execute(&insert, [serde_json::to_string(item)?])?;
+                upserted += 1;
+            }
+        } else {
+            let insert = format!("INSERT OR REPLACE INTO {table} (id, data) VALUES (?1, ?2)");
+            for item in items {
+                let Some(id) = id(item) else { continue };
+                tx.execute(&insert, params![id, serde_json::to_string(item)?])?;
+                upserted += 1;
+            }
+        }
+        save_state(&tx, resource, None)?;
+        tx.commit()?;
+        Ok(RefreshReport {
+            resource,
+            upserted,
+            deleted,
+            full: true,
+        })
+    }
+
+    fn load_state(&self, resource: Resource) -> Result<SyncState> {
+        let state: Option<Option<String>> = self
+            .conn
+            .query_row(
+                "SELECT state FROM sync_state WHERE resource = ?1",
+                [resource.table()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match state.flatten() {
+            Some(state) => Ok(serde_json::from_str(&state)?),
+            None => Ok(SyncState::new()),
+        }
+    }
+}
+
+fn save_state(conn: &Connection, resource: Resource, state: Option<&SyncState>) -> Result<()> {
+    let state = state.map(serde_json::to_string).transpose()?;
+    conn.execute(
+        "INSERT INTO sync_state (resource, state, refreshed_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(resource) DO UPDATE SET state = excluded.state,
+             refreshed_at = excluded.refreshed_at",
+        params![resource.table(), state, Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,451 @@
+//! Offline read-through snapshots and a durable write outbox.
+//!
+//! Requires the `offline` feature. An [`OfflineStore`] is a small SQLite
+//! database with two parts:
+//!
+//! - **Snapshots**: when a store is set with
+//!   [`ClientConfig::offline_store`](crate::ClientConfig::offline_store),
+//!   every successful `GET` (including `get`, `list` and `search`) is saved
+//!   by URL. If a later `GET` to the same URL fails because the API cannot be
+//!   reached or the rate limit is exhausted, the saved body is returned
+//!   instead. The `*_with_meta` methods report this through
+//!   [`Response::cached_at`](crate::Response::cached_at).
+//! - **Outbox**: writes queued while offline are kept in order and sent by
+//!   [`OfflineStore::replay`] once the API is reachable again. Updates and
+//!   deletes remember the `ModifiedUtc` the change was based on; if the
+//!   server copy changed in the meantime, the replay stops with a
+//!   [`ReplayStop::Conflict`] instead of overwriting it.
+//!
+//! Snapshots are keyed by URL only, so use one store per company.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::offline::OfflineStore;
+//! use spiris::{AccessToken, Client, ClientConfig};
+//!
+//! # async fn example(mut customer: spiris::Customer) -> Result<(), Box<dyn std::error::Error>> {
+//! let store = OfflineStore::open("offline.db")?;
+//! let client = Client::with_config(
+//!     AccessToken::new("token".to_string(), 3600, None),
+//!     ClientConfig::new().offline_store(store.clone()),
+//! );
+//!
+//! let response = client.customers().list_with_meta(None).await?;
+//! if let Some(age) = response.staleness() {
+//!     println!("Showing data from {} minutes ago", age.num_minutes());
+//! }
+//!
+//! // Queue an edit and send it later.
+//! customer.email = Some("new@example.com".to_string());
+//! let path = format!("/customers/{}", customer.id.as_deref().unwrap_or_default());
+//! store.queue_update(&path, &customer)?;
+//!
+//! let report = store.replay(&client).await?;
+//! println!("{} queued writes sent", report.applied.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::request::Method;
+use crate::response::RawResponse;
+use crate::types::Modified;
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS snapshots (
+    url TEXT PRIMARY KEY NOT NULL,
+    status INTEGER NOT NULL,
+    body BLOB NOT NULL,
+    fetched_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS outbox (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    method TEXT NOT NULL,
+    path TEXT NOT NULL,
+    body TEXT,
+    expected_modified_utc TEXT,
+    queued_at TEXT NOT NULL,
+    last_error TEXT
+);
+"#;
+
+/// Local store for response snapshots and queued writes.
+///
+/// Cheap to clone; clones share the same database connection.
+#[derive(Clone)]
+pub struct OfflineStore {
+    conn: Arc<Mutex<Connection>>,
+    max_age: Option<Duration>,
+}
+
+impl fmt::Debug for OfflineStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OfflineStore")
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OfflineStore {
+    /// Open or create a store at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Create a store in memory, mainly for tests.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            max_age: None,
+        })
+    }
+
+    /// Do not serve snapshots older than `max_age`.
+    ///
+    /// By default snapshots are served regardless of their age.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    fn with_conn<T>(&self, f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> Result<T> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(f(&conn)?)
+    }
+
+    /// Save a successful response body for `url`.
+    pub(crate) fn save_snapshot(&self, url: &str, response: &RawResponse) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "INSERT INTO snapshots (url, status, body, fetched_at) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(url) DO UPDATE SET status = excluded.status,
+                     body = excluded.body, fetched_at = excluded.fetched_at",
+                params![url, response.status, response.data, Utc::now().to_rfc3339()],
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Load the snapshot for `url`, unless it is older than the maximum age.
+    pub(crate) fn snapshot(&self, url: &str) -> Result<Option<RawResponse>> {
+        let row: Option<(u16, Vec<u8>, String)> = self.with_conn(|conn| {
+            conn.query_row(
+                "SELECT status, body, fetched_at FROM snapshots WHERE url = ?1",
+                [url],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+        })?;
+        let Some((status, body, fetched_at)) = row else {
+            return Ok(None);
+        };
+        let Some(fetched_at) = parse_time(&fetched_at) else {
+            return Ok(None);
+        };
+        if let Some(max_age) = self.max_age {
+            let too_old = (Utc::now() - fetched_at)
+                .to_std()
+                .is_ok_and(|age| age > max_age);
+            if too_old {
+                return Ok(None);
+            }
+        }
+        Ok(Some(RawResponse {
+            data: body,
+            status,
+            headers: HeaderMap::new(),
+            elapsed: Duration::ZERO,
+            attempts: 0,
+            cached_at: Some(fetched_at),
+        }))
+    }
+
+    /// Remove all snapshots.
+    pub fn clear_snapshots(&self) -> Result<()> {
+        self.with_conn(|conn| conn.execute("DELETE FROM snapshots", []))?;
+        Ok(())
+    }
+
+    /// Queue the creation of a resource, e.g. `POST /customers`.
+    ///
+    /// Returns the sequence number of the queued write.
+    pub fn queue_create<B: Serialize + ?Sized>(&self, path: &str, body: &B) -> Result<i64> {
+        self.queue(Method::POST, path, Some(serde_json::to_string(body)?), None)
+    }
+
+    /// Queue an update of a resource, e.g. `PUT /customers/{id}`.
+    ///
+    /// The item's `ModifiedUtc` is remembered; the write is only replayed
+    /// if the server copy still has the same value.
+    pub fn queue_update<B: Serialize + Modified>(&self, path: &str, body: &B) -> Result<i64> {
+        self.queue(
+            Method::PUT,
+            path,
+            Some(serde_json::to_string(body)?),
+            body.modified_utc(),
+        )
+    }
+
+    /// Queue the deletion of a resource.
+    ///
+    /// When `expected_modified_utc` is set, the delete is only replayed if
+    /// the server copy still has that `ModifiedUtc`.
+    pub fn queue_delete(
+        &self,
+        path: &str,
+        expected_modified_utc: Option<DateTime<Utc>>,
+    ) -> Result<i64> {
+        self.queue(Method::DELETE, path, None, expected_modified_utc)
+    }
+
+    fn queue(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+        expected_modified_utc: Option<DateTime<Utc>>,
+    ) -> Result<i64> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "INSERT INTO outbox (method, path, body, expected_modified_utc, queued_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    method.as_str(),
+                    path,
+                    body,
+                    expected_modified_utc.map(|t| t.to_rfc3339()),
+                    Utc::now().to_rfc3339()
+                ],
+            )?;
+            Ok(conn.last_insert_rowid())
+        })
+    }
+
+    /// List the queued writes in replay order.
+    pub fn pending(&self) -> Result<Vec<PendingWrite>> {
+        let rows = self.with_conn(|conn| {
+            let mut statement = conn.prepare(
+                "SELECT seq, method, path, body, expected_modified_utc, queued_at, last_error
+                 FROM outbox ORDER BY seq",
+            )?;
+            let rows = statement.query_map([], |row| {
+                Ok(RawPendingWrite {
+                    seq: row.get(0)?,
+                    method: row.get(1)?,
+                    path: row.get(2)?,
+                    body: row.get(3)?,
+                    expected_modified_utc: row.get(4)?,
+                    queued_at: row.get(5)?,
+                    last_error: row.get(6)?,
+                })
+            })?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+        })?;
+        rows.into_iter().map(RawPendingWrite::parse).collect()
+    }
+
+    /// Remove a queued write without sending it.
+    ///
+    /// Returns `false` if there was no write with that sequence number.
+    pub fn discard(&self, seq: i64) -> Result<bool> {
+        let removed =
+            self.with_conn(|conn| conn.execute("DELETE FROM outbox WHERE seq = ?1", [seq]))?;
+        Ok(removed > 0)
+    }
+
+    /// Drop the `ModifiedUtc` check of a queued write, so the next replay
+    /// overwrites the server copy.
+    pub fn force(&self, seq: i64) -> Result<bool> {
+        let updated = self.with_conn(|conn| {
+            conn.execute(
+                "UPDATE outbox SET expected_modified_utc = NULL WHERE seq = ?1",
+                [seq],
+            )
+        })?;
+        Ok(updated > 0)
+    }
+
+    /// Send the queued writes in order.
+    ///
+    /// Stops at the first conflict or failed write, leaving it and all later
+    /// writes in the outbox, so writes are never applied out of order.
+    /// Writes that were sent are removed from the outbox.
+    pub async fn replay(&self, client: &Client) -> Result<ReplayReport> {
+        let mut report = ReplayReport::default();
+
+        for write in self.pending()? {
+            if let Some(expected) = write.expected_modified_utc {
+                let current = match current_modified_utc(client, &write.path).await {
+                    Ok(current) => current,
+                    Err(error) if error.is_not_found() && write.method == Method::DELETE => {
+                        // Already gone; nothing left to delete.
+                        self.discard(write.seq)?;
+                        report.applied.push(write);
+                        continue;
+                    }
+                    Err(error) if error.is_not_found() => None,
+                    Err(error) => {
+                        self.record_error(write.seq, &error)?;
+                        report.stopped = Some(ReplayStop::Failed { write, error });
+                        return Ok(report);
+                    }
+                };
+                if current != Some(expected) {
+                    report.stopped = Some(ReplayStop::Conflict { write, current });
+                    return Ok(report);
+                }
+            }
+
+            let mut request = client.request(write.method.clone(), &write.path);
+            if let Some(body) = &write.body {
+                request = request.json(body);
+            }
+            match request.send_raw().await {
+                Ok(_) => {
+                    self.discard(write.seq)?;
+                    report.applied.push(write);
+                }
+                Err(error) => {
+                    self.record_error(write.seq, &error)?;
+                    report.stopped = Some(ReplayStop::Failed { write, error });
+                    return Ok(report);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn record_error(&self, seq: i64, error: &Error) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "UPDATE outbox SET last_error = ?1 WHERE seq = ?2",
+                params![error.to_string(), seq],
+            )
+        })?;
+        Ok(())
+    }
+}
+
+/// Fetch the server's current `ModifiedUtc` of a resource, bypassing the
+/// snapshots.
+async fn current_modified_utc(client: &Client, path: &str) -> Result<Option<DateTime<Utc>>> {
+    let response = client
+        .request(Method::GET, path)
+        .without_snapshots()
+        .send_raw()
+        .await?;
+    let value: serde_json::Value = response.json()?;
+    Ok(value
+        .get("ModifiedUtc")
+        .and_then(|v| v.as_str())
+        .and_then(parse_time))
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// A write waiting in the outbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingWrite {
+    /// Sequence number; writes are replayed in ascending order.
+    pub seq: i64,
+    /// HTTP method.
+    pub method: Method,
+    /// API path, e.g. `/customers/{id}`.
+    pub path: String,
+    /// JSON request body.
+    pub body: Option<serde_json::Value>,
+    /// `ModifiedUtc` the server copy must still have for the write to be sent.
+    pub expected_modified_utc: Option<DateTime<Utc>>,
+    /// When the write was queued.
+    pub queued_at: DateTime<Utc>,
+    /// Error from the last failed replay attempt.
+    pub last_error: Option<String>,
+}
+
+struct RawPendingWrite {
+    seq: i64,
+    method: String,
+    path: String,
+    body: Option<String>,
+    expected_modified_utc: Option<String>,
+    queued_at: String,
+    last_error: Option<String>,
+}
+
+impl RawPendingWrite {
+    fn parse(self) -> Result<PendingWrite> {
+        let method = Method::from_bytes(self.method.as_bytes())
+            .map_err(|_| Error::InvalidRequest(format!("Invalid method: {}", self.method)))?;
+        Ok(PendingWrite {
+            seq: self.seq,
+            method,
+            path: self.path,
+            body: self
+                .body
+                .map(|body| serde_json::from_str(&body))
+                .transpose()?,
+            expected_modified_utc: self.expected_modified_utc.as_deref().and_then(parse_time),
+            queued_at: parse_time(&self.queued_at).unwrap_or_default(),
+            last_error: self.last_error,
+        })
+    }
+}
+
+/// Outcome of [`OfflineStore::replay`].
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    /// Writes sent successfully, in order.
+    pub applied: Vec<PendingWrite>,
+    /// Why the replay stopped early, if it did.
+    pub stopped: Option<ReplayStop>,
+}
+
+impl ReplayReport {
+    /// Whether every queued write was sent.
+    pub fn is_complete(&self) -> bool {
+        self.stopped.is_none()
+    }
+}
+
+/// Why a replay stopped before the end of the outbox.
+#[derive(Debug)]
+pub enum ReplayStop {
+    /// The server copy was modified after the write was queued.
+    ///
+    /// Resolve it with [`OfflineStore::force`] or [`OfflineStore::discard`].
+    Conflict {
+        /// The conflicting write, still in the outbox.
+        write: PendingWrite,
+        /// The server's current `ModifiedUtc`; `None` if the resource no
+        /// longer exists.
+        current: Option<DateTime<Utc>>,
+    },
+    /// The write could not be sent; it stays in the outbox.
+    Failed {
+        /// The failed write.
+        write: PendingWrite,
+        /// The error returned by the API.
+        error: Error,
+    },
+}
@@ -5,7 +5,8 @@
 //!
 //! # Feature Flag
 //!
-//! This module is only available when the `stream` feature is enabled:
+//! The streams are only available when the `stream` feature is enabled;
+//! [`for_each_page`] and [`fetch_all`] are always available:
 //!
 //! ```toml
 //! [dependencies]
@@ -37,61 +38,602 @@
 //!     .list_stream()
 //!     .try_collect()
 //!     .await?;
+//!
+//! // Stream a filtered subset, with an item cap and progress reporting
+//! use spiris::pagination::StreamOptions;
+//! use spiris::QueryParams;
+//! let query = QueryParams::new().filter("ModifiedUtc gt 2024-01-01T00:00:00Z");
+//! let options = StreamOptions::new()
+//!     .page_size(500)
+//!     .max_items(10_000)
+//!     .on_progress(|p| println!("{}/{} invoices", p.items_fetched, p.expected_items));
+//! let invoices: Vec<_> = client.invoices()
+//!     .search_stream_with(query, options)
+//!     .try_collect()
+//!     .await?;
 //! # Ok(())
 //! # }
 //! ```
 
 use crate::error::Result;
-use crate::types::{PaginatedResponse, PaginationParams};
-use futures::Stream;
+#[cfg(feature = "stream")]
+use crate::types::Identifiable;
+use crate::types::{PaginatedResponse, PaginationParams, QueryParams};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "stream")]
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "stream")]
+use std::collections::HashSet;
+use std::fmt;
 use std::future::Future;
+#[cfg(feature = "stream")]
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(feature = "stream")]
+use std::sync::Mutex;
+#[cfg(feature = "stream")]
+use std::task::{Context, Poll};
 
 /// Default page size for pagination streams.
 pub const DEFAULT_PAGE_SIZE: u32 = 50;
 
-/// Creates a stream that automatically paginates through API responses.
+/// Progress of a pagination stream, reported after each page is fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamProgress {
+    /// Zero-based index of the page that was just fetched.
+    pub page: u32,
+    /// Total number of pages reported by the API.
+    pub total_pages: u32,
+    /// Number of items fetched so far, including the current page.
+    pub items_fetched: usize,
+    /// Number of items the stream will yield in total: the API's
+    /// `total_count`, capped by `max_items`.
+    pub expected_items: usize,
+    /// Total number of matching items reported by the API.
+    pub total_count: u32,
+}
+
+impl StreamProgress {
+    /// Fraction of the expected items fetched so far, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f64 {
+        if self.expected_items == 0 {
+            1.0
+        } else {
+            (self.items_fetched as f64 / self.expected_items as f64).min(1.0)
+        }
+    }
+}
+
+type ProgressCallback = Arc<dyn Fn(&StreamProgress) + Send + Sync>;
+
+/// Options for pagination streams.
 ///
-/// This is a macro-based stream that yields items one at a time from paginated
-/// API responses, automatically fetching the next page when needed.
+/// # Example
 ///
-/// # Type Parameters
+/// ```
+/// use spiris::pagination::StreamOptions;
 ///
-/// * `T` - The type of items in the paginated response
-/// * `F` - A function that takes pagination parameters and returns a future
-/// * `Fut` - The future type returned by `F`
+/// let options = StreamOptions::new()
+///     .page_size(500)
+///     .max_items(10_000)
+///     .concurrency(4)
+///     .on_progress(|progress| {
+///         println!("{:.0}%", progress.fraction() * 100.0);
+///     });
+/// ```
+#[derive(Clone)]
+pub struct StreamOptions {
+    page_size: u32,
+    max_items: Option<usize>,
+    concurrency: usize,
+    ordered: bool,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            max_items: None,
+            concurrency: 1,
+            ordered: true,
+            on_progress: None,
+        }
+    }
+}
+
+impl fmt::Debug for StreamOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamOptions")
+            .field("page_size", &self.page_size)
+            .field("max_items", &self.max_items)
+            .field("concurrency", &self.concurrency)
+            .field("ordered", &self.ordered)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl StreamOptions {
+    /// Create options with the default page size and no item cap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of items requested per page.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Stop the stream after yielding this many items.
+    ///
+    /// No further pages are fetched once the cap is reached.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Fetch up to `concurrency` pages at the same time.
+    ///
+    /// The first page is always fetched alone to learn the total page count;
+    /// the remaining pages are then fetched ahead of the consumer. Every
+    /// request still goes through the client's rate limiter. Defaults to `1`
+    /// (one page after another).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Whether prefetched pages are yielded in page order (the default).
+    ///
+    /// With `ordered(false)`, pages are yielded as soon as they arrive. When
+    /// combined with `max_items`, the items kept are then not necessarily the
+    /// first ones.
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    /// Call `callback` after each page is fetched.
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&StreamProgress) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// Page-by-page state of a pagination stream.
+///
+/// Used by the [`paginated_page_stream!`](crate::paginated_page_stream) macro;
+/// not intended to be used directly.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PageCursor {
+    options: StreamOptions,
+    page: u32,
+    total_pages: Option<u32>,
+    items_fetched: usize,
+    done: bool,
+}
+
+impl PageCursor {
+    /// Create a cursor positioned at the first page.
+    pub fn new(options: StreamOptions) -> Self {
+        Self::resume(options, 0, 0)
+    }
+
+    /// Create a cursor positioned at `page`, with `items_fetched` items
+    /// already counted towards `max_items`.
+    pub fn resume(options: StreamOptions, page: u32, items_fetched: usize) -> Self {
+        let mut cursor = Self {
+            options,
+            page,
+            total_pages: None,
+            items_fetched,
+            done: false,
+        };
+        cursor.done = cursor.is_capped();
+        cursor
+    }
+
+    /// Maximum number of pages fetched at the same time.
+    pub fn concurrency(&self) -> usize {
+        self.options.concurrency
+    }
+
+    /// Whether prefetched pages must be yielded in page order.
+    pub fn ordered(&self) -> bool {
+        self.options.ordered
+    }
+
+    /// Whether `max_items` items have been fetched.
+    pub fn is_capped(&self) -> bool {
+        self.options
+            .max_items
+            .is_some_and(|max_items| self.items_fetched >= max_items)
+    }
+
+    fn params(&self, page: u32) -> PaginationParams {
+        PaginationParams::new()
+            .page(page)
+            .pagesize(self.options.page_size)
+    }
+
+    /// Parameters for the next page to fetch, or `None` when the stream is done.
+    pub fn next_params(&self) -> Option<PaginationParams> {
+        if self.done {
+            return None;
+        }
+        Some(self.params(self.page))
+    }
+
+    /// Record the page requested with [`next_params`](Self::next_params) and
+    /// return the items that should be yielded.
+    pub fn advance<T>(&mut self, response: PaginatedResponse<T>) -> Vec<T> {
+        let has_next_page = response.meta.has_next_page;
+        self.total_pages = Some(response.meta.total_pages);
+
+        let data = self.record(self.page, response);
+
+        self.done = self.is_capped() || !has_next_page;
+        self.page += 1;
+
+        data
+    }
+
+    /// Take the remaining pages to fetch concurrently, if concurrency is
+    /// enabled and the page count is known.
+    ///
+    /// Once the pages are taken, [`next_params`](Self::next_params) returns
+    /// `None`.
+    pub fn take_prefetch(&mut self) -> Vec<(u32, PaginationParams)> {
+        if self.done || self.options.concurrency <= 1 {
+            return Vec::new();
+        }
+        let Some(mut end) = self.total_pages else {
+            return Vec::new();
+        };
+        if let Some(max_items) = self.options.max_items {
+            let page_size = self.options.page_size.max(1) as usize;
+            let pages_needed = max_items.div_ceil(page_size);
+            end = end.min(u32::try_from(pages_needed).unwrap_or(u32::MAX));
+        }
+        if end <= self.page {
+            // The page count doesn't match `HasNextPage`; keep going one page
+            // at a time.
+            return Vec::new();
+        }
+
+        self.done = true;
+        (self.page..end)
+            .map(|page| (page, self.params(page)))
+            .collect()
+    }
+
+    /// Record a fetched page and return the items that should be yielded.
+    pub fn record<T>(&mut self, page: u32, response: PaginatedResponse<T>) -> Vec<T> {
+        let PaginatedResponse { mut data, meta } = response;
+
+        if let Some(max_items) = self.options.max_items {
+            data.truncate(max_items.saturating_sub(self.items_fetched));
+        }
+        self.items_fetched += data.len();
+
+        let total_count = meta.total_count as usize;
+        let expected_items = match self.options.max_items {
+            Some(max_items) => total_count.min(max_items),
+            None => total_count,
+        };
+
+        if let Some(callback) = &self.options.on_progress {
+            callback(&StreamProgress {
+                page,
+                total_pages: meta.total_pages,
+                items_fetched: self.items_fetched,
+                expected_items,
+                total_count: meta.total_count,
+            });
+        }
+
+        data
+    }
+}
+
+/// A saved position in a pagination stream.
+///
+/// Take one with [`ResumableStream::checkpoint`], store it (it is
+/// serializable), and pass it to an endpoint's `resume_stream` to continue
+/// where the stream stopped instead of starting again from page 0.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// API path of the paginated resource, e.g. `/customerinvoices`.
+    pub path: String,
+    /// Page to fetch next. Items of this page already yielded are listed in
+    /// `seen_ids` and skipped on resume.
+    pub page: u32,
+    /// Page size the stream was started with.
+    pub page_size: u32,
+    /// Filter and field selection of a `search_stream`, `None` for a list.
+    pub query: Option<QueryParams>,
+    /// Items yielded so far.
+    pub items_yielded: usize,
+    /// Items skipped because they had already been yielded.
+    pub duplicates_skipped: usize,
+    /// IDs yielded from the previous and the current page, used to skip
+    /// items that move across a page boundary when the server-side ordering
+    /// shifts.
+    pub seen_ids: Vec<String>,
+    /// Whether the stream reached the last page.
+    pub finished: bool,
+    /// When the checkpoint was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Checkpoint {
+    /// Create a checkpoint at the first page of a stream over `path` with the
+    /// page size of `options`.
+    #[doc(hidden)]
+    pub fn start(path: &str, query: Option<QueryParams>, options: &StreamOptions) -> Self {
+        Self::new(path, options.page_size, query)
+    }
+
+    /// Create a checkpoint at the first page of `path`.
+    pub fn new(path: impl Into<String>, page_size: u32, query: Option<QueryParams>) -> Self {
+        Self {
+            path: path.into(),
+            page: 0,
+            page_size,
+            query,
+            items_yielded: 0,
+            duplicates_skipped: 0,
+            seen_ids: Vec::new(),
+            finished: false,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// A pagination stream that records a [`Checkpoint`] as it goes.
+///
+/// An item is recorded once the next item is requested, so a checkpoint
+/// taken after a failure or crash never skips an item the consumer had not
+/// finished with. Resumable streams fetch one page at a time and ignore
+/// [`StreamOptions::concurrency`].
+#[cfg(feature = "stream")]
+pub struct ResumableStream<T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T>> + Send>>,
+    state: Arc<Mutex<Checkpoint>>,
+}
+
+#[cfg(feature = "stream")]
+impl<T> ResumableStream<T> {
+    /// The current checkpoint.
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.state.lock().unwrap().clone()
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<T> fmt::Debug for ResumableStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResumableStream")
+            .field("checkpoint", &self.checkpoint())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<T> Stream for ResumableStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Creates a [`ResumableStream`] starting at `checkpoint`.
+///
+/// `fetch` must return futures that do not borrow from the closure; clone an
+/// owned endpoint handle into each future.
+#[cfg(feature = "stream")]
+pub fn resumable_stream<T, F, Fut>(
+    checkpoint: Checkpoint,
+    options: StreamOptions,
+    fetch: F,
+) -> ResumableStream<T>
+where
+    T: Identifiable + Send + 'static,
+    F: Fn(PaginationParams) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<PaginatedResponse<T>>> + Send + 'static,
+{
+    let options = options.page_size(checkpoint.page_size);
+    let mut cursor = PageCursor::resume(options, checkpoint.page, checkpoint.items_yielded);
+    let mut seen: HashSet<String> = checkpoint.seen_ids.iter().cloned().collect();
+    let finished = checkpoint.finished;
+
+    let state = Arc::new(Mutex::new(checkpoint));
+    let shared = state.clone();
+
+    let inner = async_stream::try_stream! {
+        if finished {
+            return;
+        }
+
+        let mut reached_end = false;
+        while let Some(params) = cursor.next_params() {
+            let page = params.page.unwrap_or(0);
+            let response = fetch(params).await?;
+            reached_end = !response.meta.has_next_page;
+            let items = cursor.advance(response);
+
+            let mut page_ids = Vec::new();
+            for item in items {
+                let id = item.id().map(str::to_string);
+                if let Some(id) = &id {
+                    if !seen.insert(id.clone()) {
+                        page_ids.push(id.clone());
+                        let mut state = shared.lock().unwrap();
+                        state.duplicates_skipped += 1;
+                        state.updated_at = Utc::now();
+                        continue;
+                    }
+                }
+
+                yield item;
+
+                let mut state = shared.lock().unwrap();
+                state.items_yielded += 1;
+                state.updated_at = Utc::now();
+                if let Some(id) = id {
+                    state.seen_ids.push(id.clone());
+                    page_ids.push(id);
+                }
+            }
+
+            if cursor.is_capped() {
+                // `max_items` may have cut this page short, so a resumed
+                // stream starts from it again and skips what was yielded.
+                break;
+            }
+
+            seen = page_ids.iter().cloned().collect();
+            let mut state = shared.lock().unwrap();
+            state.page = page + 1;
+            state.seen_ids = page_ids;
+            state.updated_at = Utc::now();
+        }
+
+        if reached_end && !cursor.is_capped() {
+            let mut state = shared.lock().unwrap();
+            state.finished = true;
+            state.updated_at = Utc::now();
+        }
+    };
+
+    ResumableStream {
+        inner: Box::pin(inner),
+        state,
+    }
+}
+
+/// Flatten a stream of pages into a stream of items.
+#[doc(hidden)]
+#[cfg(feature = "stream")]
+pub fn flatten_pages<T, S>(pages: S) -> impl Stream<Item = Result<T>>
+where
+    S: Stream<Item = Result<Vec<T>>>,
+{
+    async_stream::try_stream! {
+        futures::pin_mut!(pages);
+        while let Some(page) = pages.next().await {
+            for item in page? {
+                yield item;
+            }
+        }
+    }
+}
+
+/// Creates a stream of pages from a paginated API.
+///
+/// Each item of the stream is the data of one page. Pages are fetched one
+/// after another, or concurrently once the page count is known when
+/// [`StreamOptions::concurrency`] is above 1. Empty pages are skipped.
 ///
 /// # Arguments
 ///
-/// * `page_size` - Number of items per page
+/// * `options: StreamOptions` - Page size, item cap, concurrency and progress
 /// * `fetch` - A closure that fetches a page given `PaginationParams`
 #[macro_export]
-macro_rules! paginated_stream {
-    ($page_size:expr, $fetch:expr) => {{
+#[cfg(feature = "stream")]
+macro_rules! paginated_page_stream {
+    (options: $options:expr, $fetch:expr) => {{
         async_stream::try_stream! {
-            let mut current_page = 0u32;
+            let mut cursor = $crate::pagination::PageCursor::new($options);
             let fetch_fn = $fetch;
 
-            loop {
-                let params = $crate::PaginationParams::new()
-                    .page(current_page)
-                    .pagesize($page_size);
-
+            while let Some(params) = cursor.next_params() {
                 let response = fetch_fn(params).await?;
-
-                for item in response.data {
-                    yield item;
+                let data = cursor.advance(response);
+                if !data.is_empty() {
+                    yield data;
                 }
 
-                if !response.meta.has_next_page {
-                    break;
+                let prefetch = cursor.take_prefetch();
+                if prefetch.is_empty() {
+                    continue;
                 }
 
-                current_page += 1;
+                let fetches = futures::StreamExt::map(
+                    futures::stream::iter(prefetch),
+                    |(page, params)| {
+                        let fetch = fetch_fn(params);
+                        async move { (page, fetch.await) }
+                    },
+                );
+                let fetches = if cursor.ordered() {
+                    futures::future::Either::Left(futures::StreamExt::buffered(
+                        fetches,
+                        cursor.concurrency(),
+                    ))
+                } else {
+                    futures::future::Either::Right(futures::StreamExt::buffer_unordered(
+                        fetches,
+                        cursor.concurrency(),
+                    ))
+                };
+                futures::pin_mut!(fetches);
+
+                while let Some((page, response)) = futures::StreamExt::next(&mut fetches).await {
+                    let data = cursor.record(page, response?);
+                    if !data.is_empty() {
+                        yield data;
+                    }
+                    if cursor.is_capped() {
+                        break;
+                    }
+                }
             }
         }
     }};
 }
 
+/// Creates a stream that automatically paginates through API responses.
+///
+/// This is a macro-based stream that yields items one at a time from paginated
+/// API responses, automatically fetching the next page when needed.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of items in the paginated response
+/// * `F` - A function that takes pagination parameters and returns a future
+/// * `Fut` - The future type returned by `F`
+///
+/// # Arguments
+///
+/// * `page_size` - Number of items per page, or `options: StreamOptions` to
+///   also cap the number of items, prefetch pages and report progress
+/// * `fetch` - A closure that fetches a page given `PaginationParams`
+#[macro_export]
+#[cfg(feature = "stream")]
+macro_rules! paginated_stream {
+    (options: $options:expr, $fetch:expr) => {
+        $crate::pagination::flatten_pages($crate::paginated_page_stream!(
+            options: $options,
+            $fetch
+        ))
+    };
+    ($page_size:expr, $fetch:expr) => {
+        $crate::paginated_stream!(
+            options: $crate::pagination::StreamOptions::new().page_size($page_size),
+            $fetch
+        )
+    };
+}
+
 /// Creates a stream that automatically paginates through API responses.
 ///
 /// This function takes a fetch function that retrieves a page of data and
@@ -108,36 +650,85 @@ macro_rules! paginated_stream {
 ///
 /// * `page_size` - Number of items per page (default: 50)
 /// * `fetch` - A function that fetches a page given `PaginationParams`
+#[cfg(feature = "stream")]
 pub fn paginated_stream<T, F, Fut>(page_size: u32, fetch: F) -> impl Stream<Item = Result<T>>
 where
     T: 'static,
     F: Fn(PaginationParams) -> Fut + 'static,
     Fut: Future<Output = Result<PaginatedResponse<T>>>,
 {
-    async_stream::try_stream! {
-        let mut current_page = 0u32;
-
-        loop {
-            let params = PaginationParams::new()
-                .page(current_page)
-                .pagesize(page_size);
-
-            let response = fetch(params).await?;
+    paginated_stream_with(StreamOptions::new().page_size(page_size), fetch)
+}
 
-            for item in response.data {
-                yield item;
-            }
+/// Creates a stream of items with [`StreamOptions`].
+///
+/// Like [`paginated_stream`], but with an item cap, concurrent page
+/// prefetching and progress reporting.
+#[cfg(feature = "stream")]
+pub fn paginated_stream_with<T, F, Fut>(
+    options: StreamOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T>>
+where
+    T: 'static,
+    F: Fn(PaginationParams) -> Fut + 'static,
+    Fut: Future<Output = Result<PaginatedResponse<T>>>,
+{
+    flatten_pages(paginated_page_stream(options, fetch))
+}
 
-            if !response.meta.has_next_page {
-                break;
-            }
+/// Creates a stream that yields one `Vec` per page, for batch consumers.
+///
+/// Empty pages are skipped.
+#[cfg(feature = "stream")]
+pub fn paginated_page_stream<T, F, Fut>(
+    options: StreamOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<Vec<T>>>
+where
+    T: 'static,
+    F: Fn(PaginationParams) -> Fut + 'static,
+    Fut: Future<Output = Result<PaginatedResponse<T>>>,
+{
+    crate::paginated_page_stream!(options: options, fetch)
+}
 
-            current_page += 1;
+/// Fetch every page of a paginated endpoint and pass each one to `on_page`.
+///
+/// Pages are requested one at a time, starting at the first, until the API
+/// reports no next page or returns an empty page.
+pub async fn for_each_page<T, F, Fut, G>(page_size: u32, mut fetch: F, mut on_page: G) -> Result<()>
+where
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<T>>>,
+    G: FnMut(PaginatedResponse<T>),
+{
+    let mut page = 0;
+    loop {
+        let response = fetch(PaginationParams::new().page(page).pagesize(page_size)).await?;
+        let done = response.data.is_empty() || !response.meta.has_next_page;
+        on_page(response);
+        if done {
+            return Ok(());
         }
+        page += 1;
     }
 }
 
-#[cfg(test)]
+/// Fetch every page of a paginated endpoint into a `Vec`.
+///
+/// See [`for_each_page`].
+pub async fn fetch_all<T, F, Fut>(page_size: u32, fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<T>>>,
+{
+    let mut items = Vec::new();
+    for_each_page(page_size, fetch, |response| items.extend(response.data)).await?;
+    Ok(items)
+}
+
+#[cfg(all(test, feature = "stream"))]
 mod tests {
     use super::*;
     use crate::types::ResponseMetadata;
@@ -250,4 +841,236 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert!(results[0].is_err());
     }
+
+    fn page(
+        page: u32,
+        data: Vec<u32>,
+        total_pages: u32,
+        total_count: u32,
+    ) -> PaginatedResponse<u32> {
+        PaginatedResponse {
+            data,
+            meta: ResponseMetadata {
+                current_page: page,
+                page_size: 2,
+                total_pages,
+                total_count,
+                has_next_page: page + 1 < total_pages,
+                has_previous_page: page > 0,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginated_stream_max_items_stops_fetching() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+
+        let options = StreamOptions::new().page_size(2).max_items(3);
+        let stream = crate::paginated_stream!(options: options, move |params: PaginationParams| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            let current = params.page.unwrap_or(0);
+            async move { Ok::<_, crate::error::Error>(page(current, vec![current * 2, current * 2 + 1], 5, 10)) }
+        });
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        let items: Vec<u32> = results.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_paginated_stream_max_items_zero() {
+        let options = StreamOptions::new().max_items(0);
+        let stream = crate::paginated_stream!(options: options, |_params| async move {
+            Ok::<_, crate::error::Error>(page(0, vec![1, 2], 1, 2))
+        });
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_paginated_stream_reports_progress() {
+        use std::sync::Mutex;
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let reports_clone = reports.clone();
+
+        let options = StreamOptions::new()
+            .page_size(2)
+            .on_progress(move |progress| reports_clone.lock().unwrap().push(*progress));
+        let stream = crate::paginated_stream!(options: options, |params: PaginationParams| {
+            let current = params.page.unwrap_or(0);
+            let data = if current < 2 { vec![1, 2] } else { vec![3] };
+            async move { Ok::<_, crate::error::Error>(page(current, data, 3, 5)) }
+        });
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        assert_eq!(results.len(), 5);
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].items_fetched, 2);
+        assert_eq!(reports[0].expected_items, 5);
+        assert_eq!(reports[2].page, 2);
+        assert_eq!(reports[2].total_pages, 3);
+        assert_eq!(reports[2].items_fetched, 5);
+        assert_eq!(reports[2].fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_stream_progress_fraction_with_cap() {
+        let progress = StreamProgress {
+            page: 0,
+            total_pages: 10,
+            items_fetched: 50,
+            expected_items: 100,
+            total_count: 1000,
+        };
+        assert_eq!(progress.fraction(), 0.5);
+    }
+
+    /// Fetch function over 10 items in pages of 2 that tracks the peak
+    /// number of requests in flight. Later pages respond faster.
+    fn slow_pages(
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    ) -> impl Fn(
+        PaginationParams,
+    )
+        -> std::pin::Pin<Box<dyn Future<Output = Result<PaginatedResponse<u32>>> + Send>> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let in_flight = Arc::new(AtomicUsize::new(0));
+
+        move |params: PaginationParams| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            Box::pin(async move {
+                let current = params.page.unwrap_or(0);
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(50 - 10 * current as u64))
+                    .await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(page(current, vec![current * 2, current * 2 + 1], 5, 10))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_ordered_keeps_page_order() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let peak = Arc::new(AtomicUsize::new(0));
+        let options = StreamOptions::new().page_size(2).concurrency(4);
+        let stream = paginated_stream_with(options, slow_pages(peak.clone()));
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        let items: Vec<u32> = results.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_unordered_yields_pages_as_they_arrive() {
+        use std::sync::atomic::AtomicUsize;
+
+        let peak = Arc::new(AtomicUsize::new(0));
+        let options = StreamOptions::new()
+            .page_size(2)
+            .concurrency(4)
+            .ordered(false);
+        let stream = paginated_page_stream(options, slow_pages(peak));
+
+        let pages: Vec<Vec<u32>> = stream.map(|r| r.unwrap()).collect().await;
+        assert_eq!(pages.len(), 5);
+        assert_eq!(pages[0], vec![0, 1]);
+        // Page 4 responds fastest once pages 1-4 are in flight.
+        assert_eq!(pages[1], vec![8, 9]);
+
+        let mut items: Vec<u32> = pages.into_iter().flatten().collect();
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_respects_max_items() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+
+        let options = StreamOptions::new()
+            .page_size(2)
+            .concurrency(8)
+            .max_items(5);
+        let stream = paginated_stream_with(options, move |params: PaginationParams| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            let current = params.page.unwrap_or(0);
+            async move { Ok(page(current, vec![current * 2, current * 2 + 1], 50, 100)) }
+        });
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        let items: Vec<u32> = results.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_propagates_errors() {
+        use crate::error::Error;
+
+        let options = StreamOptions::new().page_size(2).concurrency(3);
+        let stream = paginated_page_stream(options, |params: PaginationParams| async move {
+            match params.page.unwrap_or(0) {
+                2 => Err(Error::NotFound("gone".to_string())),
+                current => Ok(page(current, vec![current], 4, 4)),
+            }
+        });
+
+        let results: Vec<Result<Vec<u32>>> = stream.collect().await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &vec![0]);
+        assert_eq!(results[1].as_ref().unwrap(), &vec![1]);
+        assert!(results[2].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_stops_on_last_or_empty_page() {
+        let page = |page: u32, data: Vec<u32>, has_next_page: bool| PaginatedResponse {
+            data,
+            meta: ResponseMetadata {
+                current_page: page,
+                page_size: 2,
+                total_pages: 3,
+                total_count: 5,
+                has_next_page,
+                has_previous_page: page > 0,
+            },
+        };
+
+        let requested = Mutex::new(Vec::new());
+        let items = fetch_all(2, |params| {
+            let index = params.page.unwrap();
+            requested.lock().unwrap().push(index);
+            async move {
+                Ok(match index {
+                    0 => page(0, vec![1, 2], true),
+                    1 => page(1, vec![3, 4], true),
+                    _ => page(index, vec![], true),
+                })
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(*requested.lock().unwrap(), vec![0, 1, 2]);
+
+        let items = fetch_all(2, |_| async { Ok(page(0, vec![7], false)) })
+            .await
+            .unwrap();
+        assert_eq!(items, vec![7]);
+    }
 }
@@ -410,6 +410,24 @@ impl FilterValue for chrono::NaiveDate {
     }
 }
 
+impl FilterValue for crate::codes::VoucherType {
+    fn to_odata(&self) -> String {
+        self.code().to_string()
+    }
+}
+
+impl FilterValue for crate::codes::DocumentType {
+    fn to_odata(&self) -> String {
+        self.code().to_string()
+    }
+}
+
+impl FilterValue for crate::codes::BookkeepingMethod {
+    fn to_odata(&self) -> String {
+        self.code().to_string()
+    }
+}
+
 /// Escape a string for use in OData expressions.
 /// Single quotes are escaped by doubling them.
 fn escape_string(s: &str) -> String {
@@ -554,6 +572,14 @@ mod tests {
         assert_eq!(filter.to_string(), "Amount gt 1000.5");
     }
 
+    #[test]
+    fn test_code_values() {
+        use crate::codes::VoucherType;
+
+        let filter = Filter::field("VoucherType").eq(VoucherType::ManualVoucher);
+        assert_eq!(filter.to_string(), "VoucherType eq 2");
+    }
+
     #[test]
     fn test_into_string() {
         let filter = Filter::field("IsActive").eq(true);
@@ -0,0 +1,223 @@
+//! Raw request builder for endpoints not covered by the typed API.
+//!
+//! The typed endpoint accessors (`client.customers()`, `client.invoices()`, ...)
+//! cover the most common resources. For anything else, [`Client::request`]
+//! returns a [`RequestBuilder`] that goes through the same authentication,
+//! token refresh, retry, rate limiting and middleware pipeline.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::{Client, AccessToken, Method};
+//! use serde_json::Value;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//!
+//! // Typed response body
+//! let price_lists: Value = client
+//!     .request(Method::GET, "/pricelists")
+//!     .query(&[("$pagesize", "100")])
+//!     .send_json()
+//!     .await?;
+//!
+//! // Full access to status and headers
+//! let response = client
+//!     .request(Method::POST, "/vouchers")
+//!     .json(&serde_json::json!({ "VoucherText": "Manual entry" }))
+//!     .header("X-Correlation-Id", "abc-123")
+//!     .send_raw()
+//!     .await?;
+//! println!("Created: {} {:?}", response.status, response.header("Location"));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::response::{RawResponse, Response};
+use reqwest::header;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use reqwest::Method;
+
+/// Builder for a raw API request.
+///
+/// Created by [`Client::request`]. Nothing is sent until one of the
+/// `send_*` methods is awaited.
+pub struct RequestBuilder<'a> {
+    client: &'a Client,
+    method: Method,
+    url: Result<url::Url>,
+    inner: Option<reqwest::RequestBuilder>,
+    #[cfg(feature = "offline")]
+    use_snapshots: bool,
+}
+
+impl<'a> RequestBuilder<'a> {
+    pub(crate) fn new(client: &'a Client, method: Method, path: &str) -> Self {
+        let url = client.build_url(path);
+        let inner = url
+            .as_ref()
+            .ok()
+            .map(|url| client.base_request(method.clone(), url.clone()));
+
+        Self {
+            client,
+            method,
+            url,
+            inner,
+            #[cfg(feature = "offline")]
+            use_snapshots: true,
+        }
+    }
+
+    fn map(mut self, f: impl FnOnce(reqwest::RequestBuilder) -> reqwest::RequestBuilder) -> Self {
+        self.inner = self.inner.map(f);
+        self
+    }
+
+    /// Append query parameters to the request URL.
+    ///
+    /// Accepts anything that serializes to a flat key/value structure, such as
+    /// `&[("key", "value")]`, a `HashMap`, [`QueryParams`](crate::QueryParams)
+    /// or [`PaginationParams`](crate::PaginationParams). May be called
+    /// multiple times.
+    pub fn query<P: Serialize + ?Sized>(self, params: &P) -> Self {
+        self.map(|req| req.query(params))
+    }
+
+    /// Set a JSON request body.
+    ///
+    /// Also sets the `Content-Type: application/json` header.
+    pub fn json<B: Serialize + ?Sized>(self, body: &B) -> Self {
+        self.map(|req| {
+            req.header(header::CONTENT_TYPE, "application/json")
+                .json(body)
+        })
+    }
+
+    /// Add a header to the request.
+    ///
+    /// Invalid header names or values are reported when the request is sent.
+    pub fn header(self, key: &str, value: &str) -> Self {
+        self.map(|req| req.header(key, value))
+    }
+
+    /// Never answer this request from the offline snapshots.
+    #[cfg(feature = "offline")]
+    pub(crate) fn without_snapshots(mut self) -> Self {
+        self.use_snapshots = false;
+        self
+    }
+
+    fn drift_endpoint(&self) -> Option<String> {
+        let url = self.url.as_ref().ok()?;
+        self.client.drift_endpoint(&self.method, url)
+    }
+
+    /// Send the request and deserialize the JSON response body.
+    pub async fn send_json<T: DeserializeOwned>(self) -> Result<T> {
+        #[cfg(feature = "offline")]
+        if self.snapshot_store().is_some() {
+            return Ok(self.send_json_with_meta().await?.data);
+        }
+        let client = self.client;
+        let endpoint = self.drift_endpoint();
+        let response = self.send().await?;
+        client.decode_json(&response.data.bytes().await?, endpoint.as_deref())
+    }
+
+    /// Send the request and deserialize the JSON response body, keeping the
+    /// status, headers and timing information.
+    pub async fn send_json_with_meta<T: DeserializeOwned>(self) -> Result<Response<T>> {
+        let client = self.client;
+        let endpoint = self.drift_endpoint();
+        let response = self.send_raw().await?;
+        let data = client.decode_json(&response.data, endpoint.as_deref())?;
+        Ok(response.map(|_| data))
+    }
+
+    /// Send the request and return the raw response body.
+    pub async fn send_bytes(self) -> Result<Vec<u8>> {
+        #[cfg(feature = "offline")]
+        if self.snapshot_store().is_some() {
+            return Ok(self.send_raw().await?.data);
+        }
+        let response = self.send().await?;
+        Ok(response.data.bytes().await?.to_vec())
+    }
+
+    /// Send the request and return the status, headers and undecoded body.
+    pub async fn send_raw(self) -> Result<RawResponse> {
+        #[cfg(feature = "offline")]
+        if let Some(store) = self.snapshot_store() {
+            return self.send_read_through(store).await;
+        }
+        self.send_raw_live().await
+    }
+
+    async fn send_raw_live(self) -> Result<RawResponse> {
+        let (response, meta) = self.send().await?.split();
+        let body = response.bytes().await?.to_vec();
+        Ok(meta.map(|()| body))
+    }
+
+    /// The offline store, if this is a `GET` that may use snapshots.
+    #[cfg(feature = "offline")]
+    fn snapshot_store(&self) -> Option<crate::offline::OfflineStore> {
+        if self.method != Method::GET || !self.use_snapshots || self.url.is_err() {
+            return None;
+        }
+        self.client.offline_store().cloned()
+    }
+
+    /// The request URL including its query string.
+    #[cfg(feature = "offline")]
+    fn final_url(&self) -> Option<url::Url> {
+        let request = self.inner.as_ref()?.try_clone()?.build().ok()?;
+        Some(request.url().clone())
+    }
+
+    /// Send the request, saving the response as a snapshot, or fall back to
+    /// the last snapshot if the API cannot be reached.
+    #[cfg(feature = "offline")]
+    async fn send_read_through(self, store: crate::offline::OfflineStore) -> Result<RawResponse> {
+        // The key is the final URL, so pages and filters get their own
+        // snapshots.
+        let url = match self.final_url() {
+            Some(url) => url.to_string(),
+            None => return self.send_raw_live().await,
+        };
+        match self.send_raw_live().await {
+            Ok(response) => {
+                // A failed save must not fail a successful request.
+                let _ = store.save_snapshot(&url, &response);
+                Ok(response)
+            }
+            Err(error) if error.is_retryable() => match store.snapshot(&url) {
+                Ok(Some(snapshot)) => Ok(snapshot),
+                _ => Err(error),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn send(self) -> Result<Response<reqwest::Response>> {
+        let url = self.url?;
+        let request = self
+            .inner
+            .expect("request builder is always set when the URL is valid");
+
+        if let Some(plan) = self.client.dry_run_plan() {
+            if crate::dry_run::DryRunPlan::intercepts(&self.method) {
+                return plan.record(request);
+            }
+        }
+
+        self.client
+            .send_request(self.method.as_str(), url.as_str(), request)
+            .await
+    }
+}
@@ -0,0 +1,223 @@
+//! Response metadata returned alongside deserialized data.
+//!
+//! The regular endpoint methods return only the parsed body. Every endpoint
+//! also has a `*_with_meta` variant that returns a [`Response`] with the HTTP
+//! status, headers, elapsed time and number of attempts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::{Client, AccessToken, Customer};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//!
+//! let customer = Customer {
+//!     name: Some("Acme Corporation".to_string()),
+//!     ..Default::default()
+//! };
+//! let response = client.customers().create_with_meta(&customer).await?;
+//!
+//! println!("Status: {}", response.status);
+//! println!("Location: {:?}", response.location());
+//! println!("Request id: {:?}", response.request_id());
+//! println!("Took {:?} over {} attempt(s)", response.elapsed, response.attempts);
+//! let created = response.data;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use reqwest::header::{self, HeaderMap};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+/// Header names checked, in order, by [`Response::request_id`].
+pub const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "request-id", "x-correlation-id"];
+
+/// A successful API response together with its HTTP metadata.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// The deserialized response body.
+    pub data: T,
+
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Response headers.
+    pub headers: HeaderMap,
+
+    /// Total time spent on the request, including retries and rate limiting.
+    pub elapsed: Duration,
+
+    /// Number of times the request was sent (1 if it succeeded without retries).
+    ///
+    /// 0 for responses served from an offline snapshot.
+    pub attempts: u32,
+
+    /// When the body was saved, if it was served from an offline snapshot
+    /// because the API could not be reached. `None` for live responses.
+    pub cached_at: Option<DateTime<Utc>>,
+}
+
+impl<T> Response<T> {
+    /// Get a response header as a string, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Get the server-assigned request id, useful for support tickets.
+    ///
+    /// Checks the headers listed in [`REQUEST_ID_HEADERS`].
+    pub fn request_id(&self) -> Option<&str> {
+        REQUEST_ID_HEADERS.iter().find_map(|name| self.header(name))
+    }
+
+    /// Get the `Location` header, typically set after creating a resource.
+    pub fn location(&self) -> Option<&str> {
+        self.header(header::LOCATION.as_str())
+    }
+
+    /// Get the remaining request quota from the `X-RateLimit-Remaining` header.
+    pub fn rate_limit_remaining(&self) -> Option<u32> {
+        self.header("x-ratelimit-remaining")?.trim().parse().ok()
+    }
+
+    /// Get the request quota from the `X-RateLimit-Limit` header.
+    pub fn rate_limit_limit(&self) -> Option<u32> {
+        self.header("x-ratelimit-limit")?.trim().parse().ok()
+    }
+
+    /// Get the number of retries performed before the request succeeded.
+    pub fn retries(&self) -> u32 {
+        self.attempts.saturating_sub(1)
+    }
+
+    /// Check if the body was served from an offline snapshot.
+    pub fn is_cached(&self) -> bool {
+        self.cached_at.is_some()
+    }
+
+    /// Get the age of an offline snapshot, or `None` for live responses.
+    pub fn staleness(&self) -> Option<chrono::Duration> {
+        self.cached_at.map(|cached_at| Utc::now() - cached_at)
+    }
+
+    /// Discard the metadata and return the response body.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Transform the response body, keeping the metadata.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            data: f(self.data),
+            status: self.status,
+            headers: self.headers,
+            elapsed: self.elapsed,
+            attempts: self.attempts,
+            cached_at: self.cached_at,
+        }
+    }
+
+    /// Split the body from the metadata.
+    pub(crate) fn split(self) -> (T, Response<()>) {
+        let Response {
+            data,
+            status,
+            headers,
+            elapsed,
+            attempts,
+            cached_at,
+        } = self;
+        (
+            data,
+            Response {
+                data: (),
+                status,
+                headers,
+                elapsed,
+                attempts,
+                cached_at,
+            },
+        )
+    }
+}
+
+/// A response with an undecoded body, returned by
+/// [`RequestBuilder::send_raw`](crate::request::RequestBuilder::send_raw).
+pub type RawResponse = Response<Vec<u8>>;
+
+impl Response<Vec<u8>> {
+    /// Get the response body as text.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.data).into_owned()
+    }
+
+    /// Deserialize the response body as JSON.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_with_headers(headers: &[(&'static str, &'static str)]) -> Response<()> {
+        let mut map = HeaderMap::new();
+        for (k, v) in headers {
+            map.insert(*k, v.parse().unwrap());
+        }
+        Response {
+            data: (),
+            status: 200,
+            headers: map,
+            elapsed: Duration::from_millis(5),
+            attempts: 1,
+            cached_at: None,
+        }
+    }
+
+    #[test]
+    fn test_request_id_header_fallbacks() {
+        let response = response_with_headers(&[("x-correlation-id", "corr-1")]);
+        assert_eq!(response.request_id(), Some("corr-1"));
+
+        let response =
+            response_with_headers(&[("x-correlation-id", "corr-1"), ("x-request-id", "req-1")]);
+        assert_eq!(response.request_id(), Some("req-1"));
+
+        assert!(response_with_headers(&[]).request_id().is_none());
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let response = response_with_headers(&[
+            ("X-RateLimit-Limit", "600"),
+            ("X-RateLimit-Remaining", "599"),
+        ]);
+        assert_eq!(response.rate_limit_limit(), Some(600));
+        assert_eq!(response.rate_limit_remaining(), Some(599));
+    }
+
+    #[test]
+    fn test_map_keeps_metadata() {
+        let mut response = response_with_headers(&[("Location", "/customers/1")]);
+        response.attempts = 3;
+
+        let mapped = response.map(|()| 42);
+        assert_eq!(mapped.data, 42);
+        assert_eq!(mapped.location(), Some("/customers/1"));
+        assert_eq!(mapped.retries(), 2);
+    }
+
+    #[test]
+    fn test_raw_response_body() {
+        let response = response_with_headers(&[]).map(|()| br#"{"Id": "1"}"#.to_vec());
+        assert_eq!(response.text(), r#"{"Id": "1"}"#);
+        let value: serde_json::Value = response.json().unwrap();
+        assert_eq!(value["Id"], "1");
+    }
+}
@@ -101,8 +101,9 @@ pub fn is_retryable_error(error: &Error) -> bool {
         Error::Http(_) => true,              // Network errors are retryable
         Error::RateLimitExceeded(_) => true, // Rate limits are retryable
         Error::ApiError { status_code, .. } => {
-            // Retry on server errors (5xx) but not client errors (4xx)
-            *status_code >= 500
+            // Retry on server errors (5xx) and rate limiting (429), but not
+            // other client errors (4xx)
+            *status_code >= 500 || *status_code == 429
         }
         Error::TokenExpired => false, // Need to refresh, not retry
         _ => false,
@@ -141,12 +142,18 @@ mod tests {
             status_code: 500,
             response: ApiErrorResponse::from_raw("Server error".to_string()),
             raw_body: "Server error".to_string(),
+            headers: Default::default(),
         }));
         assert!(!is_retryable_error(&Error::ApiError {
             status_code: 400,
             response: ApiErrorResponse::from_raw("Bad request".to_string()),
             raw_body: "Bad request".to_string(),
+            headers: Default::default(),
         }));
+        assert!(is_retryable_error(&Error::from_api_response(
+            429,
+            "Too many requests".to_string()
+        )));
         assert!(!is_retryable_error(&Error::TokenExpired));
     }
 }
@@ -0,0 +1,134 @@
+//! Unknown field handling.
+//!
+//! Spiris adds fields to its responses over time. Every resource type keeps
+//! the fields it does not model in its `extra` map, so a fetched item can be
+//! sent back with [`update`](crate::endpoints::CustomersEndpoint::update)
+//! without dropping them.
+//!
+//! For contract monitoring, [`unknown_fields`] lists those fields, and
+//! [`ClientConfig::strict_deserialization`](crate::ClientConfig::strict_deserialization)
+//! makes the client reject responses that contain any.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::strict;
+//! use spiris::Customer;
+//!
+//! let json = br#"{"Name": "Acme", "LoyaltyTier": "Gold"}"#;
+//!
+//! let (customer, unknown) = strict::unknown_fields::<Customer>(json)?;
+//! assert_eq!(customer.extra["LoyaltyTier"], "Gold");
+//! assert_eq!(unknown, vec!["Customer.LoyaltyTier".to_string()]);
+//!
+//! assert!(strict::from_slice::<Customer>(json).is_err());
+//! # Ok::<(), spiris::Error>(())
+//! ```
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+thread_local! {
+    static UNKNOWN: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Deserialize the `extra` map of `T`, recording its keys while
+/// [`unknown_fields`] is collecting.
+pub(crate) fn capture<'de, T, D>(
+    deserializer: D,
+) -> std::result::Result<Map<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extra = Map::deserialize(deserializer)?;
+    if !extra.is_empty() {
+        UNKNOWN.with(|unknown| {
+            if let Some(fields) = unknown.borrow_mut().as_mut() {
+                let type_name = std::any::type_name::<T>();
+                let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+                fields.extend(extra.keys().map(|key| format!("{}.{}", type_name, key)));
+            }
+        });
+    }
+    Ok(extra)
+}
+
+/// Deserialize JSON and list the fields the target types do not model.
+///
+/// Fields are reported as `Type.Field`, e.g. `InvoiceRow.Discount`, in the
+/// order they appear.
+pub fn unknown_fields<T: DeserializeOwned>(json: &[u8]) -> Result<(T, Vec<String>)> {
+    let (result, fields) = collect(|| serde_json::from_slice(json));
+    Ok((result?, fields))
+}
+
+/// Run `f`, collecting the unknown fields of everything it deserializes.
+pub(crate) fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let previous = UNKNOWN.with(|unknown| unknown.replace(Some(Vec::new())));
+    let result = f();
+    let fields = UNKNOWN
+        .with(|unknown| unknown.replace(previous))
+        .unwrap_or_default();
+    (result, fields)
+}
+
+/// Deserialize JSON, failing with [`Error::UnknownFields`] if it contains
+/// fields the target types do not model.
+pub fn from_slice<T: DeserializeOwned>(json: &[u8]) -> Result<T> {
+    let (value, fields) = unknown_fields(json)?;
+    if fields.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::UnknownFields(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Customer, Invoice, PaginatedResponse};
+
+    #[test]
+    fn test_extra_fields_round_trip() {
+        let json = r#"{"Name":"Acme","NewField":{"Nested":[1,2]}}"#;
+        let customer: Customer = serde_json::from_str(json).unwrap();
+        assert_eq!(customer.name.as_deref(), Some("Acme"));
+        assert_eq!(customer.extra["NewField"]["Nested"][1], 2);
+
+        let value = serde_json::to_value(&customer).unwrap();
+        assert_eq!(value, serde_json::from_str::<Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_fields_include_nested_types() {
+        let json = br#"{
+            "Data": [{"InvoiceNumber": "1", "Rows": [{"Text": "A", "Margin": 3}], "Origin": "Web"}],
+            "Meta": {"CurrentPage": 0, "PageSize": 50, "TotalPages": 1, "TotalCount": 1,
+                     "HasNextPage": false, "HasPreviousPage": false}
+        }"#;
+
+        let (page, fields) = unknown_fields::<PaginatedResponse<Invoice>>(json).unwrap();
+        assert_eq!(page.data[0].rows[0].extra["Margin"], 3);
+        assert_eq!(fields, vec!["InvoiceRow.Margin", "Invoice.Origin"]);
+    }
+
+    #[test]
+    fn test_from_slice_rejects_unknown_fields() {
+        assert!(from_slice::<Customer>(br#"{"Name": "Acme"}"#).is_ok());
+
+        let err = from_slice::<Customer>(br#"{"Name": "Acme", "Tier": 1}"#).unwrap_err();
+        assert!(matches!(&err, Error::UnknownFields(fields) if fields == &["Customer.Tier"]));
+        assert_eq!(
+            err.to_string(),
+            "Response contains unknown fields: Customer.Tier"
+        );
+
+        // Collection is only active inside `unknown_fields`.
+        let customer: Customer = serde_json::from_str(r#"{"Tier": 1}"#).unwrap();
+        assert_eq!(customer.extra.len(), 1);
+        assert!(UNKNOWN.with(|unknown| unknown.borrow().is_none()));
+    }
+}
@@ -0,0 +1,463 @@
+//! Local invoice totals, VAT and öresavrundning.
+//!
+//! An [`InvoiceCalculator`] computes the figures the API fills in on
+//! invoices, drafts, orders and quotations, so they can be previewed before
+//! sending and reconciled afterwards:
+//!
+//! - Each row amount is `unit price × quantity × (1 − discount %)`, rounded
+//!   to öre.
+//! - VAT is computed once per VAT rate on the sum of the rows with that
+//!   rate, rounded to öre, as on a Swedish invoice.
+//! - For SEK invoices ([`InvoiceCalculator::currency`]) or with
+//!   [`Rounding::WholeUnits`], the amount to pay is rounded to whole kronor
+//!   (öresavrundning) and the difference is reported as
+//!   [`InvoiceTotals::rounding_amount`]. Other currencies keep öre.
+//!
+//! Rounding is half away from zero under both `Money` backends.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::totals::InvoiceCalculator;
+//! use spiris::{money, InvoiceRowCreate, VatRateId};
+//!
+//! # fn main() -> spiris::Result<()> {
+//! let standard: VatRateId = "fa000000-0000-4000-8000-000000000025".parse()?;
+//! let calculator = InvoiceCalculator::new()
+//!     .rate(standard.clone(), money!(25.0))
+//!     .currency("SEK");
+//!
+//! let rows = vec![
+//!     InvoiceRowCreate::with_text("Consulting".to_string(), money!(999.0), money!(1.5))
+//!         .vat_rate_id(standard),
+//! ];
+//! let totals = calculator.calculate(&rows)?;
+//!
+//! assert_eq!(totals.total_amount, money!(1498.5));
+//! assert_eq!(totals.total_vat_amount, money!(374.63));
+//! assert_eq!(totals.rounding_amount, money!(-0.13));
+//! assert_eq!(totals.total_amount_including_vat, money!(1873.0));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use crate::ids::VatRateId;
+use crate::types::{
+    round_money, CustomerInvoiceDraft, CustomerInvoiceDraftRow, Invoice, InvoiceRow,
+    InvoiceRowCreate, Money, OrderRow, QuotationRow, VatCode,
+};
+use std::collections::HashMap;
+
+/// A row with a price, quantity and discount.
+pub trait PricedRow {
+    /// Unit price excluding VAT.
+    fn unit_price(&self) -> Option<Money>;
+
+    /// Quantity.
+    fn quantity(&self) -> Option<Money>;
+
+    /// Discount percentage (0-100).
+    fn discount_percentage(&self) -> Option<Money>;
+
+    /// VAT rate of the row; `None` uses the calculator's default rate.
+    fn vat_rate_id(&self) -> Option<&VatRateId> {
+        None
+    }
+}
+
+macro_rules! impl_priced_row {
+    ($($type:ty $(, $vat:ident)?;)*) => {
+        $(
+            impl PricedRow for $type {
+                fn unit_price(&self) -> Option<Money> {
+                    self.unit_price
+                }
+
+                fn quantity(&self) -> Option<Money> {
+                    self.quantity
+                }
+
+                fn discount_percentage(&self) -> Option<Money> {
+                    self.discount_percentage
+                }
+
+                $(
+                    fn vat_rate_id(&self) -> Option<&VatRateId> {
+                        self.$vat.as_ref()
+                    }
+                )?
+            }
+        )*
+    };
+}
+
+impl_priced_row! {
+    InvoiceRow, vat_rate_id;
+    CustomerInvoiceDraftRow, vat_rate_id;
+    InvoiceRowCreate, vat_rate_id;
+    OrderRow;
+    QuotationRow;
+}
+
+/// How the amount to pay is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to whole currency units (öresavrundning).
+    WholeUnits,
+    /// Keep öre.
+    #[default]
+    None,
+}
+
+/// Computed amounts for one row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowTotal {
+    /// Amount excluding VAT, after discount.
+    pub amount: Money,
+    /// VAT rate percentage applied.
+    pub vat_rate: Money,
+    /// VAT on this row alone.
+    ///
+    /// The invoice VAT is computed per rate, so row VAT amounts may differ
+    /// from [`VatTotal::vat_amount`] by an öre.
+    pub vat_amount: Money,
+}
+
+/// Computed amounts for one VAT rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VatTotal {
+    /// VAT rate percentage.
+    pub rate: Money,
+    /// Sum of the row amounts with this rate.
+    pub base: Money,
+    /// VAT on `base`.
+    pub vat_amount: Money,
+}
+
+/// Totals computed by an [`InvoiceCalculator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceTotals {
+    /// Per-row amounts, in row order.
+    pub rows: Vec<RowTotal>,
+    /// Per-rate amounts, highest rate first.
+    pub vat: Vec<VatTotal>,
+    /// Total excluding VAT.
+    pub total_amount: Money,
+    /// Total VAT.
+    pub total_vat_amount: Money,
+    /// Adjustment added by öresavrundning.
+    pub rounding_amount: Money,
+    /// Amount to pay, including VAT and rounding.
+    pub total_amount_including_vat: Money,
+}
+
+/// A figure that differs from the API's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// API field path, e.g. `TotalVatAmount` or `Rows[1].TotalAmount`.
+    pub field: String,
+    /// Locally computed value.
+    pub expected: Money,
+    /// Value returned by the API.
+    pub actual: Money,
+}
+
+impl InvoiceTotals {
+    /// Compare with the totals the API returned on an invoice.
+    ///
+    /// Fields the invoice does not have are skipped.
+    pub fn reconcile(&self, invoice: &Invoice) -> Vec<Mismatch> {
+        let row_totals = invoice.rows.iter().map(|row| row.total_amount);
+        self.compare(
+            row_totals,
+            invoice.total_amount,
+            invoice.total_vat_amount,
+            invoice.total_amount_including_vat,
+        )
+    }
+
+    /// Compare with the totals the API returned on a draft.
+    pub fn reconcile_draft(&self, draft: &CustomerInvoiceDraft) -> Vec<Mismatch> {
+        let row_totals = draft.rows.iter().map(|row| row.total_amount);
+        self.compare(
+            row_totals,
+            draft.total_amount,
+            draft.total_vat_amount,
+            draft.total_amount_including_vat,
+        )
+    }
+
+    fn compare(
+        &self,
+        row_totals: impl Iterator<Item = Option<Money>>,
+        total_amount: Option<Money>,
+        total_vat_amount: Option<Money>,
+        total_amount_including_vat: Option<Money>,
+    ) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+        let mut check = |field: String, expected: Money, actual: Option<Money>| {
+            if let Some(actual) = actual {
+                if round_money(actual, 2) != expected {
+                    mismatches.push(Mismatch {
+                        field,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        };
+
+        for (index, (row, actual)) in self.rows.iter().zip(row_totals).enumerate() {
+            check(format!("Rows[{}].TotalAmount", index), row.amount, actual);
+        }
+        check("TotalAmount".to_string(), self.total_amount, total_amount);
+        check(
+            "TotalVatAmount".to_string(),
+            self.total_vat_amount,
+            total_vat_amount,
+        );
+        check(
+            "TotalAmountIncludingVat".to_string(),
+            self.total_amount_including_vat,
+            total_amount_including_vat,
+        );
+        mismatches
+    }
+}
+
+/// Computes row, VAT and invoice totals.
+#[derive(Debug, Clone, Default)]
+pub struct InvoiceCalculator {
+    rates: HashMap<VatRateId, Money>,
+    default_rate: Option<Money>,
+    rounding: Rounding,
+}
+
+impl InvoiceCalculator {
+    /// Create a calculator with no VAT rates that keeps öre.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the percentage (e.g. 25) of a VAT rate id.
+    pub fn rate(mut self, id: VatRateId, percentage: Money) -> Self {
+        self.rates.insert(id, percentage);
+        self
+    }
+
+    /// Register VAT codes fetched from the API, by id.
+    ///
+    /// Codes without an id or rate are skipped.
+    pub fn vat_codes<'a>(mut self, codes: impl IntoIterator<Item = &'a VatCode>) -> Self {
+        for code in codes {
+            if let (Some(id), Some(rate)) = (&code.id, code.vat_rate) {
+                self.rates.insert(id.clone(), rate);
+            }
+        }
+        self
+    }
+
+    /// Set the percentage used for rows without a VAT rate id.
+    pub fn default_rate(mut self, percentage: Money) -> Self {
+        self.default_rate = Some(percentage);
+        self
+    }
+
+    /// Set the invoice currency, e.g. from `Invoice.currency_code`.
+    ///
+    /// Öresavrundning only applies to SEK, so this rounds to whole units
+    /// for SEK and keeps öre for every other currency.
+    pub fn currency(mut self, code: &str) -> Self {
+        self.rounding = if code.eq_ignore_ascii_case("SEK") {
+            Rounding::WholeUnits
+        } else {
+            Rounding::None
+        };
+        self
+    }
+
+    /// Set how the amount to pay is rounded.
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Compute the totals of `rows`.
+    ///
+    /// Missing prices and quantities count as zero, so text-only rows add
+    /// nothing. Fails if a row has a VAT rate id that is not registered, or
+    /// no id and no default rate is set.
+    pub fn calculate<R: PricedRow>(&self, rows: &[R]) -> Result<InvoiceTotals> {
+        let zero = Money::default();
+        let hundred = crate::money!(100.0);
+
+        let mut row_totals = Vec::with_capacity(rows.len());
+        let mut bases: Vec<(Money, Money)> = Vec::new();
+        for (index, row) in rows.iter().enumerate() {
+            let vat_rate = self.rate_for(index, row)?;
+            let gross = row.unit_price().unwrap_or(zero) * row.quantity().unwrap_or(zero);
+            let discount = row.discount_percentage().unwrap_or(zero);
+            let amount = round_money(gross * (hundred - discount) / hundred, 2);
+
+            match bases.iter_mut().find(|(rate, _)| *rate == vat_rate) {
+                Some((_, base)) => *base += amount,
+                None => bases.push((vat_rate, amount)),
+            }
+            row_totals.push(RowTotal {
+                amount,
+                vat_rate,
+                vat_amount: round_money(amount * vat_rate / hundred, 2),
+            });
+        }
+
+        bases.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        let vat: Vec<VatTotal> = bases
+            .into_iter()
+            .map(|(rate, base)| VatTotal {
+                rate,
+                base: round_money(base, 2),
+                vat_amount: round_money(base * rate / hundred, 2),
+            })
+            .collect();
+
+        let total_amount =
+            round_money(row_totals.iter().fold(zero, |sum, row| sum + row.amount), 2);
+        let total_vat_amount = round_money(vat.iter().fold(zero, |sum, v| sum + v.vat_amount), 2);
+        let unrounded = total_amount + total_vat_amount;
+        let total_amount_including_vat = match self.rounding {
+            Rounding::WholeUnits => round_money(unrounded, 0),
+            Rounding::None => round_money(unrounded, 2),
+        };
+
+        Ok(InvoiceTotals {
+            rows: row_totals,
+            vat,
+            total_amount,
+            total_vat_amount,
+            rounding_amount: round_money(total_amount_including_vat - unrounded, 2),
+            total_amount_including_vat,
+        })
+    }
+
+    fn rate_for<R: PricedRow>(&self, index: usize, row: &R) -> Result<Money> {
+        match row.vat_rate_id() {
+            Some(id) => self.rates.get(id).copied().ok_or_else(|| {
+                Error::InvalidRequest(format!("Unknown VAT rate {} on row {}", id, index))
+            }),
+            None => self.default_rate.ok_or_else(|| {
+                Error::InvalidRequest(format!(
+                    "Row {} has no VAT rate and no default rate is set",
+                    index
+                ))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money;
+
+    const STANDARD: &str = "fa000000-0000-4000-8000-000000000025";
+    const FOOD: &str = "fa000000-0000-4000-8000-000000000012";
+
+    fn calculator() -> InvoiceCalculator {
+        InvoiceCalculator::new()
+            .rate(STANDARD.parse().unwrap(), money!(25.0))
+            .rate(FOOD.parse().unwrap(), money!(12.0))
+    }
+
+    fn row(price: Money, quantity: Money, rate: &str) -> InvoiceRowCreate {
+        InvoiceRowCreate::new()
+            .unit_price(price)
+            .quantity(quantity)
+            .vat_rate_id(rate.parse().unwrap())
+    }
+
+    #[test]
+    fn test_round_half_away_from_zero() {
+        assert_eq!(round_money(money!(1.005), 2), money!(1.01));
+        assert_eq!(round_money(money!(-1.005), 2), money!(-1.01));
+        assert_eq!(round_money(money!(2.5), 0), money!(3.0));
+        assert_eq!(round_money(money!(2.49), 0), money!(2.0));
+    }
+
+    #[test]
+    fn test_vat_per_rate() {
+        let rows = vec![
+            row(money!(10.05), money!(1.0), STANDARD),
+            row(money!(10.05), money!(1.0), STANDARD),
+            row(money!(99.9), money!(3.0), FOOD).discount_percentage(money!(10.0)),
+        ];
+        let totals = calculator().currency("SEK").calculate(&rows).unwrap();
+
+        // Per row VAT would be 2.51 + 2.51; per rate it is 5.03 (20.10 × 25%).
+        assert_eq!(totals.rows[0].vat_amount, money!(2.51));
+        assert_eq!(totals.vat[0].rate, money!(25.0));
+        assert_eq!(totals.vat[0].vat_amount, money!(5.03));
+        assert_eq!(totals.rows[2].amount, money!(269.73));
+        assert_eq!(totals.vat[1].vat_amount, money!(32.37));
+
+        assert_eq!(totals.total_amount, money!(289.83));
+        assert_eq!(totals.total_vat_amount, money!(37.4));
+        assert_eq!(totals.rounding_amount, money!(-0.23));
+        assert_eq!(totals.total_amount_including_vat, money!(327.0));
+
+        // Öresavrundning only applies to SEK.
+        let totals = calculator().currency("EUR").calculate(&rows).unwrap();
+        assert_eq!(totals.rounding_amount, money!(0.0));
+        assert_eq!(totals.total_amount_including_vat, money!(327.23));
+    }
+
+    #[test]
+    fn test_rounding_none_and_default_rate() {
+        let rows = vec![OrderRow {
+            unit_price: Some(money!(10.1)),
+            quantity: Some(money!(1.0)),
+            ..Default::default()
+        }];
+        let totals = InvoiceCalculator::new()
+            .default_rate(money!(25.0))
+            .calculate(&rows)
+            .unwrap();
+        assert_eq!(InvoiceCalculator::new().rounding, Rounding::None);
+
+        assert_eq!(totals.total_amount_including_vat, money!(12.63));
+        assert_eq!(totals.rounding_amount, money!(0.0));
+    }
+
+    #[test]
+    fn test_unknown_rate_is_an_error() {
+        let rows = vec![row(money!(1.0), money!(1.0), FOOD)];
+        let err = InvoiceCalculator::new().calculate(&rows).unwrap_err();
+        assert!(err.to_string().contains("Unknown VAT rate"));
+
+        let rows = vec![QuotationRow::default()];
+        assert!(InvoiceCalculator::new().calculate(&rows).is_err());
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let rows = vec![InvoiceRow {
+            unit_price: Some(money!(100.0)),
+            quantity: Some(money!(2.0)),
+            vat_rate_id: Some(STANDARD.parse().unwrap()),
+            total_amount: Some(money!(200.0)),
+            ..Default::default()
+        }];
+        let invoice = Invoice {
+            rows,
+            total_amount: Some(money!(200.0)),
+            total_vat_amount: Some(money!(40.0)),
+            ..Default::default()
+        };
+        let totals = calculator().calculate(&invoice.rows).unwrap();
+
+        let mismatches = totals.reconcile(&invoice);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].field, "TotalVatAmount");
+        assert_eq!(mismatches[0].expected, money!(50.0));
+    }
+}
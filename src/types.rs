@@ -1,7 +1,18 @@
 //! Common types and data models for the Visma eAccounting API.
 
+use crate::codes::{BookkeepingMethod, DocumentType, VoucherType};
+use crate::ids::{
+    AllocationPeriodId, ArticleAccountCodingId, ArticleId, ArticleLabelId, AttachmentId,
+    BankAccountId, BankId, CostCenterId, CostCenterItemId, CustomerId, CustomerInvoiceDraftId,
+    CustomerLabelId, CustomerLedgerItemId, DeliveryMethodId, DeliveryTermId, DocumentId,
+    FiscalYearId, Guid, InvoiceId, MessageId, MessageThreadId, OrderId, ProjectId, QuotationId,
+    SupplierId, SupplierInvoiceDraftId, SupplierInvoiceId, SupplierLabelId, SupplierLedgerItemId,
+    TermsOfPaymentId, UnitId, UserId, VatCodeId, VatRateId, VoucherId,
+};
+use crate::validate::{BankAccountNumber, Bankgiro, CorporateIdentity, Ocr, Plusgiro};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 /// Type alias for monetary values.
@@ -62,6 +73,27 @@ macro_rules! money {
     }};
 }
 
+/// Round half away from zero to `decimals` places.
+#[cfg(feature = "decimal")]
+pub(crate) fn round_money(value: Money, decimals: u32) -> Money {
+    value.round_dp_with_strategy(
+        decimals,
+        rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+    )
+}
+
+/// Round half away from zero to `decimals` places.
+///
+/// Values such as `1.005` are stored slightly below the midpoint, so the
+/// scaled value is nudged by a tiny relative amount before rounding.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn round_money(value: Money, decimals: u32) -> Money {
+    let factor = 10f64.powi(decimals as i32);
+    let scaled = value * factor;
+    let nudge = (scaled.abs() * 1e-12).max(1e-9);
+    (scaled + scaled.signum() * nudge).round() / factor
+}
+
 /// Pagination parameters for list requests.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct PaginationParams {
@@ -133,7 +165,7 @@ pub struct ResponseMetadata {
 pub struct Customer {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CustomerId>,
 
     /// Customer number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,6 +222,10 @@ pub struct Customer {
     /// When the customer was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Customer, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Address information.
@@ -215,6 +251,10 @@ pub struct Address {
     /// Country code (ISO 3166-1 alpha-2).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<String>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Address, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Invoice/Customer invoice.
@@ -223,7 +263,7 @@ pub struct Address {
 pub struct Invoice {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<InvoiceId>,
 
     /// Invoice number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -231,7 +271,7 @@ pub struct Invoice {
 
     /// Customer ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
 
     /// Invoice date.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,6 +290,7 @@ pub struct Invoice {
     pub currency_code: Option<String>,
 
     /// Invoice rows/line items.
+    #[serde(default)]
     pub rows: Vec<InvoiceRow>,
 
     /// Total amount excluding VAT.
@@ -279,6 +320,10 @@ pub struct Invoice {
     /// When the invoice was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Invoice, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Invoice row/line item.
@@ -287,11 +332,11 @@ pub struct Invoice {
 pub struct InvoiceRow {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Guid>,
 
     /// Article/product ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub article_id: Option<String>,
+    pub article_id: Option<ArticleId>,
 
     /// Description/text.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,11 +356,15 @@ pub struct InvoiceRow {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 
     /// Total amount for this row.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_amount: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<InvoiceRow, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Article/Product.
@@ -324,7 +373,7 @@ pub struct InvoiceRow {
 pub struct Article {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ArticleId>,
 
     /// Article number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -352,7 +401,7 @@ pub struct Article {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 
     /// When the article was created.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -361,6 +410,10 @@ pub struct Article {
     /// When the article was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Article, _>")]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -373,11 +426,11 @@ pub struct Article {
 pub struct CustomerInvoiceDraft {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CustomerInvoiceDraftId>,
 
     /// Customer ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
 
     /// Invoice date.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,6 +483,13 @@ pub struct CustomerInvoiceDraft {
     /// When the draft was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CustomerInvoiceDraft, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Customer invoice draft row/line item.
@@ -438,11 +498,11 @@ pub struct CustomerInvoiceDraft {
 pub struct CustomerInvoiceDraftRow {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Guid>,
 
     /// Article/product ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub article_id: Option<String>,
+    pub article_id: Option<ArticleId>,
 
     /// Description/text.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,11 +522,18 @@ pub struct CustomerInvoiceDraftRow {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 
     /// Total amount for this row.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_amount: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CustomerInvoiceDraftRow, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Options for converting a draft to an invoice.
@@ -488,15 +555,15 @@ pub struct ConvertDraftOptions {
 pub struct CustomerLedgerItem {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CustomerLedgerItemId>,
 
     /// Customer ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
 
     /// Customer invoice ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_invoice_id: Option<String>,
+    pub customer_invoice_id: Option<InvoiceId>,
 
     /// Amount in currency.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -520,7 +587,7 @@ pub struct CustomerLedgerItem {
 
     /// Voucher ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voucher_id: Option<String>,
+    pub voucher_id: Option<VoucherId>,
 
     /// Voucher number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -529,6 +596,13 @@ pub struct CustomerLedgerItem {
     /// When the item was created.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CustomerLedgerItem, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -541,7 +615,7 @@ pub struct CustomerLedgerItem {
 pub struct CustomerLabel {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CustomerLabelId>,
 
     /// Label name.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -550,6 +624,13 @@ pub struct CustomerLabel {
     /// Label description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CustomerLabel, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -570,7 +651,7 @@ pub struct InvoicePayment {
 
     /// Bank account ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bank_account_id: Option<String>,
+    pub bank_account_id: Option<BankAccountId>,
 
     /// Payment reference number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -579,6 +660,13 @@ pub struct InvoicePayment {
     /// Currency rate (exchange rate).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency_rate: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<InvoicePayment, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -591,7 +679,7 @@ pub struct InvoicePayment {
 pub struct Supplier {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<SupplierId>,
 
     /// Supplier number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,6 +736,10 @@ pub struct Supplier {
     /// When the supplier was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Supplier, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Supplier invoice.
@@ -656,11 +748,11 @@ pub struct Supplier {
 pub struct SupplierInvoice {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<SupplierInvoiceId>,
 
     /// Supplier ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supplier_id: Option<String>,
+    pub supplier_id: Option<SupplierId>,
 
     /// Invoice number from supplier.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -717,6 +809,13 @@ pub struct SupplierInvoice {
     /// When the invoice was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<SupplierInvoice, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Supplier invoice row/line item.
@@ -725,7 +824,7 @@ pub struct SupplierInvoice {
 pub struct SupplierInvoiceRow {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Guid>,
 
     /// Account number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,15 +844,22 @@ pub struct SupplierInvoiceRow {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 
     /// Cost center item ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_center_item_id: Option<String>,
+    pub cost_center_item_id: Option<CostCenterItemId>,
 
     /// Project ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<String>,
+    pub project_id: Option<ProjectId>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<SupplierInvoiceRow, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -778,11 +884,11 @@ pub struct Account {
 
     /// VAT code ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_code_id: Option<String>,
+    pub vat_code_id: Option<VatCodeId>,
 
     /// Fiscal year ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fiscal_year_id: Option<String>,
+    pub fiscal_year_id: Option<FiscalYearId>,
 
     /// Whether the account is active.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -791,6 +897,10 @@ pub struct Account {
     /// Opening balance.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opening_balance: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Account, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Account balance at a specific date.
@@ -808,6 +918,13 @@ pub struct AccountBalance {
     /// Balance amount.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub balance: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<AccountBalance, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Account type definition.
@@ -821,6 +938,10 @@ pub struct AccountType {
     /// Account type name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<AccountType, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Fiscal year.
@@ -829,7 +950,7 @@ pub struct AccountType {
 pub struct FiscalYear {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<FiscalYearId>,
 
     /// Start date.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -843,9 +964,13 @@ pub struct FiscalYear {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_locked: Option<bool>,
 
-    /// Bookkeeping method (1 = Invoice, 2 = Cash).
+    /// Bookkeeping method.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bookkeeping_method: Option<i32>,
+    pub bookkeeping_method: Option<BookkeepingMethod>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<FiscalYear, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// VAT code.
@@ -854,7 +979,7 @@ pub struct FiscalYear {
 pub struct VatCode {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<VatCodeId>,
 
     /// VAT code.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -867,6 +992,10 @@ pub struct VatCode {
     /// VAT rate percentage.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vat_rate: Option<Money>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<VatCode, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Voucher (journal entry).
@@ -875,7 +1004,7 @@ pub struct VatCode {
 pub struct Voucher {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<VoucherId>,
 
     /// Voucher number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -885,9 +1014,9 @@ pub struct Voucher {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub voucher_date: Option<DateTime<Utc>>,
 
-    /// Voucher type (0 = Manual, etc.).
+    /// Voucher type.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voucher_type: Option<i32>,
+    pub voucher_type: Option<VoucherType>,
 
     /// Voucher text/description.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -904,6 +1033,10 @@ pub struct Voucher {
     /// When the voucher was last modified.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Voucher, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Voucher row (journal entry line).
@@ -928,11 +1061,15 @@ pub struct VoucherRow {
 
     /// Cost center item ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_center_item_id: Option<String>,
+    pub cost_center_item_id: Option<CostCenterItemId>,
 
     /// Project ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<String>,
+    pub project_id: Option<ProjectId>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<VoucherRow, _>")]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -945,7 +1082,7 @@ pub struct VoucherRow {
 pub struct BankAccount {
     /// Unique identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<BankAccountId>,
 
     /// Bank account name.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -978,6 +1115,10 @@ pub struct BankAccount {
     /// Whether the bank account is active.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
+
+    /// Fields not modelled by this type.
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<BankAccount, _>")]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -989,7 +1130,7 @@ pub struct BankAccount {
 #[serde(rename_all = "PascalCase")]
 pub struct Project {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ProjectId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -999,7 +1140,7 @@ pub struct Project {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_completed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1008,6 +1149,8 @@ pub struct Project {
     pub created_utc: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Project, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Cost center for allocating expenses.
@@ -1015,11 +1158,13 @@ pub struct Project {
 #[serde(rename_all = "PascalCase")]
 pub struct CostCenter {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CostCenterId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<CostCenter, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Cost center item (specific allocation).
@@ -1027,15 +1172,20 @@ pub struct CostCenter {
 #[serde(rename_all = "PascalCase")]
 pub struct CostCenterItem {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CostCenterItemId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_center_id: Option<String>,
+    pub cost_center_id: Option<CostCenterId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub short_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CostCenterItem, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Allocation period for cost distribution.
@@ -1043,13 +1193,18 @@ pub struct CostCenterItem {
 #[serde(rename_all = "PascalCase")]
 pub struct AllocationPeriod {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<AllocationPeriodId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_date: Option<DateTime<Utc>>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<AllocationPeriod, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1061,11 +1216,11 @@ pub struct AllocationPeriod {
 #[serde(rename_all = "PascalCase")]
 pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<OrderId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1088,6 +1243,8 @@ pub struct Order {
     pub created_utc: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Order, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Order row/line item.
@@ -1095,9 +1252,9 @@ pub struct Order {
 #[serde(rename_all = "PascalCase")]
 pub struct OrderRow {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Guid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub article_id: Option<String>,
+    pub article_id: Option<ArticleId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1108,6 +1265,8 @@ pub struct OrderRow {
     pub discount_percentage: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delivered_quantity: Option<Money>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<OrderRow, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Sales quotation.
@@ -1115,11 +1274,11 @@ pub struct OrderRow {
 #[serde(rename_all = "PascalCase")]
 pub struct Quotation {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<QuotationId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quotation_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quotation_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1138,6 +1297,8 @@ pub struct Quotation {
     pub created_utc: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Quotation, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Quotation row/line item.
@@ -1145,9 +1306,9 @@ pub struct Quotation {
 #[serde(rename_all = "PascalCase")]
 pub struct QuotationRow {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Guid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub article_id: Option<String>,
+    pub article_id: Option<ArticleId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1156,6 +1317,11 @@ pub struct QuotationRow {
     pub quantity: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discount_percentage: Option<Money>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<QuotationRow, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1167,9 +1333,9 @@ pub struct QuotationRow {
 #[serde(rename_all = "PascalCase")]
 pub struct SupplierInvoiceDraft {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<SupplierInvoiceDraftId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supplier_id: Option<String>,
+    pub supplier_id: Option<SupplierId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1186,6 +1352,11 @@ pub struct SupplierInvoiceDraft {
     pub created_utc: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_utc: Option<DateTime<Utc>>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<SupplierInvoiceDraft, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Supplier ledger item.
@@ -1193,11 +1364,11 @@ pub struct SupplierInvoiceDraft {
 #[serde(rename_all = "PascalCase")]
 pub struct SupplierLedgerItem {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<SupplierLedgerItemId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supplier_id: Option<String>,
+    pub supplier_id: Option<SupplierId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supplier_invoice_id: Option<String>,
+    pub supplier_invoice_id: Option<SupplierInvoiceId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency_amount: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1207,9 +1378,14 @@ pub struct SupplierLedgerItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_date: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voucher_id: Option<String>,
+    pub voucher_id: Option<VoucherId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_utc: Option<DateTime<Utc>>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<SupplierLedgerItem, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Supplier label for categorization.
@@ -1217,11 +1393,16 @@ pub struct SupplierLedgerItem {
 #[serde(rename_all = "PascalCase")]
 pub struct SupplierLabel {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<SupplierLabelId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<SupplierLabel, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1233,11 +1414,16 @@ pub struct SupplierLabel {
 #[serde(rename_all = "PascalCase")]
 pub struct ArticleLabel {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ArticleLabelId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<ArticleLabel, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Article account coding (GL mapping).
@@ -1245,13 +1431,18 @@ pub struct ArticleLabel {
 #[serde(rename_all = "PascalCase")]
 pub struct ArticleAccountCoding {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<ArticleAccountCodingId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sales_account_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub purchase_account_number: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<ArticleAccountCoding, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Unit of measurement.
@@ -1259,11 +1450,13 @@ pub struct ArticleAccountCoding {
 #[serde(rename_all = "PascalCase")]
 pub struct Unit {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<UnitId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Unit, _>")]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1275,11 +1468,16 @@ pub struct Unit {
 #[serde(rename_all = "PascalCase")]
 pub struct DeliveryMethod {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<DeliveryMethodId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<DeliveryMethod, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Delivery terms.
@@ -1287,11 +1485,16 @@ pub struct DeliveryMethod {
 #[serde(rename_all = "PascalCase")]
 pub struct DeliveryTerm {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<DeliveryTermId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<DeliveryTerm, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Terms of payment.
@@ -1299,7 +1502,7 @@ pub struct DeliveryTerm {
 #[serde(rename_all = "PascalCase")]
 pub struct TermsOfPayment {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<TermsOfPaymentId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1308,6 +1511,11 @@ pub struct TermsOfPayment {
     pub number_of_days: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub terms_of_payment_type: Option<i32>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<TermsOfPayment, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1319,7 +1527,7 @@ pub struct TermsOfPayment {
 #[serde(rename_all = "PascalCase")]
 pub struct Attachment {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<AttachmentId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1328,6 +1536,8 @@ pub struct Attachment {
     pub temporary_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_utc: Option<DateTime<Utc>>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Attachment, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Link between attachment and document.
@@ -1335,11 +1545,16 @@ pub struct Attachment {
 #[serde(rename_all = "PascalCase")]
 pub struct AttachmentLink {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attachment_id: Option<String>,
+    pub attachment_id: Option<AttachmentId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_id: Option<String>,
+    pub document_id: Option<Guid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<i32>,
+    pub document_type: Option<DocumentType>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<AttachmentLink, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Document reference.
@@ -1347,11 +1562,13 @@ pub struct AttachmentLink {
 #[serde(rename_all = "PascalCase")]
 pub struct Document {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<DocumentId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<i32>,
+    pub document_type: Option<DocumentType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub document_number: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Document, _>")]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1378,6 +1595,11 @@ pub struct CompanySettings {
     pub currency_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<CompanySettings, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Country.
@@ -1390,6 +1612,8 @@ pub struct Country {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub english_name: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Country, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Currency.
@@ -1400,6 +1624,8 @@ pub struct Currency {
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Currency, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// User.
@@ -1407,7 +1633,7 @@ pub struct Currency {
 #[serde(rename_all = "PascalCase")]
 pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<UserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1416,6 +1642,8 @@ pub struct User {
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<User, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Bank reference data.
@@ -1423,11 +1651,13 @@ pub struct User {
 #[serde(rename_all = "PascalCase")]
 pub struct Bank {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<BankId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bic: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Bank, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Foreign payment code.
@@ -1438,6 +1668,11 @@ pub struct ForeignPaymentCode {
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<ForeignPaymentCode, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 // =============================================================================
@@ -1449,17 +1684,22 @@ pub struct ForeignPaymentCode {
 #[serde(rename_all = "PascalCase")]
 pub struct MessageThread {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<MessageThreadId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_type: Option<i32>,
+    pub document_type: Option<DocumentType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub document_id: Option<String>,
+    pub document_id: Option<Guid>,
     #[serde(default)]
     pub messages: Vec<Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_utc: Option<DateTime<Utc>>,
+    #[serde(
+        flatten,
+        deserialize_with = "crate::strict::capture::<MessageThread, _>"
+    )]
+    pub extra: Map<String, Value>,
 }
 
 /// Message in a thread.
@@ -1467,17 +1707,19 @@ pub struct MessageThread {
 #[serde(rename_all = "PascalCase")]
 pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<MessageId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_utc: Option<DateTime<Utc>>,
+    #[serde(flatten, deserialize_with = "crate::strict::capture::<Message, _>")]
+    pub extra: Map<String, Value>,
 }
 
 /// Generic query parameters for filtering and selecting fields.
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct QueryParams {
     /// Filter expression.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1487,6 +1729,10 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub select: Option<String>,
 
+    /// Sort order, such as `"Name"` or `"ModifiedUtc desc"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orderby: Option<String>,
+
     /// Additional custom parameters.
     #[serde(flatten)]
     pub extra: HashMap<String, String>,
@@ -1527,6 +1773,15 @@ impl QueryParams {
         self
     }
 
+    /// Set the sort order.
+    ///
+    /// Paging through a result that changes while it is read only returns
+    /// every item once if the order is stable.
+    pub fn orderby(mut self, orderby: impl Into<String>) -> Self {
+        self.orderby = Some(orderby.into());
+        self
+    }
+
     /// Add a custom parameter.
     pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.extra.insert(key.into(), value.into());
@@ -1534,6 +1789,78 @@ impl QueryParams {
     }
 }
 
+// =============================================================================
+// Resource Identity and Modification Time
+// =============================================================================
+
+/// Resources with a stable identifier.
+///
+/// Used to detect items seen twice while paginating and to match items
+/// between syncs. Most resources use their `Id`; countries and currencies use
+/// their code and accounts their account number.
+pub trait Identifiable {
+    /// The resource's identifier, if known.
+    fn id(&self) -> Option<&str>;
+}
+
+macro_rules! impl_identifiable {
+    ($field:ident: $($type:ty),* $(,)?) => {
+        $(
+            impl Identifiable for $type {
+                fn id(&self) -> Option<&str> {
+                    self.$field.as_deref()
+                }
+            }
+        )*
+    };
+}
+
+impl_identifiable!(
+    id:
+    Customer, Invoice, InvoiceRow, Article, CustomerInvoiceDraft, CustomerInvoiceDraftRow,
+    CustomerLedgerItem, CustomerLabel, Supplier, SupplierInvoice, SupplierInvoiceRow,
+    FiscalYear, VatCode, Voucher, BankAccount, Project, CostCenter, CostCenterItem,
+    AllocationPeriod, Order, OrderRow, Quotation, QuotationRow, SupplierInvoiceDraft,
+    SupplierLedgerItem, SupplierLabel, ArticleLabel, ArticleAccountCoding, Unit, DeliveryMethod,
+    DeliveryTerm, TermsOfPayment, Attachment, Document, User, Bank, MessageThread, Message,
+);
+impl_identifiable!(code: Country, Currency);
+impl_identifiable!(account_number: Account);
+
+/// Resources that record when they were last modified.
+///
+/// Used by the [`sync`](crate::sync) module to pull only changed items.
+pub trait Modified {
+    /// When the resource was last modified on the server.
+    fn modified_utc(&self) -> Option<DateTime<Utc>>;
+}
+
+macro_rules! impl_modified {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Modified for $type {
+                fn modified_utc(&self) -> Option<DateTime<Utc>> {
+                    self.modified_utc
+                }
+            }
+        )*
+    };
+}
+
+impl_modified!(
+    Customer,
+    Invoice,
+    Article,
+    CustomerInvoiceDraft,
+    Supplier,
+    SupplierInvoice,
+    Voucher,
+    Project,
+    Order,
+    Quotation,
+    SupplierInvoiceDraft,
+);
+
 // =============================================================================
 // Typed Request Types (Create/Update)
 // =============================================================================
@@ -1543,6 +1870,49 @@ impl QueryParams {
 // - Response types (what the API returns)
 // - Create types (required fields for creating new entities)
 // - Update types (all optional for partial updates)
+//
+// Endpoints accept either the response type or the typed request through the
+// `CreateRequest` and `UpdateRequest` traits.
+
+/// Request body accepted by the `create` method of the endpoint for `T`.
+///
+/// Implemented by `T` itself and by its typed create request, such as
+/// [`SupplierCreate`] for [`Supplier`].
+pub trait CreateRequest<T>: Serialize {}
+
+/// Request body accepted by the `update` method of the endpoint for `T`.
+///
+/// Implemented by `T` itself and by its typed update request, such as
+/// [`SupplierUpdate`] for [`Supplier`].
+pub trait UpdateRequest<T>: Serialize {}
+
+impl<T: Serialize> CreateRequest<T> for T {}
+impl<T: Serialize> UpdateRequest<T> for T {}
+
+macro_rules! impl_request_types {
+    ($($type:ty => $create:ty $(, $update:ty)?;)*) => {
+        $(
+            impl CreateRequest<$type> for $create {}
+            $(impl UpdateRequest<$type> for $update {})?
+        )*
+    };
+}
+
+impl_request_types! {
+    Customer => CustomerCreate, CustomerUpdate;
+    Article => ArticleCreate, ArticleUpdate;
+    Invoice => InvoiceCreate, InvoiceUpdate;
+    Supplier => SupplierCreate, SupplierUpdate;
+    SupplierInvoice => SupplierInvoiceCreate, SupplierInvoiceUpdate;
+    Voucher => VoucherCreate, VoucherUpdate;
+    Order => OrderCreate, OrderUpdate;
+    Quotation => QuotationCreate, QuotationUpdate;
+    Project => ProjectCreate, ProjectUpdate;
+    CostCenterItem => CostCenterItemCreate, CostCenterItemUpdate;
+    FiscalYear => FiscalYearCreate;
+    CustomerInvoiceDraft => CustomerInvoiceDraftCreate, CustomerInvoiceDraftUpdate;
+    SupplierInvoiceDraft => SupplierInvoiceDraftCreate, SupplierInvoiceDraftUpdate;
+}
 
 /// Data required to create a new customer.
 ///
@@ -1640,6 +2010,12 @@ impl CustomerCreate {
         self
     }
 
+    /// Set a checked organisation or personal number, normalized.
+    pub fn corporate_identity(mut self, value: impl Into<CorporateIdentity>) -> Self {
+        self.corporate_identity_number = Some(value.into().into());
+        self
+    }
+
     /// Set the email address.
     pub fn email(mut self, value: String) -> Self {
         self.email = Some(value);
@@ -1879,7 +2255,7 @@ pub struct ArticleCreate {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 }
 
 impl ArticleCreate {
@@ -1927,7 +2303,7 @@ impl ArticleCreate {
     }
 
     /// Set the VAT rate ID.
-    pub fn vat_rate_id(mut self, value: String) -> Self {
+    pub fn vat_rate_id(mut self, value: VatRateId) -> Self {
         self.vat_rate_id = Some(value);
         self
     }
@@ -1965,7 +2341,7 @@ pub struct ArticleUpdate {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 }
 
 impl ArticleUpdate {
@@ -2011,7 +2387,7 @@ impl ArticleUpdate {
     }
 
     /// Set the VAT rate ID.
-    pub fn vat_rate_id(mut self, value: String) -> Self {
+    pub fn vat_rate_id(mut self, value: VatRateId) -> Self {
         self.vat_rate_id = Some(value);
         self
     }
@@ -2023,7 +2399,7 @@ impl ArticleUpdate {
 pub struct InvoiceRowCreate {
     /// Article/product ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub article_id: Option<String>,
+    pub article_id: Option<ArticleId>,
 
     /// Description/text.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2043,7 +2419,7 @@ pub struct InvoiceRowCreate {
 
     /// VAT rate ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_rate_id: Option<String>,
+    pub vat_rate_id: Option<VatRateId>,
 }
 
 impl InvoiceRowCreate {
@@ -2053,7 +2429,7 @@ impl InvoiceRowCreate {
     }
 
     /// Create an invoice row with an article reference.
-    pub fn with_article(article_id: String, quantity: Money) -> Self {
+    pub fn with_article(article_id: ArticleId, quantity: Money) -> Self {
         Self {
             article_id: Some(article_id),
             quantity: Some(quantity),
@@ -2072,7 +2448,7 @@ impl InvoiceRowCreate {
     }
 
     /// Set the article ID.
-    pub fn article_id(mut self, value: String) -> Self {
+    pub fn article_id(mut self, value: ArticleId) -> Self {
         self.article_id = Some(value);
         self
     }
@@ -2102,7 +2478,7 @@ impl InvoiceRowCreate {
     }
 
     /// Set the VAT rate ID.
-    pub fn vat_rate_id(mut self, value: String) -> Self {
+    pub fn vat_rate_id(mut self, value: VatRateId) -> Self {
         self.vat_rate_id = Some(value);
         self
     }
@@ -2115,19 +2491,22 @@ impl InvoiceRowCreate {
 /// ```
 /// use spiris::{InvoiceCreate, InvoiceRowCreate, money};
 ///
-/// let invoice = InvoiceCreate::new("customer-id-123".to_string())
+/// # fn main() -> spiris::Result<()> {
+/// let invoice = InvoiceCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse()?)
 ///     .row(InvoiceRowCreate::with_text(
 ///         "Consulting".to_string(),
 ///         money!(1500.0),
 ///         money!(10.0),
 ///     ))
 ///     .remarks("Thank you for your business!".to_string());
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct InvoiceCreate {
     /// Customer ID (required).
-    pub customer_id: String,
+    pub customer_id: CustomerId,
 
     /// Invoice date.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2155,7 +2534,7 @@ pub struct InvoiceCreate {
 
 impl InvoiceCreate {
     /// Create a new invoice for a customer.
-    pub fn new(customer_id: String) -> Self {
+    pub fn new(customer_id: CustomerId) -> Self {
         Self {
             customer_id,
             invoice_date: None,
@@ -2218,7 +2597,7 @@ impl InvoiceCreate {
 pub struct InvoiceUpdate {
     /// Customer ID.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
 
     /// Invoice date.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2252,7 +2631,7 @@ impl InvoiceUpdate {
     }
 
     /// Set the customer ID.
-    pub fn customer_id(mut self, value: String) -> Self {
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
         self.customer_id = Some(value);
         self
     }
@@ -2293,3 +2672,1735 @@ impl InvoiceUpdate {
         self
     }
 }
+
+/// Data required to create a new supplier.
+///
+/// # Example
+///
+/// ```
+/// use spiris::SupplierCreate;
+///
+/// let supplier = SupplierCreate::new("Office Supplies AB".to_string())
+///     .corporate_identity_number("556677-8899".to_string())
+///     .bank_giro_number("123-4567".to_string());
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierCreate {
+    /// Supplier name (required).
+    pub name: String,
+
+    /// Supplier number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier_number: Option<String>,
+
+    /// Corporate identity number (organization number).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corporate_identity_number: Option<String>,
+
+    /// Email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// Mobile phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_phone: Option<String>,
+
+    /// Website URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+
+    /// Address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// Bank account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_number: Option<String>,
+
+    /// Bankgiro number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_giro_number: Option<String>,
+
+    /// Plusgiro number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plus_giro_number: Option<String>,
+
+    /// Whether the supplier is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl SupplierCreate {
+    /// Create a new supplier with the required name.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            supplier_number: None,
+            corporate_identity_number: None,
+            email: None,
+            phone: None,
+            mobile_phone: None,
+            website: None,
+            address: None,
+            bank_account_number: None,
+            bank_giro_number: None,
+            plus_giro_number: None,
+            is_active: None,
+        }
+    }
+
+    /// Set the supplier number.
+    pub fn supplier_number(mut self, value: String) -> Self {
+        self.supplier_number = Some(value);
+        self
+    }
+
+    /// Set the corporate identity number.
+    pub fn corporate_identity_number(mut self, value: String) -> Self {
+        self.corporate_identity_number = Some(value);
+        self
+    }
+
+    /// Set a checked organisation or personal number, normalized.
+    pub fn corporate_identity(mut self, value: impl Into<CorporateIdentity>) -> Self {
+        self.corporate_identity_number = Some(value.into().into());
+        self
+    }
+
+    /// Set the email address.
+    pub fn email(mut self, value: String) -> Self {
+        self.email = Some(value);
+        self
+    }
+
+    /// Set the phone number.
+    pub fn phone(mut self, value: String) -> Self {
+        self.phone = Some(value);
+        self
+    }
+
+    /// Set the mobile phone number.
+    pub fn mobile_phone(mut self, value: String) -> Self {
+        self.mobile_phone = Some(value);
+        self
+    }
+
+    /// Set the website URL.
+    pub fn website(mut self, value: String) -> Self {
+        self.website = Some(value);
+        self
+    }
+
+    /// Set the address.
+    pub fn address(mut self, value: Address) -> Self {
+        self.address = Some(value);
+        self
+    }
+
+    /// Set the bank account number.
+    pub fn bank_account_number(mut self, value: String) -> Self {
+        self.bank_account_number = Some(value);
+        self
+    }
+
+    /// Set the Bankgiro number.
+    pub fn bank_giro_number(mut self, value: String) -> Self {
+        self.bank_giro_number = Some(value);
+        self
+    }
+
+    /// Set the Plusgiro number.
+    pub fn plus_giro_number(mut self, value: String) -> Self {
+        self.plus_giro_number = Some(value);
+        self
+    }
+
+    /// Set a checked Swedish bank account number, normalized.
+    pub fn bank_account(mut self, value: BankAccountNumber) -> Self {
+        self.bank_account_number = Some(value.into());
+        self
+    }
+
+    /// Set a checked Bankgiro number, normalized.
+    pub fn bank_giro(mut self, value: Bankgiro) -> Self {
+        self.bank_giro_number = Some(value.into());
+        self
+    }
+
+    /// Set a checked Plusgiro number, normalized.
+    pub fn plus_giro(mut self, value: Plusgiro) -> Self {
+        self.plus_giro_number = Some(value.into());
+        self
+    }
+
+    /// Set the whether the supplier is active.
+    pub fn is_active(mut self, value: bool) -> Self {
+        self.is_active = Some(value);
+        self
+    }
+}
+
+/// Data for updating an existing supplier.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierUpdate {
+    /// Supplier name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Supplier number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier_number: Option<String>,
+
+    /// Corporate identity number (organization number).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corporate_identity_number: Option<String>,
+
+    /// Email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// Mobile phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_phone: Option<String>,
+
+    /// Website URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+
+    /// Address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// Bank account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_number: Option<String>,
+
+    /// Bankgiro number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_giro_number: Option<String>,
+
+    /// Plusgiro number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plus_giro_number: Option<String>,
+
+    /// Whether the supplier is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl SupplierUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the supplier name.
+    pub fn name(mut self, value: String) -> Self {
+        self.name = Some(value);
+        self
+    }
+
+    /// Set the supplier number.
+    pub fn supplier_number(mut self, value: String) -> Self {
+        self.supplier_number = Some(value);
+        self
+    }
+
+    /// Set the corporate identity number.
+    pub fn corporate_identity_number(mut self, value: String) -> Self {
+        self.corporate_identity_number = Some(value);
+        self
+    }
+
+    /// Set the email address.
+    pub fn email(mut self, value: String) -> Self {
+        self.email = Some(value);
+        self
+    }
+
+    /// Set the phone number.
+    pub fn phone(mut self, value: String) -> Self {
+        self.phone = Some(value);
+        self
+    }
+
+    /// Set the mobile phone number.
+    pub fn mobile_phone(mut self, value: String) -> Self {
+        self.mobile_phone = Some(value);
+        self
+    }
+
+    /// Set the website URL.
+    pub fn website(mut self, value: String) -> Self {
+        self.website = Some(value);
+        self
+    }
+
+    /// Set the address.
+    pub fn address(mut self, value: Address) -> Self {
+        self.address = Some(value);
+        self
+    }
+
+    /// Set the bank account number.
+    pub fn bank_account_number(mut self, value: String) -> Self {
+        self.bank_account_number = Some(value);
+        self
+    }
+
+    /// Set the Bankgiro number.
+    pub fn bank_giro_number(mut self, value: String) -> Self {
+        self.bank_giro_number = Some(value);
+        self
+    }
+
+    /// Set the Plusgiro number.
+    pub fn plus_giro_number(mut self, value: String) -> Self {
+        self.plus_giro_number = Some(value);
+        self
+    }
+
+    /// Set the whether the supplier is active.
+    pub fn is_active(mut self, value: bool) -> Self {
+        self.is_active = Some(value);
+        self
+    }
+}
+
+/// A row for creating a supplier invoice.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierInvoiceRowCreate {
+    /// Account number (required).
+    pub account_number: String,
+
+    /// Amount excluding VAT (required).
+    pub amount: Money,
+
+    /// Description/text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// VAT amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_amount: Option<Money>,
+
+    /// VAT rate ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_rate_id: Option<VatRateId>,
+
+    /// Cost center item ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_center_item_id: Option<CostCenterItemId>,
+
+    /// Project ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<ProjectId>,
+}
+
+impl SupplierInvoiceRowCreate {
+    /// Create a row booking `amount` on an account.
+    pub fn new(account_number: String, amount: impl Into<Money>) -> Self {
+        Self {
+            account_number,
+            amount: amount.into(),
+            text: None,
+            vat_amount: None,
+            vat_rate_id: None,
+            cost_center_item_id: None,
+            project_id: None,
+        }
+    }
+
+    /// Set the text description.
+    pub fn text(mut self, value: String) -> Self {
+        self.text = Some(value);
+        self
+    }
+
+    /// Set the VAT amount.
+    pub fn vat_amount(mut self, value: impl Into<Money>) -> Self {
+        self.vat_amount = Some(value.into());
+        self
+    }
+
+    /// Set the VAT rate ID.
+    pub fn vat_rate_id(mut self, value: VatRateId) -> Self {
+        self.vat_rate_id = Some(value);
+        self
+    }
+
+    /// Set the cost center item ID.
+    pub fn cost_center_item_id(mut self, value: CostCenterItemId) -> Self {
+        self.cost_center_item_id = Some(value);
+        self
+    }
+
+    /// Set the project ID.
+    pub fn project_id(mut self, value: ProjectId) -> Self {
+        self.project_id = Some(value);
+        self
+    }
+}
+
+/// Data required to create a new supplier invoice.
+///
+/// # Example
+///
+/// ```
+/// use spiris::{SupplierInvoiceCreate, SupplierInvoiceRowCreate, money};
+///
+/// # fn main() -> spiris::Result<()> {
+/// let invoice = SupplierInvoiceCreate::new(
+///     "5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse()?,
+///     chrono::Utc::now(),
+/// )
+/// .ocr_number("4711".to_string())
+/// .row(SupplierInvoiceRowCreate::new("5410".to_string(), money!(800.0)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierInvoiceCreate {
+    /// Supplier ID (required).
+    pub supplier_id: SupplierId,
+
+    /// Invoice date (required).
+    pub invoice_date: DateTime<Utc>,
+
+    /// Invoice number from the supplier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<String>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Currency exchange rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_rate: Option<Money>,
+
+    /// OCR payment reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_number: Option<String>,
+
+    /// Invoice rows.
+    pub rows: Vec<SupplierInvoiceRowCreate>,
+}
+
+impl SupplierInvoiceCreate {
+    /// Create a new supplier invoice for a supplier.
+    pub fn new(supplier_id: SupplierId, invoice_date: DateTime<Utc>) -> Self {
+        Self {
+            supplier_id,
+            invoice_date,
+            invoice_number: None,
+            due_date: None,
+            currency_code: None,
+            currency_rate: None,
+            ocr_number: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the invoice number.
+    pub fn invoice_number(mut self, value: String) -> Self {
+        self.invoice_number = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the currency exchange rate.
+    pub fn currency_rate(mut self, value: impl Into<Money>) -> Self {
+        self.currency_rate = Some(value.into());
+        self
+    }
+
+    /// Set the OCR payment reference.
+    pub fn ocr_number(mut self, value: String) -> Self {
+        self.ocr_number = Some(value);
+        self
+    }
+
+    /// Set a checked OCR payment reference.
+    pub fn ocr(mut self, value: Ocr) -> Self {
+        self.ocr_number = Some(value.into());
+        self
+    }
+
+    /// Add a row to the invoice.
+    pub fn row(mut self, row: SupplierInvoiceRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<SupplierInvoiceRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing supplier invoice.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierInvoiceUpdate {
+    /// Supplier ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier_id: Option<SupplierId>,
+
+    /// Invoice date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<DateTime<Utc>>,
+
+    /// Invoice number from the supplier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<String>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Currency exchange rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_rate: Option<Money>,
+
+    /// OCR payment reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_number: Option<String>,
+
+    /// Invoice rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<SupplierInvoiceRowCreate>>,
+}
+
+impl SupplierInvoiceUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the supplier ID.
+    pub fn supplier_id(mut self, value: SupplierId) -> Self {
+        self.supplier_id = Some(value);
+        self
+    }
+
+    /// Set the invoice date.
+    pub fn invoice_date(mut self, value: DateTime<Utc>) -> Self {
+        self.invoice_date = Some(value);
+        self
+    }
+
+    /// Set the invoice number.
+    pub fn invoice_number(mut self, value: String) -> Self {
+        self.invoice_number = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the currency exchange rate.
+    pub fn currency_rate(mut self, value: impl Into<Money>) -> Self {
+        self.currency_rate = Some(value.into());
+        self
+    }
+
+    /// Set the OCR payment reference.
+    pub fn ocr_number(mut self, value: String) -> Self {
+        self.ocr_number = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<SupplierInvoiceRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+/// A row for creating a voucher.
+///
+/// Each row either debits or credits an account.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoucherRowCreate {
+    /// Account number (required).
+    pub account_number: String,
+
+    /// Debit amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debit_amount: Option<Money>,
+
+    /// Credit amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_amount: Option<Money>,
+
+    /// Transaction text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_text: Option<String>,
+
+    /// Cost center item ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_center_item_id: Option<CostCenterItemId>,
+
+    /// Project ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<ProjectId>,
+}
+
+impl VoucherRowCreate {
+    /// Create a row debiting `amount` to an account.
+    pub fn debit(account_number: String, amount: impl Into<Money>) -> Self {
+        Self {
+            debit_amount: Some(amount.into()),
+            ..Self::empty(account_number)
+        }
+    }
+
+    /// Create a row crediting `amount` to an account.
+    pub fn credit(account_number: String, amount: impl Into<Money>) -> Self {
+        Self {
+            credit_amount: Some(amount.into()),
+            ..Self::empty(account_number)
+        }
+    }
+
+    fn empty(account_number: String) -> Self {
+        Self {
+            account_number,
+            debit_amount: None,
+            credit_amount: None,
+            transaction_text: None,
+            cost_center_item_id: None,
+            project_id: None,
+        }
+    }
+
+    /// Set the transaction text.
+    pub fn transaction_text(mut self, value: String) -> Self {
+        self.transaction_text = Some(value);
+        self
+    }
+
+    /// Set the cost center item ID.
+    pub fn cost_center_item_id(mut self, value: CostCenterItemId) -> Self {
+        self.cost_center_item_id = Some(value);
+        self
+    }
+
+    /// Set the project ID.
+    pub fn project_id(mut self, value: ProjectId) -> Self {
+        self.project_id = Some(value);
+        self
+    }
+}
+
+/// Data required to create a new voucher.
+///
+/// # Example
+///
+/// ```
+/// use spiris::{money, VoucherCreate, VoucherRowCreate};
+///
+/// let voucher = VoucherCreate::new(chrono::Utc::now(), "Office rent".to_string())
+///     .row(VoucherRowCreate::debit("5010".to_string(), money!(12000.0)))
+///     .row(VoucherRowCreate::credit("1930".to_string(), money!(12000.0)));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoucherCreate {
+    /// Voucher date (required).
+    pub voucher_date: DateTime<Utc>,
+
+    /// Voucher text (required).
+    pub voucher_text: String,
+
+    /// Voucher type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voucher_type: Option<VoucherType>,
+
+    /// Voucher rows.
+    pub rows: Vec<VoucherRowCreate>,
+}
+
+impl VoucherCreate {
+    /// Create a new voucher with a date and text.
+    pub fn new(voucher_date: DateTime<Utc>, voucher_text: String) -> Self {
+        Self {
+            voucher_date,
+            voucher_text,
+            voucher_type: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the voucher type.
+    pub fn voucher_type(mut self, value: VoucherType) -> Self {
+        self.voucher_type = Some(value);
+        self
+    }
+
+    /// Add a row to the voucher.
+    pub fn row(mut self, row: VoucherRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<VoucherRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing voucher.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoucherUpdate {
+    /// Voucher date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voucher_date: Option<DateTime<Utc>>,
+
+    /// Voucher text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voucher_text: Option<String>,
+
+    /// Voucher type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voucher_type: Option<VoucherType>,
+
+    /// Voucher rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<VoucherRowCreate>>,
+}
+
+impl VoucherUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the voucher date.
+    pub fn voucher_date(mut self, value: DateTime<Utc>) -> Self {
+        self.voucher_date = Some(value);
+        self
+    }
+
+    /// Set the voucher text.
+    pub fn voucher_text(mut self, value: String) -> Self {
+        self.voucher_text = Some(value);
+        self
+    }
+
+    /// Set the voucher type.
+    pub fn voucher_type(mut self, value: VoucherType) -> Self {
+        self.voucher_type = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<VoucherRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+/// Data required to create a new order.
+///
+/// Rows use [`InvoiceRowCreate`], which has the same fields as an order row.
+///
+/// # Example
+///
+/// ```
+/// use spiris::{money, InvoiceRowCreate, OrderCreate};
+///
+/// # fn main() -> spiris::Result<()> {
+/// let order = OrderCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse()?, chrono::Utc::now())
+///     .row(InvoiceRowCreate::with_text("Desk".to_string(), money!(4500.0), money!(2.0)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OrderCreate {
+    /// Customer ID (required).
+    pub customer_id: CustomerId,
+
+    /// Order date (required).
+    pub order_date: DateTime<Utc>,
+
+    /// Delivery date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Customer's reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub your_reference: Option<String>,
+
+    /// Our reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub our_reference: Option<String>,
+
+    /// Order rows.
+    pub rows: Vec<InvoiceRowCreate>,
+}
+
+impl OrderCreate {
+    /// Create a new order for a customer.
+    pub fn new(customer_id: CustomerId, order_date: DateTime<Utc>) -> Self {
+        Self {
+            customer_id,
+            order_date,
+            delivery_date: None,
+            currency_code: None,
+            your_reference: None,
+            our_reference: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the delivery date.
+    pub fn delivery_date(mut self, value: DateTime<Utc>) -> Self {
+        self.delivery_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the customer's reference.
+    pub fn your_reference(mut self, value: String) -> Self {
+        self.your_reference = Some(value);
+        self
+    }
+
+    /// Set the our reference.
+    pub fn our_reference(mut self, value: String) -> Self {
+        self.our_reference = Some(value);
+        self
+    }
+
+    /// Add a row to the order.
+    pub fn row(mut self, row: InvoiceRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing order.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OrderUpdate {
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Order date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_date: Option<DateTime<Utc>>,
+
+    /// Delivery date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Customer's reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub your_reference: Option<String>,
+
+    /// Our reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub our_reference: Option<String>,
+
+    /// Order rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<InvoiceRowCreate>>,
+}
+
+impl OrderUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the customer ID.
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
+        self.customer_id = Some(value);
+        self
+    }
+
+    /// Set the order date.
+    pub fn order_date(mut self, value: DateTime<Utc>) -> Self {
+        self.order_date = Some(value);
+        self
+    }
+
+    /// Set the delivery date.
+    pub fn delivery_date(mut self, value: DateTime<Utc>) -> Self {
+        self.delivery_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the customer's reference.
+    pub fn your_reference(mut self, value: String) -> Self {
+        self.your_reference = Some(value);
+        self
+    }
+
+    /// Set the our reference.
+    pub fn our_reference(mut self, value: String) -> Self {
+        self.our_reference = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+/// Data required to create a new quotation.
+///
+/// Rows use [`InvoiceRowCreate`], which has the same fields as a quotation row.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotationCreate {
+    /// Customer ID (required).
+    pub customer_id: CustomerId,
+
+    /// Quotation date (required).
+    pub quotation_date: DateTime<Utc>,
+
+    /// Date the quotation is valid until.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Quotation rows.
+    pub rows: Vec<InvoiceRowCreate>,
+}
+
+impl QuotationCreate {
+    /// Create a new quotation for a customer.
+    pub fn new(customer_id: CustomerId, quotation_date: DateTime<Utc>) -> Self {
+        Self {
+            customer_id,
+            quotation_date,
+            valid_until_date: None,
+            currency_code: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the date the quotation is valid until.
+    pub fn valid_until_date(mut self, value: DateTime<Utc>) -> Self {
+        self.valid_until_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Add a row to the quotation.
+    pub fn row(mut self, row: InvoiceRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing quotation.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotationUpdate {
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Quotation date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quotation_date: Option<DateTime<Utc>>,
+
+    /// Date the quotation is valid until.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Quotation rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<InvoiceRowCreate>>,
+}
+
+impl QuotationUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the customer ID.
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
+        self.customer_id = Some(value);
+        self
+    }
+
+    /// Set the quotation date.
+    pub fn quotation_date(mut self, value: DateTime<Utc>) -> Self {
+        self.quotation_date = Some(value);
+        self
+    }
+
+    /// Set the date the quotation is valid until.
+    pub fn valid_until_date(mut self, value: DateTime<Utc>) -> Self {
+        self.valid_until_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+/// Data required to create a new project.
+///
+/// # Example
+///
+/// ```
+/// use spiris::ProjectCreate;
+///
+/// let project = ProjectCreate::new("P-100".to_string(), "Website".to_string(), chrono::Utc::now())
+///     .notes("Phase one".to_string());
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProjectCreate {
+    /// Project number (required).
+    pub project_number: String,
+
+    /// Project name (required).
+    pub name: String,
+
+    /// Start date (required).
+    pub start_date: DateTime<Utc>,
+
+    /// End date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Whether the project is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_completed: Option<bool>,
+
+    /// Notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl ProjectCreate {
+    /// Create a new project with a number, name and start date.
+    pub fn new(project_number: String, name: String, start_date: DateTime<Utc>) -> Self {
+        Self {
+            project_number,
+            name,
+            start_date,
+            end_date: None,
+            customer_id: None,
+            is_completed: None,
+            notes: None,
+        }
+    }
+
+    /// Set the end date.
+    pub fn end_date(mut self, value: DateTime<Utc>) -> Self {
+        self.end_date = Some(value);
+        self
+    }
+
+    /// Set the customer ID.
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
+        self.customer_id = Some(value);
+        self
+    }
+
+    /// Set the whether the project is completed.
+    pub fn is_completed(mut self, value: bool) -> Self {
+        self.is_completed = Some(value);
+        self
+    }
+
+    /// Set the notes.
+    pub fn notes(mut self, value: String) -> Self {
+        self.notes = Some(value);
+        self
+    }
+}
+
+/// Data for updating an existing project.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProjectUpdate {
+    /// Project number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_number: Option<String>,
+
+    /// Project name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Start date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+
+    /// End date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Whether the project is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_completed: Option<bool>,
+
+    /// Notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl ProjectUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the project number.
+    pub fn project_number(mut self, value: String) -> Self {
+        self.project_number = Some(value);
+        self
+    }
+
+    /// Set the project name.
+    pub fn name(mut self, value: String) -> Self {
+        self.name = Some(value);
+        self
+    }
+
+    /// Set the start date.
+    pub fn start_date(mut self, value: DateTime<Utc>) -> Self {
+        self.start_date = Some(value);
+        self
+    }
+
+    /// Set the end date.
+    pub fn end_date(mut self, value: DateTime<Utc>) -> Self {
+        self.end_date = Some(value);
+        self
+    }
+
+    /// Set the customer ID.
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
+        self.customer_id = Some(value);
+        self
+    }
+
+    /// Set the whether the project is completed.
+    pub fn is_completed(mut self, value: bool) -> Self {
+        self.is_completed = Some(value);
+        self
+    }
+
+    /// Set the notes.
+    pub fn notes(mut self, value: String) -> Self {
+        self.notes = Some(value);
+        self
+    }
+}
+
+/// Data required to create a new cost center item.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CostCenterItemCreate {
+    /// Cost center ID (required).
+    pub cost_center_id: CostCenterId,
+
+    /// Item name (required).
+    pub name: String,
+
+    /// Short name (required).
+    pub short_name: String,
+
+    /// Whether the item is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl CostCenterItemCreate {
+    /// Create a new item in a cost center.
+    pub fn new(cost_center_id: CostCenterId, name: String, short_name: String) -> Self {
+        Self {
+            cost_center_id,
+            name,
+            short_name,
+            is_active: None,
+        }
+    }
+
+    /// Set the whether the item is active.
+    pub fn is_active(mut self, value: bool) -> Self {
+        self.is_active = Some(value);
+        self
+    }
+}
+
+/// Data for updating an existing cost center item.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CostCenterItemUpdate {
+    /// Cost center ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_center_id: Option<CostCenterId>,
+
+    /// Item name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Short name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+
+    /// Whether the item is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl CostCenterItemUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cost center ID.
+    pub fn cost_center_id(mut self, value: CostCenterId) -> Self {
+        self.cost_center_id = Some(value);
+        self
+    }
+
+    /// Set the item name.
+    pub fn name(mut self, value: String) -> Self {
+        self.name = Some(value);
+        self
+    }
+
+    /// Set the short name.
+    pub fn short_name(mut self, value: String) -> Self {
+        self.short_name = Some(value);
+        self
+    }
+
+    /// Set the whether the item is active.
+    pub fn is_active(mut self, value: bool) -> Self {
+        self.is_active = Some(value);
+        self
+    }
+}
+
+/// Data required to create a new fiscal year.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FiscalYearCreate {
+    /// Start date (required).
+    pub start_date: DateTime<Utc>,
+
+    /// End date (required).
+    pub end_date: DateTime<Utc>,
+
+    /// Bookkeeping method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bookkeeping_method: Option<BookkeepingMethod>,
+}
+
+impl FiscalYearCreate {
+    /// Create a new fiscal year covering the given dates.
+    pub fn new(start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Self {
+        Self {
+            start_date,
+            end_date,
+            bookkeeping_method: None,
+        }
+    }
+
+    /// Set the bookkeeping method.
+    pub fn bookkeeping_method(mut self, value: BookkeepingMethod) -> Self {
+        self.bookkeeping_method = Some(value);
+        self
+    }
+}
+
+/// Data required to create a new customer invoice draft.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CustomerInvoiceDraftCreate {
+    /// Customer ID (required).
+    pub customer_id: CustomerId,
+
+    /// Invoice date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<DateTime<Utc>>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Delivery date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Remarks/notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+
+    /// Customer's reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub your_reference: Option<String>,
+
+    /// Our reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub our_reference: Option<String>,
+
+    /// Draft rows.
+    pub rows: Vec<InvoiceRowCreate>,
+}
+
+impl CustomerInvoiceDraftCreate {
+    /// Create a new invoice draft for a customer.
+    pub fn new(customer_id: CustomerId) -> Self {
+        Self {
+            customer_id,
+            invoice_date: None,
+            due_date: None,
+            delivery_date: None,
+            currency_code: None,
+            remarks: None,
+            your_reference: None,
+            our_reference: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the invoice date.
+    pub fn invoice_date(mut self, value: DateTime<Utc>) -> Self {
+        self.invoice_date = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the delivery date.
+    pub fn delivery_date(mut self, value: DateTime<Utc>) -> Self {
+        self.delivery_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the remarks/notes.
+    pub fn remarks(mut self, value: String) -> Self {
+        self.remarks = Some(value);
+        self
+    }
+
+    /// Set the customer's reference.
+    pub fn your_reference(mut self, value: String) -> Self {
+        self.your_reference = Some(value);
+        self
+    }
+
+    /// Set the our reference.
+    pub fn our_reference(mut self, value: String) -> Self {
+        self.our_reference = Some(value);
+        self
+    }
+
+    /// Add a row to the draft.
+    pub fn row(mut self, row: InvoiceRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing customer invoice draft.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CustomerInvoiceDraftUpdate {
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Invoice date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<DateTime<Utc>>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Delivery date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Remarks/notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+
+    /// Customer's reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub your_reference: Option<String>,
+
+    /// Our reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub our_reference: Option<String>,
+
+    /// Draft rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<InvoiceRowCreate>>,
+}
+
+impl CustomerInvoiceDraftUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the customer ID.
+    pub fn customer_id(mut self, value: CustomerId) -> Self {
+        self.customer_id = Some(value);
+        self
+    }
+
+    /// Set the invoice date.
+    pub fn invoice_date(mut self, value: DateTime<Utc>) -> Self {
+        self.invoice_date = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the delivery date.
+    pub fn delivery_date(mut self, value: DateTime<Utc>) -> Self {
+        self.delivery_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the remarks/notes.
+    pub fn remarks(mut self, value: String) -> Self {
+        self.remarks = Some(value);
+        self
+    }
+
+    /// Set the customer's reference.
+    pub fn your_reference(mut self, value: String) -> Self {
+        self.your_reference = Some(value);
+        self
+    }
+
+    /// Set the our reference.
+    pub fn our_reference(mut self, value: String) -> Self {
+        self.our_reference = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<InvoiceRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+/// Data required to create a new supplier invoice draft.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierInvoiceDraftCreate {
+    /// Supplier ID (required).
+    pub supplier_id: SupplierId,
+
+    /// Invoice number from the supplier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<String>,
+
+    /// Invoice date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<DateTime<Utc>>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Draft rows.
+    pub rows: Vec<SupplierInvoiceRowCreate>,
+}
+
+impl SupplierInvoiceDraftCreate {
+    /// Create a new invoice draft for a supplier.
+    pub fn new(supplier_id: SupplierId) -> Self {
+        Self {
+            supplier_id,
+            invoice_number: None,
+            invoice_date: None,
+            due_date: None,
+            currency_code: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the invoice number.
+    pub fn invoice_number(mut self, value: String) -> Self {
+        self.invoice_number = Some(value);
+        self
+    }
+
+    /// Set the invoice date.
+    pub fn invoice_date(mut self, value: DateTime<Utc>) -> Self {
+        self.invoice_date = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Add a row to the draft.
+    pub fn row(mut self, row: SupplierInvoiceRowCreate) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set all rows at once.
+    pub fn rows(mut self, rows: Vec<SupplierInvoiceRowCreate>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+/// Data for updating an existing supplier invoice draft.
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupplierInvoiceDraftUpdate {
+    /// Supplier ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier_id: Option<SupplierId>,
+
+    /// Invoice number from the supplier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<String>,
+
+    /// Invoice date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<DateTime<Utc>>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Currency code (ISO 4217).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+
+    /// Draft rows (replaces existing rows if set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<Vec<SupplierInvoiceRowCreate>>,
+}
+
+impl SupplierInvoiceDraftUpdate {
+    /// Create an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the supplier ID.
+    pub fn supplier_id(mut self, value: SupplierId) -> Self {
+        self.supplier_id = Some(value);
+        self
+    }
+
+    /// Set the invoice number.
+    pub fn invoice_number(mut self, value: String) -> Self {
+        self.invoice_number = Some(value);
+        self
+    }
+
+    /// Set the invoice date.
+    pub fn invoice_date(mut self, value: DateTime<Utc>) -> Self {
+        self.invoice_date = Some(value);
+        self
+    }
+
+    /// Set the due date.
+    pub fn due_date(mut self, value: DateTime<Utc>) -> Self {
+        self.due_date = Some(value);
+        self
+    }
+
+    /// Set the currency code.
+    pub fn currency_code(mut self, value: String) -> Self {
+        self.currency_code = Some(value);
+        self
+    }
+
+    /// Set the rows (replaces all existing rows).
+    pub fn rows(mut self, rows: Vec<SupplierInvoiceRowCreate>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
@@ -0,0 +1,2064 @@
+//! Client-side validation of requests and Swedish identifiers.
+//!
+//! The API stores identifiers such as organisation numbers and bankgiro
+//! numbers as free text and accepts almost anything. The types here parse
+//! the usual written forms, verify the check digits and format the value
+//! the way Swedish documents print it:
+//!
+//! - [`Organisationsnummer`] and [`Personnummer`], combined in
+//!   [`CorporateIdentity`] for `CorporateIdentityNumber` fields.
+//! - [`Ocr`] payment references (Luhn with length digit), with
+//!   [`Ocr::from_invoice_number`] to generate one.
+//! - [`Bankgiro`], [`Plusgiro`] and [`BankAccountNumber`] (clearing number
+//!   and account, checked per bank).
+//! - [`Iban`] and [`Bic`] for foreign payments.
+//!
+//! Each type implements [`FromStr`] and [`Display`](fmt::Display), and the
+//! typed setters on [`CustomerCreate`](crate::CustomerCreate),
+//! [`SupplierCreate`](crate::SupplierCreate) and
+//! [`SupplierInvoiceCreate`](crate::SupplierInvoiceCreate) accept them
+//! directly.
+//!
+//! # Example
+//!
+//! ```
+//! use spiris::validate::{Bankgiro, CorporateIdentity, Ocr};
+//! use spiris::SupplierCreate;
+//!
+//! let org: CorporateIdentity = "5560125790".parse()?;
+//! assert_eq!(org.to_string(), "556012-5790");
+//!
+//! let supplier = SupplierCreate::new("Office Supplies AB".to_string())
+//!     .corporate_identity(org)
+//!     .bank_giro("50501055".parse::<Bankgiro>()?);
+//! assert_eq!(supplier.bank_giro_number.as_deref(), Some("5050-1055"));
+//!
+//! let ocr = Ocr::from_invoice_number("12345")?;
+//! assert_eq!(ocr.as_str(), "1234574");
+//! # Ok::<(), spiris::validate::IdentifierError>(())
+//! ```
+//!
+//! The [`Validate`] trait checks whole create and update requests before
+//! they are sent, and reports [`ValidationError`]s with the same field paths
+//! and shape as the API's, so one display path serves both:
+//!
+//! ```
+//! use spiris::validate::Validate;
+//! use spiris::{Address, CustomerCreate};
+//!
+//! let customer = CustomerCreate::new("Acme AB".to_string())
+//!     .email("info@acme".to_string())
+//!     .invoice_address(Address {
+//!         postal_code: Some("123 45".to_string()),
+//!         city: Some("Stockholm".to_string()),
+//!         ..Default::default()
+//!     });
+//!
+//! let errors = customer.validate().unwrap_err();
+//! assert_eq!(errors[0].field, "Email");
+//! assert_eq!(errors[0].message, "Invalid email address");
+//! ```
+
+use crate::amount::CurrencyCode;
+use crate::error::{Error, ValidationError};
+use crate::ids::VatRateId;
+use crate::types::{
+    Address, ArticleCreate, ArticleUpdate, CostCenterItemCreate, CostCenterItemUpdate,
+    CustomerCreate, CustomerInvoiceDraftCreate, CustomerInvoiceDraftUpdate, CustomerUpdate,
+    FiscalYearCreate, InvoiceCreate, InvoiceRowCreate, InvoiceUpdate, Money, OrderCreate,
+    OrderUpdate, ProjectCreate, ProjectUpdate, QuotationCreate, QuotationUpdate, SupplierCreate,
+    SupplierInvoiceCreate, SupplierInvoiceDraftCreate, SupplierInvoiceDraftUpdate,
+    SupplierInvoiceRowCreate, SupplierInvoiceUpdate, SupplierUpdate, VatCode, VoucherCreate,
+    VoucherRowCreate, VoucherUpdate,
+};
+use crate::voucher::check_rows;
+use chrono::{DateTime, Datelike, Local, NaiveDate, Utc};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The kind of identifier an [`IdentifierError`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierKind {
+    /// Swedish organisation number.
+    Organisationsnummer,
+    /// Swedish personal or coordination number.
+    Personnummer,
+    /// Organisation or personal number.
+    CorporateIdentity,
+    /// OCR payment reference.
+    Ocr,
+    /// Bankgiro number.
+    Bankgiro,
+    /// Plusgiro number.
+    Plusgiro,
+    /// Swedish bank account number with clearing number.
+    BankAccount,
+    /// International bank account number.
+    Iban,
+    /// Bank identifier code.
+    Bic,
+}
+
+impl fmt::Display for IdentifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IdentifierKind::Organisationsnummer => "organisationsnummer",
+            IdentifierKind::Personnummer => "personnummer",
+            IdentifierKind::CorporateIdentity => "corporate identity number",
+            IdentifierKind::Ocr => "OCR reference",
+            IdentifierKind::Bankgiro => "bankgiro number",
+            IdentifierKind::Plusgiro => "plusgiro number",
+            IdentifierKind::BankAccount => "bank account number",
+            IdentifierKind::Iban => "IBAN",
+            IdentifierKind::Bic => "BIC",
+        })
+    }
+}
+
+/// Errors from parsing an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum IdentifierError {
+    /// The value has the wrong characters or length.
+    #[error("invalid {0} format")]
+    Format(IdentifierKind),
+
+    /// The check digit does not match.
+    #[error("invalid {0} check digit")]
+    Checksum(IdentifierKind),
+
+    /// The date part of a personal number is not a real date.
+    #[error("invalid {0} date")]
+    Date(IdentifierKind),
+
+    /// The OCR length digit does not match the number of digits.
+    #[error("invalid {0} length digit")]
+    LengthDigit(IdentifierKind),
+
+    /// The clearing number belongs to no known bank.
+    #[error("unknown clearing number in {0}")]
+    UnknownClearing(IdentifierKind),
+}
+
+impl IdentifierError {
+    /// The kind of identifier that failed to parse.
+    pub fn kind(&self) -> IdentifierKind {
+        match *self {
+            IdentifierError::Format(kind)
+            | IdentifierError::Checksum(kind)
+            | IdentifierError::Date(kind)
+            | IdentifierError::LengthDigit(kind)
+            | IdentifierError::UnknownClearing(kind) => kind,
+        }
+    }
+}
+
+impl From<IdentifierError> for Error {
+    fn from(err: IdentifierError) -> Self {
+        Error::InvalidRequest(err.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, IdentifierError>;
+
+/// Implements `FromStr`, `Display`, `as_str` and `From<T> for String` for a
+/// type storing its normalized form in a `String` field.
+macro_rules! impl_identifier {
+    ($name:ident, $field:tt) => {
+        impl $name {
+            /// The normalized form.
+            pub fn as_str(&self) -> &str {
+                &self.$field
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = IdentifierError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                $name::parse(s)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.$field)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.$field
+            }
+        }
+    };
+}
+
+/// Digits of `s`, ignoring spaces and the given separators. `None` if any
+/// other character occurs.
+fn digits(s: &str, separators: &[char]) -> Option<Vec<u8>> {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && !separators.contains(c))
+        .map(|c| c.to_digit(10).map(|d| d as u8))
+        .collect()
+}
+
+fn to_string(digits: &[u8]) -> String {
+    digits.iter().map(|d| char::from(b'0' + d)).collect()
+}
+
+/// Luhn sum, doubling the rightmost digit if `double_rightmost` is set.
+fn luhn_sum(digits: &[u8], double_rightmost: bool) -> u32 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            let d = u32::from(d);
+            if (i % 2 == 0) == double_rightmost {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum()
+}
+
+/// Whether the last digit is a valid Luhn (mod 10) check digit.
+fn luhn_valid(digits: &[u8]) -> bool {
+    luhn_sum(digits, false) % 10 == 0
+}
+
+/// The Luhn check digit to append to `payload`.
+fn luhn_check_digit(payload: &[u8]) -> u8 {
+    ((10 - luhn_sum(payload, true) % 10) % 10) as u8
+}
+
+/// Whether the last digit is a valid mod 11 check digit, with weights
+/// 1, 2, ..., 10 from the right.
+fn mod11_valid(digits: &[u8]) -> bool {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| u32::from(d) * (i as u32 % 10 + 1))
+        .sum::<u32>()
+        % 11
+        == 0
+}
+
+/// A Swedish organisation number, formatted `NNNNNN-NNNN`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Organisationsnummer(String);
+
+impl Organisationsnummer {
+    /// Parse `NNNNNN-NNNN` or `NNNNNNNNNN`, optionally with the `16` century
+    /// prefix used in some registers.
+    pub fn parse(s: &str) -> Result<Self> {
+        const KIND: IdentifierKind = IdentifierKind::Organisationsnummer;
+        let mut d = digits(s, &['-']).ok_or(IdentifierError::Format(KIND))?;
+        if d.len() == 12 && d[..2] == [1, 6] {
+            d.drain(..2);
+        }
+        // The third digit is at least 2, which tells organisation numbers
+        // apart from personal numbers (month 01-12).
+        if d.len() != 10 || d[2] < 2 {
+            return Err(IdentifierError::Format(KIND));
+        }
+        if !luhn_valid(&d) {
+            return Err(IdentifierError::Checksum(KIND));
+        }
+        Ok(Organisationsnummer(format!(
+            "{}-{}",
+            to_string(&d[..6]),
+            to_string(&d[6..])
+        )))
+    }
+}
+
+impl_identifier!(Organisationsnummer, 0);
+
+/// A Swedish personal number or coordination number (samordningsnummer),
+/// formatted `YYYYMMDD-NNNN`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Personnummer(String);
+
+impl Personnummer {
+    /// Parse `YYYYMMDD-NNNN`, `YYMMDD-NNNN` or the forms without separator.
+    ///
+    /// A two-digit year is placed in the last hundred years, or the hundred
+    /// before that with the `+` separator used for people aged 100 or more.
+    pub fn parse(s: &str) -> Result<Self> {
+        Self::parse_at(s, Local::now().date_naive())
+    }
+
+    fn parse_at(s: &str, today: NaiveDate) -> Result<Self> {
+        const KIND: IdentifierKind = IdentifierKind::Personnummer;
+        let s = s.trim();
+        let centenarian = s.contains('+');
+        let d = digits(s, &['-', '+']).ok_or(IdentifierError::Format(KIND))?;
+        if s.matches(['-', '+']).count() > 1 {
+            return Err(IdentifierError::Format(KIND));
+        }
+
+        let (year, rest) = match d.len() {
+            12 => (
+                d[..4].iter().fold(0, |acc, &x| acc * 10 + i32::from(x)),
+                &d[4..],
+            ),
+            10 => {
+                let yy = i32::from(d[0]) * 10 + i32::from(d[1]);
+                let mut year = today.year() - (today.year() - yy).rem_euclid(100);
+                if centenarian {
+                    year -= 100;
+                }
+                (year, &d[2..])
+            }
+            _ => return Err(IdentifierError::Format(KIND)),
+        };
+
+        let month = u32::from(rest[0]) * 10 + u32::from(rest[1]);
+        let mut day = u32::from(rest[2]) * 10 + u32::from(rest[3]);
+        // Coordination numbers add 60 to the day.
+        if day > 60 {
+            day -= 60;
+        }
+        if NaiveDate::from_ymd_opt(year, month, day).is_none() {
+            return Err(IdentifierError::Date(KIND));
+        }
+
+        let checked = &d[d.len() - 10..];
+        if !luhn_valid(checked) {
+            return Err(IdentifierError::Checksum(KIND));
+        }
+        Ok(Personnummer(format!(
+            "{:04}{}-{}",
+            year,
+            to_string(&rest[..4]),
+            to_string(&rest[4..])
+        )))
+    }
+
+    /// Whether this is a coordination number (day of birth plus 60).
+    pub fn is_coordination_number(&self) -> bool {
+        self.0.as_bytes()[6] >= b'6'
+    }
+}
+
+impl_identifier!(Personnummer, 0);
+
+/// The corporate identity number of a customer or supplier: an
+/// organisation number, or the personal number of a sole trader or private
+/// person.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CorporateIdentity {
+    /// A legal entity.
+    Organisation(Organisationsnummer),
+    /// A natural person.
+    Person(Personnummer),
+}
+
+impl CorporateIdentity {
+    /// Parse an organisation number or a personal number.
+    pub fn parse(s: &str) -> Result<Self> {
+        match Organisationsnummer::parse(s) {
+            Ok(org) => return Ok(CorporateIdentity::Organisation(org)),
+            // Shaped like an organisation number, but with a bad check digit.
+            Err(err @ IdentifierError::Checksum(_)) => return Err(err),
+            Err(_) => {}
+        }
+        match Personnummer::parse(s) {
+            Ok(person) => Ok(CorporateIdentity::Person(person)),
+            Err(IdentifierError::Format(_)) => {
+                Err(IdentifierError::Format(IdentifierKind::CorporateIdentity))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// The normalized form.
+    pub fn as_str(&self) -> &str {
+        match self {
+            CorporateIdentity::Organisation(org) => org.as_str(),
+            CorporateIdentity::Person(person) => person.as_str(),
+        }
+    }
+}
+
+impl From<Organisationsnummer> for CorporateIdentity {
+    fn from(value: Organisationsnummer) -> Self {
+        CorporateIdentity::Organisation(value)
+    }
+}
+
+impl From<Personnummer> for CorporateIdentity {
+    fn from(value: Personnummer) -> Self {
+        CorporateIdentity::Person(value)
+    }
+}
+
+impl FromStr for CorporateIdentity {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        CorporateIdentity::parse(s)
+    }
+}
+
+impl fmt::Display for CorporateIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<CorporateIdentity> for String {
+    fn from(value: CorporateIdentity) -> Self {
+        match value {
+            CorporateIdentity::Organisation(org) => org.into(),
+            CorporateIdentity::Person(person) => person.into(),
+        }
+    }
+}
+
+/// An OCR payment reference: 2 to 25 digits ending in a length digit and a
+/// Luhn check digit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ocr(String);
+
+impl Ocr {
+    /// Longest reference Bankgirot accepts.
+    pub const MAX_LEN: usize = 25;
+
+    /// Parse a reference with length digit and check digit. Spaces are
+    /// ignored.
+    pub fn parse(s: &str) -> Result<Self> {
+        let d = Self::parse_digits(s)?;
+        let length_digit = d[d.len() - 2];
+        if usize::from(length_digit) != d.len() % 10 {
+            return Err(IdentifierError::LengthDigit(IdentifierKind::Ocr));
+        }
+        Ok(Ocr(to_string(&d)))
+    }
+
+    /// Parse a reference that only carries the Luhn check digit, as used by
+    /// payees whose bankgiro agreement has no length check.
+    pub fn parse_without_length_digit(s: &str) -> Result<Self> {
+        Self::parse_digits(s).map(|d| Ocr(to_string(&d)))
+    }
+
+    fn parse_digits(s: &str) -> Result<Vec<u8>> {
+        let d = digits(s, &[]).ok_or(IdentifierError::Format(IdentifierKind::Ocr))?;
+        if !(2..=Self::MAX_LEN).contains(&d.len()) {
+            return Err(IdentifierError::Format(IdentifierKind::Ocr));
+        }
+        if !luhn_valid(&d) {
+            return Err(IdentifierError::Checksum(IdentifierKind::Ocr));
+        }
+        Ok(d)
+    }
+
+    /// Generate a reference from an invoice number by appending the length
+    /// digit and the check digit. The invoice number must be all digits and
+    /// at most 23 long.
+    pub fn from_invoice_number(invoice_number: &str) -> Result<Self> {
+        let mut d = digits(invoice_number, &[])
+            .filter(|d| !d.is_empty() && d.len() + 2 <= Self::MAX_LEN)
+            .ok_or(IdentifierError::Format(IdentifierKind::Ocr))?;
+        d.push(((d.len() + 2) % 10) as u8);
+        d.push(luhn_check_digit(&d));
+        Ok(Ocr(to_string(&d)))
+    }
+}
+
+impl_identifier!(Ocr, 0);
+
+/// A bankgiro number, formatted `NNN-NNNN` or `NNNN-NNNN`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bankgiro(String);
+
+impl Bankgiro {
+    /// Parse a 7 or 8 digit bankgiro number, with or without the dash.
+    pub fn parse(s: &str) -> Result<Self> {
+        let d = digits(s, &['-']).ok_or(IdentifierError::Format(IdentifierKind::Bankgiro))?;
+        if !(7..=8).contains(&d.len()) {
+            return Err(IdentifierError::Format(IdentifierKind::Bankgiro));
+        }
+        if !luhn_valid(&d) {
+            return Err(IdentifierError::Checksum(IdentifierKind::Bankgiro));
+        }
+        let split = d.len() - 4;
+        Ok(Bankgiro(format!(
+            "{}-{}",
+            to_string(&d[..split]),
+            to_string(&d[split..])
+        )))
+    }
+}
+
+impl_identifier!(Bankgiro, 0);
+
+/// A plusgiro number, formatted with a dash before the check digit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Plusgiro(String);
+
+impl Plusgiro {
+    /// Parse a plusgiro number of 2 to 8 digits. Spaces and dashes are
+    /// ignored.
+    pub fn parse(s: &str) -> Result<Self> {
+        let d = digits(s, &['-']).ok_or(IdentifierError::Format(IdentifierKind::Plusgiro))?;
+        if !(2..=8).contains(&d.len()) {
+            return Err(IdentifierError::Format(IdentifierKind::Plusgiro));
+        }
+        if !luhn_valid(&d) {
+            return Err(IdentifierError::Checksum(IdentifierKind::Plusgiro));
+        }
+        let split = d.len() - 1;
+        Ok(Plusgiro(format!(
+            "{}-{}",
+            to_string(&d[..split]),
+            to_string(&d[split..])
+        )))
+    }
+}
+
+impl_identifier!(Plusgiro, 0);
+
+/// How a bank checks its account numbers, per Bankgirot's
+/// "Bankernas kontonummeruppbyggnad".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AccountRule {
+    /// 7 digit account, mod 11 over the last 3 clearing digits and the
+    /// account.
+    Type1Comment1,
+    /// 7 digit account, mod 11 over the whole clearing number and the
+    /// account.
+    Type1Comment2,
+    /// 10 digit account, mod 10 over the account.
+    Type2Comment1,
+    /// 9 digit account, mod 11 over the account (Handelsbanken).
+    Type2Comment2,
+    /// Up to 10 digit account, mod 10 over the account (Swedbank savings
+    /// banks and Nordea Plusgirot).
+    Type2Comment3,
+}
+
+const CLEARING_RANGES: &[(u16, u16, AccountRule)] = {
+    use AccountRule::*;
+    &[
+        (1100, 1199, Type1Comment1),
+        (1200, 1399, Type1Comment1),
+        (1400, 2099, Type1Comment1),
+        (2300, 2399, Type1Comment2),
+        (2400, 2499, Type1Comment1),
+        (3000, 3299, Type1Comment1),
+        (3300, 3300, Type2Comment1),
+        (3301, 3399, Type1Comment1),
+        (3400, 3409, Type1Comment1),
+        (3410, 3781, Type1Comment1),
+        (3782, 3782, Type2Comment1),
+        (3783, 3999, Type1Comment1),
+        (5000, 5999, Type1Comment1),
+        (6000, 6999, Type2Comment2),
+        (7000, 7999, Type1Comment1),
+        (8000, 8999, Type2Comment3),
+        (9020, 9029, Type1Comment2),
+        (9040, 9049, Type1Comment2),
+        (9060, 9069, Type1Comment1),
+        (9100, 9109, Type1Comment2),
+        (9120, 9124, Type1Comment1),
+        (9130, 9149, Type1Comment1),
+        (9150, 9169, Type1Comment2),
+        (9170, 9179, Type1Comment1),
+        (9180, 9189, Type2Comment1),
+        (9190, 9199, Type1Comment2),
+        (9230, 9239, Type1Comment1),
+        (9250, 9259, Type1Comment1),
+        (9270, 9279, Type1Comment1),
+        (9280, 9289, Type1Comment1),
+        (9300, 9349, Type2Comment3),
+        (9390, 9399, Type1Comment2),
+        (9400, 9449, Type1Comment1),
+        (9460, 9469, Type1Comment1),
+        (9470, 9479, Type1Comment2),
+        (9500, 9549, Type2Comment3),
+        (9550, 9569, Type1Comment2),
+        (9570, 9579, Type2Comment3),
+        (9630, 9639, Type1Comment1),
+        (9640, 9649, Type1Comment2),
+        (9660, 9669, Type1Comment2),
+        (9670, 9679, Type1Comment2),
+        (9680, 9689, Type1Comment1),
+        (9700, 9709, Type1Comment2),
+        (9750, 9759, Type1Comment1),
+        (9780, 9789, Type1Comment1),
+        (9880, 9889, Type1Comment2),
+        (9890, 9899, Type2Comment1),
+        (9960, 9969, Type2Comment3),
+    ]
+};
+
+fn account_rule(clearing: u16) -> Option<AccountRule> {
+    CLEARING_RANGES
+        .iter()
+        .find(|(from, to, _)| (*from..=*to).contains(&clearing))
+        .map(|&(_, _, rule)| rule)
+}
+
+/// A Swedish bank account number, formatted `CCCC-NNNNNNN` with the
+/// clearing number first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BankAccountNumber {
+    text: String,
+    clearing_len: usize,
+}
+
+impl BankAccountNumber {
+    /// Parse a clearing number followed by an account number. Spaces,
+    /// dashes, commas and dots are ignored; Swedbank's five digit clearing
+    /// numbers starting with 8 are supported.
+    pub fn parse(s: &str) -> Result<Self> {
+        const KIND: IdentifierKind = IdentifierKind::BankAccount;
+        let d = digits(s, &['-', ',', '.']).ok_or(IdentifierError::Format(KIND))?;
+        if d.len() < 5 {
+            return Err(IdentifierError::Format(KIND));
+        }
+        let clearing = d[..4].iter().fold(0u16, |acc, &x| acc * 10 + u16::from(x));
+        let rule = account_rule(clearing).ok_or(IdentifierError::UnknownClearing(KIND))?;
+
+        let clearing_len = if d[0] == 8 { 5 } else { 4 };
+        let (clearing_digits, account) = d.split_at(clearing_len.min(d.len()));
+        let length_ok = match rule {
+            AccountRule::Type1Comment1 | AccountRule::Type1Comment2 => account.len() == 7,
+            AccountRule::Type2Comment1 => account.len() == 10,
+            AccountRule::Type2Comment2 => account.len() == 9,
+            AccountRule::Type2Comment3 => (1..=10).contains(&account.len()),
+        };
+        if !length_ok {
+            return Err(IdentifierError::Format(KIND));
+        }
+        let valid = match rule {
+            AccountRule::Type1Comment1 => mod11_valid(&[&clearing_digits[1..], account].concat()),
+            AccountRule::Type1Comment2 => mod11_valid(&[clearing_digits, account].concat()),
+            AccountRule::Type2Comment1 => luhn_valid(account),
+            AccountRule::Type2Comment2 => mod11_valid(account),
+            // Swedbank's fifth clearing digit is a check digit of its own.
+            AccountRule::Type2Comment3 => {
+                luhn_valid(account) && (clearing_len == 4 || luhn_valid(clearing_digits))
+            }
+        };
+        if !valid {
+            return Err(IdentifierError::Checksum(KIND));
+        }
+        Ok(BankAccountNumber {
+            text: format!("{}-{}", to_string(clearing_digits), to_string(account)),
+            clearing_len,
+        })
+    }
+
+    /// The clearing number.
+    pub fn clearing_number(&self) -> &str {
+        &self.text[..self.clearing_len]
+    }
+
+    /// The account number without the clearing number.
+    pub fn account_number(&self) -> &str {
+        &self.text[self.clearing_len + 1..]
+    }
+}
+
+impl_identifier!(BankAccountNumber, text);
+
+/// IBAN lengths per country, for the countries Swedish businesses most
+/// often pay to. Other countries are checked by checksum only.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AT", 20),
+    ("BE", 16),
+    ("CH", 21),
+    ("DE", 22),
+    ("DK", 18),
+    ("EE", 20),
+    ("ES", 24),
+    ("FI", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("IE", 22),
+    ("IS", 26),
+    ("IT", 27),
+    ("LT", 20),
+    ("LV", 21),
+    ("NL", 18),
+    ("NO", 15),
+    ("PL", 28),
+    ("PT", 25),
+    ("SE", 24),
+];
+
+/// An international bank account number, formatted in groups of four.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iban(String);
+
+impl Iban {
+    /// Parse an IBAN; spaces are ignored and letters are uppercased.
+    pub fn parse(s: &str) -> Result<Self> {
+        const KIND: IdentifierKind = IdentifierKind::Iban;
+        let compact: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let bytes = compact.as_bytes();
+        if !(15..=34).contains(&bytes.len())
+            || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+            || !bytes[2..4].iter().all(u8::is_ascii_digit)
+            || !bytes.iter().all(u8::is_ascii_alphanumeric)
+        {
+            return Err(IdentifierError::Format(KIND));
+        }
+        if let Some(&(_, len)) = IBAN_LENGTHS.iter().find(|(cc, _)| *cc == &compact[..2]) {
+            if bytes.len() != len {
+                return Err(IdentifierError::Format(KIND));
+            }
+        }
+
+        // Move the first four characters to the end, map letters to 10-35
+        // and take the remainder mod 97 piecewise.
+        let remainder = bytes[4..].iter().chain(&bytes[..4]).fold(0u32, |acc, &b| {
+            let value = (b as char).to_digit(36).unwrap_or(0);
+            if value >= 10 {
+                (acc * 100 + value) % 97
+            } else {
+                (acc * 10 + value) % 97
+            }
+        });
+        if remainder != 1 {
+            return Err(IdentifierError::Checksum(KIND));
+        }
+
+        let grouped = compact
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(Iban(grouped))
+    }
+
+    /// The two-letter country code.
+    pub fn country(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// The IBAN without spaces, as payment files expect it.
+    pub fn compact(&self) -> String {
+        self.0.replace(' ', "")
+    }
+}
+
+impl_identifier!(Iban, 0);
+
+/// A bank identifier code (SWIFT code) of 8 or 11 characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bic(String);
+
+impl Bic {
+    /// Parse a BIC; spaces are ignored and letters are uppercased.
+    pub fn parse(s: &str) -> Result<Self> {
+        let compact: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let bytes = compact.as_bytes();
+        let valid = matches!(bytes.len(), 8 | 11)
+            && bytes[..6].iter().all(u8::is_ascii_uppercase)
+            && bytes[6..].iter().all(u8::is_ascii_alphanumeric);
+        if !valid {
+            return Err(IdentifierError::Format(IdentifierKind::Bic));
+        }
+        Ok(Bic(compact))
+    }
+
+    /// The two-letter country code.
+    pub fn country(&self) -> &str {
+        &self.0[4..6]
+    }
+}
+
+impl_identifier!(Bic, 0);
+
+/// Whether `email` looks like an email address: text before a single `@`
+/// and a dotted domain after it.
+pub fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    email.len() >= 5
+        && !local.is_empty()
+        && domain.len() >= 3
+        && domain.contains('.')
+        && !domain.starts_with('.')
+}
+
+/// Parse a number typed into a form, accepting only values of zero or more.
+pub fn parse_non_negative(s: &str) -> Option<Money> {
+    s.parse::<Money>()
+        .ok()
+        .filter(|value| *value >= Money::default())
+}
+
+/// What is wrong with a field.
+///
+/// The [`Validator`] stores these in [`ValidationError::message`] in English,
+/// the form [`parse`](Self::parse) reads back, and
+/// [`Localize`](crate::locale::Localize) renders them in Swedish or English.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationMessage {
+    /// The field must have a value.
+    Required,
+    /// The value is longer than this many characters.
+    TooLong(usize),
+    /// The value is not an email address.
+    InvalidEmail,
+    /// The value is below zero.
+    Negative,
+    /// The VAT rate is not among the known VAT codes.
+    UnknownVatRate,
+    /// The percentage is outside 0-100.
+    NotPercentage,
+    /// The date is before the named field.
+    Before(String),
+    /// The date is not after the named field.
+    NotAfter(String),
+    /// The value has the wrong characters or length.
+    InvalidFormat,
+    /// The check digit does not match.
+    InvalidCheckDigit,
+    /// The date part is not a real date.
+    InvalidDate,
+    /// The OCR length digit does not match.
+    InvalidLengthDigit,
+    /// The clearing number belongs to no known bank.
+    UnknownClearingNumber,
+}
+
+impl ValidationMessage {
+    /// Read back a message written by the [`Validator`], or the same text
+    /// from the API.
+    pub fn parse(message: &str) -> Option<Self> {
+        use ValidationMessage::*;
+        Some(match message {
+            "Required" => Required,
+            "Invalid email address" => InvalidEmail,
+            "Must not be negative" => Negative,
+            "Unknown VAT rate" => UnknownVatRate,
+            "Must be between 0 and 100" => NotPercentage,
+            "Invalid format" => InvalidFormat,
+            "Invalid check digit" => InvalidCheckDigit,
+            "Invalid date" => InvalidDate,
+            "Invalid length digit" => InvalidLengthDigit,
+            "Unknown clearing number" => UnknownClearingNumber,
+            _ => {
+                if let Some(field) = message.strip_prefix("Must not be before ") {
+                    Before(field.to_string())
+                } else if let Some(field) = message.strip_prefix("Must be after ") {
+                    NotAfter(field.to_string())
+                } else {
+                    let max = message
+                        .strip_prefix("Must be at most ")?
+                        .strip_suffix(" characters")?;
+                    TooLong(max.parse().ok()?)
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for ValidationMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationMessage::Required => f.write_str("Required"),
+            ValidationMessage::TooLong(max) => write!(f, "Must be at most {} characters", max),
+            ValidationMessage::InvalidEmail => f.write_str("Invalid email address"),
+            ValidationMessage::Negative => f.write_str("Must not be negative"),
+            ValidationMessage::UnknownVatRate => f.write_str("Unknown VAT rate"),
+            ValidationMessage::NotPercentage => f.write_str("Must be between 0 and 100"),
+            ValidationMessage::Before(field) => write!(f, "Must not be before {}", field),
+            ValidationMessage::NotAfter(field) => write!(f, "Must be after {}", field),
+            ValidationMessage::InvalidFormat => f.write_str("Invalid format"),
+            ValidationMessage::InvalidCheckDigit => f.write_str("Invalid check digit"),
+            ValidationMessage::InvalidDate => f.write_str("Invalid date"),
+            ValidationMessage::InvalidLengthDigit => f.write_str("Invalid length digit"),
+            ValidationMessage::UnknownClearingNumber => f.write_str("Unknown clearing number"),
+        }
+    }
+}
+
+impl From<ValidationMessage> for String {
+    fn from(message: ValidationMessage) -> Self {
+        message.to_string()
+    }
+}
+
+/// Reference data for checks that need more than the request itself.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationContext {
+    vat_rates: Option<HashSet<VatRateId>>,
+}
+
+impl ValidationContext {
+    /// An empty context; references are not checked.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require VAT rate ids on rows and articles to be among `codes`.
+    pub fn vat_codes<'a>(mut self, codes: impl IntoIterator<Item = &'a VatCode>) -> Self {
+        let rates = self.vat_rates.get_or_insert_with(HashSet::new);
+        for code in codes {
+            if let Some(id) = &code.id {
+                rates.insert(id.clone());
+            }
+        }
+        self
+    }
+}
+
+/// Collects [`ValidationError`]s while a request is checked.
+///
+/// Field paths follow the API, e.g. `InvoiceAddress.PostalCode` or
+/// `Rows[1].Quantity`.
+#[derive(Debug)]
+pub struct Validator<'a> {
+    context: &'a ValidationContext,
+    prefix: String,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    /// Start collecting errors.
+    pub fn new(context: &'a ValidationContext) -> Self {
+        Self {
+            context,
+            prefix: String::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Record a problem with `field`.
+    pub fn error(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(ValidationError {
+            field: format!("{}{}", self.prefix, field),
+            message: message.into(),
+        });
+    }
+
+    /// Require a non-blank value.
+    pub fn required(&mut self, field: &str, value: Option<&str>) {
+        if value.is_none_or(|value| value.trim().is_empty()) {
+            self.error(field, ValidationMessage::Required);
+        }
+    }
+
+    /// Limit a value to `max` characters.
+    pub fn max_len(&mut self, field: &str, value: Option<&str>, max: usize) {
+        if value.is_some_and(|value| value.chars().count() > max) {
+            self.error(field, ValidationMessage::TooLong(max));
+        }
+    }
+
+    /// Require a plausible email address, see [`is_valid_email`].
+    pub fn email(&mut self, field: &str, value: Option<&str>) {
+        if value.is_some_and(|value| !is_valid_email(value)) {
+            self.error(field, ValidationMessage::InvalidEmail);
+        }
+    }
+
+    /// Require a value of zero or more.
+    pub fn non_negative(&mut self, field: &str, value: Option<Money>) {
+        if value.is_some_and(|value| value < Money::default()) {
+            self.error(field, ValidationMessage::Negative);
+        }
+    }
+
+    /// Require a VAT rate known to the context, if it has any.
+    pub fn vat_rate(&mut self, field: &str, value: Option<&VatRateId>) {
+        if let (Some(known), Some(id)) = (&self.context.vat_rates, value) {
+            if !known.contains(id) {
+                self.error(field, ValidationMessage::UnknownVatRate);
+            }
+        }
+    }
+
+    /// Require a value that parses as an identifier, e.g. with
+    /// [`Bankgiro::parse`].
+    pub fn identifier<T>(
+        &mut self,
+        field: &str,
+        value: Option<&str>,
+        parse: impl FnOnce(&str) -> Result<T>,
+    ) {
+        if let Some(Err(err)) = value.map(parse) {
+            self.error(field, err.message());
+        }
+    }
+
+    /// Check a nested value, prefixing its field paths with `field`.
+    pub fn nested(&mut self, field: &str, value: &(impl Validate + ?Sized)) {
+        let outer = self.prefix.clone();
+        self.prefix = format!("{}{}.", outer, field);
+        value.validate_into(self);
+        self.prefix = outer;
+    }
+
+    /// Check each item of a list as `field[index]`.
+    pub fn each<T: Validate>(&mut self, field: &str, items: &[T]) {
+        for (index, item) in items.iter().enumerate() {
+            self.nested(&format!("{}[{}]", field, index), item);
+        }
+    }
+
+    /// The errors found, if any.
+    pub fn finish(self) -> std::result::Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// Client-side checks of a request before it is sent.
+///
+/// The checks cover length limits, email format, the invoice address parts
+/// the API requires, non-negative quantities and prices, identifier check
+/// digits and, with a [`ValidationContext`], VAT rate existence. Problems
+/// are reported as [`ValidationError`]s with the API's field paths, and
+/// convert to [`Error::Validation`] with `?`.
+pub trait Validate {
+    /// Record the problems found in `validator`.
+    fn validate_into(&self, validator: &mut Validator<'_>);
+
+    /// Check the request on its own.
+    fn validate(&self) -> std::result::Result<(), Vec<ValidationError>> {
+        self.validate_with(&ValidationContext::default())
+    }
+
+    /// Check the request, including references known to `context`.
+    fn validate_with(
+        &self,
+        context: &ValidationContext,
+    ) -> std::result::Result<(), Vec<ValidationError>> {
+        let mut validator = Validator::new(context);
+        self.validate_into(&mut validator);
+        validator.finish()
+    }
+}
+
+impl IdentifierError {
+    /// The message used in a [`ValidationError`].
+    fn message(&self) -> ValidationMessage {
+        match self {
+            IdentifierError::Format(_) => ValidationMessage::InvalidFormat,
+            IdentifierError::Checksum(_) => ValidationMessage::InvalidCheckDigit,
+            IdentifierError::Date(_) => ValidationMessage::InvalidDate,
+            IdentifierError::LengthDigit(_) => ValidationMessage::InvalidLengthDigit,
+            IdentifierError::UnknownClearing(_) => ValidationMessage::UnknownClearingNumber,
+        }
+    }
+}
+
+fn currency(v: &mut Validator<'_>, value: Option<&str>) {
+    if value.is_some_and(|code| CurrencyCode::parse(code).is_err()) {
+        v.error("CurrencyCode", ValidationMessage::InvalidFormat);
+    }
+}
+
+fn date_order(
+    v: &mut Validator<'_>,
+    (earlier_field, earlier): (&str, Option<DateTime<Utc>>),
+    (later_field, later): (&str, Option<DateTime<Utc>>),
+) {
+    if let (Some(earlier), Some(later)) = (earlier, later) {
+        if later < earlier {
+            v.error(
+                later_field,
+                ValidationMessage::Before(earlier_field.to_string()),
+            );
+        }
+    }
+}
+
+fn document_rows(v: &mut Validator<'_>, rows: Option<&[InvoiceRowCreate]>, required: bool) {
+    match rows {
+        Some([]) | None if required => v.error("Rows", ValidationMessage::Required),
+        Some(rows) => v.each("Rows", rows),
+        None => {}
+    }
+}
+
+fn references(v: &mut Validator<'_>, yours: Option<&str>, ours: Option<&str>) {
+    v.max_len("YourReference", yours, 50);
+    v.max_len("OurReference", ours, 50);
+}
+
+/// A given invoice address; postal code and city are required for invoicing.
+fn invoice_address(v: &mut Validator<'_>, address: &Address) {
+    v.nested("InvoiceAddress", address);
+    v.required("InvoiceAddress.PostalCode", address.postal_code.as_deref());
+    v.required("InvoiceAddress.City", address.city.as_deref());
+}
+
+impl Validate for Address {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        v.max_len("Address1", self.address1.as_deref(), 50);
+        v.max_len("Address2", self.address2.as_deref(), 50);
+        v.max_len("PostalCode", self.postal_code.as_deref(), 10);
+        v.max_len("City", self.city.as_deref(), 50);
+        let country = self.country_code.as_deref();
+        if country
+            .is_some_and(|code| code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()))
+        {
+            v.error("CountryCode", ValidationMessage::InvalidFormat);
+        }
+        // Swedish postal codes are five digits, often written "123 45".
+        let swedish = country.is_none_or(|code| code.eq_ignore_ascii_case("SE"));
+        if let Some(postal_code) = self.postal_code.as_deref().filter(|_| swedish) {
+            let digits = digits(postal_code, &[]);
+            if !postal_code.trim().is_empty() && digits.is_none_or(|d| d.len() != 5) {
+                v.error("PostalCode", ValidationMessage::InvalidFormat);
+            }
+        }
+    }
+}
+
+/// Contact fields shared by customers and suppliers.
+struct Contact<'a> {
+    name: Option<&'a str>,
+    corporate_identity_number: Option<&'a str>,
+    email: Option<&'a str>,
+    phone: Option<&'a str>,
+    mobile_phone: Option<&'a str>,
+    website: Option<&'a str>,
+}
+
+impl Validate for Contact<'_> {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        v.max_len("Name", self.name, 50);
+        v.identifier(
+            "CorporateIdentityNumber",
+            self.corporate_identity_number,
+            CorporateIdentity::parse,
+        );
+        v.max_len("Email", self.email, 255);
+        v.email("Email", self.email);
+        v.max_len("Phone", self.phone, 50);
+        v.max_len("MobilePhone", self.mobile_phone, 50);
+        v.max_len("Website", self.website, 255);
+    }
+}
+
+impl Validate for CustomerCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        v.required("Name", Some(&self.name));
+        v.max_len("CustomerNumber", self.customer_number.as_deref(), 16);
+        Contact {
+            name: Some(&self.name),
+            corporate_identity_number: self.corporate_identity_number.as_deref(),
+            email: self.email.as_deref(),
+            phone: self.phone.as_deref(),
+            mobile_phone: self.mobile_phone.as_deref(),
+            website: self.website.as_deref(),
+        }
+        .validate_into(v);
+        if let Some(address) = &self.invoice_address {
+            invoice_address(v, address);
+        }
+        if let Some(address) = &self.delivery_address {
+            v.nested("DeliveryAddress", address);
+        }
+    }
+}
+
+impl Validate for CustomerUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.name.is_some() {
+            v.required("Name", self.name.as_deref());
+        }
+        v.max_len("CustomerNumber", self.customer_number.as_deref(), 16);
+        Contact {
+            name: self.name.as_deref(),
+            corporate_identity_number: self.corporate_identity_number.as_deref(),
+            email: self.email.as_deref(),
+            phone: self.phone.as_deref(),
+            mobile_phone: self.mobile_phone.as_deref(),
+            website: self.website.as_deref(),
+        }
+        .validate_into(v);
+        if let Some(address) = &self.invoice_address {
+            invoice_address(v, address);
+        }
+        if let Some(address) = &self.delivery_address {
+            v.nested("DeliveryAddress", address);
+        }
+    }
+}
+
+fn supplier_payment(
+    v: &mut Validator<'_>,
+    bank_account_number: Option<&str>,
+    bank_giro_number: Option<&str>,
+    plus_giro_number: Option<&str>,
+) {
+    v.identifier(
+        "BankAccountNumber",
+        bank_account_number,
+        BankAccountNumber::parse,
+    );
+    v.identifier("BankGiroNumber", bank_giro_number, Bankgiro::parse);
+    v.identifier("PlusGiroNumber", plus_giro_number, Plusgiro::parse);
+}
+
+impl Validate for SupplierCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        v.required("Name", Some(&self.name));
+        v.max_len("SupplierNumber", self.supplier_number.as_deref(), 16);
+        Contact {
+            name: Some(&self.name),
+            corporate_identity_number: self.corporate_identity_number.as_deref(),
+            email: self.email.as_deref(),
+            phone: self.phone.as_deref(),
+            mobile_phone: self.mobile_phone.as_deref(),
+            website: self.website.as_deref(),
+        }
+        .validate_into(v);
+        if let Some(address) = &self.address {
+            v.nested("Address", address);
+        }
+        supplier_payment(
+            v,
+            self.bank_account_number.as_deref(),
+            self.bank_giro_number.as_deref(),
+            self.plus_giro_number.as_deref(),
+        );
+    }
+}
+
+impl Validate for SupplierUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.name.is_some() {
+            v.required("Name", self.name.as_deref());
+        }
+        v.max_len("SupplierNumber", self.supplier_number.as_deref(), 16);
+        Contact {
+            name: self.name.as_deref(),
+            corporate_identity_number: self.corporate_identity_number.as_deref(),
+            email: self.email.as_deref(),
+            phone: self.phone.as_deref(),
+            mobile_phone: self.mobile_phone.as_deref(),
+            website: self.website.as_deref(),
+        }
+        .validate_into(v);
+        if let Some(address) = &self.address {
+            v.nested("Address", address);
+        }
+        supplier_payment(
+            v,
+            self.bank_account_number.as_deref(),
+            self.bank_giro_number.as_deref(),
+            self.plus_giro_number.as_deref(),
+        );
+    }
+}
+
+/// Fields shared by article create and update requests.
+struct ArticleFields<'a> {
+    name: Option<&'a str>,
+    article_number: Option<&'a str>,
+    unit: Option<&'a str>,
+    sales_price: Option<Money>,
+    purchase_price: Option<Money>,
+    vat_rate_id: Option<&'a VatRateId>,
+}
+
+impl Validate for ArticleFields<'_> {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.name.is_some() {
+            v.required("Name", self.name);
+        }
+        v.max_len("Name", self.name, 50);
+        v.max_len("ArticleNumber", self.article_number, 40);
+        v.max_len("Unit", self.unit, 20);
+        v.non_negative("SalesPrice", self.sales_price);
+        v.non_negative("PurchasePrice", self.purchase_price);
+        v.vat_rate("VatRateId", self.vat_rate_id);
+    }
+}
+
+impl Validate for ArticleCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        ArticleFields {
+            name: Some(&self.name),
+            article_number: self.article_number.as_deref(),
+            unit: self.unit.as_deref(),
+            sales_price: self.sales_price,
+            purchase_price: self.purchase_price,
+            vat_rate_id: self.vat_rate_id.as_ref(),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for ArticleUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        ArticleFields {
+            name: self.name.as_deref(),
+            article_number: self.article_number.as_deref(),
+            unit: self.unit.as_deref(),
+            sales_price: self.sales_price,
+            purchase_price: self.purchase_price,
+            vat_rate_id: self.vat_rate_id.as_ref(),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for InvoiceRowCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.article_id.is_none() {
+            v.required("Text", self.text.as_deref());
+        }
+        v.max_len("Text", self.text.as_deref(), 1000);
+        v.non_negative("Quantity", self.quantity);
+        let hundred = crate::money!(100.0);
+        if self
+            .discount_percentage
+            .is_some_and(|discount| discount < Money::default() || discount > hundred)
+        {
+            v.error("DiscountPercentage", ValidationMessage::NotPercentage);
+        }
+        v.vat_rate("VatRateId", self.vat_rate_id.as_ref());
+    }
+}
+
+impl Validate for InvoiceCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("InvoiceDate", self.invoice_date),
+            ("DueDate", self.due_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, Some(&self.rows), true);
+        v.max_len("Remarks", self.remarks.as_deref(), 1000);
+    }
+}
+
+impl Validate for InvoiceUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("InvoiceDate", self.invoice_date),
+            ("DueDate", self.due_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, self.rows.as_deref(), false);
+        v.max_len("Remarks", self.remarks.as_deref(), 1000);
+    }
+}
+
+impl Validate for CustomerInvoiceDraftCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("InvoiceDate", self.invoice_date),
+            ("DueDate", self.due_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, Some(&self.rows), false);
+        v.max_len("Remarks", self.remarks.as_deref(), 1000);
+        references(
+            v,
+            self.your_reference.as_deref(),
+            self.our_reference.as_deref(),
+        );
+    }
+}
+
+impl Validate for CustomerInvoiceDraftUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("InvoiceDate", self.invoice_date),
+            ("DueDate", self.due_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, self.rows.as_deref(), false);
+        v.max_len("Remarks", self.remarks.as_deref(), 1000);
+        references(
+            v,
+            self.your_reference.as_deref(),
+            self.our_reference.as_deref(),
+        );
+    }
+}
+
+impl Validate for OrderCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("OrderDate", Some(self.order_date)),
+            ("DeliveryDate", self.delivery_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, Some(&self.rows), true);
+        references(
+            v,
+            self.your_reference.as_deref(),
+            self.our_reference.as_deref(),
+        );
+    }
+}
+
+impl Validate for OrderUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("OrderDate", self.order_date),
+            ("DeliveryDate", self.delivery_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, self.rows.as_deref(), false);
+        references(
+            v,
+            self.your_reference.as_deref(),
+            self.our_reference.as_deref(),
+        );
+    }
+}
+
+impl Validate for QuotationCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("QuotationDate", Some(self.quotation_date)),
+            ("ValidUntilDate", self.valid_until_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, Some(&self.rows), true);
+    }
+}
+
+impl Validate for QuotationUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("QuotationDate", self.quotation_date),
+            ("ValidUntilDate", self.valid_until_date),
+        );
+        currency(v, self.currency_code.as_deref());
+        document_rows(v, self.rows.as_deref(), false);
+    }
+}
+
+impl Validate for SupplierInvoiceRowCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if digits(&self.account_number, &[]).is_none_or(|d| d.len() != 4) {
+            v.error("AccountNumber", ValidationMessage::InvalidFormat);
+        }
+        v.max_len("Text", self.text.as_deref(), 1000);
+        v.vat_rate("VatRateId", self.vat_rate_id.as_ref());
+    }
+}
+
+/// Fields shared by supplier invoice create and update requests.
+struct SupplierInvoiceFields<'a> {
+    invoice_date: Option<DateTime<Utc>>,
+    due_date: Option<DateTime<Utc>>,
+    invoice_number: Option<&'a str>,
+    currency_code: Option<&'a str>,
+    currency_rate: Option<Money>,
+    ocr_number: Option<&'a str>,
+    rows: Option<&'a [SupplierInvoiceRowCreate]>,
+}
+
+impl Validate for SupplierInvoiceFields<'_> {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        date_order(
+            v,
+            ("InvoiceDate", self.invoice_date),
+            ("DueDate", self.due_date),
+        );
+        v.max_len("InvoiceNumber", self.invoice_number, 50);
+        currency(v, self.currency_code);
+        v.non_negative("CurrencyRate", self.currency_rate);
+        // The reference comes from the supplier, whose bankgiro agreement
+        // may not include the length digit.
+        v.identifier(
+            "OcrNumber",
+            self.ocr_number,
+            Ocr::parse_without_length_digit,
+        );
+        if let Some(rows) = self.rows {
+            v.each("Rows", rows);
+        }
+    }
+}
+
+impl Validate for SupplierInvoiceCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        SupplierInvoiceFields {
+            invoice_date: Some(self.invoice_date),
+            due_date: self.due_date,
+            invoice_number: self.invoice_number.as_deref(),
+            currency_code: self.currency_code.as_deref(),
+            currency_rate: self.currency_rate,
+            ocr_number: self.ocr_number.as_deref(),
+            rows: Some(&self.rows),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for SupplierInvoiceUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        SupplierInvoiceFields {
+            invoice_date: self.invoice_date,
+            due_date: self.due_date,
+            invoice_number: self.invoice_number.as_deref(),
+            currency_code: self.currency_code.as_deref(),
+            currency_rate: self.currency_rate,
+            ocr_number: self.ocr_number.as_deref(),
+            rows: self.rows.as_deref(),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for SupplierInvoiceDraftCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        SupplierInvoiceFields {
+            invoice_date: self.invoice_date,
+            due_date: self.due_date,
+            invoice_number: self.invoice_number.as_deref(),
+            currency_code: self.currency_code.as_deref(),
+            currency_rate: None,
+            ocr_number: None,
+            rows: Some(&self.rows),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for SupplierInvoiceDraftUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        SupplierInvoiceFields {
+            invoice_date: self.invoice_date,
+            due_date: self.due_date,
+            invoice_number: self.invoice_number.as_deref(),
+            currency_code: self.currency_code.as_deref(),
+            currency_rate: None,
+            ocr_number: None,
+            rows: self.rows.as_deref(),
+        }
+        .validate_into(v);
+    }
+}
+
+/// Voucher rows, checked as [`VoucherBuilder::check`](crate::voucher::VoucherBuilder::check)
+/// does.
+fn voucher_rows(v: &mut Validator<'_>, rows: &[VoucherRowCreate]) {
+    for error in check_rows(rows) {
+        let error = ValidationError::from(error);
+        v.error(&error.field, error.message);
+    }
+    for (index, row) in rows.iter().enumerate() {
+        v.max_len(
+            &format!("Rows[{}].TransactionText", index),
+            row.transaction_text.as_deref(),
+            100,
+        );
+    }
+}
+
+impl Validate for VoucherCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        v.required("VoucherText", Some(&self.voucher_text));
+        v.max_len("VoucherText", Some(&self.voucher_text), 1000);
+        voucher_rows(v, &self.rows);
+    }
+}
+
+impl Validate for VoucherUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.voucher_text.is_some() {
+            v.required("VoucherText", self.voucher_text.as_deref());
+        }
+        v.max_len("VoucherText", self.voucher_text.as_deref(), 1000);
+        if let Some(rows) = &self.rows {
+            voucher_rows(v, rows);
+        }
+    }
+}
+
+/// Fields shared by project create and update requests.
+struct ProjectFields<'a> {
+    project_number: Option<&'a str>,
+    name: Option<&'a str>,
+    start_date: Option<DateTime<Utc>>,
+    end_date: Option<DateTime<Utc>>,
+    notes: Option<&'a str>,
+}
+
+impl Validate for ProjectFields<'_> {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.project_number.is_some() {
+            v.required("ProjectNumber", self.project_number);
+        }
+        v.max_len("ProjectNumber", self.project_number, 16);
+        if self.name.is_some() {
+            v.required("Name", self.name);
+        }
+        v.max_len("Name", self.name, 50);
+        date_order(
+            v,
+            ("StartDate", self.start_date),
+            ("EndDate", self.end_date),
+        );
+        v.max_len("Notes", self.notes, 2000);
+    }
+}
+
+impl Validate for ProjectCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        ProjectFields {
+            project_number: Some(&self.project_number),
+            name: Some(&self.name),
+            start_date: Some(self.start_date),
+            end_date: self.end_date,
+            notes: self.notes.as_deref(),
+        }
+        .validate_into(v);
+    }
+}
+
+impl Validate for ProjectUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        ProjectFields {
+            project_number: self.project_number.as_deref(),
+            name: self.name.as_deref(),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            notes: self.notes.as_deref(),
+        }
+        .validate_into(v);
+    }
+}
+
+fn cost_center_item(v: &mut Validator<'_>, name: Option<&str>, short_name: Option<&str>) {
+    if name.is_some() {
+        v.required("Name", name);
+    }
+    v.max_len("Name", name, 50);
+    if short_name.is_some() {
+        v.required("ShortName", short_name);
+    }
+    v.max_len("ShortName", short_name, 10);
+}
+
+impl Validate for CostCenterItemCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        cost_center_item(v, Some(&self.name), Some(&self.short_name));
+    }
+}
+
+impl Validate for CostCenterItemUpdate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        cost_center_item(v, self.name.as_deref(), self.short_name.as_deref());
+    }
+}
+
+impl Validate for FiscalYearCreate {
+    fn validate_into(&self, v: &mut Validator<'_>) {
+        if self.end_date <= self.start_date {
+            v.error(
+                "EndDate",
+                ValidationMessage::NotAfter("StartDate".to_string()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn_and_mod11() {
+        assert!(luhn_valid(&[5, 5, 6, 0, 1, 2, 5, 7, 9, 0]));
+        assert!(!luhn_valid(&[5, 5, 6, 0, 1, 2, 5, 7, 9, 1]));
+        assert_eq!(luhn_check_digit(&[1, 2, 3, 4, 5, 7]), 4);
+        assert!(mod11_valid(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn test_organisationsnummer() {
+        let org = Organisationsnummer::parse("5560125790").unwrap();
+        assert_eq!(org.to_string(), "556012-5790");
+        assert_eq!(Organisationsnummer::parse("16556012-5790").unwrap(), org);
+        assert_eq!(
+            Organisationsnummer::parse("556012-5791"),
+            Err(IdentifierError::Checksum(
+                IdentifierKind::Organisationsnummer
+            ))
+        );
+        // Third digit below 2 is a personal number.
+        assert_eq!(
+            Organisationsnummer::parse("811228-9874"),
+            Err(IdentifierError::Format(IdentifierKind::Organisationsnummer))
+        );
+    }
+
+    #[test]
+    fn test_personnummer() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let pnr = Personnummer::parse_at("811228-9874", today).unwrap();
+        assert_eq!(pnr.as_str(), "19811228-9874");
+        assert!(!pnr.is_coordination_number());
+
+        assert_eq!(
+            Personnummer::parse_at("121212-1212", today)
+                .unwrap()
+                .as_str(),
+            "20121212-1212"
+        );
+        assert_eq!(
+            Personnummer::parse_at("121212+1212", today)
+                .unwrap()
+                .as_str(),
+            "19121212-1212"
+        );
+        assert_eq!(
+            Personnummer::parse_at("191212121212", today)
+                .unwrap()
+                .as_str(),
+            "19121212-1212"
+        );
+
+        let samordning = Personnummer::parse_at("701063-2391", today).unwrap();
+        assert!(samordning.is_coordination_number());
+
+        assert_eq!(
+            Personnummer::parse_at("811328-9874", today),
+            Err(IdentifierError::Date(IdentifierKind::Personnummer))
+        );
+        assert_eq!(
+            Personnummer::parse_at("811228-9875", today),
+            Err(IdentifierError::Checksum(IdentifierKind::Personnummer))
+        );
+    }
+
+    #[test]
+    fn test_corporate_identity() {
+        assert!(matches!(
+            CorporateIdentity::parse("556012-5790"),
+            Ok(CorporateIdentity::Organisation(_))
+        ));
+        assert!(matches!(
+            CorporateIdentity::parse("19811228-9874"),
+            Ok(CorporateIdentity::Person(_))
+        ));
+        assert_eq!(
+            CorporateIdentity::parse("556012-5791"),
+            Err(IdentifierError::Checksum(
+                IdentifierKind::Organisationsnummer
+            ))
+        );
+        assert_eq!(
+            CorporateIdentity::parse("abc"),
+            Err(IdentifierError::Format(IdentifierKind::CorporateIdentity))
+        );
+    }
+
+    #[test]
+    fn test_ocr() {
+        assert_eq!(
+            Ocr::from_invoice_number("12345").unwrap().as_str(),
+            "1234574"
+        );
+        assert_eq!(Ocr::parse("123 4574").unwrap().as_str(), "1234574");
+        assert_eq!(
+            Ocr::parse("1234584"),
+            Err(IdentifierError::Checksum(IdentifierKind::Ocr))
+        );
+        // Passes Luhn, but the length digit says 9 rather than 0.
+        assert_eq!(
+            Ocr::parse("5560125790"),
+            Err(IdentifierError::LengthDigit(IdentifierKind::Ocr))
+        );
+        assert!(Ocr::parse_without_length_digit("5560125790").is_ok());
+        assert!(Ocr::from_invoice_number("A-100").is_err());
+        assert!(Ocr::from_invoice_number(&"1".repeat(24)).is_err());
+        for n in ["1", "99", "1001", "20240001"] {
+            let ocr = Ocr::from_invoice_number(n).unwrap();
+            assert_eq!(Ocr::parse(ocr.as_str()).unwrap(), ocr);
+        }
+    }
+
+    #[test]
+    fn test_giro_numbers() {
+        assert_eq!(Bankgiro::parse("50501055").unwrap().as_str(), "5050-1055");
+        assert_eq!(Bankgiro::parse("902-0033").unwrap().as_str(), "902-0033");
+        assert_eq!(
+            Bankgiro::parse("5550-1055"),
+            Err(IdentifierError::Checksum(IdentifierKind::Bankgiro))
+        );
+        assert_eq!(Plusgiro::parse("90 20 03-3").unwrap().as_str(), "902003-3");
+        assert!(Plusgiro::parse("4479251").is_err());
+    }
+
+    #[test]
+    fn test_bank_account_number() {
+        let seb = BankAccountNumber::parse("5000 123 456-0").unwrap();
+        assert_eq!(seb.as_str(), "5000-1234560");
+        assert_eq!(seb.clearing_number(), "5000");
+        assert_eq!(seb.account_number(), "1234560");
+
+        assert!(BankAccountNumber::parse("9020-1234566").is_ok());
+        // Type 1, comment 2: the check covers the whole clearing number.
+        for account in [
+            "2300-1234561",
+            "9040-1234578",
+            "9100-1234563",
+            "9150-1234562",
+            "9190-1234578",
+            "9390-1234561",
+            "9470-1234569",
+            "9550-1234566",
+            "9640-1234565",
+            "9660-1234569",
+            "9670-1234560",
+            "9700-1234569",
+        ] {
+            assert!(BankAccountNumber::parse(account).is_ok(), "{}", account);
+        }
+        assert!(BankAccountNumber::parse("6000-123456789").is_ok());
+        assert!(BankAccountNumber::parse("3300-8112289874").is_ok());
+
+        let swedbank = BankAccountNumber::parse("8327-9, 123 456 782").unwrap();
+        assert_eq!(swedbank.as_str(), "83279-123456782");
+        assert_eq!(swedbank.clearing_number(), "83279");
+
+        assert_eq!(
+            BankAccountNumber::parse("5000-1234561"),
+            Err(IdentifierError::Checksum(IdentifierKind::BankAccount))
+        );
+        assert_eq!(
+            BankAccountNumber::parse("5000-123456"),
+            Err(IdentifierError::Format(IdentifierKind::BankAccount))
+        );
+        assert_eq!(
+            BankAccountNumber::parse("0100-1234567"),
+            Err(IdentifierError::UnknownClearing(
+                IdentifierKind::BankAccount
+            ))
+        );
+    }
+
+    #[test]
+    fn test_iban_and_bic() {
+        let iban = Iban::parse("se4550000000058398257466").unwrap();
+        assert_eq!(iban.as_str(), "SE45 5000 0000 0583 9825 7466");
+        assert_eq!(iban.country(), "SE");
+        assert_eq!(iban.compact(), "SE4550000000058398257466");
+        assert!(Iban::parse("GB82 WEST 1234 5698 7654 32").is_ok());
+        assert!(Iban::parse("NO93 8601 1117 947").is_ok());
+        assert_eq!(
+            Iban::parse("SE4650000000058398257466"),
+            Err(IdentifierError::Checksum(IdentifierKind::Iban))
+        );
+        assert_eq!(
+            Iban::parse("SE45500000000583982574"),
+            Err(IdentifierError::Format(IdentifierKind::Iban))
+        );
+
+        assert_eq!(Bic::parse("essesess").unwrap().as_str(), "ESSESESS");
+        assert_eq!(Bic::parse("NDEASESSXXX").unwrap().country(), "SE");
+        assert!(Bic::parse("ESSE1ESS").is_err());
+        assert!(Bic::parse("ESSESES").is_err());
+    }
+
+    #[test]
+    fn test_email_and_number() {
+        assert!(is_valid_email("test@example.com"));
+        assert!(is_valid_email("user@domain.co.uk"));
+        assert!(!is_valid_email("invalid"));
+        assert!(!is_valid_email("no@domain"));
+        assert!(!is_valid_email("@missing.com"));
+        assert!(!is_valid_email("a@.example"));
+
+        assert_eq!(parse_non_negative("123.45"), Some(crate::money!(123.45)));
+        assert_eq!(parse_non_negative("0"), Some(Money::default()));
+        assert!(parse_non_negative("100").is_some());
+        assert!(parse_non_negative("-10").is_none());
+        assert!(parse_non_negative("abc").is_none());
+        assert!(parse_non_negative("12.34.56").is_none());
+    }
+
+    fn fields(result: std::result::Result<(), Vec<ValidationError>>) -> Vec<String> {
+        result
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.field)
+            .collect()
+    }
+
+    fn address(postal_code: &str, city: &str) -> Address {
+        Address {
+            postal_code: Some(postal_code.to_string()),
+            city: Some(city.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validation_message_round_trip() {
+        for message in [
+            ValidationMessage::Required,
+            ValidationMessage::TooLong(50),
+            ValidationMessage::NotPercentage,
+            ValidationMessage::Before("InvoiceDate".to_string()),
+            ValidationMessage::NotAfter("StartDate".to_string()),
+            ValidationMessage::UnknownClearingNumber,
+        ] {
+            assert_eq!(
+                ValidationMessage::parse(&message.to_string()),
+                Some(message)
+            );
+        }
+        assert_eq!(ValidationMessage::parse("Something else"), None);
+    }
+
+    #[test]
+    fn test_customer_create() {
+        let customer = CustomerCreate::new("Acme AB".to_string())
+            .email("info@acme.se".to_string())
+            .corporate_identity_number("556012-5790".to_string())
+            .invoice_address(address("123 45", "Stockholm"));
+        assert_eq!(customer.validate(), Ok(()));
+
+        let customer = CustomerCreate::new(" ".to_string())
+            .email("info@acme".to_string())
+            .corporate_identity_number("556012-5791".to_string())
+            .phone("0".repeat(51))
+            .invoice_address(address("1234", ""));
+        let errors = customer.validate().unwrap_err();
+        let found: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Name", "Required"),
+                ("CorporateIdentityNumber", "Invalid check digit"),
+                ("Email", "Invalid email address"),
+                ("Phone", "Must be at most 50 characters"),
+                ("InvoiceAddress.PostalCode", "Invalid format"),
+                ("InvoiceAddress.City", "Required"),
+            ]
+        );
+
+        // The address is optional, but must be complete when given.
+        assert_eq!(
+            CustomerCreate::new("Acme AB".to_string()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            fields(
+                CustomerCreate::new("Acme AB".to_string())
+                    .invoice_address(address("123 45", ""))
+                    .validate()
+            ),
+            ["InvoiceAddress.City"]
+        );
+        // Updates only check the fields they change.
+        assert_eq!(CustomerUpdate::new().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invoice_rows_and_vat_rates() {
+        let known: VatCode =
+            serde_json::from_str(r#"{"Id": "fa000000-0000-4000-8000-000000000025"}"#).unwrap();
+        let unknown: VatRateId = "fa000000-0000-4000-8000-000000000012".parse().unwrap();
+        let context = ValidationContext::new().vat_codes([&known]);
+
+        let invoice = InvoiceCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse().unwrap())
+            .row(
+                InvoiceRowCreate::with_text(
+                    "Consulting".to_string(),
+                    crate::money!(1500.0),
+                    crate::money!(-1.0),
+                )
+                .vat_rate_id(unknown),
+            )
+            .row(InvoiceRowCreate::new().discount_percentage(crate::money!(120.0)));
+
+        assert_eq!(
+            fields(invoice.validate()),
+            [
+                "Rows[0].Quantity",
+                "Rows[1].Text",
+                "Rows[1].DiscountPercentage"
+            ]
+        );
+        assert_eq!(
+            fields(invoice.validate_with(&context)),
+            [
+                "Rows[0].Quantity",
+                "Rows[0].VatRateId",
+                "Rows[1].Text",
+                "Rows[1].DiscountPercentage"
+            ]
+        );
+
+        let empty = InvoiceCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse().unwrap())
+            .currency_code("kronor".to_string());
+        assert_eq!(fields(empty.validate()), ["CurrencyCode", "Rows"]);
+    }
+
+    #[test]
+    fn test_article_supplier_and_voucher() {
+        let article = ArticleCreate::new("Consulting".to_string()).sales_price(crate::money!(-1.0));
+        assert_eq!(fields(article.validate()), ["SalesPrice"]);
+
+        let supplier = SupplierCreate::new("Office Supplies AB".to_string())
+            .bank_giro_number("5050-1055".to_string())
+            .plus_giro_number("447925-1".to_string())
+            .bank_account_number("0100-1234567".to_string());
+        let errors = supplier.validate().unwrap_err();
+        assert_eq!(errors[0].field, "BankAccountNumber");
+        assert_eq!(errors[0].message, "Unknown clearing number");
+        assert_eq!(errors[1].field, "PlusGiroNumber");
+
+        let date = "2024-03-01T00:00:00Z".parse().unwrap();
+        let voucher = VoucherCreate::new(date, "Rent".to_string())
+            .row(VoucherRowCreate::debit(
+                "5010".to_string(),
+                crate::money!(100.0),
+            ))
+            .row(VoucherRowCreate::credit(
+                "1930".to_string(),
+                crate::money!(90.0),
+            ));
+        assert_eq!(fields(voucher.validate()), ["Rows"]);
+
+        let year = FiscalYearCreate::new(date, date);
+        assert_eq!(fields(year.validate()), ["EndDate"]);
+    }
+}
@@ -0,0 +1,654 @@
+//! Balanced voucher builder with accounting checks.
+//!
+//! A [`VoucherBuilder`] collects debit and credit rows and produces a
+//! [`VoucherCreate`] only if the voucher would be accepted by the API:
+//!
+//! - Every row has exactly one positive debit or credit amount in whole
+//!   minor units (öre), and debits equal credits. Sums are kept as integer
+//!   minor units, so the check is exact under both `Money` backends.
+//! - With a [`Ledger`], the voucher date falls in a fiscal year that is not
+//!   locked, every account is in that year's chart of accounts and active,
+//!   and every cost center item and project exists.
+//!
+//! All problems are reported at once as [`VoucherError`]s, which convert to
+//! [`ValidationError`]s with the same field paths the API uses.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use spiris::voucher::{Ledger, VoucherBuilder};
+//! use spiris::{money, AccessToken, Client};
+//! use chrono::Utc;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token".to_string(), 3600, None));
+//! let ledger = Ledger::fetch(&client).await?;
+//!
+//! let voucher = VoucherBuilder::new(Utc::now(), "Office rent")
+//!     .debit("5010", money!(12000.0))
+//!     .credit("1930", money!(12000.0))
+//!     .build_checked(&ledger)
+//!     .map_err(|errors| errors[0].to_string())?;
+//!
+//! client.vouchers().create(&voucher).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::codes::VoucherType;
+use crate::error::{Result, ValidationError};
+use crate::ids::{CostCenterItemId, FiscalYearId, ProjectId};
+use crate::pagination::fetch_all;
+use crate::types::{
+    Account, CostCenterItem, FiscalYear, Money, Project, VoucherCreate, VoucherRowCreate,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Items requested per page when fetching the ledger.
+const PAGE_SIZE: u32 = 500;
+
+/// A reason a voucher would be rejected.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum VoucherError {
+    /// The voucher has no rows.
+    #[error("voucher has no rows")]
+    NoRows,
+
+    /// A row has both or neither of debit and credit amount.
+    #[error("row {row}: set exactly one of debit and credit amount")]
+    AmbiguousAmount {
+        /// Row index.
+        row: usize,
+    },
+
+    /// A row amount is zero or negative.
+    #[error("row {row}: amount must be positive")]
+    NonPositiveAmount {
+        /// Row index.
+        row: usize,
+    },
+
+    /// A row amount has fractions of the minor unit, such as 10.005.
+    #[error("row {row}: amount has more than two decimals")]
+    FractionalAmount {
+        /// Row index.
+        row: usize,
+    },
+
+    /// Total debits differ from total credits.
+    #[error("debits ({debit}) do not equal credits ({credit})")]
+    Unbalanced {
+        /// Sum of debit amounts.
+        debit: Money,
+        /// Sum of credit amounts.
+        credit: Money,
+    },
+
+    /// No fiscal year covers the voucher date.
+    #[error("no fiscal year covers {date}")]
+    NoFiscalYear {
+        /// The voucher date.
+        date: NaiveDate,
+    },
+
+    /// The fiscal year covering the voucher date is locked.
+    #[error("fiscal year covering {date} is locked")]
+    LockedFiscalYear {
+        /// The voucher date.
+        date: NaiveDate,
+        /// The locked fiscal year.
+        fiscal_year_id: Option<FiscalYearId>,
+    },
+
+    /// An account is not in the chart of accounts.
+    #[error("row {row}: account {account} is not in the chart of accounts")]
+    UnknownAccount {
+        /// Row index.
+        row: usize,
+        /// Account number.
+        account: String,
+    },
+
+    /// An account is inactive.
+    #[error("row {row}: account {account} is inactive")]
+    InactiveAccount {
+        /// Row index.
+        row: usize,
+        /// Account number.
+        account: String,
+    },
+
+    /// A cost center item does not exist.
+    #[error("row {row}: cost center item {id} does not exist")]
+    UnknownCostCenterItem {
+        /// Row index.
+        row: usize,
+        /// Cost center item ID.
+        id: CostCenterItemId,
+    },
+
+    /// A project does not exist.
+    #[error("row {row}: project {id} does not exist")]
+    UnknownProject {
+        /// Row index.
+        row: usize,
+        /// Project ID.
+        id: ProjectId,
+    },
+}
+
+impl VoucherError {
+    /// API field path the error refers to, e.g. `Rows[1].AccountNumber`.
+    pub fn field(&self) -> String {
+        match self {
+            VoucherError::NoRows | VoucherError::Unbalanced { .. } => "Rows".to_string(),
+            VoucherError::AmbiguousAmount { row }
+            | VoucherError::NonPositiveAmount { row }
+            | VoucherError::FractionalAmount { row } => format!("Rows[{}].DebitAmount", row),
+            VoucherError::NoFiscalYear { .. } | VoucherError::LockedFiscalYear { .. } => {
+                "VoucherDate".to_string()
+            }
+            VoucherError::UnknownAccount { row, .. }
+            | VoucherError::InactiveAccount { row, .. } => {
+                format!("Rows[{}].AccountNumber", row)
+            }
+            VoucherError::UnknownCostCenterItem { row, .. } => {
+                format!("Rows[{}].CostCenterItemId", row)
+            }
+            VoucherError::UnknownProject { row, .. } => format!("Rows[{}].ProjectId", row),
+        }
+    }
+}
+
+impl From<&VoucherError> for ValidationError {
+    fn from(error: &VoucherError) -> Self {
+        ValidationError {
+            field: error.field(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<VoucherError> for ValidationError {
+    fn from(error: VoucherError) -> Self {
+        ValidationError::from(&error)
+    }
+}
+
+/// Chart of accounts, fiscal years, cost center items and projects used to
+/// check vouchers.
+///
+/// Fetch it once with [`Ledger::fetch`] and reuse it for many vouchers.
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    accounts: Vec<Account>,
+    fiscal_years: Vec<FiscalYear>,
+    cost_center_items: HashSet<CostCenterItemId>,
+    projects: HashSet<ProjectId>,
+}
+
+impl Ledger {
+    /// Create a ledger from already fetched data.
+    pub fn new(
+        accounts: Vec<Account>,
+        fiscal_years: Vec<FiscalYear>,
+        cost_center_items: Vec<CostCenterItem>,
+        projects: Vec<Project>,
+    ) -> Self {
+        Self {
+            accounts,
+            fiscal_years,
+            cost_center_items: cost_center_items.into_iter().filter_map(|i| i.id).collect(),
+            projects: projects.into_iter().filter_map(|p| p.id).collect(),
+        }
+    }
+
+    /// Fetch all fiscal years, the chart of accounts of every unlocked
+    /// fiscal year, and all cost center items and projects.
+    pub async fn fetch(client: &Client) -> Result<Self> {
+        let fiscal_years_endpoint = client.fiscal_years();
+        let fiscal_years =
+            fetch_all(PAGE_SIZE, |params| fiscal_years_endpoint.list(Some(params))).await?;
+
+        let accounts_endpoint = client.accounts();
+        let mut accounts = Vec::new();
+        for fiscal_year in &fiscal_years {
+            if fiscal_year.is_locked == Some(true) {
+                continue;
+            }
+            let Some(id) = &fiscal_year.id else {
+                continue;
+            };
+            let mut year = fetch_all(PAGE_SIZE, |params| {
+                accounts_endpoint.list_by_fiscal_year(id, Some(params))
+            })
+            .await?;
+            for account in &mut year {
+                account.fiscal_year_id.get_or_insert_with(|| id.clone());
+            }
+            accounts.extend(year);
+        }
+
+        let cost_centers = client.cost_centers();
+        let cost_center_items =
+            fetch_all(PAGE_SIZE, |params| cost_centers.list_items(Some(params))).await?;
+        let projects_endpoint = client.projects();
+        let projects = fetch_all(PAGE_SIZE, |params| projects_endpoint.list(Some(params))).await?;
+
+        Ok(Self::new(
+            accounts,
+            fiscal_years,
+            cost_center_items,
+            projects,
+        ))
+    }
+
+    /// The fiscal year whose start and end dates include `date`.
+    pub fn fiscal_year_for(&self, date: NaiveDate) -> Option<&FiscalYear> {
+        self.fiscal_years.iter().find(|year| {
+            let starts = year
+                .start_date
+                .is_none_or(|start| start.date_naive() <= date);
+            let ends = year.end_date.is_none_or(|end| date <= end.date_naive());
+            starts && ends
+        })
+    }
+
+    /// Look up an account in the chart of accounts of a fiscal year.
+    ///
+    /// Accounts without a fiscal year match any year.
+    pub fn account(
+        &self,
+        fiscal_year_id: Option<&FiscalYearId>,
+        account_number: &str,
+    ) -> Option<&Account> {
+        self.accounts.iter().find(|account| {
+            account.account_number.as_deref() == Some(account_number)
+                && match (fiscal_year_id, &account.fiscal_year_id) {
+                    (Some(wanted), Some(year)) => wanted == year,
+                    _ => true,
+                }
+        })
+    }
+
+    fn check(&self, voucher: &VoucherCreate, errors: &mut Vec<VoucherError>) {
+        let date = voucher.voucher_date.date_naive();
+        // The chart of accounts is only fetched for open years, so the rows'
+        // accounts are checked only when the voucher falls in one.
+        let fiscal_year_id = match self.fiscal_year_for(date) {
+            None => {
+                errors.push(VoucherError::NoFiscalYear { date });
+                None
+            }
+            Some(year) if year.is_locked == Some(true) => {
+                errors.push(VoucherError::LockedFiscalYear {
+                    date,
+                    fiscal_year_id: year.id.clone(),
+                });
+                None
+            }
+            Some(year) => Some(year.id.as_ref()),
+        };
+
+        for (row, item) in voucher.rows.iter().enumerate() {
+            if let Some(fiscal_year_id) = fiscal_year_id {
+                match self.account(fiscal_year_id, &item.account_number) {
+                    None => errors.push(VoucherError::UnknownAccount {
+                        row,
+                        account: item.account_number.clone(),
+                    }),
+                    Some(account) if account.is_active == Some(false) => {
+                        errors.push(VoucherError::InactiveAccount {
+                            row,
+                            account: item.account_number.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+            if let Some(id) = &item.cost_center_item_id {
+                if !self.cost_center_items.contains(id) {
+                    errors.push(VoucherError::UnknownCostCenterItem {
+                        row,
+                        id: id.clone(),
+                    });
+                }
+            }
+            if let Some(id) = &item.project_id {
+                if !self.projects.contains(id) {
+                    errors.push(VoucherError::UnknownProject {
+                        row,
+                        id: id.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Builds a [`VoucherCreate`] whose debits equal its credits.
+///
+/// # Example
+///
+/// ```
+/// use spiris::voucher::VoucherBuilder;
+/// use spiris::money;
+///
+/// let builder = VoucherBuilder::new(chrono::Utc::now(), "Office rent")
+///     .debit("5010", money!(12000.0))
+///     .credit("1930", money!(11000.0));
+/// assert!(!builder.is_balanced());
+///
+/// let voucher = builder.credit("2640", money!(1000.0)).build().unwrap();
+/// assert_eq!(voucher.rows.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VoucherBuilder {
+    voucher: VoucherCreate,
+}
+
+impl VoucherBuilder {
+    /// Start a voucher with a date and text.
+    pub fn new(voucher_date: DateTime<Utc>, voucher_text: impl Into<String>) -> Self {
+        Self {
+            voucher: VoucherCreate::new(voucher_date, voucher_text.into()),
+        }
+    }
+
+    /// Set the voucher type.
+    pub fn voucher_type(mut self, value: VoucherType) -> Self {
+        self.voucher.voucher_type = Some(value);
+        self
+    }
+
+    /// Add a row debiting `amount` to an account.
+    pub fn debit(self, account_number: impl Into<String>, amount: Money) -> Self {
+        self.row(VoucherRowCreate::debit(account_number.into(), amount))
+    }
+
+    /// Add a row crediting `amount` to an account.
+    pub fn credit(self, account_number: impl Into<String>, amount: Money) -> Self {
+        self.row(VoucherRowCreate::credit(account_number.into(), amount))
+    }
+
+    /// Add a prepared row, e.g. one with a cost center item or project.
+    pub fn row(mut self, row: VoucherRowCreate) -> Self {
+        self.voucher.rows.push(row);
+        self
+    }
+
+    /// Sum of debit and credit amounts, in that order.
+    pub fn totals(&self) -> (Money, Money) {
+        let (debit, credit) = self.minor_totals();
+        (from_minor_units(debit), from_minor_units(credit))
+    }
+
+    /// Whether debits equal credits.
+    pub fn is_balanced(&self) -> bool {
+        let (debit, credit) = self.minor_totals();
+        debit == credit
+    }
+
+    /// List the problems found without contacting the API.
+    pub fn check(&self) -> Vec<VoucherError> {
+        check_rows(&self.voucher.rows)
+    }
+
+    /// List the problems found, including those against the ledger.
+    pub fn check_against(&self, ledger: &Ledger) -> Vec<VoucherError> {
+        let mut errors = self.check();
+        ledger.check(&self.voucher, &mut errors);
+        errors
+    }
+
+    /// Build the voucher if [`check`](Self::check) finds no problems.
+    pub fn build(self) -> std::result::Result<VoucherCreate, Vec<VoucherError>> {
+        let errors = self.check();
+        if errors.is_empty() {
+            Ok(self.voucher)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Build the voucher if [`check_against`](Self::check_against) finds no
+    /// problems.
+    pub fn build_checked(
+        self,
+        ledger: &Ledger,
+    ) -> std::result::Result<VoucherCreate, Vec<VoucherError>> {
+        let errors = self.check_against(ledger);
+        if errors.is_empty() {
+            Ok(self.voucher)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn minor_totals(&self) -> (i64, i64) {
+        minor_totals(&self.voucher.rows)
+    }
+}
+
+/// Check the rows of a voucher on their own, as [`VoucherBuilder::check`]
+/// does.
+pub(crate) fn check_rows(rows: &[VoucherRowCreate]) -> Vec<VoucherError> {
+    let mut errors = Vec::new();
+    if rows.is_empty() {
+        errors.push(VoucherError::NoRows);
+    }
+
+    for (row, item) in rows.iter().enumerate() {
+        let amount = match (item.debit_amount, item.credit_amount) {
+            (Some(amount), None) | (None, Some(amount)) => amount,
+            _ => {
+                errors.push(VoucherError::AmbiguousAmount { row });
+                continue;
+            }
+        };
+        match to_minor_units(amount) {
+            None => errors.push(VoucherError::FractionalAmount { row }),
+            Some(minor) if minor <= 0 => errors.push(VoucherError::NonPositiveAmount { row }),
+            Some(_) => {}
+        }
+    }
+
+    let (debit, credit) = minor_totals(rows);
+    if debit != credit {
+        errors.push(VoucherError::Unbalanced {
+            debit: from_minor_units(debit),
+            credit: from_minor_units(credit),
+        });
+    }
+    errors
+}
+
+/// Sum debits and credits in minor units, skipping amounts that are not
+/// whole minor units.
+fn minor_totals(rows: &[VoucherRowCreate]) -> (i64, i64) {
+    rows.iter().fold((0, 0), |(debit, credit), row| {
+        let minor = |amount: Option<Money>| amount.and_then(to_minor_units).unwrap_or(0);
+        (
+            debit + minor(row.debit_amount),
+            credit + minor(row.credit_amount),
+        )
+    })
+}
+
+/// Convert an amount to whole minor units, or `None` if it has more than
+/// two decimals.
+#[cfg(feature = "decimal")]
+fn to_minor_units(amount: Money) -> Option<i64> {
+    use rust_decimal::prelude::ToPrimitive;
+
+    let minor = amount * Money::ONE_HUNDRED;
+    if minor.fract().is_zero() {
+        minor.to_i64()
+    } else {
+        None
+    }
+}
+
+/// Convert an amount to whole minor units, or `None` if it has more than
+/// two decimals.
+///
+/// `f64` cannot represent most decimal amounts exactly, so amounts within a
+/// millionth of a minor unit of a whole number are accepted.
+#[cfg(not(feature = "decimal"))]
+fn to_minor_units(amount: Money) -> Option<i64> {
+    let minor = amount * 100.0;
+    let rounded = minor.round();
+    if (minor - rounded).abs() < 1e-6 && rounded.abs() < i64::MAX as f64 {
+        Some(rounded as i64)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "decimal")]
+fn from_minor_units(minor: i64) -> Money {
+    Money::new(minor, 2)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn from_minor_units(minor: i64) -> Money {
+    minor as f64 / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money;
+    use chrono::TimeZone;
+
+    const FY: &str = "f0000000-0000-4000-8000-000000000001";
+    const ITEM: &str = "c0000000-0000-4000-8000-000000000001";
+
+    fn date(month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn account(number: &str, active: bool) -> Account {
+        Account {
+            account_number: Some(number.to_string()),
+            fiscal_year_id: Some(FY.parse().unwrap()),
+            is_active: Some(active),
+            ..Default::default()
+        }
+    }
+
+    fn ledger(locked: bool) -> Ledger {
+        let fiscal_year = FiscalYear {
+            id: Some(FY.parse().unwrap()),
+            start_date: Some(date(1, 1)),
+            end_date: Some(date(12, 31)),
+            is_locked: Some(locked),
+            ..Default::default()
+        };
+        let item = CostCenterItem {
+            id: Some(ITEM.parse().unwrap()),
+            ..Default::default()
+        };
+        Ledger::new(
+            vec![
+                account("1930", true),
+                account("5010", true),
+                account("5011", false),
+            ],
+            vec![fiscal_year],
+            vec![item],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_exact_balance() {
+        let builder = VoucherBuilder::new(date(3, 1), "Split")
+            .debit("5010", money!(0.1))
+            .debit("5010", money!(0.2))
+            .credit("1930", money!(0.3));
+
+        assert!(builder.is_balanced());
+        assert_eq!(builder.totals(), (money!(0.3), money!(0.3)));
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_row_problems() {
+        let both = VoucherRowCreate {
+            credit_amount: Some(money!(1.0)),
+            ..VoucherRowCreate::debit("1930".to_string(), money!(1.0))
+        };
+        let errors = VoucherBuilder::new(date(3, 1), "Bad rows")
+            .row(both)
+            .debit("5010", money!(0.005))
+            .credit("1930", money!(-5.0))
+            .check();
+
+        assert_eq!(errors[0], VoucherError::AmbiguousAmount { row: 0 });
+        assert_eq!(errors[1], VoucherError::FractionalAmount { row: 1 });
+        assert_eq!(errors[2], VoucherError::NonPositiveAmount { row: 2 });
+        assert!(matches!(errors[3], VoucherError::Unbalanced { .. }));
+        assert!(VoucherBuilder::new(date(3, 1), "Empty")
+            .check()
+            .contains(&VoucherError::NoRows));
+    }
+
+    #[test]
+    fn test_ledger_checks() {
+        let project: ProjectId = "b0000000-0000-4000-8000-000000000001".parse().unwrap();
+        let builder = VoucherBuilder::new(date(3, 1), "Rent")
+            .row(
+                VoucherRowCreate::debit("5010".to_string(), money!(100.0))
+                    .cost_center_item_id(ITEM.parse().unwrap())
+                    .project_id(project.clone()),
+            )
+            .debit("5011", money!(50.0))
+            .credit("9999", money!(150.0));
+
+        let errors = builder.check_against(&ledger(false));
+        assert_eq!(
+            errors,
+            vec![
+                VoucherError::UnknownProject {
+                    row: 0,
+                    id: project
+                },
+                VoucherError::InactiveAccount {
+                    row: 1,
+                    account: "5011".to_string()
+                },
+                VoucherError::UnknownAccount {
+                    row: 2,
+                    account: "9999".to_string()
+                },
+            ]
+        );
+        assert_eq!(errors[2].field(), "Rows[2].AccountNumber");
+    }
+
+    #[test]
+    fn test_fiscal_year_checks() {
+        let builder = |at| {
+            VoucherBuilder::new(at, "Rent")
+                .debit("5010", money!(100.0))
+                .credit("1930", money!(100.0))
+        };
+
+        assert!(builder(date(12, 31)).build_checked(&ledger(false)).is_ok());
+        assert!(matches!(
+            builder(date(6, 1)).check_against(&ledger(true))[..],
+            [VoucherError::LockedFiscalYear { .. }]
+        ));
+
+        let outside = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let errors = builder(outside).check_against(&ledger(false));
+        assert!(matches!(errors[..], [VoucherError::NoFiscalYear { .. }]));
+
+        let validation = ValidationError::from(&errors[0]);
+        assert_eq!(validation.field, "VoucherDate");
+    }
+}
@@ -4,7 +4,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::Account;
 
 #[tokio::test]
@@ -29,7 +29,7 @@ async fn test_list_accounts() {
                 "AccountNumber": "3000",
                 "Name": "Försäljning",
                 "IsActive": true,
-                "VatCodeId": "vat-25"
+                "VatCodeId": "fa000000-0000-4000-8000-000000000025"
             }
         ],
         "Meta": {
@@ -50,7 +50,10 @@ async fn test_list_accounts() {
     assert_eq!(result.data.len(), 3);
     assert_eq!(result.data[0].account_number, Some("1910".to_string()));
     assert_eq!(result.data[0].name, Some("Kassa".to_string()));
-    assert_eq!(result.data[2].vat_code_id, Some("vat-25".to_string()));
+    assert_eq!(
+        result.data[2].vat_code_id,
+        Some("fa000000-0000-4000-8000-000000000025".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -62,12 +65,20 @@ async fn test_get_account() {
         "Name": "Företagskonto",
         "IsActive": true,
         "VatCodeId": null,
-        "FiscalYearId": "fy-2024"
+        "FiscalYearId": "f1000000-0000-4000-8000-000000002024"
     }"#;
 
-    let mock = api.mock_get("/accounts/fy-2024/1930", response_body);
+    let mock = api.mock_get(
+        "/accounts/f1000000-0000-4000-8000-000000002024/1930",
+        response_body,
+    );
 
-    let result = api.client.accounts().get("fy-2024", "1930").await.unwrap();
+    let result = api
+        .client
+        .accounts()
+        .get(&id("f1000000-0000-4000-8000-000000002024"), "1930")
+        .await
+        .unwrap();
 
     mock.assert();
     assert_eq!(result.account_number, Some("1930".to_string()));
@@ -110,7 +121,10 @@ async fn test_update_account() {
         "IsActive": true
     }"#;
 
-    let mock = api.mock_put("/accounts/fy-2024/4000", response_body);
+    let mock = api.mock_put(
+        "/accounts/f1000000-0000-4000-8000-000000002024/4000",
+        response_body,
+    );
 
     let updated_account = Account {
         account_number: Some("4000".to_string()),
@@ -121,7 +135,11 @@ async fn test_update_account() {
     let result = api
         .client
         .accounts()
-        .update("fy-2024", "4000", &updated_account)
+        .update(
+            &id("f1000000-0000-4000-8000-000000002024"),
+            "4000",
+            &updated_account,
+        )
         .await
         .unwrap();
 
@@ -4,7 +4,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Article, PaginationParams, QueryParams};
 
 #[tokio::test]
@@ -14,7 +14,7 @@ async fn test_list_articles() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "art-001",
+                "Id": "a0000000-0000-4000-8000-000000000001",
                 "ArticleNumber": "PROD-001",
                 "Name": "Widget A",
                 "SalesPrice": 199.99,
@@ -22,7 +22,7 @@ async fn test_list_articles() {
                 "IsActive": true
             },
             {
-                "Id": "art-002",
+                "Id": "a0000000-0000-4000-8000-000000000002",
                 "ArticleNumber": "PROD-002",
                 "Name": "Widget B",
                 "SalesPrice": 299.99,
@@ -43,7 +43,10 @@ async fn test_list_articles() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("art-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].name, Some("Widget A".to_string()));
     assert_eq!(result.data[0].sales_price, Some(199.99));
 }
@@ -53,22 +56,33 @@ async fn test_get_article() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "art-123",
+        "Id": "a0000000-0000-4000-8000-000000000123",
         "ArticleNumber": "SERV-001",
         "Name": "Consulting Hour",
         "Unit": "hour",
         "SalesPrice": 1500.0,
         "PurchasePrice": 0.0,
         "IsActive": true,
-        "VatRateId": "vat-25"
+        "VatRateId": "fa000000-0000-4000-8000-000000000025"
     }"#;
 
-    let mock = api.mock_get("/articles/art-123", response_body);
+    let mock = api.mock_get(
+        "/articles/a0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.articles().get("art-123").await.unwrap();
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("art-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("a0000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.name, Some("Consulting Hour".to_string()));
     assert_eq!(result.unit, Some("hour".to_string()));
     assert_eq!(result.sales_price, Some(1500.0));
@@ -79,7 +93,7 @@ async fn test_create_article() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "art-new-001",
+        "Id": "a0000000-0000-4000-0001-000000000001",
         "ArticleNumber": "NEW-001",
         "Name": "New Product",
         "SalesPrice": 500.0,
@@ -98,7 +112,10 @@ async fn test_create_article() {
     let result = api.client.articles().create(&new_article).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("art-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("a0000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.article_number, Some("NEW-001".to_string()));
 }
 
@@ -107,17 +124,20 @@ async fn test_update_article() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "art-123",
+        "Id": "a0000000-0000-4000-8000-000000000123",
         "ArticleNumber": "PROD-001",
         "Name": "Updated Product Name",
         "SalesPrice": 599.99,
         "IsActive": true
     }"#;
 
-    let mock = api.mock_put("/articles/art-123", response_body);
+    let mock = api.mock_put(
+        "/articles/a0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_article = Article {
-        id: Some("art-123".to_string()),
+        id: Some("a0000000-0000-4000-8000-000000000123".parse().unwrap()),
         name: Some("Updated Product Name".to_string()),
         sales_price: Some(599.99),
         ..Default::default()
@@ -126,7 +146,10 @@ async fn test_update_article() {
     let result = api
         .client
         .articles()
-        .update("art-123", &updated_article)
+        .update(
+            &id("a0000000-0000-4000-8000-000000000123"),
+            &updated_article,
+        )
         .await
         .unwrap();
 
@@ -139,9 +162,13 @@ async fn test_update_article() {
 async fn test_delete_article() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/articles/art-123");
+    let mock = api.mock_delete("/articles/a0000000-0000-4000-8000-000000000123");
 
-    let result = api.client.articles().delete("art-123").await;
+    let result = api
+        .client
+        .articles()
+        .delete(&id("a0000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -153,7 +180,7 @@ async fn test_search_articles() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "art-active-1", "Name": "Active Article", "IsActive": true}
+            {"Id": "a0000000-0000-4000-0002-000000000001", "Name": "Active Article", "IsActive": true}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -182,7 +209,7 @@ async fn test_list_articles_with_pagination() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "art-101", "Name": "Article 101", "SalesPrice": 100.0}
+            {"Id": "a0000000-0000-4000-8000-000000000101", "Name": "Article 101", "SalesPrice": 100.0}
         ],
         "Meta": {
             "CurrentPage": 2,
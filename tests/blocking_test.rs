@@ -0,0 +1,146 @@
+//! Tests for the blocking client.
+//!
+//! Run with: `cargo test --features blocking blocking_test`
+
+#![cfg(feature = "blocking")]
+
+use mockito::Matcher;
+use spiris::blocking::Client;
+use spiris::{AccessToken, ClientConfig, Customer, Error, PaginationParams, RetryConfig};
+
+fn client(server: &mockito::Server) -> Client {
+    let token = AccessToken::new("test_token".to_string(), 3600, None);
+    let config = ClientConfig::new()
+        .base_url(server.url())
+        .retry_config(RetryConfig::new().max_retries(0));
+    Client::with_config(token, config)
+}
+
+#[test]
+fn test_blocking_list_customers() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/customers")
+        .match_header("Authorization", "Bearer test_token")
+        .match_query(Matcher::UrlEncoded("pagesize".into(), "10".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"Data": [{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Acme"}],
+                "Meta": {"CurrentPage": 0, "PageSize": 10, "TotalPages": 1, "TotalCount": 1,
+                         "HasNextPage": false, "HasPreviousPage": false}}"#,
+        )
+        .create();
+
+    let client = client(&server);
+    let customers = client
+        .customers()
+        .list(Some(PaginationParams::new().pagesize(10)))
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(customers.data.len(), 1);
+    assert_eq!(customers.data[0].name.as_deref(), Some("Acme"));
+}
+
+#[test]
+fn test_blocking_create_and_delete() {
+    let mut server = mockito::Server::new();
+    let create = server
+        .mock("POST", "/customers")
+        .match_body(Matcher::PartialJson(serde_json::json!({"Name": "Acme"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Acme"}"#)
+        .create();
+    let delete = server
+        .mock("DELETE", "/customers/c0000000-0000-4001-8000-000000000001")
+        .with_status(204)
+        .create();
+
+    let client = client(&server);
+    let customer = Customer {
+        name: Some("Acme".to_string()),
+        ..Default::default()
+    };
+    let created = client.customers().create(&customer).unwrap();
+    client
+        .customers()
+        .delete(&"c0000000-0000-4001-8000-000000000001".parse().unwrap())
+        .unwrap();
+
+    create.assert();
+    delete.assert();
+    assert_eq!(
+        created.id.as_deref(),
+        Some("c0000000-0000-4001-8000-000000000001")
+    );
+}
+
+#[test]
+fn test_blocking_macro_endpoint_and_extra_methods() {
+    let mut server = mockito::Server::new();
+    let unit = server
+        .mock("GET", "/units/d0000000-0000-4000-8000-000000000001")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "d0000000-0000-4000-8000-000000000001", "Name": "Styck"}"#)
+        .create();
+    let pdf = server
+        .mock(
+            "GET",
+            "/customerinvoices/f0000000-0000-4001-8000-000000000001/pdf",
+        )
+        .with_status(200)
+        .with_body(b"%PDF-1.4")
+        .create();
+
+    let client = client(&server);
+    let fetched = client
+        .units()
+        .get(&"d0000000-0000-4000-8000-000000000001".parse().unwrap())
+        .unwrap();
+    let bytes = client
+        .invoices()
+        .get_pdf(&"f0000000-0000-4001-8000-000000000001".parse().unwrap())
+        .unwrap();
+
+    unit.assert();
+    pdf.assert();
+    assert_eq!(fetched.name.as_deref(), Some("Styck"));
+    assert_eq!(bytes, b"%PDF-1.4");
+}
+
+#[test]
+fn test_blocking_error() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/customers/c0000000-0000-4000-dead-000000000000")
+        .with_status(404)
+        .with_body(r#"{"Message": "Not found"}"#)
+        .create();
+
+    let client = client(&server);
+    let result = client
+        .customers()
+        .get(&"c0000000-0000-4000-dead-000000000000".parse().unwrap());
+
+    mock.assert();
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 404,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_blocking_from_async_shares_token() {
+    let token = AccessToken::new("first".to_string(), 3600, None);
+    let client = Client::from_async(spiris::Client::new(token)).unwrap();
+
+    client.set_access_token(AccessToken::new("second".to_string(), 3600, None));
+    assert_eq!(client.as_async().get_access_token().token, "second");
+    assert!(!client.is_token_expired());
+}
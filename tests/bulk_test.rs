@@ -0,0 +1,186 @@
+//! Integration tests for bulk operations.
+#![cfg(feature = "bulk")]
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use mockito::{Matcher, Mock};
+use spiris::bulk::{Bulk, BulkOperation, BulkOptions, BulkReport, OperationKind, Outcome};
+use spiris::Customer;
+
+fn customer(name: &str) -> Customer {
+    Customer {
+        name: Some(name.to_string()),
+        ..Default::default()
+    }
+}
+
+fn mock_create(api: &mut MockApi, name: &str, status: usize, body: &str, hits: usize) -> Mock {
+    api.server
+        .mock("POST", "/customers")
+        .match_body(Matcher::PartialJsonString(format!(
+            r#"{{"Name": "{}"}}"#,
+            name
+        )))
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(hits)
+        .create()
+}
+
+const INVALID: &str = r#"{"Message": "Validation failed", "ValidationErrors": [{"Field": "Email", "Message": "Invalid email"}]}"#;
+
+#[tokio::test]
+async fn test_bulk_reports_every_item() {
+    let mut api = MockApi::new().await;
+
+    let created = mock_create(
+        &mut api,
+        "Acme",
+        201,
+        r#"{"Id": "b0000000-0000-4000-8000-000000000001", "Name": "Acme"}"#,
+        1,
+    );
+    let rejected = mock_create(&mut api, "Broken", 400, INVALID, 1);
+    let updated = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4002-8000-000000000001")
+        .with_status(200)
+        .with_body(r#"{"Id": "c0000000-0000-4002-8000-000000000001"}"#)
+        .create();
+    let deleted = api
+        .server
+        .mock("DELETE", "/customers/c0000000-0000-4002-8000-000000000002")
+        .with_status(204)
+        .create();
+
+    let operations = vec![
+        BulkOperation::Create(customer("Acme")),
+        BulkOperation::Create(customer("Broken")),
+        BulkOperation::Update(
+            id("c0000000-0000-4002-8000-000000000001"),
+            customer("Renamed"),
+        ),
+        BulkOperation::Delete(id("c0000000-0000-4002-8000-000000000002")),
+    ];
+    let report = Bulk::with_options(api.client.customers(), BulkOptions::new().concurrency(3))
+        .run(operations)
+        .await;
+
+    created.assert();
+    rejected.assert();
+    updated.assert();
+    deleted.assert();
+
+    assert_eq!(report.succeeded(), 3);
+    assert_eq!(report.failed(), 1);
+    let indexes: Vec<_> = report.items.iter().map(|item| item.index).collect();
+    assert_eq!(indexes, vec![0, 1, 2, 3]);
+
+    assert_eq!(
+        report.items[0].outcome,
+        Outcome::Succeeded {
+            id: Some("b0000000-0000-4000-8000-000000000001".parse().unwrap())
+        }
+    );
+    match &report.items[1].outcome {
+        Outcome::Failed {
+            status_code,
+            validation_errors,
+            ..
+        } => {
+            assert_eq!(*status_code, Some(400));
+            assert_eq!(validation_errors[0].field, "Email");
+        }
+        other => panic!("expected a failure, got {:?}", other),
+    }
+    assert_eq!(report.items[3].kind, OperationKind::Delete);
+    assert_eq!(
+        report.items[3].target_id.as_deref(),
+        Some("c0000000-0000-4002-8000-000000000002")
+    );
+
+    let json = serde_json::to_string(&report).unwrap();
+    let restored: BulkReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, report);
+}
+
+#[tokio::test]
+async fn test_resume_only_reruns_unsuccessful_items() {
+    let mut api = MockApi::new().await;
+
+    let first = mock_create(
+        &mut api,
+        "Acme",
+        201,
+        r#"{"Id": "b0000000-0000-4000-8000-000000000001"}"#,
+        1,
+    );
+    let failing = mock_create(&mut api, "Later", 500, r#"{"Message": "Down"}"#, 1);
+
+    let operations = || {
+        vec![
+            BulkOperation::Create(customer("Acme")),
+            BulkOperation::Create(customer("Later")),
+        ]
+    };
+    let report = Bulk::new(api.client.customers()).run(operations()).await;
+    first.assert();
+    failing.assert();
+    assert_eq!(report.failed(), 1);
+
+    failing.remove();
+    let retried = mock_create(
+        &mut api,
+        "Later",
+        201,
+        r#"{"Id": "b0000000-0000-4000-8000-000000000002"}"#,
+        1,
+    );
+
+    let resumed = Bulk::new(api.client.customers())
+        .resume_from(report)
+        .run(operations())
+        .await;
+
+    // The first customer is not created a second time.
+    first.assert();
+    retried.assert();
+    assert!(resumed.is_success());
+    assert_eq!(
+        resumed.items[1].outcome,
+        Outcome::Succeeded {
+            id: Some("b0000000-0000-4000-8000-000000000002".parse().unwrap())
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_max_failures_skips_remaining_items() {
+    let mut api = MockApi::new().await;
+
+    let rejected = mock_create(&mut api, "Broken", 400, INVALID, 1);
+    let untouched = mock_create(
+        &mut api,
+        "Acme",
+        201,
+        r#"{"Id": "b0000000-0000-4000-8000-000000000001"}"#,
+        0,
+    );
+
+    let operations = vec![
+        BulkOperation::Create(customer("Broken")),
+        BulkOperation::Create(customer("Acme")),
+        BulkOperation::Create(customer("Acme")),
+    ];
+    let options = BulkOptions::new().concurrency(1).max_failures(1);
+    let report = Bulk::with_options(api.client.customers(), options)
+        .run(operations)
+        .await;
+
+    rejected.assert();
+    untouched.assert();
+    assert_eq!(report.failed(), 1);
+    assert_eq!(report.skipped(), 2);
+}
@@ -10,7 +10,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::client::{ClientConfig, DEFAULT_BASE_URL, RATE_LIMIT_PER_MINUTE};
 use spiris::retry::RetryConfig;
 use spiris::{AccessToken, Client, Error};
@@ -487,13 +487,17 @@ async fn test_client_get_method() {
 
     let mock = api
         .server
-        .mock("GET", "/customers/cust-1")
+        .mock("GET", "/customers/c0000000-0000-4001-8000-000000000001")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
         .create();
 
-    let result = api.client.customers().get("cust-1").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4001-8000-000000000001"))
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
@@ -529,13 +533,17 @@ async fn test_client_put_method() {
 
     let mock = api
         .server
-        .mock("PUT", "/customers/cust-1")
+        .mock("PUT", "/customers/c0000000-0000-4001-8000-000000000001")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
         .create();
 
-    let result = api.client.customers().update("cust-1", &customer).await;
+    let result = api
+        .client
+        .customers()
+        .update(&id("c0000000-0000-4001-8000-000000000001"), &customer)
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
@@ -547,11 +555,15 @@ async fn test_client_delete_method() {
 
     let mock = api
         .server
-        .mock("DELETE", "/customers/cust-1")
+        .mock("DELETE", "/customers/c0000000-0000-4001-8000-000000000001")
         .with_status(204)
         .create();
 
-    let result = api.client.customers().delete("cust-1").await;
+    let result = api
+        .client
+        .customers()
+        .delete(&id("c0000000-0000-4001-8000-000000000001"))
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
@@ -569,7 +581,13 @@ async fn test_client_handles_401_unauthorized() {
 
     let result = api.client.customers().list(None).await;
 
-    assert!(matches!(result, Err(Error::AuthError(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 401,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -580,7 +598,13 @@ async fn test_client_handles_403_forbidden() {
 
     let result = api.client.customers().list(None).await;
 
-    assert!(matches!(result, Err(Error::AuthError(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 403,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -589,14 +613,24 @@ async fn test_client_handles_404_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/customers/nonexistent",
+        "/customers/c0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Not found"}"#,
     );
 
-    let result = api.client.customers().get("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
-    assert!(matches!(result, Err(Error::NotFound(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 404,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -612,7 +646,13 @@ async fn test_client_handles_429_rate_limit() {
 
     let result = api.client.customers().list(None).await;
 
-    assert!(matches!(result, Err(Error::RateLimitExceeded(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 429,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -624,7 +664,13 @@ async fn test_client_handles_400_bad_request() {
     let customer = mock_server::fixtures::customer(1);
     let result = api.client.customers().create(&customer).await;
 
-    assert!(matches!(result, Err(Error::InvalidRequest(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 400,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -685,13 +731,17 @@ async fn test_client_builds_correct_url_for_get_by_id() {
 
     let mock = api
         .server
-        .mock("GET", "/customers/test-id-123")
+        .mock("GET", "/customers/7e000000-0000-4000-0004-000000000123")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
         .create();
 
-    let result = api.client.customers().get("test-id-123").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("7e000000-0000-4000-0004-000000000123"))
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
@@ -732,14 +782,18 @@ async fn test_put_sends_content_type_json() {
 
     let mock = api
         .server
-        .mock("PUT", "/customers/cust-1")
+        .mock("PUT", "/customers/c0000000-0000-4001-8000-000000000001")
         .match_header("content-type", "application/json")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
         .create();
 
-    let result = api.client.customers().update("cust-1", &customer).await;
+    let result = api
+        .client
+        .customers()
+        .update(&id("c0000000-0000-4001-8000-000000000001"), &customer)
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
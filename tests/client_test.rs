@@ -2,7 +2,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{AccessToken, Client, ClientConfig};
 
 #[tokio::test]
@@ -38,16 +38,24 @@ async fn test_client_token_update() {
     // First request with original token
     let mock1 = api
         .server
-        .mock("GET", "/customers/cust-1")
+        .mock("GET", "/customers/c0000000-0000-4001-8000-000000000001")
         .match_header("Authorization", "Bearer test_token")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(r#"{"Id": "cust-1", "Name": "Customer 1"}"#)
+        .with_body(r#"{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Customer 1"}"#)
         .create();
 
-    let result1 = api.client.customers().get("cust-1").await.unwrap();
+    let result1 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4001-8000-000000000001"))
+        .await
+        .unwrap();
     mock1.assert();
-    assert_eq!(result1.id, Some("cust-1".to_string()));
+    assert_eq!(
+        result1.id,
+        Some("c0000000-0000-4001-8000-000000000001".parse().unwrap())
+    );
 
     // Update the token
     let new_token = AccessToken::new("new_token".to_string(), 3600, None);
@@ -56,16 +64,24 @@ async fn test_client_token_update() {
     // Second request with new token
     let mock2 = api
         .server
-        .mock("GET", "/customers/cust-2")
+        .mock("GET", "/customers/c0000000-0000-4001-8000-000000000002")
         .match_header("Authorization", "Bearer new_token")
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(r#"{"Id": "cust-2", "Name": "Customer 2"}"#)
+        .with_body(r#"{"Id": "c0000000-0000-4001-8000-000000000002", "Name": "Customer 2"}"#)
         .create();
 
-    let result2 = api.client.customers().get("cust-2").await.unwrap();
+    let result2 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4001-8000-000000000002"))
+        .await
+        .unwrap();
     mock2.assert();
-    assert_eq!(result2.id, Some("cust-2".to_string()));
+    assert_eq!(
+        result2.id,
+        Some("c0000000-0000-4001-8000-000000000002".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -152,7 +168,7 @@ async fn test_client_content_type_on_post() {
         .match_header("Content-Type", "application/json")
         .with_status(201)
         .with_header("content-type", "application/json")
-        .with_body(r#"{"Id": "new-cust", "Name": "Test"}"#)
+        .with_body(r#"{"Id": "c0000000-0000-4000-0001-000000000000", "Name": "Test"}"#)
         .create();
 
     let customer = spiris::Customer {
@@ -171,15 +187,22 @@ async fn test_client_clone() {
     let cloned_client = api.client.clone();
 
     let mock = api.mock_get(
-        "/customers/cust-1",
-        r#"{"Id": "cust-1", "Name": "Customer"}"#,
+        "/customers/c0000000-0000-4001-8000-000000000001",
+        r#"{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Customer"}"#,
     );
 
     // Use the cloned client
-    let result = cloned_client.customers().get("cust-1").await.unwrap();
+    let result = cloned_client
+        .customers()
+        .get(&id("c0000000-0000-4001-8000-000000000001"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("cust-1".to_string()));
+    assert_eq!(
+        result.id,
+        Some("c0000000-0000-4001-8000-000000000001".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -0,0 +1,87 @@
+//! Integration tests for conflict-checked updates.
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use spiris::diff::ConditionalUpdate;
+use spiris::Customer;
+
+const ORIGINAL: &str = r#"{"Id": "c0000000-0000-4002-8000-000000000001", "Name": "Acme", "Email": null, "ModifiedUtc": "2024-01-01T10:00:00Z"}"#;
+
+fn original() -> Customer {
+    serde_json::from_str(ORIGINAL).unwrap()
+}
+
+#[tokio::test]
+async fn test_update_sent_when_unmodified() {
+    let mut api = MockApi::new().await;
+
+    let get = api.mock_get("/customers/c0000000-0000-4002-8000-000000000001", ORIGINAL);
+    let put = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4002-8000-000000000001")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "c0000000-0000-4002-8000-000000000001", "Name": "Acme AB"}"#)
+        .create();
+
+    let original = original();
+    let mut edited = original.clone();
+    edited.name = Some("Acme AB".to_string());
+
+    let outcome = api
+        .client
+        .customers()
+        .update_if_unmodified(
+            &id("c0000000-0000-4002-8000-000000000001"),
+            &original,
+            &edited,
+        )
+        .await
+        .unwrap();
+
+    get.assert();
+    put.assert();
+    assert_eq!(outcome.updated().unwrap().name.as_deref(), Some("Acme AB"));
+}
+
+#[tokio::test]
+async fn test_conflict_reported_when_modified() {
+    let mut api = MockApi::new().await;
+
+    let get = api.mock_get(
+        "/customers/c0000000-0000-4002-8000-000000000001",
+        r#"{"Id": "c0000000-0000-4002-8000-000000000001", "Name": "Acme Holding", "ModifiedUtc": "2024-01-02T08:30:00Z"}"#,
+    );
+    let put = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4002-8000-000000000001")
+        .expect(0)
+        .create();
+
+    let original = original();
+    let mut edited = original.clone();
+    edited.name = Some("Acme AB".to_string());
+
+    let outcome = api
+        .client
+        .customers()
+        .update_if_unmodified(
+            &id("c0000000-0000-4002-8000-000000000001"),
+            &original,
+            &edited,
+        )
+        .await
+        .unwrap();
+
+    get.assert();
+    put.assert();
+    let ConditionalUpdate::Conflict(conflict) = outcome else {
+        panic!("expected a conflict");
+    };
+    assert_eq!(conflict.current.name.as_deref(), Some("Acme Holding"));
+    let theirs: Vec<_> = conflict.theirs.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(theirs, vec!["ModifiedUtc", "Name"]);
+    assert_eq!(conflict.overlapping, vec!["Name".to_string()]);
+    assert!(!conflict.is_disjoint());
+}
@@ -10,7 +10,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Customer, PaginationParams};
 
 // =============================================================================
@@ -22,7 +22,7 @@ async fn test_customer_response_has_required_fields() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "CustomerNumber": "C001",
         "Name": "Test Customer",
         "IsActive": true,
@@ -30,9 +30,14 @@ async fn test_customer_response_has_required_fields() {
         "ModifiedUtc": "2024-01-15T10:30:00Z"
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(customer.id.is_some());
     assert!(customer.customer_number.is_some());
@@ -45,7 +50,7 @@ async fn test_customer_response_optional_fields() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "CustomerNumber": "C001",
         "Name": "Full Customer",
         "Email": "test@example.com",
@@ -55,9 +60,14 @@ async fn test_customer_response_optional_fields() {
         "IsActive": true
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert_eq!(customer.email, Some("test@example.com".to_string()));
     assert_eq!(customer.phone, Some("+46701234567".to_string()));
@@ -73,7 +83,7 @@ async fn test_customer_with_address() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Customer With Address",
         "InvoiceAddress": {
             "Address1": "Main Street 1",
@@ -88,9 +98,14 @@ async fn test_customer_with_address() {
         }
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(customer.invoice_address.is_some());
     let invoice_addr = customer.invoice_address.unwrap();
@@ -110,9 +125,9 @@ async fn test_invoice_response_structure() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "InvoiceNumber": "1001",
-        "CustomerId": "cust-123",
+        "CustomerId": "c0000000-0000-4000-8000-000000000123",
         "InvoiceDate": "2024-01-15T00:00:00Z",
         "DueDate": "2024-02-15T00:00:00Z",
         "TotalAmount": 1000.00,
@@ -121,9 +136,17 @@ async fn test_invoice_response_structure() {
         "Rows": []
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-123", json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        json,
+    );
 
-    let invoice = api.client.invoices().get("inv-123").await.unwrap();
+    let invoice = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(invoice.id.is_some());
     assert!(invoice.invoice_number.is_some());
@@ -138,12 +161,12 @@ async fn test_invoice_with_rows() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "InvoiceNumber": "1001",
         "Rows": [
             {
-                "Id": "row-1",
-                "ArticleId": "art-001",
+                "Id": "e0000000-0000-4001-8000-000000000001",
+                "ArticleId": "a0000000-0000-4000-8000-000000000001",
                 "Text": "Product A",
                 "Quantity": 2.0,
                 "UnitPrice": 100.00,
@@ -151,8 +174,8 @@ async fn test_invoice_with_rows() {
                 "TotalAmount": 200.00
             },
             {
-                "Id": "row-2",
-                "ArticleId": "art-002",
+                "Id": "e0000000-0000-4000-8000-000000000002",
+                "ArticleId": "a0000000-0000-4000-8000-000000000002",
                 "Text": "Service B",
                 "Quantity": 1.0,
                 "UnitPrice": 500.00,
@@ -161,14 +184,25 @@ async fn test_invoice_with_rows() {
         ]
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-123", json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        json,
+    );
 
-    let invoice = api.client.invoices().get("inv-123").await.unwrap();
+    let invoice = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert_eq!(invoice.rows.len(), 2);
 
     let row1 = &invoice.rows[0];
-    assert_eq!(row1.article_id, Some("art-001".to_string()));
+    assert_eq!(
+        row1.article_id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(row1.quantity, Some(2.0));
     assert_eq!(row1.unit_price, Some(100.00));
     assert_eq!(row1.total_amount, Some(200.00));
@@ -182,16 +216,24 @@ async fn test_invoice_monetary_values_precision() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "TotalAmount": 999.99,
         "TotalVatAmount": 249.9975,
         "TotalAmountIncludingVat": 1249.9875,
         "Rows": []
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-123", json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        json,
+    );
 
-    let invoice = api.client.invoices().get("inv-123").await.unwrap();
+    let invoice = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert_eq!(invoice.total_amount, Some(999.99));
     // Note: f64 may have precision issues
@@ -208,7 +250,7 @@ async fn test_article_response_structure() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "art-123",
+        "Id": "a0000000-0000-4000-8000-000000000123",
         "ArticleNumber": "A001",
         "Name": "Test Article",
         "SalesPrice": 99.99,
@@ -216,9 +258,14 @@ async fn test_article_response_structure() {
         "IsActive": true
     }"#;
 
-    let _mock = api.mock_get("/articles/art-123", json);
+    let _mock = api.mock_get("/articles/a0000000-0000-4000-8000-000000000123", json);
 
-    let article = api.client.articles().get("art-123").await.unwrap();
+    let article = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(article.id.is_some());
     assert!(article.article_number.is_some());
@@ -232,20 +279,28 @@ async fn test_article_with_vat_rate() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "art-123",
+        "Id": "a0000000-0000-4000-8000-000000000123",
         "ArticleNumber": "A001",
         "Name": "Article with VAT",
         "SalesPrice": 100.00,
-        "VatRateId": "vat-25",
+        "VatRateId": "fa000000-0000-4000-8000-000000000025",
         "Unit": "pcs",
         "IsActive": true
     }"#;
 
-    let _mock = api.mock_get("/articles/art-123", json);
+    let _mock = api.mock_get("/articles/a0000000-0000-4000-8000-000000000123", json);
 
-    let article = api.client.articles().get("art-123").await.unwrap();
+    let article = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
-    assert_eq!(article.vat_rate_id, Some("vat-25".to_string()));
+    assert_eq!(
+        article.vat_rate_id,
+        Some("fa000000-0000-4000-8000-000000000025".parse().unwrap())
+    );
     assert_eq!(article.unit, Some("pcs".to_string()));
 }
 
@@ -259,8 +314,8 @@ async fn test_paginated_response_metadata() {
 
     let json = r#"{
         "Data": [
-            {"Id": "cust-1", "Name": "Customer 1"},
-            {"Id": "cust-2", "Name": "Customer 2"}
+            {"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Customer 1"},
+            {"Id": "c0000000-0000-4001-8000-000000000002", "Name": "Customer 2"}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -291,8 +346,8 @@ async fn test_paginated_response_last_page() {
 
     let json = r#"{
         "Data": [
-            {"Id": "cust-249", "Name": "Customer 249"},
-            {"Id": "cust-250", "Name": "Customer 250"}
+            {"Id": "c0000000-0000-4000-8000-000000000249", "Name": "Customer 249"},
+            {"Id": "c0000000-0000-4000-8000-000000000250", "Name": "Customer 250"}
         ],
         "Meta": {
             "CurrentPage": 4,
@@ -379,18 +434,32 @@ async fn test_not_found_error_response_format() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Message": "Customer with id 'nonexistent' was not found",
+        "Message": "Customer with id 'c0000000-0000-4000-dead-000000000000' was not found",
         "ErrorCode": "NOT_FOUND"
     }"#;
 
-    let _mock = api.mock_error("GET", "/customers/nonexistent", 404, json);
+    let _mock = api.mock_error(
+        "GET",
+        "/customers/c0000000-0000-4000-dead-000000000000",
+        404,
+        json,
+    );
 
-    let result = api.client.customers().get("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        spiris::Error::NotFound(msg) => {
-            assert!(msg.contains("not found") || msg.contains("NOT_FOUND"));
+        spiris::Error::ApiError {
+            status_code: 404,
+            response,
+            ..
+        } => {
+            assert!(response.message.contains("not found"));
+            assert_eq!(response.error_code.as_deref(), Some("NOT_FOUND"));
         }
         other => panic!("Expected NotFound error, got {:?}", other),
     }
@@ -413,8 +482,13 @@ async fn test_rate_limit_error_response_format() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        spiris::Error::RateLimitExceeded(_) => {}
-        other => panic!("Expected RateLimitExceeded error, got {:?}", other),
+        err @ spiris::Error::ApiError {
+            status_code: 429, ..
+        } => {
+            assert!(err.is_rate_limited());
+            assert_eq!(err.headers().unwrap()["Retry-After"], "60");
+        }
+        other => panic!("Expected 429 error, got {:?}", other),
     }
 }
 
@@ -450,15 +524,20 @@ async fn test_boolean_fields_parse_correctly() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Test",
         "IsActive": true,
         "IsPrivatePerson": false
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert_eq!(customer.is_active, Some(true));
     assert_eq!(customer.is_private_person, Some(false));
@@ -469,7 +548,7 @@ async fn test_numeric_fields_as_integers() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Data": [{"Id": "cust-1", "Name": "Test"}],
+        "Data": [{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Test"}],
         "Meta": {
             "CurrentPage": 0,
             "PageSize": 50,
@@ -494,15 +573,23 @@ async fn test_date_fields_parse_as_datetime() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "InvoiceDate": "2024-01-15T00:00:00Z",
         "DueDate": "2024-02-15T00:00:00Z",
         "Rows": []
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-123", json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        json,
+    );
 
-    let invoice = api.client.invoices().get("inv-123").await.unwrap();
+    let invoice = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     // Dates are parsed as DateTime<Utc>
     assert!(invoice.invoice_date.is_some());
@@ -514,15 +601,20 @@ async fn test_datetime_fields_parse_correctly() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Test",
         "CreatedUtc": "2024-01-15T10:30:00Z",
         "ModifiedUtc": "2024-01-16T14:45:30Z"
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(customer.created_utc.is_some());
     assert!(customer.modified_utc.is_some());
@@ -537,16 +629,21 @@ async fn test_null_fields_become_none() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Test",
         "Email": null,
         "Phone": null,
         "CorporateIdentityNumber": null
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(customer.email.is_none());
     assert!(customer.phone.is_none());
@@ -559,13 +656,18 @@ async fn test_missing_fields_become_none() {
 
     // Minimal response with only required fields
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Test"
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     // All optional fields should be None
     assert!(customer.email.is_none());
@@ -578,15 +680,20 @@ async fn test_empty_string_vs_null() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "Name": "Test",
         "Email": "",
         "Phone": null
     }"#;
 
-    let _mock = api.mock_get("/customers/cust-123", json);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000123", json);
 
-    let customer = api.client.customers().get("cust-123").await.unwrap();
+    let customer = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     // Empty string is Some(""), null is None
     assert_eq!(customer.email, Some("".to_string()));
@@ -608,7 +715,7 @@ async fn test_create_request_uses_pascal_case() {
         .match_body(mockito::Matcher::Regex("\"Email\"".to_string()))
         .with_status(201)
         .with_header("content-type", "application/json")
-        .with_body(r#"{"Id": "new-123", "Name": "New Customer"}"#)
+        .with_body(r#"{"Id": "b0000000-0000-4000-8000-000000000123", "Name": "New Customer"}"#)
         .create();
 
     let customer = Customer {
@@ -630,11 +737,11 @@ async fn test_update_request_omits_none_fields() {
     // Create a mock that expects Name but not Email (since Email is None)
     let mock = api
         .server
-        .mock("PUT", "/customers/cust-123")
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-000000000123")
         .match_body(mockito::Matcher::Regex("\"Name\"".to_string()))
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(r#"{"Id": "cust-123", "Name": "Updated"}"#)
+        .with_body(r#"{"Id": "c0000000-0000-4000-8000-000000000123", "Name": "Updated"}"#)
         .create();
 
     let customer = Customer {
@@ -643,7 +750,11 @@ async fn test_update_request_omits_none_fields() {
         ..Default::default()
     };
 
-    let result = api.client.customers().update("cust-123", &customer).await;
+    let result = api
+        .client
+        .customers()
+        .update(&id("c0000000-0000-4000-8000-000000000123"), &customer)
+        .await;
     assert!(result.is_ok());
 
     mock.assert();
@@ -658,16 +769,27 @@ async fn test_invoice_with_nested_address() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "inv-123",
-        "CustomerId": "cust-456",
+        "Id": "f0000000-0000-4000-8000-000000000123",
+        "CustomerId": "c0000000-0000-4000-8000-000000000456",
         "Rows": []
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-123", json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        json,
+    );
 
-    let invoice = api.client.invoices().get("inv-123").await.unwrap();
+    let invoice = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
-    assert_eq!(invoice.customer_id, Some("cust-456".to_string()));
+    assert_eq!(
+        invoice.customer_id,
+        Some("c0000000-0000-4000-8000-000000000456".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -677,18 +799,18 @@ async fn test_customer_list_with_mixed_completeness() {
     let json = r#"{
         "Data": [
             {
-                "Id": "cust-1",
+                "Id": "c0000000-0000-4001-8000-000000000001",
                 "Name": "Full Customer",
                 "Email": "full@example.com",
                 "Phone": "+46701234567",
                 "IsActive": true
             },
             {
-                "Id": "cust-2",
+                "Id": "c0000000-0000-4001-8000-000000000002",
                 "Name": "Minimal Customer"
             },
             {
-                "Id": "cust-3",
+                "Id": "c0000000-0000-4001-8000-000000000003",
                 "Name": "Partial Customer",
                 "Email": null,
                 "IsActive": false
@@ -732,7 +854,7 @@ async fn test_voucher_response_structure() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "voucher-123",
+        "Id": "70000000-0000-4001-8000-000000000123",
         "VoucherNumber": "V001",
         "VoucherDate": "2024-01-15T00:00:00Z",
         "VoucherType": 0,
@@ -753,9 +875,14 @@ async fn test_voucher_response_structure() {
         ]
     }"#;
 
-    let _mock = api.mock_get("/vouchers/voucher-123", json);
+    let _mock = api.mock_get("/vouchers/70000000-0000-4001-8000-000000000123", json);
 
-    let voucher = api.client.vouchers().get("voucher-123").await.unwrap();
+    let voucher = api
+        .client
+        .vouchers()
+        .get(&id("70000000-0000-4001-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(voucher.id.is_some());
     assert!(voucher.voucher_number.is_some());
@@ -775,7 +902,7 @@ async fn test_supplier_response_structure() {
     let mut api = MockApi::new().await;
 
     let json = r#"{
-        "Id": "sup-123",
+        "Id": "50000000-0000-4000-8000-000000000123",
         "SupplierNumber": "S001",
         "Name": "Test Supplier",
         "Email": "supplier@example.com",
@@ -788,9 +915,14 @@ async fn test_supplier_response_structure() {
         }
     }"#;
 
-    let _mock = api.mock_get("/suppliers/sup-123", json);
+    let _mock = api.mock_get("/suppliers/50000000-0000-4000-8000-000000000123", json);
 
-    let supplier = api.client.suppliers().get("sup-123").await.unwrap();
+    let supplier = api
+        .client
+        .suppliers()
+        .get(&id("50000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     assert!(supplier.id.is_some());
     assert!(supplier.supplier_number.is_some());
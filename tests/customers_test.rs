@@ -2,7 +2,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Customer, PaginationParams, QueryParams};
 
 #[tokio::test]
@@ -12,14 +12,14 @@ async fn test_list_customers() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "cust-001",
+                "Id": "c0000000-0000-4000-8000-000000000001",
                 "CustomerNumber": "1001",
                 "Name": "Acme Corp",
                 "Email": "contact@acme.com",
                 "IsActive": true
             },
             {
-                "Id": "cust-002",
+                "Id": "c0000000-0000-4000-8000-000000000002",
                 "CustomerNumber": "1002",
                 "Name": "Beta Inc",
                 "Email": "info@beta.com",
@@ -40,7 +40,10 @@ async fn test_list_customers() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("cust-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("c0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].name, Some("Acme Corp".to_string()));
     assert_eq!(result.data[1].customer_number, Some("1002".to_string()));
     assert_eq!(result.meta.total_count, 2);
@@ -52,7 +55,7 @@ async fn test_list_customers_with_pagination() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "cust-101", "Name": "Page 2 Customer"}
+            {"Id": "c0000000-0000-4000-8000-000000000101", "Name": "Page 2 Customer"}
         ],
         "Meta": {
             "CurrentPage": 1,
@@ -83,7 +86,7 @@ async fn test_get_customer() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "CustomerNumber": "2001",
         "Name": "Test Customer AB",
         "Email": "test@customer.se",
@@ -93,12 +96,23 @@ async fn test_get_customer() {
         "PaymentTermsInDays": 30
     }"#;
 
-    let mock = api.mock_get("/customers/cust-123", response_body);
+    let mock = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.customers().get("cust-123").await.unwrap();
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("cust-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("c0000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.name, Some("Test Customer AB".to_string()));
     assert_eq!(result.email, Some("test@customer.se".to_string()));
     assert_eq!(result.payment_terms_in_days, Some(30));
@@ -109,7 +123,7 @@ async fn test_create_customer() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "cust-new-001",
+        "Id": "c0000000-0000-4000-0001-000000000001",
         "CustomerNumber": "3001",
         "Name": "New Customer",
         "Email": "new@customer.com",
@@ -128,7 +142,10 @@ async fn test_create_customer() {
     let result = api.client.customers().create(&new_customer).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("cust-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("c0000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.customer_number, Some("3001".to_string()));
     assert_eq!(result.name, Some("New Customer".to_string()));
 }
@@ -138,17 +155,20 @@ async fn test_update_customer() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "cust-123",
+        "Id": "c0000000-0000-4000-8000-000000000123",
         "CustomerNumber": "1001",
         "Name": "Updated Customer Name",
         "Email": "updated@email.com",
         "IsActive": true
     }"#;
 
-    let mock = api.mock_put("/customers/cust-123", response_body);
+    let mock = api.mock_put(
+        "/customers/c0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_customer = Customer {
-        id: Some("cust-123".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000123".parse().unwrap()),
         name: Some("Updated Customer Name".to_string()),
         email: Some("updated@email.com".to_string()),
         ..Default::default()
@@ -157,7 +177,10 @@ async fn test_update_customer() {
     let result = api
         .client
         .customers()
-        .update("cust-123", &updated_customer)
+        .update(
+            &id("c0000000-0000-4000-8000-000000000123"),
+            &updated_customer,
+        )
         .await
         .unwrap();
 
@@ -169,9 +192,13 @@ async fn test_update_customer() {
 async fn test_delete_customer() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/customers/cust-123");
+    let mock = api.mock_delete("/customers/c0000000-0000-4000-8000-000000000123");
 
-    let result = api.client.customers().delete("cust-123").await;
+    let result = api
+        .client
+        .customers()
+        .delete(&id("c0000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -183,7 +210,7 @@ async fn test_search_customers() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "cust-active-1", "Name": "Active Customer", "IsActive": true}
+            {"Id": "c0000000-0000-4000-0002-000000000001", "Name": "Active Customer", "IsActive": true}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -213,8 +240,8 @@ async fn test_search_customers_with_select() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "cust-1", "Name": "Customer 1"},
-            {"Id": "cust-2", "Name": "Customer 2"}
+            {"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Customer 1"},
+            {"Id": "c0000000-0000-4001-8000-000000000002", "Name": "Customer 2"}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -0,0 +1,130 @@
+//! Integration tests for dry-run mode.
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use spiris::{AccessToken, Client, ClientConfig, Customer, Method, RetryConfig};
+
+fn dry_run_client(api: &MockApi) -> Client {
+    let config = ClientConfig::new()
+        .base_url(api.url())
+        .retry_config(RetryConfig::new().max_retries(0))
+        .dry_run(true);
+    Client::with_config(
+        AccessToken::new("test_token".to_string(), 3600, None),
+        config,
+    )
+}
+
+#[tokio::test]
+async fn test_mutations_are_recorded_not_sent() {
+    let mut api = MockApi::new().await;
+    let client = dry_run_client(&api);
+
+    let post = api.server.mock("POST", "/customers").expect(0).create();
+    let put = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4002-8000-000000000001")
+        .expect(0)
+        .create();
+    let delete = api
+        .server
+        .mock("DELETE", "/customers/c0000000-0000-4002-8000-000000000002")
+        .expect(0)
+        .create();
+
+    let customer = Customer {
+        name: Some("Acme AB".to_string()),
+        ..Default::default()
+    };
+    let created = client.customers().create(&customer).await.unwrap();
+    assert_eq!(created.name.as_deref(), Some("Acme AB"));
+
+    let response = client
+        .customers()
+        .update_with_meta(&id("c0000000-0000-4002-8000-000000000001"), &customer)
+        .await
+        .unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.attempts, 0);
+
+    client
+        .customers()
+        .delete(&id("c0000000-0000-4002-8000-000000000002"))
+        .await
+        .unwrap();
+
+    post.assert();
+    put.assert();
+    delete.assert();
+
+    let plan = client.dry_run_plan().unwrap();
+    let requests = plan.requests();
+    let methods: Vec<_> = requests.iter().map(|r| r.method.clone()).collect();
+    assert_eq!(methods, vec![Method::POST, Method::PUT, Method::DELETE]);
+    assert!(requests[0].url.ends_with("/customers"));
+    assert_eq!(requests[0].body.as_ref().unwrap()["Name"], "Acme AB");
+    assert!(requests[2].body.is_none());
+    assert!(requests[2].to_string().starts_with("DELETE "));
+
+    assert_eq!(plan.take().len(), 3);
+    assert!(plan.is_empty());
+}
+
+#[tokio::test]
+async fn test_reads_still_hit_the_api() {
+    let mut api = MockApi::new().await;
+    let client = dry_run_client(&api);
+
+    let get = api.mock_get(
+        "/customers/c0000000-0000-4002-8000-000000000001",
+        r#"{"Id": "c0000000-0000-4002-8000-000000000001", "Name": "Acme"}"#,
+    );
+
+    let customer = client
+        .customers()
+        .get(&id("c0000000-0000-4002-8000-000000000001"))
+        .await
+        .unwrap();
+    get.assert();
+    assert_eq!(customer.name.as_deref(), Some("Acme"));
+    assert!(client.dry_run_plan().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_dry_run_is_off_by_default() {
+    let api = MockApi::new().await;
+    assert!(api.client.dry_run_plan().is_none());
+}
+
+#[tokio::test]
+async fn test_actions_without_body_get_an_empty_object() {
+    let mut api = MockApi::new().await;
+    let client = dry_run_client(&api);
+
+    let convert = api
+        .server
+        .mock(
+            "POST",
+            "/supplierinvoicedrafts/c0000000-0000-4002-8000-000000000003/convert",
+        )
+        .expect(0)
+        .create();
+
+    let invoice = client
+        .supplier_invoice_drafts()
+        .convert(&id("c0000000-0000-4002-8000-000000000003"))
+        .await
+        .unwrap();
+    assert!(invoice.id.is_none());
+    client
+        .invoices()
+        .send_einvoice(&id("c0000000-0000-4002-8000-000000000004"))
+        .await
+        .unwrap();
+
+    convert.assert();
+    let requests = client.dry_run_plan().unwrap().take();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].url.ends_with("/convert"));
+}
@@ -12,7 +12,7 @@
 
 mod mock_server;
 
-use mock_server::{fixtures, meta_json, MockApi};
+use mock_server::{fixtures, id, meta_json, MockApi};
 use spiris::{Article, Customer, Invoice, InvoiceRow, PaginationParams};
 
 // =============================================================================
@@ -69,13 +69,20 @@ async fn test_customer_get() {
     let customer = fixtures::customer(1);
     let response = serde_json::to_string(&customer).unwrap();
 
-    let _mock = api.mock_get("/customers/cust-001", &response);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-8000-000000000001", &response);
 
-    let result = api.client.customers().get("cust-001").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
     let customer = result.unwrap();
-    assert_eq!(customer.id, Some("cust-001".to_string()));
+    assert_eq!(
+        customer.id,
+        Some("c0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(customer.name, Some("Test Customer 1".to_string()));
 }
 
@@ -91,7 +98,7 @@ async fn test_customer_create() {
     };
 
     let created_customer = Customer {
-        id: Some("cust-new".to_string()),
+        id: Some(id("c0000000-0000-4000-0001-000000000000")),
         name: Some("New Customer".to_string()),
         email: Some("new@example.com".to_string()),
         is_active: Some(true),
@@ -121,7 +128,7 @@ async fn test_customer_update() {
     let mut api = MockApi::new().await;
 
     let updated_customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Updated Name".to_string()),
         email: Some("updated@example.com".to_string()),
         is_active: Some(true),
@@ -132,7 +139,7 @@ async fn test_customer_update() {
 
     let _mock = api
         .server
-        .mock("PUT", "/customers/cust-001")
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-000000000001")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
@@ -141,7 +148,10 @@ async fn test_customer_update() {
     let result = api
         .client
         .customers()
-        .update("cust-001", &updated_customer)
+        .update(
+            &id("c0000000-0000-4000-8000-000000000001"),
+            &updated_customer,
+        )
         .await;
 
     assert!(result.is_ok());
@@ -155,11 +165,15 @@ async fn test_customer_delete() {
 
     let _mock = api
         .server
-        .mock("DELETE", "/customers/cust-001")
+        .mock("DELETE", "/customers/c0000000-0000-4000-8000-000000000001")
         .with_status(204)
         .create();
 
-    let result = api.client.customers().delete("cust-001").await;
+    let result = api
+        .client
+        .customers()
+        .delete(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
 }
@@ -200,8 +214,8 @@ async fn test_invoice_list() {
     let mut api = MockApi::new().await;
 
     let invoices = vec![
-        fixtures::invoice(1, "cust-001"),
-        fixtures::invoice(2, "cust-002"),
+        fixtures::invoice(1, "c0000000-0000-4000-8000-000000000001"),
+        fixtures::invoice(2, "c0000000-0000-4000-8000-000000000002"),
     ];
     let data = serde_json::to_string(&invoices).unwrap();
     let meta = meta_json(0, 50, 1, 2);
@@ -220,16 +234,26 @@ async fn test_invoice_list() {
 async fn test_invoice_get() {
     let mut api = MockApi::new().await;
 
-    let invoice = fixtures::invoice(1, "cust-001");
+    let invoice = fixtures::invoice(1, "c0000000-0000-4000-8000-000000000001");
     let response = serde_json::to_string(&invoice).unwrap();
 
-    let _mock = api.mock_get("/customerinvoices/inv-001", &response);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000001",
+        &response,
+    );
 
-    let result = api.client.invoices().get("inv-001").await;
+    let result = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
     let invoice = result.unwrap();
-    assert_eq!(invoice.id, Some("inv-001".to_string()));
+    assert_eq!(
+        invoice.id,
+        Some("f0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -237,9 +261,9 @@ async fn test_invoice_create() {
     let mut api = MockApi::new().await;
 
     let new_invoice = Invoice {
-        customer_id: Some("cust-001".to_string()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         rows: vec![InvoiceRow {
-            article_id: Some("art-001".to_string()),
+            article_id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
             quantity: Some(2.0),
             unit_price: Some(100.0),
             ..Default::default()
@@ -248,9 +272,9 @@ async fn test_invoice_create() {
     };
 
     let created_invoice = Invoice {
-        id: Some("inv-new".to_string()),
+        id: Some(id("f0000000-0000-4000-0001-000000000000")),
         invoice_number: Some("1001".to_string()),
-        customer_id: Some("cust-001".to_string()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         total_amount: Some(200.0),
         rows: vec![],
         ..Default::default()
@@ -278,8 +302,8 @@ async fn test_invoice_update() {
     let mut api = MockApi::new().await;
 
     let updated_invoice = Invoice {
-        id: Some("inv-001".to_string()),
-        customer_id: Some("cust-001".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000000001".parse().unwrap()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         rows: vec![],
         ..Default::default()
     };
@@ -288,7 +312,10 @@ async fn test_invoice_update() {
 
     let _mock = api
         .server
-        .mock("PUT", "/customerinvoices/inv-001")
+        .mock(
+            "PUT",
+            "/customerinvoices/f0000000-0000-4000-8000-000000000001",
+        )
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
@@ -297,7 +324,10 @@ async fn test_invoice_update() {
     let result = api
         .client
         .invoices()
-        .update("inv-001", &updated_invoice)
+        .update(
+            &id("f0000000-0000-4000-8000-000000000001"),
+            &updated_invoice,
+        )
         .await;
 
     assert!(result.is_ok());
@@ -309,11 +339,18 @@ async fn test_invoice_delete() {
 
     let _mock = api
         .server
-        .mock("DELETE", "/customerinvoices/inv-001")
+        .mock(
+            "DELETE",
+            "/customerinvoices/f0000000-0000-4000-8000-000000000001",
+        )
         .with_status(204)
         .create();
 
-    let result = api.client.invoices().delete("inv-001").await;
+    let result = api
+        .client
+        .invoices()
+        .delete(&id("f0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
 }
@@ -347,13 +384,20 @@ async fn test_article_get() {
     let article = fixtures::article(1);
     let response = serde_json::to_string(&article).unwrap();
 
-    let _mock = api.mock_get("/articles/art-001", &response);
+    let _mock = api.mock_get("/articles/a0000000-0000-4000-8000-000000000001", &response);
 
-    let result = api.client.articles().get("art-001").await;
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
     let article = result.unwrap();
-    assert_eq!(article.id, Some("art-001".to_string()));
+    assert_eq!(
+        article.id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -369,7 +413,7 @@ async fn test_article_create() {
     };
 
     let created_article = Article {
-        id: Some("art-new".to_string()),
+        id: Some(id("a0000000-0000-4000-0001-000000000000")),
         name: Some("New Article".to_string()),
         article_number: Some("ART-NEW".to_string()),
         sales_price: Some(99.99),
@@ -399,7 +443,7 @@ async fn test_article_update() {
     let mut api = MockApi::new().await;
 
     let updated_article = Article {
-        id: Some("art-001".to_string()),
+        id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Updated Article".to_string()),
         sales_price: Some(149.99),
         is_active: Some(true),
@@ -410,7 +454,7 @@ async fn test_article_update() {
 
     let _mock = api
         .server
-        .mock("PUT", "/articles/art-001")
+        .mock("PUT", "/articles/a0000000-0000-4000-8000-000000000001")
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(&response)
@@ -419,7 +463,10 @@ async fn test_article_update() {
     let result = api
         .client
         .articles()
-        .update("art-001", &updated_article)
+        .update(
+            &id("a0000000-0000-4000-8000-000000000001"),
+            &updated_article,
+        )
         .await;
 
     assert!(result.is_ok());
@@ -431,11 +478,15 @@ async fn test_article_delete() {
 
     let _mock = api
         .server
-        .mock("DELETE", "/articles/art-001")
+        .mock("DELETE", "/articles/a0000000-0000-4000-8000-000000000001")
         .with_status(204)
         .create();
 
-    let result = api.client.articles().delete("art-001").await;
+    let result = api
+        .client
+        .articles()
+        .delete(&id("a0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_ok());
 }
@@ -501,12 +552,16 @@ async fn test_customer_get_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/customers/nonexistent",
+        "/customers/c0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Customer not found"}"#,
     );
 
-    let result = api.client.customers().get("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(result.is_err());
 }
@@ -517,12 +572,16 @@ async fn test_invoice_get_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/customerinvoices/nonexistent",
+        "/customerinvoices/f0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Invoice not found"}"#,
     );
 
-    let result = api.client.invoices().get("nonexistent").await;
+    let result = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(result.is_err());
 }
@@ -533,12 +592,16 @@ async fn test_article_get_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/articles/nonexistent",
+        "/articles/a0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Article not found"}"#,
     );
 
-    let result = api.client.articles().get("nonexistent").await;
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(result.is_err());
 }
@@ -594,16 +657,20 @@ async fn test_customer_update_conflict() {
 
     let _mock = api.mock_error(
         "PUT",
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         409,
         r#"{"Message": "Resource was modified"}"#,
     );
 
     let customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         ..Default::default()
     };
-    let result = api.client.customers().update("cust-001", &customer).await;
+    let result = api
+        .client
+        .customers()
+        .update(&id("c0000000-0000-4000-8000-000000000001"), &customer)
+        .await;
 
     assert!(result.is_err());
 }
@@ -618,12 +685,16 @@ async fn test_customer_delete_not_found() {
 
     let _mock = api.mock_error(
         "DELETE",
-        "/customers/nonexistent",
+        "/customers/c0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Customer not found"}"#,
     );
 
-    let result = api.client.customers().delete("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .delete(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(result.is_err());
 }
@@ -662,15 +733,19 @@ async fn test_customer_get_with_special_id() {
     let mut api = MockApi::new().await;
 
     let customer = Customer {
-        id: Some("cust-abc-123-xyz".to_string()),
+        id: Some("c0000000-0000-4000-0005-000000000123".parse().unwrap()),
         name: Some("Special Customer".to_string()),
         ..Default::default()
     };
     let response = serde_json::to_string(&customer).unwrap();
 
-    let _mock = api.mock_get("/customers/cust-abc-123-xyz", &response);
+    let _mock = api.mock_get("/customers/c0000000-0000-4000-0005-000000000123", &response);
 
-    let result = api.client.customers().get("cust-abc-123-xyz").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-0005-000000000123"))
+        .await;
 
     assert!(result.is_ok());
 }
@@ -2,7 +2,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{AccessToken, ApiErrorResponse, Client, ClientConfig, Customer, Error};
 
 fn api_error(status_code: u16, message: &str) -> Error {
@@ -10,6 +10,7 @@ fn api_error(status_code: u16, message: &str) -> Error {
         status_code,
         response: ApiErrorResponse::from_raw(message.to_string()),
         raw_body: message.to_string(),
+        headers: Default::default(),
     }
 }
 
@@ -19,18 +20,58 @@ async fn test_not_found_error() {
 
     let mock = api.mock_error(
         "GET",
-        "/customers/nonexistent",
+        "/customers/c0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Customer not found"}"#,
     );
 
-    let result = api.client.customers().get("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::NotFound(_))));
-    if let Err(Error::NotFound(msg)) = result {
-        assert!(msg.contains("Customer not found"));
-    }
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 404,
+            ..
+        })
+    ));
+    let err = result.unwrap_err();
+    assert!(err.is_not_found());
+    assert_eq!(err.api_response().unwrap().message, "Customer not found");
+}
+
+#[tokio::test]
+async fn test_error_keeps_headers_and_request_id() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("POST", "/vouchers")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_header("X-Request-Id", "req-789")
+        .with_body(r#"{"ErrorCode": "VALIDATION_ERROR", "Message": "Validation failed"}"#)
+        .create();
+
+    let result = api
+        .client
+        .vouchers()
+        .create(&spiris::Voucher::default())
+        .await;
+
+    mock.assert();
+    let err = result.unwrap_err();
+    assert_eq!(err.status_code(), Some(400));
+    assert_eq!(err.request_id(), Some("req-789"));
+    assert_eq!(
+        err.api_error_code(),
+        Some(spiris::ApiErrorCode::ValidationError)
+    );
+    assert!(err.is_bad_request());
 }
 
 #[tokio::test]
@@ -47,7 +88,13 @@ async fn test_unauthorized_error() {
     let result = api.client.customers().list(None).await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::AuthError(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 401,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -64,7 +111,13 @@ async fn test_forbidden_error() {
     let result = api.client.customers().list(None).await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::AuthError(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 403,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -81,9 +134,15 @@ async fn test_rate_limit_error() {
     let result = api.client.customers().list(None).await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::RateLimitExceeded(_))));
-    if let Err(Error::RateLimitExceeded(msg)) = result {
-        assert!(msg.contains("Rate limit"));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 429,
+            ..
+        })
+    ));
+    if let Err(Error::ApiError { response, .. }) = result {
+        assert!(response.message.contains("Rate limit"));
     }
 }
 
@@ -102,9 +161,15 @@ async fn test_bad_request_error() {
     let result = api.client.customers().create(&invalid_customer).await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::InvalidRequest(_))));
-    if let Err(Error::InvalidRequest(msg)) = result {
-        assert!(msg.contains("Name is required"));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 400,
+            ..
+        })
+    ));
+    if let Err(Error::ApiError { response, .. }) = result {
+        assert!(response.message.contains("Name is required"));
     }
 }
 
@@ -177,15 +242,25 @@ async fn test_delete_not_found_error() {
 
     let mock = api.mock_error(
         "DELETE",
-        "/customers/nonexistent",
+        "/customers/c0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Customer not found"}"#,
     );
 
-    let result = api.client.customers().delete("nonexistent").await;
+    let result = api
+        .client
+        .customers()
+        .delete(&id("c0000000-0000-4000-dead-000000000000"))
+        .await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::NotFound(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 404,
+            ..
+        })
+    ));
 }
 
 #[tokio::test]
@@ -194,17 +269,21 @@ async fn test_update_conflict_error() {
 
     let mock = api.mock_error(
         "PUT",
-        "/customers/cust-123",
+        "/customers/c0000000-0000-4000-8000-000000000123",
         409,
         r#"{"Message": "Conflict: Resource was modified by another request"}"#,
     );
 
     let customer = Customer {
-        id: Some("cust-123".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000123".parse().unwrap()),
         name: Some("Updated".to_string()),
         ..Default::default()
     };
-    let result = api.client.customers().update("cust-123", &customer).await;
+    let result = api
+        .client
+        .customers()
+        .update(&id("c0000000-0000-4000-8000-000000000123"), &customer)
+        .await;
 
     mock.assert();
     assert!(matches!(
@@ -238,9 +317,15 @@ async fn test_validation_error_details() {
     let result = api.client.customers().create(&invalid_customer).await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::InvalidRequest(_))));
-    if let Err(Error::InvalidRequest(msg)) = result {
-        assert!(msg.contains("Validation failed"));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 400,
+            ..
+        })
+    ));
+    if let Err(Error::ApiError { raw_body, .. }) = result {
+        assert!(raw_body.contains("Validation failed"));
     }
 }
 
@@ -248,12 +333,27 @@ async fn test_validation_error_details() {
 async fn test_empty_response_body_error() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_error("GET", "/customers/empty", 404, "");
+    let mock = api.mock_error(
+        "GET",
+        "/customers/c0000000-0000-4000-8000-000000000000",
+        404,
+        "",
+    );
 
-    let result = api.client.customers().get("empty").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000000"))
+        .await;
 
     mock.assert();
-    assert!(matches!(result, Err(Error::NotFound(_))));
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 404,
+            ..
+        })
+    ));
 }
 
 // =============================================================================
@@ -313,8 +413,13 @@ async fn test_422_unprocessable_entity() {
     let customer = Customer::default();
     let result = api.client.customers().create(&customer).await;
 
-    // 422 may be treated as InvalidRequest or ApiError
-    assert!(result.is_err());
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 422,
+            ..
+        })
+    ));
 }
 
 // =============================================================================
@@ -510,12 +615,16 @@ async fn test_invoice_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/customerinvoices/inv-nonexistent",
+        "/customerinvoices/f0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Invoice not found"}"#,
     );
 
-    let result = api.client.invoices().get("inv-nonexistent").await;
+    let result = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(matches!(
         result,
@@ -533,12 +642,16 @@ async fn test_article_not_found() {
 
     let _mock = api.mock_error(
         "GET",
-        "/articles/art-nonexistent",
+        "/articles/a0000000-0000-4000-dead-000000000000",
         404,
         r#"{"Message": "Article not found"}"#,
     );
 
-    let result = api.client.articles().get("art-nonexistent").await;
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-dead-000000000000"))
+        .await;
 
     assert!(matches!(
         result,
@@ -564,7 +677,7 @@ async fn test_success_after_error() {
     let customer_json = serde_json::to_string(&customer).unwrap();
 
     let _mocks = api.mock_get_sequence(
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         vec![
             MockResponse::error(500, "Temporary error"),
             MockResponse::ok(&customer_json),
@@ -572,11 +685,19 @@ async fn test_success_after_error() {
     );
 
     // First call fails
-    let result1 = api.client.customers().get("cust-001").await;
+    let result1 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result1.is_err());
 
     // Second call succeeds
-    let result2 = api.client.customers().get("cust-001").await;
+    let result2 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result2.is_ok());
 }
 
@@ -587,7 +708,7 @@ async fn test_different_errors_in_sequence() {
     let mut api = MockApi::new().await;
 
     let _mocks = api.mock_get_sequence(
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         vec![
             MockResponse::error(500, "Server error"),
             MockResponse::error(503, "Service unavailable"),
@@ -596,17 +717,29 @@ async fn test_different_errors_in_sequence() {
     );
 
     // Each call returns different error
-    let result1 = api.client.customers().get("cust-001").await;
+    let result1 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     if let Err(Error::ApiError { status_code, .. }) = result1 {
         assert_eq!(status_code, 500);
     }
 
-    let result2 = api.client.customers().get("cust-001").await;
+    let result2 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     if let Err(Error::ApiError { status_code, .. }) = result2 {
         assert_eq!(status_code, 503);
     }
 
-    let result3 = api.client.customers().get("cust-001").await;
+    let result3 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     if let Err(Error::ApiError { status_code, .. }) = result3 {
         assert_eq!(status_code, 502);
     }
@@ -0,0 +1,61 @@
+//! Tests for the local invoice totals calculator against API responses.
+
+mod mock_server;
+
+use mock_server::money;
+use spiris::totals::{InvoiceCalculator, Rounding};
+use spiris::{Invoice, VatCode};
+
+fn standard_vat() -> VatCode {
+    serde_json::from_str(
+        r#"{"Id": "fa000000-0000-4000-8000-000000000025", "Code": "MP1", "VatRate": 25.0}"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_calculated_totals_match_api_invoice() {
+    let invoice: Invoice =
+        serde_json::from_str(include_str!("fixtures/invoice_with_rows.json")).unwrap();
+    let codes = [standard_vat()];
+
+    let totals = InvoiceCalculator::new()
+        .vat_codes(&codes)
+        .currency(invoice.currency_code.as_deref().unwrap())
+        .calculate(&invoice.rows)
+        .unwrap();
+
+    assert_eq!(totals.rows[0].amount, money(9000.0));
+    assert_eq!(totals.vat.len(), 1);
+    assert_eq!(totals.total_vat_amount, money(14875.0));
+    assert!(totals.reconcile(&invoice).is_empty());
+}
+
+#[test]
+fn test_reconcile_reports_changed_figures() {
+    let mut invoice: Invoice =
+        serde_json::from_str(include_str!("fixtures/invoice_with_rows.json")).unwrap();
+    invoice.rows[1].quantity = Some(money(41.0));
+    let codes = [standard_vat()];
+
+    let totals = InvoiceCalculator::new()
+        .vat_codes(&codes)
+        .rounding(Rounding::None)
+        .calculate(&invoice.rows)
+        .unwrap();
+    let fields: Vec<String> = totals
+        .reconcile(&invoice)
+        .into_iter()
+        .map(|m| m.field)
+        .collect();
+
+    assert_eq!(
+        fields,
+        [
+            "Rows[1].TotalAmount",
+            "TotalAmount",
+            "TotalVatAmount",
+            "TotalAmountIncludingVat"
+        ]
+    );
+}
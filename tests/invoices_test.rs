@@ -4,7 +4,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Invoice, InvoicePayment, InvoiceRow, PaginationParams};
 
 #[tokio::test]
@@ -14,9 +14,9 @@ async fn test_list_invoices() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "inv-001",
+                "Id": "f0000000-0000-4000-8000-000000000001",
                 "InvoiceNumber": "1001",
-                "CustomerId": "cust-001",
+                "CustomerId": "c0000000-0000-4000-8000-000000000001",
                 "TotalAmount": 10000.0,
                 "TotalVatAmount": 2500.0,
                 "CurrencyCode": "SEK",
@@ -24,9 +24,9 @@ async fn test_list_invoices() {
                 "Rows": []
             },
             {
-                "Id": "inv-002",
+                "Id": "f0000000-0000-4000-8000-000000000002",
                 "InvoiceNumber": "1002",
-                "CustomerId": "cust-002",
+                "CustomerId": "c0000000-0000-4000-8000-000000000002",
                 "TotalAmount": 5000.0,
                 "TotalVatAmount": 1250.0,
                 "CurrencyCode": "SEK",
@@ -48,7 +48,10 @@ async fn test_list_invoices() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("inv-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("f0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].invoice_number, Some("1001".to_string()));
     assert_eq!(result.data[0].total_amount, Some(10000.0));
 }
@@ -58,9 +61,9 @@ async fn test_get_invoice() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "InvoiceNumber": "2001",
-        "CustomerId": "cust-456",
+        "CustomerId": "c0000000-0000-4000-8000-000000000456",
         "TotalAmount": 15000.0,
         "TotalVatAmount": 3750.0,
         "TotalAmountIncludingVat": 18750.0,
@@ -68,8 +71,8 @@ async fn test_get_invoice() {
         "IsSent": true,
         "Rows": [
             {
-                "Id": "row-001",
-                "ArticleId": "art-001",
+                "Id": "e0000000-0000-4000-8000-000000000001",
+                "ArticleId": "a0000000-0000-4000-8000-000000000001",
                 "Text": "Consulting services",
                 "UnitPrice": 1500.0,
                 "Quantity": 10.0,
@@ -78,12 +81,23 @@ async fn test_get_invoice() {
         ]
     }"#;
 
-    let mock = api.mock_get("/customerinvoices/inv-123", response_body);
+    let mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.invoices().get("inv-123").await.unwrap();
+    let result = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("inv-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("f0000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.invoice_number, Some("2001".to_string()));
     assert_eq!(result.total_amount_including_vat, Some(18750.0));
     assert_eq!(result.rows.len(), 1);
@@ -95,9 +109,9 @@ async fn test_create_invoice() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "inv-new-001",
+        "Id": "f0000000-0000-4000-0001-000000000001",
         "InvoiceNumber": "3001",
-        "CustomerId": "cust-001",
+        "CustomerId": "c0000000-0000-4000-8000-000000000001",
         "TotalAmount": 2000.0,
         "TotalVatAmount": 500.0,
         "TotalAmountIncludingVat": 2500.0,
@@ -105,7 +119,7 @@ async fn test_create_invoice() {
         "IsSent": false,
         "Rows": [
             {
-                "Id": "row-new-001",
+                "Id": "e0000000-0000-4000-0001-000000000001",
                 "Text": "Product A",
                 "UnitPrice": 200.0,
                 "Quantity": 10.0,
@@ -117,7 +131,7 @@ async fn test_create_invoice() {
     let mock = api.mock_post("/customerinvoices", response_body);
 
     let new_invoice = Invoice {
-        customer_id: Some("cust-001".to_string()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         currency_code: Some("SEK".to_string()),
         rows: vec![InvoiceRow {
             text: Some("Product A".to_string()),
@@ -131,7 +145,10 @@ async fn test_create_invoice() {
     let result = api.client.invoices().create(&new_invoice).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("inv-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("f0000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.invoice_number, Some("3001".to_string()));
     assert_eq!(result.total_amount, Some(2000.0));
 }
@@ -141,19 +158,22 @@ async fn test_update_invoice() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "inv-123",
+        "Id": "f0000000-0000-4000-8000-000000000123",
         "InvoiceNumber": "1001",
-        "CustomerId": "cust-001",
+        "CustomerId": "c0000000-0000-4000-8000-000000000001",
         "Remarks": "Updated remarks",
         "TotalAmount": 10000.0,
         "CurrencyCode": "SEK",
         "Rows": []
     }"#;
 
-    let mock = api.mock_put("/customerinvoices/inv-123", response_body);
+    let mock = api.mock_put(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_invoice = Invoice {
-        id: Some("inv-123".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000000123".parse().unwrap()),
         remarks: Some("Updated remarks".to_string()),
         ..Default::default()
     };
@@ -161,7 +181,10 @@ async fn test_update_invoice() {
     let result = api
         .client
         .invoices()
-        .update("inv-123", &updated_invoice)
+        .update(
+            &id("f0000000-0000-4000-8000-000000000123"),
+            &updated_invoice,
+        )
         .await
         .unwrap();
 
@@ -173,9 +196,13 @@ async fn test_update_invoice() {
 async fn test_delete_invoice() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/customerinvoices/inv-123");
+    let mock = api.mock_delete("/customerinvoices/f0000000-0000-4000-8000-000000000123");
 
-    let result = api.client.invoices().delete("inv-123").await;
+    let result = api
+        .client
+        .invoices()
+        .delete(&id("f0000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -187,7 +214,10 @@ async fn test_register_invoice_payment() {
 
     let mock = api
         .server
-        .mock("POST", "/customerinvoices/inv-123/payments")
+        .mock(
+            "POST",
+            "/customerinvoices/f0000000-0000-4000-8000-000000000123/payments",
+        )
         .match_header("Authorization", "Bearer test_token")
         .match_header("Content-Type", "application/json")
         .with_status(201)
@@ -198,14 +228,14 @@ async fn test_register_invoice_payment() {
     let payment = InvoicePayment {
         amount: Some(10000.0),
         payment_date: None,
-        bank_account_id: Some("bank-001".to_string()),
+        bank_account_id: Some("ba000000-0000-4000-8000-000000000001".parse().unwrap()),
         ..Default::default()
     };
 
     let result = api
         .client
         .invoices()
-        .register_payment("inv-123", &payment)
+        .register_payment(&id("f0000000-0000-4000-8000-000000000123"), &payment)
         .await;
 
     mock.assert();
@@ -217,9 +247,17 @@ async fn test_get_invoice_pdf() {
     let mut api = MockApi::new().await;
 
     let pdf_content = b"%PDF-1.4 fake pdf content";
-    let mock = api.mock_get_bytes("/customerinvoices/inv-123/pdf", pdf_content);
+    let mock = api.mock_get_bytes(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000123/pdf",
+        pdf_content,
+    );
 
-    let result = api.client.invoices().get_pdf("inv-123").await.unwrap();
+    let result = api
+        .client
+        .invoices()
+        .get_pdf(&id("f0000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
     assert_eq!(result, pdf_content.to_vec());
@@ -231,7 +269,10 @@ async fn test_send_einvoice() {
 
     let mock = api
         .server
-        .mock("POST", "/customerinvoices/inv-123/einvoice")
+        .mock(
+            "POST",
+            "/customerinvoices/f0000000-0000-4000-8000-000000000123/einvoice",
+        )
         .match_header("Authorization", "Bearer test_token")
         .match_header("Content-Type", "application/json")
         .with_status(200)
@@ -239,7 +280,11 @@ async fn test_send_einvoice() {
         .with_body("null")
         .create();
 
-    let result = api.client.invoices().send_einvoice("inv-123").await;
+    let result = api
+        .client
+        .invoices()
+        .send_einvoice(&id("f0000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -251,7 +296,7 @@ async fn test_list_invoices_with_pagination() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "inv-page2", "InvoiceNumber": "2001", "TotalAmount": 5000.0, "Rows": []}
+            {"Id": "f0000000-0000-4000-0003-000000000002", "InvoiceNumber": "2001", "TotalAmount": 5000.0, "Rows": []}
         ],
         "Meta": {
             "CurrentPage": 1,
@@ -0,0 +1,264 @@
+//! Integration tests for the SQLite mirror.
+#![cfg(feature = "mirror")]
+
+mod mock_server;
+
+use mock_server::MockApi;
+use mockito::Matcher;
+use spiris::mirror::{Mirror, RefreshReport, Resource, SCHEMA_VERSION};
+use spiris::{Account, Customer, CustomerLedgerItem};
+
+const A: &str = "c0000000-0000-4000-8000-00000000000a";
+const B: &str = "c0000000-0000-4000-8000-00000000000b";
+
+fn customers_body(customers: &[(&str, &str, &str)]) -> String {
+    let data: Vec<String> = customers
+        .iter()
+        .map(|(id, name, modified)| {
+            format!(
+                r#"{{"Id": "{}", "Name": "{}", "ModifiedUtc": "{}"}}"#,
+                id, name, modified
+            )
+        })
+        .collect();
+    mock_server::paginated_response(&format!("[{}]", data.join(",")), 0, data.len() as u32)
+}
+
+fn mock_list(api: &mut MockApi, path: &str, body: &str) -> mockito::Mock {
+    api.server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create()
+}
+
+fn names(mirror: &Mirror) -> Vec<(String, String)> {
+    let mut statement = mirror
+        .connection()
+        .prepare("SELECT id, name FROM customers ORDER BY id")
+        .unwrap();
+    statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_incremental_refresh_upserts_changes() {
+    let mut api = MockApi::new().await;
+    let mut mirror = Mirror::open_in_memory().unwrap();
+
+    let full = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::Regex(
+            "^orderby=ModifiedUtc&page=0&pagesize=500$".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(customers_body(&[
+            (A, "Acme", "2024-01-01T10:00:00Z"),
+            (B, "Bolaget", "2024-01-01T11:00:00Z"),
+        ]))
+        .expect(1)
+        .create();
+
+    let report = mirror
+        .refresh_resource(&api.client, Resource::Customers)
+        .await
+        .unwrap();
+    full.assert();
+    full.remove();
+    assert_eq!(
+        report,
+        RefreshReport {
+            resource: Resource::Customers,
+            upserted: 2,
+            deleted: 0,
+            full: false,
+        }
+    );
+    assert!(mirror
+        .last_refreshed(Resource::Customers)
+        .unwrap()
+        .is_some());
+
+    // The stored sync state limits the next refresh to recent changes.
+    let incremental = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded(
+            "filter".into(),
+            "ModifiedUtc ge 2024-01-01T10:55:00Z".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(customers_body(&[(A, "Acme AB", "2024-01-01T12:00:00Z")]))
+        .expect(1)
+        .create();
+
+    let report = mirror
+        .refresh_resource(&api.client, Resource::Customers)
+        .await
+        .unwrap();
+    incremental.assert();
+    assert_eq!(report.upserted, 1);
+    assert_eq!(
+        names(&mirror),
+        vec![
+            (A.to_string(), "Acme AB".to_string()),
+            (B.to_string(), "Bolaget".to_string()),
+        ]
+    );
+
+    let customer: Customer = mirror.get(A).unwrap().unwrap();
+    assert_eq!(customer.name.as_deref(), Some("Acme AB"));
+    assert!(mirror.get::<Customer>("missing").unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_ledger_items_are_replaced_in_full() {
+    let mut api = MockApi::new().await;
+    let mut mirror = Mirror::open_in_memory().unwrap();
+
+    let first = mock_list(
+        &mut api,
+        "/customerledgeritems",
+        &mock_server::paginated_response(
+            r#"[{"Id": "1a000000-0000-4000-8000-000000000001", "CustomerId": "c0000000-0000-4000-8000-00000000000a", "Amount": 100.0},
+                {"Id": "1a000000-0000-4000-8000-000000000002", "CustomerId": "c0000000-0000-4000-8000-00000000000b", "Amount": 50.0}]"#,
+            0,
+            2,
+        ),
+    );
+    mirror
+        .refresh_resource(&api.client, Resource::CustomerLedgerItems)
+        .await
+        .unwrap();
+    first.remove();
+
+    let _second = mock_list(
+        &mut api,
+        "/customerledgeritems",
+        &mock_server::paginated_response(
+            r#"[{"Id": "1a000000-0000-4000-8000-000000000002", "CustomerId": "c0000000-0000-4000-8000-00000000000b", "Amount": 75.0}]"#,
+            0,
+            1,
+        ),
+    );
+    let report = mirror
+        .refresh_resource(&api.client, Resource::CustomerLedgerItems)
+        .await
+        .unwrap();
+
+    assert!(report.full);
+    assert_eq!(report.deleted, 2);
+    assert_eq!(report.upserted, 1);
+
+    let items: Vec<CustomerLedgerItem> = mirror.all().unwrap();
+    assert_eq!(items.len(), 1);
+    let amount: f64 = mirror
+        .connection()
+        .query_row(
+            "SELECT amount FROM customer_ledger_items WHERE customer_id = ?1",
+            [B],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(amount, 75.0);
+}
+
+#[tokio::test]
+async fn test_accounts_are_looked_up_per_fiscal_year() {
+    let mut api = MockApi::new().await;
+    let mut mirror = Mirror::open_in_memory().unwrap();
+    let _accounts = mock_list(
+        &mut api,
+        "/accounts",
+        &mock_server::paginated_response(
+            r#"[{"AccountNumber": "1930", "Name": "Bank 2023", "FiscalYearId": "f0000000-0000-4000-8000-000000002023"},
+                {"AccountNumber": "1930", "Name": "Bank 2024", "FiscalYearId": "f0000000-0000-4000-8000-000000002024"}]"#,
+            0,
+            2,
+        ),
+    );
+    mirror
+        .refresh_resource(&api.client, Resource::Accounts)
+        .await
+        .unwrap();
+
+    let year = "f0000000-0000-4000-8000-000000002024".parse().unwrap();
+    let account = mirror.get_account(&year, "1930").unwrap().unwrap();
+    assert_eq!(account.name.as_deref(), Some("Bank 2024"));
+    assert!(mirror.get_account(&year, "3001").unwrap().is_none());
+    assert!(mirror.get::<Account>("1930").is_err());
+}
+
+#[tokio::test]
+async fn test_failed_refresh_keeps_existing_rows() {
+    let mut api = MockApi::new().await;
+    let mut mirror = Mirror::open_in_memory().unwrap();
+
+    let ok = mock_list(
+        &mut api,
+        "/customers",
+        &customers_body(&[(A, "Acme", "2024-01-01T10:00:00Z")]),
+    );
+    mirror
+        .refresh_resource(&api.client, Resource::Customers)
+        .await
+        .unwrap();
+    ok.remove();
+
+    let _error = api.mock_server_error("GET", "/customers");
+    let result = mirror
+        .refresh_resource(&api.client, Resource::Customers)
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(names(&mirror), vec![(A.to_string(), "Acme".to_string())]);
+}
+
+#[tokio::test]
+async fn test_refresh_all_resources_and_reopen() {
+    let mut api = MockApi::new().await;
+    let empty = mock_server::paginated_response("[]", 0, 0);
+    let _mocks: Vec<_> = [
+        "/customers",
+        "/suppliers",
+        "/articles",
+        "/customerinvoices",
+        "/supplierinvoices",
+        "/vouchers",
+        "/accounts",
+        "/customerledgeritems",
+        "/supplierledgeritems",
+    ]
+    .into_iter()
+    .map(|path| mock_list(&mut api, path, &empty))
+    .collect();
+
+    let path = std::env::temp_dir().join(format!("spiris-mirror-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut mirror = Mirror::open(&path).unwrap();
+    let reports = mirror.refresh(&api.client).await.unwrap();
+    assert_eq!(reports.len(), Resource::ALL.len());
+    drop(mirror);
+
+    let mirror = Mirror::open(&path).unwrap();
+    let version: i32 = mirror
+        .connection()
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .unwrap();
+    assert_eq!(version, SCHEMA_VERSION);
+    for resource in Resource::ALL {
+        assert!(mirror.last_refreshed(resource).unwrap().is_some());
+    }
+
+    drop(mirror);
+    let _ = std::fs::remove_file(&path);
+}
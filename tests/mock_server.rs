@@ -21,6 +21,16 @@ pub fn money(value: f64) -> Money {
     }
 }
 
+/// Helper function to parse a typed id such as `CustomerId` from a GUID.
+#[allow(dead_code)]
+pub fn id<T>(value: &str) -> T
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Debug,
+{
+    value.parse().expect("Invalid id")
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -883,7 +893,7 @@ pub mod fixtures {
 
     pub fn customer(id: u32) -> Customer {
         Customer {
-            id: Some(format!("cust-{:03}", id)),
+            id: Some(super::id(&format!("c0000000-0000-4000-8000-{:012}", id))),
             customer_number: Some(format!("{}", 1000 + id)),
             name: Some(format!("Test Customer {}", id)),
             email: Some(format!("customer{}@test.com", id)),
@@ -906,7 +916,7 @@ pub mod fixtures {
 
     pub fn article(id: u32) -> Article {
         Article {
-            id: Some(format!("art-{:03}", id)),
+            id: Some(super::id(&format!("a0000000-0000-4000-8000-{:012}", id))),
             article_number: Some(format!("ART-{}", id)),
             name: Some(format!("Test Article {}", id)),
             sales_price: Some(super::money(100.0 * id as f64)),
@@ -917,9 +927,9 @@ pub mod fixtures {
 
     pub fn invoice(id: u32, customer_id: &str) -> Invoice {
         Invoice {
-            id: Some(format!("inv-{:03}", id)),
+            id: Some(super::id(&format!("f0000000-0000-4000-8000-{:012}", id))),
             invoice_number: Some(format!("{}", 2000 + id)),
-            customer_id: Some(customer_id.to_string()),
+            customer_id: Some(super::id(customer_id)),
             total_amount: Some(super::money(1000.0 * id as f64)),
             rows: vec![invoice_row(1)],
             ..Default::default()
@@ -928,7 +938,7 @@ pub mod fixtures {
 
     pub fn invoice_row(id: u32) -> InvoiceRow {
         InvoiceRow {
-            id: Some(format!("row-{:03}", id)),
+            id: Some(super::id(&format!("e0000000-0000-4000-8000-{:012}", id))),
             text: Some(format!("Line item {}", id)),
             unit_price: Some(super::money(100.0)),
             quantity: Some(super::money(id as f64)),
@@ -992,12 +1002,15 @@ mod tests {
     #[test]
     fn test_fixtures() {
         let customer = fixtures::customer(1);
-        assert_eq!(customer.id, Some("cust-001".to_string()));
+        assert_eq!(customer.id.unwrap(), "c0000000-0000-4000-8000-000000000001");
 
         let customers = fixtures::customers(3);
         assert_eq!(customers.len(), 3);
 
-        let invoice = fixtures::invoice(1, "cust-001");
-        assert_eq!(invoice.customer_id, Some("cust-001".to_string()));
+        let invoice = fixtures::invoice(1, "c0000000-0000-4000-8000-000000000001");
+        assert_eq!(
+            invoice.customer_id.unwrap(),
+            "c0000000-0000-4000-8000-000000000001"
+        );
     }
 }
@@ -11,7 +11,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Article, Invoice, InvoiceRow};
 
 // =============================================================================
@@ -133,7 +133,7 @@ fn test_f64_negative_amount() {
 #[test]
 fn test_invoice_amount_serialization() {
     let invoice = Invoice {
-        id: Some("inv-001".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000000001".parse().unwrap()),
         total_amount: Some(1234.56),
         ..Default::default()
     };
@@ -146,7 +146,8 @@ fn test_invoice_amount_serialization() {
 
 #[test]
 fn test_invoice_amount_deserialization() {
-    let json = r#"{"Id": "inv-001", "TotalAmount": 1234.56, "Rows": []}"#;
+    let json =
+        r#"{"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": 1234.56, "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -156,7 +157,7 @@ fn test_invoice_amount_deserialization() {
 #[test]
 fn test_amount_deserialization_from_integer() {
     // API might return integer for whole amounts
-    let json = r#"{"Id": "inv-001", "TotalAmount": 1000, "Rows": []}"#;
+    let json = r#"{"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": 1000, "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -165,7 +166,7 @@ fn test_amount_deserialization_from_integer() {
 
 #[test]
 fn test_amount_deserialization_null() {
-    let json = r#"{"Id": "inv-001", "TotalAmount": null, "Rows": []}"#;
+    let json = r#"{"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": null, "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -174,7 +175,7 @@ fn test_amount_deserialization_null() {
 
 #[test]
 fn test_amount_deserialization_missing() {
-    let json = r#"{"Id": "inv-001", "Rows": []}"#;
+    let json = r#"{"Id": "f0000000-0000-4000-8000-000000000001", "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -190,18 +191,25 @@ async fn test_invoice_with_amount_from_api() {
     let mut api = MockApi::new().await;
 
     let invoice_json = r#"{
-        "Id": "inv-001",
+        "Id": "f0000000-0000-4000-8000-000000000001",
         "InvoiceNumber": "1001",
-        "CustomerId": "cust-001",
+        "CustomerId": "c0000000-0000-4000-8000-000000000001",
         "TotalAmount": 999.99,
         "TotalVatAmount": 200.00,
         "TotalAmountIncludingVat": 1199.99,
         "Rows": []
     }"#;
 
-    let _mock = api.mock_get("/customerinvoices/inv-001", invoice_json);
+    let _mock = api.mock_get(
+        "/customerinvoices/f0000000-0000-4000-8000-000000000001",
+        invoice_json,
+    );
 
-    let result = api.client.invoices().get("inv-001").await;
+    let result = api
+        .client
+        .invoices()
+        .get(&id("f0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result.is_ok());
 
     let invoice = result.unwrap();
@@ -215,7 +223,7 @@ async fn test_article_with_prices_from_api() {
     let mut api = MockApi::new().await;
 
     let article_json = r#"{
-        "Id": "art-001",
+        "Id": "a0000000-0000-4000-8000-000000000001",
         "ArticleNumber": "ART-001",
         "Name": "Test Article",
         "SalesPrice": 149.99,
@@ -223,9 +231,16 @@ async fn test_article_with_prices_from_api() {
         "IsActive": true
     }"#;
 
-    let _mock = api.mock_get("/articles/art-001", article_json);
+    let _mock = api.mock_get(
+        "/articles/a0000000-0000-4000-8000-000000000001",
+        article_json,
+    );
 
-    let result = api.client.articles().get("art-001").await;
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result.is_ok());
 
     let article = result.unwrap();
@@ -239,9 +254,9 @@ async fn test_invoice_list_with_amounts() {
 
     let response = r#"{
         "Data": [
-            {"Id": "inv-001", "TotalAmount": 100.00, "Rows": []},
-            {"Id": "inv-002", "TotalAmount": 200.50, "Rows": []},
-            {"Id": "inv-003", "TotalAmount": 300.99, "Rows": []}
+            {"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": 100.00, "Rows": []},
+            {"Id": "f0000000-0000-4000-8000-000000000002", "TotalAmount": 200.50, "Rows": []},
+            {"Id": "f0000000-0000-4000-8000-000000000003", "TotalAmount": 300.99, "Rows": []}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -332,7 +347,8 @@ fn test_sum_invoice_rows() {
 #[test]
 fn test_amount_with_many_decimal_places() {
     // API might return amounts with many decimal places
-    let json = r#"{"Id": "inv-001", "TotalAmount": 123.456789, "Rows": []}"#;
+    let json =
+        r#"{"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": 123.456789, "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -345,7 +361,8 @@ fn test_amount_with_many_decimal_places() {
 #[test]
 fn test_amount_scientific_notation() {
     // JSON might use scientific notation for very small/large values
-    let json = r#"{"Id": "inv-001", "TotalAmount": 1.5e6, "Rows": []}"#;
+    let json =
+        r#"{"Id": "f0000000-0000-4000-8000-000000000001", "TotalAmount": 1.5e6, "Rows": []}"#;
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
@@ -0,0 +1,292 @@
+//! Integration tests for offline snapshots and the write outbox.
+#![cfg(feature = "offline")]
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use spiris::offline::{OfflineStore, ReplayStop};
+use spiris::{AccessToken, Client, ClientConfig, Customer, Method, PaginationParams, RetryConfig};
+
+const CUSTOMER: &str = r#"{"Id": "c0000000-0000-4000-8000-00000000000a", "Name": "Acme", "ModifiedUtc": "2024-01-01T10:00:00Z"}"#;
+
+fn offline_client(api: &MockApi, store: &OfflineStore) -> Client {
+    let config = ClientConfig::new()
+        .base_url(api.url())
+        .retry_config(RetryConfig::new().max_retries(0))
+        .offline_store(store.clone());
+    Client::with_config(
+        AccessToken::new("test_token".to_string(), 3600, None),
+        config,
+    )
+}
+
+#[tokio::test]
+async fn test_get_falls_back_to_snapshot_when_unreachable() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+
+    let online = api.mock_get("/customers/c0000000-0000-4000-8000-00000000000a", CUSTOMER);
+    let live = client
+        .customers()
+        .get_with_meta(&id("c0000000-0000-4000-8000-00000000000a"))
+        .await
+        .unwrap();
+    assert!(!live.is_cached());
+    online.remove();
+
+    let _unavailable = api
+        .server
+        .mock("GET", "/customers/c0000000-0000-4000-8000-00000000000a")
+        .with_status(503)
+        .create();
+
+    let cached = client
+        .customers()
+        .get_with_meta(&id("c0000000-0000-4000-8000-00000000000a"))
+        .await
+        .unwrap();
+    assert!(cached.is_cached());
+    assert!(cached.staleness().is_some());
+    assert_eq!(cached.attempts, 0);
+    assert_eq!(cached.data.name.as_deref(), Some("Acme"));
+
+    let customer = client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-00000000000a"))
+        .await
+        .unwrap();
+    assert_eq!(
+        customer.id.as_deref(),
+        Some("c0000000-0000-4000-8000-00000000000a")
+    );
+}
+
+#[tokio::test]
+async fn test_each_page_has_its_own_snapshot() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+    let page = |page| Some(PaginationParams::new().page(page).pagesize(1));
+
+    let first = api.mock_get_with_query(
+        "/customers",
+        vec![("page", "0")],
+        &mock_server::paginated_response(
+            r#"[{"Id": "c0000000-0000-4000-8000-00000000000a", "Name": "Acme"}]"#,
+            0,
+            2,
+        ),
+    );
+    let second = api.mock_get_with_query(
+        "/customers",
+        vec![("page", "1")],
+        &mock_server::paginated_response(
+            r#"[{"Id": "c0000000-0000-4000-8000-00000000000b", "Name": "Bolaget"}]"#,
+            1,
+            2,
+        ),
+    );
+    client.customers().list(page(0)).await.unwrap();
+    client.customers().list(page(1)).await.unwrap();
+    first.remove();
+    second.remove();
+
+    let _unavailable = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .create();
+
+    let names: Vec<Option<String>> = [
+        client.customers().list(page(0)).await.unwrap(),
+        client.customers().list(page(1)).await.unwrap(),
+    ]
+    .into_iter()
+    .map(|response| response.data[0].name.clone())
+    .collect();
+    assert_eq!(
+        names,
+        [Some("Acme".to_string()), Some("Bolaget".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_client_errors_are_not_masked_by_snapshots() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+
+    let online = api.mock_get("/customers/c0000000-0000-4000-8000-00000000000a", CUSTOMER);
+    client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-00000000000a"))
+        .await
+        .unwrap();
+    online.remove();
+
+    let _gone = api.mock_not_found("/customers/c0000000-0000-4000-8000-00000000000a");
+    let error = client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-00000000000a"))
+        .await
+        .unwrap_err();
+    assert!(error.is_not_found());
+
+    // Without a snapshot, the original error is returned.
+    let _unavailable = api
+        .server
+        .mock("GET", "/customers/c0000000-0000-4000-8000-00000000000b")
+        .with_status(503)
+        .create();
+    let error = client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-00000000000b"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.status_code(), Some(503));
+}
+
+#[tokio::test]
+async fn test_replay_sends_writes_in_order() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+
+    let mut customer: Customer = serde_json::from_str(CUSTOMER).unwrap();
+    customer.name = Some("Acme AB".to_string());
+
+    store
+        .queue_create("/customers", &Customer::default())
+        .unwrap();
+    store
+        .queue_update("/customers/c0000000-0000-4000-8000-00000000000a", &customer)
+        .unwrap();
+    store
+        .queue_delete("/customers/c0000000-0000-4000-8000-00000000000b", None)
+        .unwrap();
+
+    let pending = store.pending().unwrap();
+    assert_eq!(pending.len(), 3);
+    assert_eq!(pending[1].method, Method::PUT);
+    assert_eq!(
+        pending[1].expected_modified_utc.unwrap().to_rfc3339(),
+        "2024-01-01T10:00:00+00:00"
+    );
+
+    let create = api.mock_post(
+        "/customers",
+        r#"{"Id": "c0000000-0000-4000-0001-000000000000"}"#,
+    );
+    let current = api.mock_get("/customers/c0000000-0000-4000-8000-00000000000a", CUSTOMER);
+    let update = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-00000000000a")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"Name": "Acme AB"}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_body(CUSTOMER)
+        .create();
+    let delete = api
+        .server
+        .mock("DELETE", "/customers/c0000000-0000-4000-8000-00000000000b")
+        .with_status(204)
+        .create();
+
+    let report = store.replay(&client).await.unwrap();
+    create.assert();
+    current.assert();
+    update.assert();
+    delete.assert();
+
+    assert!(report.is_complete());
+    let sent: Vec<_> = report.applied.iter().map(|w| w.method.clone()).collect();
+    assert_eq!(sent, vec![Method::POST, Method::PUT, Method::DELETE]);
+    assert!(store.pending().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_replay_stops_at_conflict() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+
+    let customer: Customer = serde_json::from_str(CUSTOMER).unwrap();
+    let seq = store
+        .queue_update("/customers/c0000000-0000-4000-8000-00000000000a", &customer)
+        .unwrap();
+    store
+        .queue_delete("/customers/c0000000-0000-4000-8000-00000000000b", None)
+        .unwrap();
+
+    let changed = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-00000000000a",
+        r#"{"Id": "c0000000-0000-4000-8000-00000000000a", "Name": "Changed", "ModifiedUtc": "2024-01-02T08:00:00Z"}"#,
+    );
+    let update = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-00000000000a")
+        .expect(0)
+        .create();
+    let delete = api
+        .server
+        .mock("DELETE", "/customers/c0000000-0000-4000-8000-00000000000b")
+        .expect(0)
+        .create();
+
+    let report = store.replay(&client).await.unwrap();
+    update.assert();
+    delete.assert();
+    match report.stopped {
+        Some(ReplayStop::Conflict { write, current }) => {
+            assert_eq!(write.seq, seq);
+            assert_eq!(current.unwrap().to_rfc3339(), "2024-01-02T08:00:00+00:00");
+        }
+        other => panic!("expected a conflict, got {:?}", other),
+    }
+    assert_eq!(store.pending().unwrap().len(), 2);
+
+    // Forcing the write skips the check on the next replay.
+    changed.remove();
+    update.remove();
+    delete.remove();
+    assert!(store.force(seq).unwrap());
+    let update = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-00000000000a")
+        .with_status(200)
+        .with_body(CUSTOMER)
+        .create();
+    let delete = api
+        .server
+        .mock("DELETE", "/customers/c0000000-0000-4000-8000-00000000000b")
+        .with_status(204)
+        .create();
+
+    let report = store.replay(&client).await.unwrap();
+    update.assert();
+    delete.assert();
+    assert_eq!(report.applied.len(), 2);
+}
+
+#[tokio::test]
+async fn test_failed_write_stays_queued() {
+    let mut api = MockApi::new().await;
+    let store = OfflineStore::open_in_memory().unwrap();
+    let client = offline_client(&api, &store);
+
+    store
+        .queue_create("/customers", &Customer::default())
+        .unwrap();
+    let _error = api.mock_server_error("POST", "/customers");
+
+    let report = store.replay(&client).await.unwrap();
+    assert!(matches!(report.stopped, Some(ReplayStop::Failed { .. })));
+    assert!(report.applied.is_empty());
+
+    let pending = store.pending().unwrap();
+    assert_eq!(pending.len(), 1);
+    assert!(pending[0].last_error.is_some());
+}
@@ -4,7 +4,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{Order, OrderRow};
 
 #[tokio::test]
@@ -14,17 +14,17 @@ async fn test_list_orders() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "ord-001",
+                "Id": "0d000000-0000-4000-8000-000000000001",
                 "OrderNumber": "1001",
-                "CustomerId": "cust-001",
+                "CustomerId": "c0000000-0000-4000-8000-000000000001",
                 "TotalAmount": 5000.0,
                 "CurrencyCode": "SEK",
                 "IsInvoiced": false
             },
             {
-                "Id": "ord-002",
+                "Id": "0d000000-0000-4000-8000-000000000002",
                 "OrderNumber": "1002",
-                "CustomerId": "cust-002",
+                "CustomerId": "c0000000-0000-4000-8000-000000000002",
                 "TotalAmount": 10000.0,
                 "CurrencyCode": "SEK",
                 "IsInvoiced": true
@@ -44,7 +44,10 @@ async fn test_list_orders() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("ord-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("0d000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].order_number, Some("1001".to_string()));
 }
 
@@ -53,16 +56,16 @@ async fn test_get_order() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "ord-123",
+        "Id": "0d000000-0000-4000-8000-000000000123",
         "OrderNumber": "2001",
-        "CustomerId": "cust-456",
+        "CustomerId": "c0000000-0000-4000-8000-000000000456",
         "TotalAmount": 15000.0,
         "CurrencyCode": "SEK",
         "IsInvoiced": false,
         "Rows": [
             {
-                "Id": "row-001",
-                "ArticleId": "art-001",
+                "Id": "e0000000-0000-4000-8000-000000000001",
+                "ArticleId": "a0000000-0000-4000-8000-000000000001",
                 "Text": "Product A",
                 "UnitPrice": 1500.0,
                 "Quantity": 10.0
@@ -70,12 +73,23 @@ async fn test_get_order() {
         ]
     }"#;
 
-    let mock = api.mock_get("/orders/ord-123", response_body);
+    let mock = api.mock_get(
+        "/orders/0d000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.orders().get("ord-123").await.unwrap();
+    let result = api
+        .client
+        .orders()
+        .get(&id("0d000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("ord-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("0d000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.order_number, Some("2001".to_string()));
     assert_eq!(result.rows.len(), 1);
 }
@@ -85,9 +99,9 @@ async fn test_create_order() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "ord-new-001",
+        "Id": "0d000000-0000-4000-0001-000000000001",
         "OrderNumber": "3001",
-        "CustomerId": "cust-001",
+        "CustomerId": "c0000000-0000-4000-8000-000000000001",
         "TotalAmount": 2000.0,
         "CurrencyCode": "SEK",
         "IsInvoiced": false
@@ -96,7 +110,7 @@ async fn test_create_order() {
     let mock = api.mock_post("/orders", response_body);
 
     let new_order = Order {
-        customer_id: Some("cust-001".to_string()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         currency_code: Some("SEK".to_string()),
         rows: vec![OrderRow {
             text: Some("Product".to_string()),
@@ -110,7 +124,10 @@ async fn test_create_order() {
     let result = api.client.orders().create(&new_order).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("ord-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("0d000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.order_number, Some("3001".to_string()));
 }
 
@@ -119,22 +136,25 @@ async fn test_update_order() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "ord-123",
+        "Id": "0d000000-0000-4000-8000-000000000123",
         "OrderNumber": "2001",
         "TotalAmount": 20000.0
     }"#;
 
-    let mock = api.mock_put("/orders/ord-123", response_body);
+    let mock = api.mock_put(
+        "/orders/0d000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_order = Order {
-        id: Some("ord-123".to_string()),
+        id: Some("0d000000-0000-4000-8000-000000000123".parse().unwrap()),
         ..Default::default()
     };
 
     let result = api
         .client
         .orders()
-        .update("ord-123", &updated_order)
+        .update(&id("0d000000-0000-4000-8000-000000000123"), &updated_order)
         .await
         .unwrap();
 
@@ -146,9 +166,13 @@ async fn test_update_order() {
 async fn test_delete_order() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/orders/ord-123");
+    let mock = api.mock_delete("/orders/0d000000-0000-4000-8000-000000000123");
 
-    let result = api.client.orders().delete("ord-123").await;
+    let result = api
+        .client
+        .orders()
+        .delete(&id("0d000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -0,0 +1,113 @@
+//! Integration tests for owned (`'static`) endpoint handles and streams.
+
+mod mock_server;
+
+use mock_server::{fixtures, id, MockApi};
+use spiris::endpoints::CustomersEndpoint;
+
+/// A long-lived service that keeps an endpoint handle around.
+struct CustomerService {
+    customers: CustomersEndpoint<'static>,
+}
+
+#[tokio::test]
+async fn test_owned_endpoint_in_spawned_task() {
+    let mut api = MockApi::new().await;
+
+    let body = format!(
+        r#"{{"Data": {}, {}}}"#,
+        fixtures::customers_json(2),
+        mock_server::meta_json(0, 50, 1, 2)
+    );
+    let mock = api.mock_get("/customers", &body);
+
+    let customers = api.client.customers().into_owned();
+    let handle = tokio::spawn(async move { customers.list(None).await });
+
+    let result = handle.await.unwrap().unwrap();
+    mock.assert();
+    assert_eq!(result.data.len(), 2);
+}
+
+#[tokio::test]
+async fn test_owned_endpoint_outlives_client() {
+    let mut api = MockApi::new().await;
+
+    let mock = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-000000000001",
+        &fixtures::customer_json(1),
+    );
+
+    let service = {
+        let client = api.client.clone();
+        CustomerService {
+            customers: client.customers().into_owned(),
+        }
+    };
+
+    let customer = service
+        .customers
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await
+        .unwrap();
+    mock.assert();
+    assert_eq!(
+        customer.id.as_deref(),
+        Some("c0000000-0000-4000-8000-000000000001")
+    );
+}
+
+#[tokio::test]
+async fn test_macro_endpoint_into_owned() {
+    let mut api = MockApi::new().await;
+
+    let mock = api.mock_get("/projects", &mock_server::paginated_response("[]", 0, 0));
+
+    let projects = api.client.projects().into_owned();
+    let result = tokio::spawn(async move { projects.list(None).await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    mock.assert();
+    assert!(result.data.is_empty());
+}
+
+#[cfg(feature = "stream")]
+mod streams {
+    use super::*;
+    use futures::{Stream, StreamExt};
+    use spiris::{Customer, Result};
+
+    fn assert_send_static<S: Stream + Send + 'static>(stream: S) -> S {
+        stream
+    }
+
+    /// Streams can be returned from functions that only borrow the client.
+    fn all_customers(client: &spiris::Client) -> impl Stream<Item = Result<Customer>> + 'static {
+        client.customers().list_stream_with_page_size(50)
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_in_spawned_task() {
+        let mut api = MockApi::new().await;
+
+        let _mocks = api.mock_paginated(
+            "/customers",
+            vec![
+                (fixtures::customers(2), true),
+                (fixtures::customers(1), false),
+            ],
+        );
+
+        let stream = assert_send_static(all_customers(&api.client));
+        let handle = tokio::spawn(async move {
+            let items: Vec<_> = stream.collect().await;
+            items
+        });
+
+        let items = handle.await.unwrap();
+        assert_eq!(items.len(), 3);
+        assert!(items.iter().all(|item| item.is_ok()));
+    }
+}
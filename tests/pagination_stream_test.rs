@@ -105,8 +105,8 @@ async fn test_list_invoices_pagination() {
     let mut api = MockApi::new().await;
 
     let invoices = vec![
-        fixtures::invoice(1, "cust-001"),
-        fixtures::invoice(2, "cust-001"),
+        fixtures::invoice(1, "c0000000-0000-4000-8000-000000000001"),
+        fixtures::invoice(2, "c0000000-0000-4000-8000-000000000001"),
     ];
 
     let data = serde_json::to_string(&invoices).unwrap();
@@ -196,8 +196,14 @@ async fn test_manual_pagination_all_pages() {
     }
 
     assert_eq!(all_customers.len(), 5);
-    assert_eq!(all_customers[0].id, Some("cust-001".to_string()));
-    assert_eq!(all_customers[4].id, Some("cust-005".to_string()));
+    assert_eq!(
+        all_customers[0].id,
+        Some("c0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
+    assert_eq!(
+        all_customers[4].id,
+        Some("c0000000-0000-4000-8000-000000000005".parse().unwrap())
+    );
 }
 
 // =============================================================================
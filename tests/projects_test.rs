@@ -2,7 +2,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::Project;
 
 #[tokio::test]
@@ -12,13 +12,13 @@ async fn test_list_projects() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "proj-001",
+                "Id": "90000000-0000-4000-8000-000000000001",
                 "ProjectNumber": "P001",
                 "Name": "Website Redesign",
                 "IsCompleted": false
             },
             {
-                "Id": "proj-002",
+                "Id": "90000000-0000-4000-8000-000000000002",
                 "ProjectNumber": "P002",
                 "Name": "Mobile App",
                 "IsCompleted": false
@@ -38,7 +38,10 @@ async fn test_list_projects() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("proj-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("90000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].name, Some("Website Redesign".to_string()));
 }
 
@@ -47,24 +50,38 @@ async fn test_get_project() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "proj-123",
+        "Id": "90000000-0000-4000-8000-000000000123",
         "ProjectNumber": "P100",
         "Name": "Enterprise Integration",
         "StartDate": "2024-01-01T00:00:00Z",
         "EndDate": "2024-12-31T00:00:00Z",
-        "CustomerId": "cust-456",
+        "CustomerId": "c0000000-0000-4000-8000-000000000456",
         "Notes": "Major client project",
         "IsCompleted": false
     }"#;
 
-    let mock = api.mock_get("/projects/proj-123", response_body);
+    let mock = api.mock_get(
+        "/projects/90000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.projects().get("proj-123").await.unwrap();
+    let result = api
+        .client
+        .projects()
+        .get(&id("90000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("proj-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("90000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.name, Some("Enterprise Integration".to_string()));
-    assert_eq!(result.customer_id, Some("cust-456".to_string()));
+    assert_eq!(
+        result.customer_id,
+        Some("c0000000-0000-4000-8000-000000000456".parse().unwrap())
+    );
 }
 
 #[tokio::test]
@@ -72,7 +89,7 @@ async fn test_create_project() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "proj-new-001",
+        "Id": "90000000-0000-4000-0001-000000000001",
         "ProjectNumber": "P200",
         "Name": "New Project",
         "IsCompleted": false
@@ -88,7 +105,10 @@ async fn test_create_project() {
     let result = api.client.projects().create(&new_project).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("proj-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("90000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.project_number, Some("P200".to_string()));
 }
 
@@ -97,16 +117,19 @@ async fn test_update_project() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "proj-123",
+        "Id": "90000000-0000-4000-8000-000000000123",
         "ProjectNumber": "P100",
         "Name": "Updated Project Name",
         "IsCompleted": true
     }"#;
 
-    let mock = api.mock_put("/projects/proj-123", response_body);
+    let mock = api.mock_put(
+        "/projects/90000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_project = Project {
-        id: Some("proj-123".to_string()),
+        id: Some("90000000-0000-4000-8000-000000000123".parse().unwrap()),
         name: Some("Updated Project Name".to_string()),
         is_completed: Some(true),
         ..Default::default()
@@ -115,7 +138,10 @@ async fn test_update_project() {
     let result = api
         .client
         .projects()
-        .update("proj-123", &updated_project)
+        .update(
+            &id("90000000-0000-4000-8000-000000000123"),
+            &updated_project,
+        )
         .await
         .unwrap();
 
@@ -128,9 +154,13 @@ async fn test_update_project() {
 async fn test_delete_project() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/projects/proj-123");
+    let mock = api.mock_delete("/projects/90000000-0000-4000-8000-000000000123");
 
-    let result = api.client.projects().delete("proj-123").await;
+    let result = api
+        .client
+        .projects()
+        .delete(&id("90000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -36,6 +36,15 @@ fn test_query_params_select() {
     assert_eq!(params.select, Some("Id,Name,Email".to_string()));
 }
 
+#[test]
+fn test_query_params_orderby() {
+    let params = QueryParams::new().orderby("ModifiedUtc desc");
+    assert_eq!(params.orderby, Some("ModifiedUtc desc".to_string()));
+
+    let json = serde_json::to_value(&params).unwrap();
+    assert_eq!(json, serde_json::json!({"orderby": "ModifiedUtc desc"}));
+}
+
 #[test]
 fn test_query_params_custom_param() {
     let params = QueryParams::new().param("customKey", "customValue");
@@ -352,8 +361,8 @@ async fn test_invoice_list_pagination() {
     let mut api = MockApi::new().await;
 
     let invoices = vec![
-        fixtures::invoice(1, "cust-001"),
-        fixtures::invoice(2, "cust-001"),
+        fixtures::invoice(1, "c0000000-0000-4000-8000-000000000001"),
+        fixtures::invoice(2, "c0000000-0000-4000-8000-000000000001"),
     ];
     let data = serde_json::to_string(&invoices).unwrap();
     let meta = meta_json(1, 20, 3, 55);
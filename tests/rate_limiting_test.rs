@@ -11,7 +11,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{ApiErrorResponse, Error};
 
 fn api_error(status_code: u16, message: &str) -> Error {
@@ -19,6 +19,7 @@ fn api_error(status_code: u16, message: &str) -> Error {
         status_code,
         response: ApiErrorResponse::from_raw(message.to_string()),
         raw_body: message.to_string(),
+        headers: Default::default(),
     }
 }
 
@@ -95,12 +96,16 @@ async fn test_rate_limit_on_article_get() {
 
     let _mock = api.mock_error(
         "GET",
-        "/articles/art-001",
+        "/articles/a0000000-0000-4000-8000-000000000001",
         429,
         r#"{"Message": "Too Many Requests"}"#,
     );
 
-    let result = api.client.articles().get("art-001").await;
+    let result = api
+        .client
+        .articles()
+        .get(&id("a0000000-0000-4000-8000-000000000001"))
+        .await;
 
     assert!(result.is_err(), "Should fail on rate limit");
 }
@@ -247,7 +252,7 @@ async fn test_recovery_after_rate_limit_sequence() {
     let customer_json = serde_json::to_string(&customer).unwrap();
 
     let _mocks = api.mock_get_sequence(
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         vec![
             MockResponse::rate_limit(5),
             MockResponse::ok(&customer_json),
@@ -255,11 +260,19 @@ async fn test_recovery_after_rate_limit_sequence() {
     );
 
     // First call - rate limited
-    let result1 = api.client.customers().get("cust-001").await;
+    let result1 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result1.is_err(), "First call should be rate limited");
 
     // Second call - success
-    let result2 = api.client.customers().get("cust-001").await;
+    let result2 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result2.is_ok(), "Second call should succeed");
 }
 
@@ -274,7 +287,7 @@ async fn test_multiple_rate_limits_then_success() {
 
     // Three rate limits then success
     let _mocks = api.mock_get_sequence(
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         vec![
             MockResponse::rate_limit(1),
             MockResponse::rate_limit(1),
@@ -285,12 +298,20 @@ async fn test_multiple_rate_limits_then_success() {
 
     // First three calls fail
     for i in 1..=3 {
-        let result = api.client.customers().get("cust-001").await;
+        let result = api
+            .client
+            .customers()
+            .get(&id("c0000000-0000-4000-8000-000000000001"))
+            .await;
         assert!(result.is_err(), "Call {} should be rate limited", i);
     }
 
     // Fourth call succeeds
-    let result = api.client.customers().get("cust-001").await;
+    let result = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result.is_ok(), "Fourth call should succeed");
 }
 
@@ -309,20 +330,18 @@ fn test_rate_limit_error_is_retryable() {
     );
 }
 
-/// Note: Currently 429 as ApiError is NOT retryable because it's a 4xx error.
-/// The client should convert 429 responses to RateLimitExceeded error variant,
-/// which IS retryable. This test documents the current behavior.
+/// 429 responses are reported as `ApiError` and are retryable, unlike
+/// other 4xx errors.
 #[test]
 fn test_429_api_error_behavior() {
     use spiris::retry::is_retryable_error;
 
     let error = api_error(429, "Too Many Requests");
-    // Currently 429 as ApiError is NOT retryable (it's a 4xx code)
-    // The proper fix is for the client to convert 429 to RateLimitExceeded
     assert!(
-        !is_retryable_error(&error),
-        "429 as ApiError is currently not retryable (should be converted to RateLimitExceeded)"
+        is_retryable_error(&error),
+        "429 as ApiError should be retryable"
     );
+    assert!(error.is_rate_limited());
 }
 
 // =============================================================================
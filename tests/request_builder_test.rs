@@ -0,0 +1,242 @@
+//! Integration tests for the raw request builder.
+
+mod mock_server;
+
+use mock_server::MockApi;
+use mockito::Matcher;
+use spiris::middleware::{Middleware, RequestContext};
+use spiris::{AccessToken, Client, ClientConfig, Error, Method, RetryConfig};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_request_send_json_with_query() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("GET", "/pricelists")
+        .match_header("Authorization", "Bearer test_token")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("$pagesize".into(), "100".into()),
+            Matcher::UrlEncoded("$filter".into(), "IsActive eq true".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"Id": "pl-1", "Name": "Standard"}]"#)
+        .create();
+
+    let result: serde_json::Value = api
+        .client
+        .request(Method::GET, "/pricelists")
+        .query(&[("$pagesize", "100")])
+        .query(&[("$filter", "IsActive eq true")])
+        .send_json()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(result[0]["Name"], "Standard");
+}
+
+#[tokio::test]
+async fn test_request_post_json_with_header() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("POST", "/voucherattachments")
+        .match_header("Content-Type", "application/json")
+        .match_header("X-Correlation-Id", "abc-123")
+        .match_body(Matcher::Json(
+            serde_json::json!({"VoucherId": "70000000-0000-4001-8000-000000000001"}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "fa000000-0000-4000-8000-000000000001"}"#)
+        .create();
+
+    let result: serde_json::Value = api
+        .client
+        .request(Method::POST, "/voucherattachments")
+        .json(&serde_json::json!({"VoucherId": "70000000-0000-4001-8000-000000000001"}))
+        .header("X-Correlation-Id", "abc-123")
+        .send_json()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(result["Id"], "fa000000-0000-4000-8000-000000000001");
+}
+
+#[tokio::test]
+async fn test_request_send_raw_exposes_status_and_headers() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("POST", "/customers")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_header(
+            "Location",
+            "/customers/b0000000-0000-4000-0004-000000000000",
+        )
+        .with_body(r#"{"Id": "b0000000-0000-4000-0004-000000000000"}"#)
+        .create();
+
+    let response = api
+        .client
+        .request(Method::POST, "/customers")
+        .json(&serde_json::json!({"Name": "Acme"}))
+        .send_raw()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 201);
+    assert_eq!(
+        response.header("Location"),
+        Some("/customers/b0000000-0000-4000-0004-000000000000")
+    );
+    let body: serde_json::Value = response.json().unwrap();
+    assert_eq!(body["Id"], "b0000000-0000-4000-0004-000000000000");
+}
+
+#[tokio::test]
+async fn test_request_send_bytes() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("GET", "/vouchers/70000000-0000-4001-8000-000000000001/pdf")
+        .with_status(200)
+        .with_header("content-type", "application/pdf")
+        .with_body(b"%PDF-1.4")
+        .create();
+
+    let bytes = api
+        .client
+        .request(
+            Method::GET,
+            "/vouchers/70000000-0000-4001-8000-000000000001/pdf",
+        )
+        .send_bytes()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(bytes, b"%PDF-1.4");
+}
+
+#[tokio::test]
+async fn test_request_error_is_mapped() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("DELETE", "/pricelists/pl-1")
+        .with_status(500)
+        .with_body(r#"{"Message": "Boom"}"#)
+        .create();
+
+    let result = api
+        .client
+        .request(Method::DELETE, "/pricelists/pl-1")
+        .send_raw()
+        .await;
+
+    mock.assert();
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 500,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_request_expired_token() {
+    let api = MockApi::with_expired_token().await;
+
+    let result = api
+        .client
+        .request(Method::GET, "/pricelists")
+        .send_bytes()
+        .await;
+
+    assert!(matches!(result, Err(Error::TokenExpired)));
+}
+
+#[tokio::test]
+async fn test_request_is_retried() {
+    let mut server = mockito::Server::new_async().await;
+    let token = AccessToken::new("test_token".to_string(), 3600, None);
+    let config = ClientConfig::new().base_url(server.url()).retry_config(
+        RetryConfig::new()
+            .max_retries(3)
+            .initial_interval(Duration::from_millis(10)),
+    );
+    let client = Client::with_config(token, config);
+
+    let failing = server
+        .mock("GET", "/vatreports")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let succeeding = server
+        .mock("GET", "/vatreports")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create();
+
+    let result: Vec<serde_json::Value> = client
+        .request(Method::GET, "/vatreports")
+        .send_json()
+        .await
+        .unwrap();
+
+    failing.assert();
+    succeeding.assert();
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn test_request_runs_middleware() {
+    struct CountingMiddleware(Arc<AtomicUsize>);
+
+    impl Middleware for CountingMiddleware {
+        fn on_request(&self, ctx: &mut RequestContext) -> spiris::Result<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            ctx.add_header("X-From-Middleware", "yes");
+            Ok(())
+        }
+    }
+
+    let mut server = mockito::Server::new_async().await;
+    let count = Arc::new(AtomicUsize::new(0));
+    let token = AccessToken::new("test_token".to_string(), 3600, None);
+    let config = ClientConfig::new()
+        .base_url(server.url())
+        .middleware(CountingMiddleware(count.clone()));
+    let client = Client::with_config(token, config);
+
+    let mock = server
+        .mock("GET", "/pricelists")
+        .match_header("X-From-Middleware", "yes")
+        .with_status(200)
+        .with_body("[]")
+        .create();
+
+    client
+        .request(Method::GET, "/pricelists")
+        .send_bytes()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,185 @@
+//! Integration tests for typed create and update requests.
+
+mod mock_server;
+
+use chrono::{TimeZone, Utc};
+use mock_server::{id, money, MockApi};
+use serde_json::json;
+use spiris::{
+    CostCenterItemCreate, CostCenterItemUpdate, FiscalYearCreate, ProjectUpdate, SupplierCreate,
+    SupplierInvoiceDraftCreate, SupplierInvoiceDraftUpdate, SupplierInvoiceRowCreate,
+    VoucherCreate, VoucherRowCreate,
+};
+
+#[tokio::test]
+async fn test_create_supplier_sends_only_set_fields() {
+    let mut api = MockApi::new().await;
+    let mock = api.mock_post_with_body(
+        "/suppliers",
+        r#"{"Name": "Office Supplies AB", "BankGiroNumber": "123-4567"}"#,
+        r#"{"Id": "d0000000-0000-4000-8000-000000000001", "Name": "Office Supplies AB"}"#,
+    );
+
+    let supplier = SupplierCreate::new("Office Supplies AB".to_string())
+        .bank_giro_number("123-4567".to_string());
+    let created = api.client.suppliers().create(&supplier).await.unwrap();
+
+    assert_eq!(created.name.as_deref(), Some("Office Supplies AB"));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_voucher_with_rows() {
+    let mut api = MockApi::new().await;
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let expected = json!({
+        "VoucherDate": date,
+        "VoucherText": "Office rent",
+        "Rows": [
+            {"AccountNumber": "5010", "DebitAmount": money(12000.0)},
+            {"AccountNumber": "1930", "CreditAmount": money(12000.0), "TransactionText": "Rent"},
+        ],
+    });
+    let mock = api.mock_post_with_body(
+        "/vouchers",
+        &expected.to_string(),
+        r#"{"Id": "e0000000-0000-4000-8000-000000000001", "VoucherText": "Office rent"}"#,
+    );
+
+    let voucher = VoucherCreate::new(date, "Office rent".to_string())
+        .row(VoucherRowCreate::debit("5010".to_string(), money(12000.0)))
+        .row(
+            VoucherRowCreate::credit("1930".to_string(), money(12000.0))
+                .transaction_text("Rent".to_string()),
+        );
+    api.client.vouchers().create(&voucher).await.unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_partial_updates() {
+    let mut api = MockApi::new().await;
+    let project = api
+        .server
+        .mock("PUT", "/projects/b0000000-0000-4000-8000-000000000001")
+        .match_body(mockito::Matcher::Json(json!({"IsCompleted": true})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "b0000000-0000-4000-8000-000000000001", "IsCompleted": true}"#)
+        .create();
+    let item = api
+        .server
+        .mock(
+            "PUT",
+            "/costcenteritems/b0000000-0000-4000-8000-000000000002",
+        )
+        .match_body(mockito::Matcher::Json(json!({"IsActive": false})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "b0000000-0000-4000-8000-000000000002", "IsActive": false}"#)
+        .create();
+
+    let updated = api
+        .client
+        .projects()
+        .update(
+            &id("b0000000-0000-4000-8000-000000000001"),
+            &ProjectUpdate::new().is_completed(true),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.is_completed, Some(true));
+
+    api.client
+        .cost_centers()
+        .update_item(
+            &id("b0000000-0000-4000-8000-000000000002"),
+            &CostCenterItemUpdate::new().is_active(false),
+        )
+        .await
+        .unwrap();
+
+    project.assert();
+    item.assert();
+}
+
+#[tokio::test]
+async fn test_supplier_invoice_draft_requests() {
+    let mut api = MockApi::new().await;
+    let create = api
+        .server
+        .mock("POST", "/supplierinvoicedrafts")
+        .match_body(mockito::Matcher::Json(json!({
+            "SupplierId": "d0000000-0000-4000-8000-000000000001",
+            "InvoiceNumber": "F-1001",
+            "Rows": [{"AccountNumber": "5410", "Amount": money(800.0)}],
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "d1000000-0000-4000-8000-000000000001", "InvoiceNumber": "F-1001"}"#)
+        .create();
+    let update = api
+        .server
+        .mock(
+            "PUT",
+            "/supplierinvoicedrafts/d1000000-0000-4000-8000-000000000001",
+        )
+        .match_body(mockito::Matcher::Json(json!({"CurrencyCode": "EUR"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "d1000000-0000-4000-8000-000000000001", "CurrencyCode": "EUR"}"#)
+        .create();
+
+    let draft = api
+        .client
+        .supplier_invoice_drafts()
+        .create(
+            &SupplierInvoiceDraftCreate::new(id("d0000000-0000-4000-8000-000000000001"))
+                .invoice_number("F-1001".to_string())
+                .row(SupplierInvoiceRowCreate::new(
+                    "5410".to_string(),
+                    money(800.0),
+                )),
+        )
+        .await
+        .unwrap();
+    assert_eq!(draft.invoice_number.as_deref(), Some("F-1001"));
+
+    let draft = api
+        .client
+        .supplier_invoice_drafts()
+        .update(
+            &id("d1000000-0000-4000-8000-000000000001"),
+            &SupplierInvoiceDraftUpdate::new().currency_code("EUR".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(draft.currency_code.as_deref(), Some("EUR"));
+
+    create.assert();
+    update.assert();
+}
+
+#[test]
+fn test_required_fields_are_always_sent() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+
+    let fiscal_year = serde_json::to_value(FiscalYearCreate::new(start, end)).unwrap();
+    assert_eq!(fiscal_year, json!({"StartDate": start, "EndDate": end}));
+
+    let item = CostCenterItemCreate::new(
+        id("b0000000-0000-4000-8000-000000000003"),
+        "Stockholm".to_string(),
+        "STH".to_string(),
+    );
+    assert_eq!(
+        serde_json::to_value(item).unwrap(),
+        json!({
+            "CostCenterId": "b0000000-0000-4000-8000-000000000003",
+            "Name": "Stockholm",
+            "ShortName": "STH",
+        })
+    );
+}
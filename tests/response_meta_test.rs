@@ -0,0 +1,141 @@
+//! Integration tests for the `*_with_meta` endpoint variants.
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use spiris::{AccessToken, Client, ClientConfig, Customer, PaginationParams, RetryConfig};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_create_with_meta_exposes_headers() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("POST", "/customers")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_header(
+            "Location",
+            "/customers/c0000000-0000-4001-8000-000000000001",
+        )
+        .with_header("X-Request-Id", "req-42")
+        .with_header("X-RateLimit-Remaining", "599")
+        .with_body(r#"{"Id": "c0000000-0000-4001-8000-000000000001", "Name": "Acme"}"#)
+        .create();
+
+    let customer = Customer {
+        name: Some("Acme".to_string()),
+        ..Default::default()
+    };
+    let response = api
+        .client
+        .customers()
+        .create_with_meta(&customer)
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 201);
+    assert_eq!(
+        response.location(),
+        Some("/customers/c0000000-0000-4001-8000-000000000001")
+    );
+    assert_eq!(response.request_id(), Some("req-42"));
+    assert_eq!(response.rate_limit_remaining(), Some(599));
+    assert_eq!(response.attempts, 1);
+    assert_eq!(
+        response.data.id.as_deref(),
+        Some("c0000000-0000-4001-8000-000000000001")
+    );
+}
+
+#[tokio::test]
+async fn test_list_with_meta_passes_pagination() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("GET", "/units")
+        .match_query(mockito::Matcher::UrlEncoded("pagesize".into(), "10".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_server::paginated_response(
+            r#"[{"Id": "d0000000-0000-4000-8000-000000000001", "Name": "Styck"}]"#,
+            0,
+            1,
+        ))
+        .create();
+
+    let response = api
+        .client
+        .units()
+        .list_with_meta(Some(PaginationParams::new().pagesize(10)))
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.data.data.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_with_meta() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock(
+            "DELETE",
+            "/attachments/ae000000-0000-4000-8000-000000000001",
+        )
+        .with_status(204)
+        .with_header("x-correlation-id", "corr-7")
+        .create();
+
+    let response = api
+        .client
+        .attachments()
+        .delete_with_meta(&id("ae000000-0000-4000-8000-000000000001"))
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 204);
+    assert_eq!(response.request_id(), Some("corr-7"));
+}
+
+#[tokio::test]
+async fn test_with_meta_counts_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let token = AccessToken::new("test_token".to_string(), 3600, None);
+    let config = ClientConfig::new().base_url(server.url()).retry_config(
+        RetryConfig::new()
+            .max_retries(3)
+            .initial_interval(Duration::from_millis(10)),
+    );
+    let client = Client::with_config(token, config);
+
+    let failing = server
+        .mock("GET", "/customers/c0000000-0000-4001-8000-000000000001")
+        .with_status(503)
+        .expect(2)
+        .create();
+    let succeeding = server
+        .mock("GET", "/customers/c0000000-0000-4001-8000-000000000001")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Id": "c0000000-0000-4001-8000-000000000001"}"#)
+        .create();
+
+    let response = client
+        .customers()
+        .get_with_meta(&id("c0000000-0000-4001-8000-000000000001"))
+        .await
+        .unwrap();
+
+    failing.assert();
+    succeeding.assert();
+    assert_eq!(response.attempts, 3);
+    assert_eq!(response.retries(), 2);
+}
@@ -0,0 +1,205 @@
+//! Integration tests for resumable pagination streams.
+#![cfg(feature = "stream")]
+
+mod mock_server;
+
+use futures::StreamExt;
+use mock_server::{fixtures, meta_json, MockApi};
+use mockito::{Matcher, Mock};
+use spiris::pagination::{Checkpoint, StreamOptions};
+use spiris::{Customer, Error, QueryParams};
+
+fn mock_page(api: &mut MockApi, page: u32, ids: &[u32], total_pages: u32) -> Mock {
+    let customers: Vec<Customer> = ids.iter().copied().map(fixtures::customer).collect();
+    let body = format!(
+        r#"{{"Data": {}, {}}}"#,
+        serde_json::to_string(&customers).unwrap(),
+        meta_json(page, 2, total_pages, total_pages * 2)
+    );
+    api.server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded("page".into(), page.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create()
+}
+
+fn ids(customers: &[Customer]) -> Vec<&str> {
+    customers.iter().filter_map(|c| c.id.as_deref()).collect()
+}
+
+#[tokio::test]
+async fn test_resume_after_failed_page() {
+    let mut api = MockApi::new().await;
+
+    let _page0 = mock_page(&mut api, 0, &[1, 2], 3);
+    let failing = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(500)
+        .with_body(r#"{"Message": "Internal error"}"#)
+        .create();
+
+    let options = StreamOptions::new().page_size(2);
+    let mut stream = api.client.customers().list_stream_resumable(options);
+
+    let mut customers = Vec::new();
+    let error = loop {
+        match stream.next().await {
+            Some(Ok(customer)) => customers.push(customer),
+            Some(Err(error)) => break error,
+            None => panic!("stream ended without the expected error"),
+        }
+    };
+    assert!(matches!(
+        error,
+        Error::ApiError {
+            status_code: 500,
+            ..
+        }
+    ));
+    assert_eq!(
+        ids(&customers),
+        vec![
+            "c0000000-0000-4000-8000-000000000001",
+            "c0000000-0000-4000-8000-000000000002"
+        ]
+    );
+
+    let checkpoint = stream.checkpoint();
+    assert_eq!(checkpoint.path, "/customers");
+    assert_eq!(checkpoint.page, 1);
+    assert_eq!(checkpoint.items_yielded, 2);
+    assert!(!checkpoint.finished);
+
+    // Checkpoints survive a round trip through storage.
+    let saved = serde_json::to_string(&checkpoint).unwrap();
+    let restored: Checkpoint = serde_json::from_str(&saved).unwrap();
+    assert_eq!(restored, checkpoint);
+
+    failing.remove();
+    let _page1 = mock_page(&mut api, 1, &[3, 4], 3);
+    let _page2 = mock_page(&mut api, 2, &[5, 6], 3);
+
+    let resumed = api
+        .client
+        .customers()
+        .resume_stream(restored, StreamOptions::new())
+        .unwrap();
+    let rest: Vec<Customer> = resumed
+        .map(|result| result.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(
+        ids(&rest),
+        vec![
+            "c0000000-0000-4000-8000-000000000003",
+            "c0000000-0000-4000-8000-000000000004",
+            "c0000000-0000-4000-8000-000000000005",
+            "c0000000-0000-4000-8000-000000000006"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_skips_items_shifted_across_pages() {
+    let mut api = MockApi::new().await;
+
+    // A customer created during the export shifts cust-002 onto page 1.
+    let _page0 = mock_page(&mut api, 0, &[1, 2], 2);
+    let _page1 = mock_page(&mut api, 1, &[2, 3], 2);
+
+    let mut stream = api
+        .client
+        .customers()
+        .list_stream_resumable(StreamOptions::new().page_size(2));
+
+    let mut customers = Vec::new();
+    while let Some(customer) = stream.next().await {
+        customers.push(customer.unwrap());
+    }
+
+    assert_eq!(
+        ids(&customers),
+        vec![
+            "c0000000-0000-4000-8000-000000000001",
+            "c0000000-0000-4000-8000-000000000002",
+            "c0000000-0000-4000-8000-000000000003"
+        ]
+    );
+    let checkpoint = stream.checkpoint();
+    assert_eq!(checkpoint.duplicates_skipped, 1);
+    assert_eq!(checkpoint.items_yielded, 3);
+    assert!(checkpoint.finished);
+}
+
+#[tokio::test]
+async fn test_resume_mid_page_skips_yielded_items() {
+    let mut api = MockApi::new().await;
+
+    let _page0 = mock_page(&mut api, 0, &[1, 2], 1);
+
+    let mut stream = api.client.customers().search_stream_resumable(
+        QueryParams::new().filter("IsActive eq true"),
+        StreamOptions::new().page_size(2),
+    );
+
+    // The first item counts as handled once the second one is requested.
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(
+        first.id.as_deref(),
+        Some("c0000000-0000-4000-8000-000000000001")
+    );
+    assert_eq!(stream.checkpoint().items_yielded, 0);
+    stream.next().await.unwrap().unwrap();
+
+    let checkpoint = stream.checkpoint();
+    assert_eq!(checkpoint.page, 0);
+    assert_eq!(checkpoint.items_yielded, 1);
+    assert_eq!(
+        checkpoint.seen_ids,
+        vec!["c0000000-0000-4000-8000-000000000001".to_string()]
+    );
+    assert_eq!(
+        checkpoint.query.as_ref().and_then(|q| q.filter.as_deref()),
+        Some("IsActive eq true")
+    );
+
+    let resumed = api
+        .client
+        .customers()
+        .resume_stream(checkpoint, StreamOptions::new())
+        .unwrap();
+    let rest: Vec<Customer> = resumed.map(|r| r.unwrap()).collect::<Vec<_>>().await;
+    assert_eq!(ids(&rest), vec!["c0000000-0000-4000-8000-000000000002"]);
+}
+
+#[tokio::test]
+async fn test_resume_finished_checkpoint_yields_nothing() {
+    let api = MockApi::new().await;
+
+    let mut checkpoint = Checkpoint::new("/customers", 2, None);
+    checkpoint.finished = true;
+
+    let stream = api
+        .client
+        .customers()
+        .resume_stream(checkpoint, StreamOptions::new())
+        .unwrap();
+    assert_eq!(stream.count().await, 0);
+}
+
+#[tokio::test]
+async fn test_resume_rejects_checkpoint_from_other_endpoint() {
+    let api = MockApi::new().await;
+
+    let checkpoint = Checkpoint::new("/customerinvoices", 50, None);
+    let result = api
+        .client
+        .customers()
+        .resume_stream(checkpoint, StreamOptions::new());
+
+    assert!(matches!(result, Err(Error::InvalidConfig(_))));
+}
@@ -9,7 +9,7 @@
 
 mod mock_server;
 
-use mock_server::{MockApi, MockResponse};
+use mock_server::{id, MockApi, MockResponse};
 use spiris::{ApiErrorResponse, Error, RetryConfig};
 use std::time::Duration;
 
@@ -18,6 +18,7 @@ fn api_error(status_code: u16, message: &str) -> Error {
         status_code,
         response: ApiErrorResponse::from_raw(message.to_string()),
         raw_body: message.to_string(),
+        headers: Default::default(),
     }
 }
 
@@ -271,13 +272,13 @@ async fn test_mock_server_sequence_setup() {
 
     // Set up a sequence: 500, 500, then success
     let customer_json = r#"{
-        "Id": "cust-001",
+        "Id": "c0000000-0000-4000-8000-000000000001",
         "Name": "Test Customer",
         "IsActive": true
     }"#;
 
     let _mocks = api.mock_get_sequence(
-        "/customers/cust-001",
+        "/customers/c0000000-0000-4000-8000-000000000001",
         vec![
             MockResponse::error(500, "Internal Server Error"),
             MockResponse::error(502, "Bad Gateway"),
@@ -286,15 +287,27 @@ async fn test_mock_server_sequence_setup() {
     );
 
     // First call - 500 error
-    let result1 = api.client.customers().get("cust-001").await;
+    let result1 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result1.is_err(), "First call should fail with 500");
 
     // Second call - 502 error
-    let result2 = api.client.customers().get("cust-001").await;
+    let result2 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result2.is_err(), "Second call should fail with 502");
 
     // Third call - success
-    let result3 = api.client.customers().get("cust-001").await;
+    let result3 = api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await;
     assert!(result3.is_ok(), "Third call should succeed");
 }
 
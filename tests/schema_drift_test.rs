@@ -0,0 +1,96 @@
+//! Integration tests for schema drift detection.
+
+mod mock_server;
+
+use mock_server::{id, paginated_response, MockApi};
+use spiris::drift::SchemaDriftChecker;
+use spiris::{AccessToken, Client, ClientConfig, Error, RetryConfig};
+use std::sync::{Arc, Mutex};
+
+fn client(api: &MockApi, checker: &SchemaDriftChecker) -> Client {
+    Client::with_config(
+        AccessToken::new("test_token".to_string(), 3600, None),
+        ClientConfig::new()
+            .base_url(api.url())
+            .retry_config(RetryConfig::new().max_retries(0))
+            .schema_drift(checker.clone()),
+    )
+}
+
+#[tokio::test]
+async fn test_drift_is_reported_per_endpoint() {
+    let mut api = MockApi::new().await;
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+    let checker = SchemaDriftChecker::new().on_drift(move |drift| {
+        sink.lock().unwrap().push(drift.clone());
+    });
+    let client = client(&api, &checker);
+
+    let _list = api.mock_get(
+        "/customers",
+        &paginated_response(
+            r#"[{"Id": "c0000000-0000-4000-8000-000000000001", "Name": "Acme", "Email": null}]"#,
+            0,
+            1,
+        ),
+    );
+    let _first = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-000000000001",
+        r#"{"Id": "c0000000-0000-4000-8000-000000000001", "Name": "Acme", "LoyaltyTier": "Gold"}"#,
+    );
+    let _second = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-000000000002",
+        r#"{"Id": "c0000000-0000-4000-8000-000000000002", "LoyaltyTier": "Silver"}"#,
+    );
+
+    client.customers().list(None).await.unwrap();
+    for customer_id in [
+        "c0000000-0000-4000-8000-000000000001",
+        "c0000000-0000-4000-8000-000000000002",
+    ] {
+        client.customers().get(&id(customer_id)).await.unwrap();
+    }
+
+    let report = checker.report();
+    let list = &report["GET /customers"];
+    assert_eq!(list.responses, 1);
+    assert!(list.new_fields.is_empty());
+    assert_eq!(list.null_fields["Data[].Email"], 1);
+
+    let get = &report["GET /customers/{id}"];
+    assert_eq!(get.responses, 2);
+    assert_eq!(get.new_fields["LoyaltyTier"], 2);
+    assert_eq!(get.missing_fields["Name"], 1);
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 2);
+    assert_eq!(reported[0].endpoint, "GET /customers/{id}");
+    assert_eq!(reported[0].new_fields, vec!["LoyaltyTier"]);
+    assert_eq!(reported[1].missing_fields, vec!["Name"]);
+}
+
+#[tokio::test]
+async fn test_type_mismatch_is_reported_and_fails() {
+    let mut api = MockApi::new().await;
+    let checker = SchemaDriftChecker::new();
+    let client = client(&api, &checker);
+
+    let _get = api.mock_get(
+        "/customers/c0000000-0000-4000-8000-000000000001",
+        r#"{"Id": "c0000000-0000-4000-8000-000000000001", "Name": ["Acme"]}"#,
+    );
+
+    let err = client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Json(_)));
+
+    let drift = checker.endpoint("GET /customers/{id}").unwrap();
+    assert_eq!(drift.type_mismatches["Name"], 1);
+
+    checker.reset();
+    assert!(checker.report().is_empty());
+}
@@ -0,0 +1,168 @@
+//! Integration tests for filtered pagination streams.
+#![cfg(feature = "stream")]
+
+mod mock_server;
+
+use futures::{StreamExt, TryStreamExt};
+use mock_server::{fixtures, meta_json, MockApi};
+use mockito::Matcher;
+use spiris::pagination::StreamOptions;
+use spiris::QueryParams;
+use std::sync::{Arc, Mutex};
+
+fn page_body(count: u32, page: u32, total_pages: u32, total_count: u32) -> String {
+    format!(
+        r#"{{"Data": {}, {}}}"#,
+        fixtures::customers_json(count),
+        meta_json(page, 2, total_pages, total_count)
+    )
+}
+
+#[tokio::test]
+async fn test_search_stream_sends_filter_on_every_page() {
+    let mut api = MockApi::new().await;
+
+    let filter = "ModifiedUtc gt 2024-01-01T00:00:00Z";
+    let mocks: Vec<_> = (0..2)
+        .map(|page| {
+            api.server
+                .mock("GET", "/customers")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("filter".into(), filter.into()),
+                    Matcher::UrlEncoded("page".into(), page.to_string()),
+                    Matcher::UrlEncoded("pagesize".into(), "2".into()),
+                ]))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(page_body(2 - page, page, 2, 3))
+                .expect(1)
+                .create()
+        })
+        .collect();
+
+    let customers: Vec<_> = api
+        .client
+        .customers()
+        .search_stream_with(
+            QueryParams::new().filter(filter),
+            StreamOptions::new().page_size(2),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+    for mock in mocks {
+        mock.assert();
+    }
+    assert_eq!(customers.len(), 3);
+}
+
+#[tokio::test]
+async fn test_search_stream_max_items_and_progress() {
+    let mut api = MockApi::new().await;
+
+    let first = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded("page".into(), "0".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_body(2, 0, 5, 10))
+        .expect(1)
+        .create();
+    let second = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_body(2, 1, 5, 10))
+        .expect(1)
+        .create();
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = reports.clone();
+    let options = StreamOptions::new()
+        .page_size(2)
+        .max_items(3)
+        .on_progress(move |progress| sink.lock().unwrap().push(*progress));
+
+    let customers: Vec<_> = api
+        .client
+        .customers()
+        .search_stream_with(QueryParams::new().filter("IsActive eq true"), options)
+        .collect()
+        .await;
+
+    first.assert();
+    second.assert();
+    assert_eq!(customers.len(), 3);
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].total_count, 10);
+    assert_eq!(reports[0].expected_items, 3);
+    assert_eq!(reports[1].items_fetched, 3);
+    assert_eq!(reports[1].fraction(), 1.0);
+}
+
+#[tokio::test]
+async fn test_macro_endpoint_search_stream() {
+    let mut api = MockApi::new().await;
+
+    let mock = api
+        .server
+        .mock("GET", "/projects")
+        .match_query(Matcher::UrlEncoded(
+            "filter".into(),
+            "IsCompleted eq false".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_server::paginated_response(
+            r#"[{"Id": "90000000-0000-4001-8000-000000000001", "Name": "Alpha"}]"#,
+            0,
+            1,
+        ))
+        .create();
+
+    let projects: Vec<_> = api
+        .client
+        .projects()
+        .search_stream(QueryParams::new().filter("IsCompleted eq false"))
+        .try_collect()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name.as_deref(), Some("Alpha"));
+}
+
+#[tokio::test]
+async fn test_list_page_stream_prefetches_pages() {
+    let mut api = MockApi::new().await;
+
+    let mocks = api.mock_paginated(
+        "/customers",
+        vec![
+            (fixtures::customers(2), true),
+            (fixtures::customers(2), true),
+            (fixtures::customers(1), false),
+        ],
+    );
+
+    let pages: Vec<_> = api
+        .client
+        .customers()
+        .list_page_stream(StreamOptions::new().page_size(2).concurrency(3))
+        .try_collect()
+        .await
+        .unwrap();
+
+    for mock in mocks {
+        mock.assert();
+    }
+    let sizes: Vec<_> = pages.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![2, 2, 1]);
+}
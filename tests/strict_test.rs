@@ -0,0 +1,71 @@
+//! Integration tests for unknown field handling.
+
+mod mock_server;
+
+use mock_server::{id, MockApi};
+use mockito::Matcher;
+use spiris::{AccessToken, Client, ClientConfig, Error, RetryConfig};
+
+const CUSTOMER: &str =
+    r#"{"Id": "c0000000-0000-4000-8000-000000000001", "Name": "Acme", "LoyaltyTier": "Gold"}"#;
+
+#[tokio::test]
+async fn test_unknown_fields_survive_read_modify_write() {
+    let mut api = MockApi::new().await;
+    let customer_id = id("c0000000-0000-4000-8000-000000000001");
+
+    let _get = api.mock_get("/customers/c0000000-0000-4000-8000-000000000001", CUSTOMER);
+    let put = api
+        .server
+        .mock("PUT", "/customers/c0000000-0000-4000-8000-000000000001")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"Name": "Acme AB", "LoyaltyTier": "Gold"}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(CUSTOMER)
+        .create();
+
+    let mut customer = api.client.customers().get(&customer_id).await.unwrap();
+    assert_eq!(customer.extra["LoyaltyTier"], "Gold");
+
+    customer.name = Some("Acme AB".to_string());
+    api.client
+        .customers()
+        .update(&customer_id, &customer)
+        .await
+        .unwrap();
+    put.assert();
+}
+
+#[tokio::test]
+async fn test_strict_mode_rejects_unknown_fields() {
+    let mut api = MockApi::new().await;
+    let client = Client::with_config(
+        AccessToken::new("test_token".to_string(), 3600, None),
+        ClientConfig::new()
+            .base_url(api.url())
+            .retry_config(RetryConfig::new().max_retries(0))
+            .strict_deserialization(true),
+    );
+
+    let _get = api.mock_get("/customers/c0000000-0000-4000-8000-000000000001", CUSTOMER);
+
+    let err = client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await
+        .unwrap_err();
+    match err {
+        Error::UnknownFields(fields) => assert_eq!(fields, vec!["Customer.LoyaltyTier"]),
+        other => panic!("expected unknown fields, got {:?}", other),
+    }
+
+    // The default client accepts the same response.
+    assert!(api
+        .client
+        .customers()
+        .get(&id("c0000000-0000-4000-8000-000000000001"))
+        .await
+        .is_ok());
+}
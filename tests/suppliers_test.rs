@@ -2,7 +2,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{PaginationParams, Supplier};
 
 #[tokio::test]
@@ -12,14 +12,14 @@ async fn test_list_suppliers() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "sup-001",
+                "Id": "50000000-0000-4000-8000-000000000001",
                 "SupplierNumber": "S001",
                 "Name": "Supplier AB",
                 "Email": "info@supplier.se",
                 "IsActive": true
             },
             {
-                "Id": "sup-002",
+                "Id": "50000000-0000-4000-8000-000000000002",
                 "SupplierNumber": "S002",
                 "Name": "Parts Inc",
                 "IsActive": true
@@ -39,7 +39,10 @@ async fn test_list_suppliers() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("sup-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("50000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].name, Some("Supplier AB".to_string()));
 }
 
@@ -48,7 +51,7 @@ async fn test_get_supplier() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "sup-123",
+        "Id": "50000000-0000-4000-8000-000000000123",
         "SupplierNumber": "S100",
         "Name": "Major Supplier AB",
         "CorporateIdentityNumber": "556123-4567",
@@ -58,12 +61,23 @@ async fn test_get_supplier() {
         "IsActive": true
     }"#;
 
-    let mock = api.mock_get("/suppliers/sup-123", response_body);
+    let mock = api.mock_get(
+        "/suppliers/50000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.suppliers().get("sup-123").await.unwrap();
+    let result = api
+        .client
+        .suppliers()
+        .get(&id("50000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("sup-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("50000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.name, Some("Major Supplier AB".to_string()));
     assert_eq!(
         result.corporate_identity_number,
@@ -76,7 +90,7 @@ async fn test_create_supplier() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "sup-new-001",
+        "Id": "50000000-0000-4000-0001-000000000001",
         "SupplierNumber": "S200",
         "Name": "New Supplier",
         "Email": "new@supplier.com",
@@ -95,7 +109,10 @@ async fn test_create_supplier() {
     let result = api.client.suppliers().create(&new_supplier).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("sup-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("50000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.supplier_number, Some("S200".to_string()));
 }
 
@@ -104,16 +121,19 @@ async fn test_update_supplier() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "sup-123",
+        "Id": "50000000-0000-4000-8000-000000000123",
         "SupplierNumber": "S100",
         "Name": "Updated Supplier Name",
         "IsActive": true
     }"#;
 
-    let mock = api.mock_put("/suppliers/sup-123", response_body);
+    let mock = api.mock_put(
+        "/suppliers/50000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_supplier = Supplier {
-        id: Some("sup-123".to_string()),
+        id: Some("50000000-0000-4000-8000-000000000123".parse().unwrap()),
         name: Some("Updated Supplier Name".to_string()),
         ..Default::default()
     };
@@ -121,7 +141,10 @@ async fn test_update_supplier() {
     let result = api
         .client
         .suppliers()
-        .update("sup-123", &updated_supplier)
+        .update(
+            &id("50000000-0000-4000-8000-000000000123"),
+            &updated_supplier,
+        )
         .await
         .unwrap();
 
@@ -133,9 +156,13 @@ async fn test_update_supplier() {
 async fn test_delete_supplier() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/suppliers/sup-123");
+    let mock = api.mock_delete("/suppliers/50000000-0000-4000-8000-000000000123");
 
-    let result = api.client.suppliers().delete("sup-123").await;
+    let result = api
+        .client
+        .suppliers()
+        .delete(&id("50000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -147,7 +174,7 @@ async fn test_list_suppliers_with_pagination() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "sup-50", "Name": "Supplier 50"}
+            {"Id": "50000000-0000-4000-8000-000000000050", "Name": "Supplier 50"}
         ],
         "Meta": {
             "CurrentPage": 5,
@@ -0,0 +1,216 @@
+//! Integration tests for the incremental sync engine.
+
+mod mock_server;
+
+use mock_server::MockApi;
+use mockito::Matcher;
+use spiris::sync::{Change, SyncConfig, SyncState, Syncer};
+use spiris::Customer;
+use std::time::Duration;
+
+const A: &str = "c0000000-0000-4000-8000-00000000000a";
+const B: &str = "c0000000-0000-4000-8000-00000000000b";
+const C: &str = "c0000000-0000-4000-8000-00000000000c";
+
+fn customers_body(customers: &[(&str, &str)]) -> String {
+    let data: Vec<String> = customers
+        .iter()
+        .map(|(id, modified)| format!(r#"{{"Id": "{}", "ModifiedUtc": "{}"}}"#, id, modified))
+        .collect();
+    mock_server::paginated_response(&format!("[{}]", data.join(",")), 0, data.len() as u32)
+}
+
+fn mock_unfiltered(api: &mut MockApi, customers: &[(&str, &str)]) -> mockito::Mock {
+    api.server
+        .mock("GET", "/customers")
+        .match_query(Matcher::Regex(
+            "^orderby=ModifiedUtc&page=0&pagesize=500$".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(customers_body(customers))
+        .expect(1)
+        .create()
+}
+
+fn ids(changes: &[Change<Customer>]) -> Vec<(&'static str, String)> {
+    changes
+        .iter()
+        .map(|change| {
+            let kind = match change {
+                Change::Created(_) => "created",
+                Change::Updated(_) => "updated",
+                Change::Deleted(_) => "deleted",
+            };
+            (kind, change.id().unwrap().to_string())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_incremental_sync() {
+    let mut api = MockApi::new().await;
+    let mut state = SyncState::new();
+    let syncer = Syncer::with_config(
+        api.client.customers().into_owned(),
+        SyncConfig::new()
+            .overlap(Duration::from_secs(60))
+            .reconcile_every(None),
+    );
+
+    // First run pulls everything without a filter.
+    let full = mock_unfiltered(
+        &mut api,
+        &[(A, "2024-01-01T10:00:00Z"), (B, "2024-01-01T11:00:00Z")],
+    );
+
+    let changes = syncer.sync(&mut state).await.unwrap();
+    full.assert();
+    full.remove();
+
+    assert_eq!(
+        ids(&changes),
+        vec![("created", A.to_string()), ("created", B.to_string())]
+    );
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state.watermark.unwrap().to_rfc3339(),
+        "2024-01-01T11:00:00+00:00"
+    );
+
+    // Second run asks for items modified since the watermark minus the
+    // overlap. `b` comes back unchanged and must not be reported again.
+    let incremental = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded(
+            "filter".into(),
+            "ModifiedUtc ge 2024-01-01T10:59:00Z".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(customers_body(&[
+            (B, "2024-01-01T11:00:00Z"),
+            (A, "2024-01-01T12:00:00Z"),
+            (C, "2024-01-01T12:00:00Z"),
+        ]))
+        .expect(1)
+        .create();
+
+    let changes = syncer.sync(&mut state).await.unwrap();
+    incremental.assert();
+    assert_eq!(
+        ids(&changes),
+        vec![("updated", A.to_string()), ("created", C.to_string())]
+    );
+    assert_eq!(state.len(), 3);
+}
+
+#[tokio::test]
+async fn test_reconcile_detects_deletions() {
+    let mut api = MockApi::new().await;
+    let mut state = SyncState::new();
+
+    let _full = mock_unfiltered(
+        &mut api,
+        &[(A, "2024-01-01T10:00:00Z"), (B, "2024-01-01T11:00:00Z")],
+    );
+
+    let syncer = Syncer::new(api.client.customers().into_owned());
+    syncer.sync(&mut state).await.unwrap();
+    assert!(state.last_reconciled.is_some());
+
+    let ids_only = api
+        .server
+        .mock("GET", "/customers")
+        .match_query(Matcher::UrlEncoded("select".into(), "Id".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_server::paginated_response(
+            r#"[{"Id": "c0000000-0000-4000-8000-00000000000a"}]"#,
+            0,
+            1,
+        ))
+        .expect(1)
+        .create();
+
+    let changes = syncer.reconcile(&mut state).await.unwrap();
+    ids_only.assert();
+    assert_eq!(ids(&changes), vec![("deleted", B.to_string())]);
+    assert_eq!(state.len(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_sync_leaves_state_unchanged() {
+    let mut api = MockApi::new().await;
+
+    let _error = api.mock_server_error("GET", "/customers");
+
+    let mut state = SyncState::new();
+    let before = state.clone();
+    let result = Syncer::new(api.client.customers()).sync(&mut state).await;
+
+    assert!(result.is_err());
+    assert_eq!(state, before);
+}
+
+#[tokio::test]
+async fn test_reconcile_aborts_when_pages_shift() {
+    let mut api = MockApi::new().await;
+    let mut state = SyncState::new();
+    for id in [A, B, C] {
+        state.versions.insert(id.to_string(), None);
+    }
+    let syncer = Syncer::with_config(
+        api.client.customers().into_owned(),
+        SyncConfig::new().page_size(2),
+    );
+
+    let ids_page = |page: u32, ids: &[&str], total_pages: u32, total_count: u32| {
+        let data: Vec<String> = ids
+            .iter()
+            .map(|id| format!(r#"{{"Id": "{}"}}"#, id))
+            .collect();
+        format!(
+            r#"{{"Data": [{}], {}}}"#,
+            data.join(","),
+            mock_server::meta_json(page, 2, total_pages, total_count)
+        )
+    };
+    let mock_page = |api: &mut MockApi, page: u32, body: String| {
+        api.server
+            .mock("GET", "/customers")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("select".into(), "Id".into()),
+                Matcher::UrlEncoded("orderby".into(), "Id".into()),
+                Matcher::UrlEncoded("page".into(), page.to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(1)
+            .create()
+    };
+
+    // `a` is deleted between the two pages, so `c` moves to the first page
+    // and the second page comes back empty.
+    let first = mock_page(&mut api, 0, ids_page(0, &[A, B], 2, 3));
+    let second = mock_page(&mut api, 1, ids_page(1, &[], 1, 2));
+
+    let changes = syncer.reconcile(&mut state).await.unwrap();
+    first.assert();
+    second.assert();
+    first.remove();
+    second.remove();
+    assert!(changes.is_empty());
+    assert_eq!(state.len(), 3);
+    assert!(state.last_reconciled.is_none());
+
+    // The next, stable listing reports the deletion.
+    let stable = mock_page(&mut api, 0, ids_page(0, &[B, C], 1, 2));
+
+    let changes = syncer.reconcile(&mut state).await.unwrap();
+    stable.assert();
+    assert_eq!(ids(&changes), vec![("deleted", A.to_string())]);
+    assert!(state.last_reconciled.is_some());
+}
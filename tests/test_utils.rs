@@ -2,7 +2,9 @@
 #![cfg(not(feature = "decimal"))]
 //! Test utility functions and helpers for integration tests.
 
-use spiris::{AccessToken, Article, Customer, Error, Invoice, PaginatedResponse, ResponseMetadata};
+use spiris::{
+    AccessToken, Article, Customer, CustomerId, Error, Invoice, PaginatedResponse, ResponseMetadata,
+};
 use std::fs;
 use std::path::Path;
 use std::time::Duration;
@@ -149,7 +151,7 @@ pub fn assert_result_error_status<T: std::fmt::Debug>(
 pub fn random_customer() -> Customer {
     let id = uuid_v4();
     Customer {
-        id: Some(id.clone()),
+        id: Some(id.parse().unwrap()),
         customer_number: Some(format!("{}", rand_u32() % 10000)),
         name: Some(format!("Test Customer {}", &id[..8])),
         email: Some(format!("test-{}@example.com", &id[..8])),
@@ -160,12 +162,12 @@ pub fn random_customer() -> Customer {
 
 /// Generate a random invoice for testing
 #[allow(dead_code)]
-pub fn random_invoice(customer_id: &str) -> Invoice {
+pub fn random_invoice(customer_id: &CustomerId) -> Invoice {
     let id = uuid_v4();
     Invoice {
-        id: Some(id.clone()),
+        id: Some(id.parse().unwrap()),
         invoice_number: Some(format!("{}", 20000 + rand_u32() % 10000)),
-        customer_id: Some(customer_id.to_string()),
+        customer_id: Some(customer_id.clone()),
         total_amount: Some((rand_u32() % 100000) as f64 / 100.0),
         rows: vec![],
         ..Default::default()
@@ -177,7 +179,7 @@ pub fn random_invoice(customer_id: &str) -> Invoice {
 pub fn random_article() -> Article {
     let id = uuid_v4();
     Article {
-        id: Some(id.clone()),
+        id: Some(id.parse().unwrap()),
         article_number: Some(format!("ART-{}", rand_u32() % 10000)),
         name: Some(format!("Test Article {}", &id[..8])),
         sales_price: Some((rand_u32() % 10000) as f64 / 100.0),
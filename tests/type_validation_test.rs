@@ -42,7 +42,7 @@ fn test_customer_default() {
 #[test]
 fn test_customer_with_fields() {
     let customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Acme Corp".to_string()),
         email: Some("info@acme.com".to_string()),
         phone: Some("+1234567890".to_string()),
@@ -51,7 +51,10 @@ fn test_customer_with_fields() {
         ..Default::default()
     };
 
-    assert_eq!(customer.id, Some("cust-001".to_string()));
+    assert_eq!(
+        customer.id,
+        Some("c0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(customer.name, Some("Acme Corp".to_string()));
     assert_eq!(customer.is_active, Some(true));
 }
@@ -59,7 +62,7 @@ fn test_customer_with_fields() {
 #[test]
 fn test_customer_serialization_pascal_case() {
     let customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Test".to_string()),
         is_active: Some(true),
         customer_number: Some("C001".to_string()),
@@ -78,7 +81,7 @@ fn test_customer_serialization_pascal_case() {
 #[test]
 fn test_customer_deserialization_pascal_case() {
     let json = r#"{
-        "Id": "cust-001",
+        "Id": "c0000000-0000-4000-8000-000000000001",
         "Name": "Test Customer",
         "Email": "test@example.com",
         "IsActive": true,
@@ -87,7 +90,10 @@ fn test_customer_deserialization_pascal_case() {
 
     let customer: Customer = serde_json::from_str(json).unwrap();
 
-    assert_eq!(customer.id, Some("cust-001".to_string()));
+    assert_eq!(
+        customer.id,
+        Some("c0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(customer.name, Some("Test Customer".to_string()));
     assert_eq!(customer.email, Some("test@example.com".to_string()));
     assert_eq!(customer.is_active, Some(true));
@@ -111,7 +117,7 @@ fn test_customer_skip_none_fields() {
 #[test]
 fn test_customer_clone() {
     let customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Test".to_string()),
         ..Default::default()
     };
@@ -125,14 +131,14 @@ fn test_customer_clone() {
 #[test]
 fn test_customer_debug() {
     let customer = Customer {
-        id: Some("cust-001".to_string()),
+        id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         ..Default::default()
     };
 
     let debug = format!("{:?}", customer);
 
     assert!(debug.contains("Customer"));
-    assert!(debug.contains("cust-001"));
+    assert!(debug.contains("c0000000-0000-4000-8000-000000000001"));
 }
 
 // =============================================================================
@@ -152,17 +158,17 @@ fn test_invoice_default() {
 #[test]
 fn test_invoice_with_rows() {
     let invoice = Invoice {
-        id: Some("inv-001".to_string()),
-        customer_id: Some("cust-001".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000000001".parse().unwrap()),
+        customer_id: Some("c0000000-0000-4000-8000-000000000001".parse().unwrap()),
         rows: vec![
             InvoiceRow {
-                article_id: Some("art-001".to_string()),
+                article_id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
                 quantity: some_money(2.0),
                 unit_price: some_money(100.0),
                 ..Default::default()
             },
             InvoiceRow {
-                article_id: Some("art-002".to_string()),
+                article_id: Some("a0000000-0000-4000-8000-000000000002".parse().unwrap()),
                 quantity: some_money(1.0),
                 unit_price: some_money(50.0),
                 ..Default::default()
@@ -172,13 +178,16 @@ fn test_invoice_with_rows() {
     };
 
     assert_eq!(invoice.rows.len(), 2);
-    assert_eq!(invoice.rows[0].article_id, Some("art-001".to_string()));
+    assert_eq!(
+        invoice.rows[0].article_id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
 }
 
 #[test]
 fn test_invoice_serialization() {
     let invoice = Invoice {
-        id: Some("inv-001".to_string()),
+        id: Some("f0000000-0000-4000-8000-000000000001".parse().unwrap()),
         invoice_number: Some("1001".to_string()),
         total_amount: some_money(250.0),
         rows: vec![],
@@ -196,13 +205,13 @@ fn test_invoice_serialization() {
 #[test]
 fn test_invoice_deserialization() {
     let json = r#"{
-        "Id": "inv-001",
+        "Id": "f0000000-0000-4000-8000-000000000001",
         "InvoiceNumber": "1001",
-        "CustomerId": "cust-001",
+        "CustomerId": "c0000000-0000-4000-8000-000000000001",
         "TotalAmount": 500.00,
         "Rows": [
             {
-                "ArticleId": "art-001",
+                "ArticleId": "a0000000-0000-4000-8000-000000000001",
                 "Quantity": 5,
                 "UnitPrice": 100.00
             }
@@ -211,7 +220,10 @@ fn test_invoice_deserialization() {
 
     let invoice: Invoice = serde_json::from_str(json).unwrap();
 
-    assert_eq!(invoice.id, Some("inv-001".to_string()));
+    assert_eq!(
+        invoice.id,
+        Some("f0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(invoice.invoice_number, Some("1001".to_string()));
     assert_eq!(invoice.total_amount, some_money(500.0));
     assert_eq!(invoice.rows.len(), 1);
@@ -233,14 +245,17 @@ fn test_invoice_row_default() {
 #[test]
 fn test_invoice_row_with_values() {
     let row = InvoiceRow {
-        article_id: Some("art-001".to_string()),
+        article_id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
         quantity: some_money(3.0),
         unit_price: some_money(25.50),
         discount_percentage: some_money(10.0),
         ..Default::default()
     };
 
-    assert_eq!(row.article_id, Some("art-001".to_string()));
+    assert_eq!(
+        row.article_id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(row.quantity, some_money(3.0));
     assert_eq!(row.unit_price, some_money(25.50));
     assert_eq!(row.discount_percentage, some_money(10.0));
@@ -249,7 +264,7 @@ fn test_invoice_row_with_values() {
 #[test]
 fn test_invoice_row_serialization() {
     let row = InvoiceRow {
-        article_id: Some("art-001".to_string()),
+        article_id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
         quantity: some_money(2.0),
         unit_price: some_money(100.0),
         ..Default::default()
@@ -278,7 +293,7 @@ fn test_article_default() {
 #[test]
 fn test_article_with_fields() {
     let article = Article {
-        id: Some("art-001".to_string()),
+        id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
         article_number: Some("ART-001".to_string()),
         name: Some("Widget".to_string()),
         sales_price: some_money(99.99),
@@ -294,7 +309,7 @@ fn test_article_with_fields() {
 #[test]
 fn test_article_serialization() {
     let article = Article {
-        id: Some("art-001".to_string()),
+        id: Some("a0000000-0000-4000-8000-000000000001".parse().unwrap()),
         name: Some("Test".to_string()),
         sales_price: some_money(49.99),
         is_active: Some(true),
@@ -312,7 +327,7 @@ fn test_article_serialization() {
 #[test]
 fn test_article_deserialization() {
     let json = r#"{
-        "Id": "art-001",
+        "Id": "a0000000-0000-4000-8000-000000000001",
         "ArticleNumber": "ART-001",
         "Name": "Test Article",
         "SalesPrice": 149.99,
@@ -321,7 +336,10 @@ fn test_article_deserialization() {
 
     let article: Article = serde_json::from_str(json).unwrap();
 
-    assert_eq!(article.id, Some("art-001".to_string()));
+    assert_eq!(
+        article.id,
+        Some("a0000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(article.article_number, Some("ART-001".to_string()));
     assert_eq!(article.sales_price, some_money(149.99));
 }
@@ -375,8 +393,8 @@ fn test_address_serialization() {
 fn test_paginated_response_deserialization() {
     let json = r#"{
         "Data": [
-            {"Id": "cust-001", "Name": "Customer 1"},
-            {"Id": "cust-002", "Name": "Customer 2"}
+            {"Id": "c0000000-0000-4000-8000-000000000001", "Name": "Customer 1"},
+            {"Id": "c0000000-0000-4000-8000-000000000002", "Name": "Customer 2"}
         ],
         "Meta": {
             "CurrentPage": 0,
@@ -704,12 +722,31 @@ fn test_very_long_string_field() {
 #[test]
 fn test_null_vs_missing_fields() {
     // Field explicitly set to null
-    let json_with_null = r#"{"Id": "test", "Name": null}"#;
+    let json_with_null = r#"{"Id": "7e000000-0000-4000-8000-000000000001", "Name": null}"#;
     let customer: Customer = serde_json::from_str(json_with_null).unwrap();
     assert!(customer.name.is_none());
 
     // Field missing entirely
-    let json_missing = r#"{"Id": "test"}"#;
+    let json_missing = r#"{"Id": "7e000000-0000-4000-8000-000000000001"}"#;
     let customer: Customer = serde_json::from_str(json_missing).unwrap();
     assert!(customer.name.is_none());
 }
+
+#[test]
+fn test_ids_must_be_guids() {
+    let customer: Customer =
+        serde_json::from_str(r#"{"Id": "7E000000-0000-4000-8000-000000000001"}"#).unwrap();
+    assert_eq!(
+        customer.id.unwrap().as_str(),
+        "7E000000-0000-4000-8000-000000000001"
+    );
+
+    let result = serde_json::from_str::<Customer>(r#"{"Id": "test"}"#);
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Invalid customer id"));
+
+    let result = serde_json::from_str::<Invoice>(r#"{"CustomerId": "cust-1"}"#);
+    assert!(result.is_err());
+}
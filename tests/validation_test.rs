@@ -0,0 +1,54 @@
+//! Tests for client-side request validation and its error path.
+
+mod mock_server;
+
+use mock_server::money;
+use spiris::locale::{Language, Localize};
+use spiris::validate::{Validate, ValidationContext};
+use spiris::{ArticleCreate, Error, InvoiceCreate, InvoiceRowCreate, Result, VatCode};
+
+fn create_checked(invoice: &InvoiceCreate, context: &ValidationContext) -> Result<()> {
+    invoice.validate_with(context)?;
+    Ok(())
+}
+
+#[test]
+fn test_validation_errors_share_the_api_error_path() {
+    let codes: Vec<VatCode> = serde_json::from_str(
+        r#"[{"Id": "fa000000-0000-4000-8000-000000000025", "Code": "MP1", "VatRate": 25.0}]"#,
+    )
+    .unwrap();
+    let invoice = InvoiceCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse().unwrap()).row(
+        InvoiceRowCreate::with_text("Consulting".to_string(), money(1500.0), money(-2.0))
+            .vat_rate_id("fa000000-0000-4000-8000-000000000012".parse().unwrap()),
+    );
+
+    let err = create_checked(&invoice, &ValidationContext::new().vat_codes(&codes)).unwrap_err();
+
+    assert!(matches!(err, Error::Validation(_)));
+    assert!(err.is_bad_request());
+    let fields: Vec<&str> = err
+        .validation_errors()
+        .unwrap()
+        .iter()
+        .map(|e| e.field.as_str())
+        .collect();
+    assert_eq!(fields, ["Rows[0].Quantity", "Rows[0].VatRateId"]);
+    assert_eq!(
+        err.localize(Language::Swedish),
+        "Kontrollera uppgifterna: Antal: Får inte vara negativt; Momssats: Okänd momssats"
+    );
+}
+
+#[test]
+fn test_valid_requests_pass() {
+    let article = ArticleCreate::new("Consulting Services".to_string())
+        .article_number("SVC-001".to_string())
+        .sales_price(money(1500.0));
+    assert_eq!(article.validate(), Ok(()));
+
+    let invoice = InvoiceCreate::new("5f3a8c2e-1b4d-4e6f-9a0b-7c8d9e0f1a2b".parse().unwrap()).row(
+        InvoiceRowCreate::with_text("Consulting".to_string(), money(1500.0), money(10.0)),
+    );
+    assert!(create_checked(&invoice, &ValidationContext::new()).is_ok());
+}
@@ -0,0 +1,92 @@
+//! Integration tests for the balanced voucher builder.
+
+mod mock_server;
+
+use chrono::{TimeZone, Utc};
+use mock_server::{money, paginated_response, MockApi};
+use mockito::Matcher;
+use spiris::voucher::{Ledger, VoucherBuilder, VoucherError};
+
+const OPEN_YEAR: &str = "f0000000-0000-4000-8000-000000000001";
+const LOCKED_YEAR: &str = "f0000000-0000-4000-8000-000000000002";
+
+/// Mock a list endpoint, ignoring the pagination query.
+fn mock_list(api: &mut MockApi, path: &str, data_json: &str, total_count: u32) {
+    api.server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(paginated_response(data_json, 0, total_count))
+        .create();
+}
+
+async fn ledger(api: &mut MockApi) -> Ledger {
+    let fiscal_years = format!(
+        r#"[
+            {{"Id": "{}", "StartDate": "2024-01-01T00:00:00Z", "EndDate": "2024-12-31T00:00:00Z", "IsLocked": false}},
+            {{"Id": "{}", "StartDate": "2023-01-01T00:00:00Z", "EndDate": "2023-12-31T00:00:00Z", "IsLocked": true}}
+        ]"#,
+        OPEN_YEAR, LOCKED_YEAR
+    );
+    mock_list(api, "/fiscalyears", &fiscal_years, 2);
+    mock_list(
+        api,
+        &format!("/accounts/{}", OPEN_YEAR),
+        r#"[
+            {"AccountNumber": "1930", "Name": "Bank", "IsActive": true},
+            {"AccountNumber": "5010", "Name": "Rent", "IsActive": true}
+        ]"#,
+        2,
+    );
+    let locked_accounts = api
+        .server
+        .mock("GET", format!("/accounts/{}", LOCKED_YEAR).as_str())
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create();
+    mock_list(
+        api,
+        "/costcenteritems",
+        r#"[{"Id": "c0000000-0000-4000-8000-000000000001"}]"#,
+        1,
+    );
+    mock_list(api, "/projects", "[]", 0);
+
+    let ledger = Ledger::fetch(&api.client).await.unwrap();
+    locked_accounts.assert();
+    ledger
+}
+
+#[tokio::test]
+async fn test_fetched_ledger_accepts_valid_voucher() {
+    let mut api = MockApi::new().await;
+    let ledger = ledger(&mut api).await;
+
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let voucher = VoucherBuilder::new(date, "Office rent")
+        .debit("5010", money(12000.0))
+        .credit("1930", money(12000.0))
+        .build_checked(&ledger)
+        .unwrap();
+
+    assert_eq!(voucher.rows.len(), 2);
+}
+
+#[tokio::test]
+async fn test_fetched_ledger_rejects_locked_year_only() {
+    let mut api = MockApi::new().await;
+    let ledger = ledger(&mut api).await;
+
+    let date = Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap();
+    let errors = VoucherBuilder::new(date, "Late entry")
+        .debit("5010", money(100.0))
+        .credit("1930", money(90.0))
+        .build_checked(&ledger)
+        .unwrap_err();
+
+    assert!(matches!(errors[0], VoucherError::Unbalanced { .. }));
+    assert!(matches!(errors[1], VoucherError::LockedFiscalYear { .. }));
+    // Accounts of locked years are not fetched, so they are not checked.
+    assert_eq!(errors.len(), 2);
+}
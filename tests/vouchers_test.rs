@@ -4,7 +4,7 @@
 
 mod mock_server;
 
-use mock_server::MockApi;
+use mock_server::{id, MockApi};
 use spiris::{PaginationParams, Voucher, VoucherRow};
 
 #[tokio::test]
@@ -14,14 +14,14 @@ async fn test_list_vouchers() {
     let response_body = r#"{
         "Data": [
             {
-                "Id": "vouch-001",
+                "Id": "70000000-0000-4000-8000-000000000001",
                 "VoucherNumber": "1",
                 "VoucherDate": "2024-01-15T00:00:00Z",
                 "VoucherText": "January sales",
                 "Rows": []
             },
             {
-                "Id": "vouch-002",
+                "Id": "70000000-0000-4000-8000-000000000002",
                 "VoucherNumber": "2",
                 "VoucherDate": "2024-01-20T00:00:00Z",
                 "VoucherText": "Office supplies",
@@ -42,7 +42,10 @@ async fn test_list_vouchers() {
 
     mock.assert();
     assert_eq!(result.data.len(), 2);
-    assert_eq!(result.data[0].id, Some("vouch-001".to_string()));
+    assert_eq!(
+        result.data[0].id,
+        Some("70000000-0000-4000-8000-000000000001".parse().unwrap())
+    );
     assert_eq!(result.data[0].voucher_number, Some("1".to_string()));
 }
 
@@ -51,7 +54,7 @@ async fn test_get_voucher() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "vouch-123",
+        "Id": "70000000-0000-4000-8000-000000000123",
         "VoucherNumber": "42",
         "VoucherDate": "2024-03-15T00:00:00Z",
         "VoucherText": "Customer payment",
@@ -71,12 +74,23 @@ async fn test_get_voucher() {
         ]
     }"#;
 
-    let mock = api.mock_get("/vouchers/vouch-123", response_body);
+    let mock = api.mock_get(
+        "/vouchers/70000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
-    let result = api.client.vouchers().get("vouch-123").await.unwrap();
+    let result = api
+        .client
+        .vouchers()
+        .get(&id("70000000-0000-4000-8000-000000000123"))
+        .await
+        .unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("vouch-123".to_string()));
+    assert_eq!(
+        result.id,
+        Some("70000000-0000-4000-8000-000000000123".parse().unwrap())
+    );
     assert_eq!(result.voucher_number, Some("42".to_string()));
     assert_eq!(result.rows.len(), 2);
     assert_eq!(result.rows[0].debit_amount, Some(10000.0));
@@ -88,7 +102,7 @@ async fn test_create_voucher() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "vouch-new-001",
+        "Id": "70000000-0000-4000-0001-000000000001",
         "VoucherNumber": "100",
         "VoucherDate": "2024-06-01T00:00:00Z",
         "VoucherText": "Test voucher",
@@ -122,7 +136,10 @@ async fn test_create_voucher() {
     let result = api.client.vouchers().create(&new_voucher).await.unwrap();
 
     mock.assert();
-    assert_eq!(result.id, Some("vouch-new-001".to_string()));
+    assert_eq!(
+        result.id,
+        Some("70000000-0000-4000-0001-000000000001".parse().unwrap())
+    );
     assert_eq!(result.voucher_number, Some("100".to_string()));
 }
 
@@ -131,16 +148,19 @@ async fn test_update_voucher() {
     let mut api = MockApi::new().await;
 
     let response_body = r#"{
-        "Id": "vouch-123",
+        "Id": "70000000-0000-4000-8000-000000000123",
         "VoucherNumber": "42",
         "VoucherText": "Updated voucher text",
         "Rows": []
     }"#;
 
-    let mock = api.mock_put("/vouchers/vouch-123", response_body);
+    let mock = api.mock_put(
+        "/vouchers/70000000-0000-4000-8000-000000000123",
+        response_body,
+    );
 
     let updated_voucher = Voucher {
-        id: Some("vouch-123".to_string()),
+        id: Some("70000000-0000-4000-8000-000000000123".parse().unwrap()),
         voucher_text: Some("Updated voucher text".to_string()),
         ..Default::default()
     };
@@ -148,7 +168,10 @@ async fn test_update_voucher() {
     let result = api
         .client
         .vouchers()
-        .update("vouch-123", &updated_voucher)
+        .update(
+            &id("70000000-0000-4000-8000-000000000123"),
+            &updated_voucher,
+        )
         .await
         .unwrap();
 
@@ -163,9 +186,13 @@ async fn test_update_voucher() {
 async fn test_delete_voucher() {
     let mut api = MockApi::new().await;
 
-    let mock = api.mock_delete("/vouchers/vouch-123");
+    let mock = api.mock_delete("/vouchers/70000000-0000-4000-8000-000000000123");
 
-    let result = api.client.vouchers().delete("vouch-123").await;
+    let result = api
+        .client
+        .vouchers()
+        .delete(&id("70000000-0000-4000-8000-000000000123"))
+        .await;
 
     mock.assert();
     assert!(result.is_ok());
@@ -177,7 +204,7 @@ async fn test_list_vouchers_with_pagination() {
 
     let response_body = r#"{
         "Data": [
-            {"Id": "vouch-101", "VoucherNumber": "101", "Rows": []}
+            {"Id": "70000000-0000-4000-8000-000000000101", "VoucherNumber": "101", "Rows": []}
         ],
         "Meta": {
             "CurrentPage": 10,